@@ -0,0 +1,3 @@
+-- AlterTable
+ALTER TABLE "public"."content_image"
+ADD COLUMN IF NOT EXISTS "caption" VARCHAR(1000);
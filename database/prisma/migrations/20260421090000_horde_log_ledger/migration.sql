@@ -0,0 +1,10 @@
+-- AlterTable
+ALTER TABLE "public"."horde_log"
+ADD COLUMN IF NOT EXISTS "event" VARCHAR(32) NOT NULL DEFAULT 'submission',
+ADD COLUMN IF NOT EXISTS "request_id" VARCHAR(100),
+ADD COLUMN IF NOT EXISTS "worker_model" VARCHAR(200),
+ADD COLUMN IF NOT EXISTS "wait_seconds" INTEGER;
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "horde_log_event_created_at_idx"
+ON "public"."horde_log"("event", "created_at");
@@ -0,0 +1,11 @@
+-- AlterTable
+ALTER TABLE "public"."content_image"
+ADD COLUMN IF NOT EXISTS "source_image_id" VARCHAR(100);
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "content_image_prompt_hash_status_idx"
+ON "public"."content_image"("prompt_hash", "status");
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "content_image_source_image_id_idx"
+ON "public"."content_image"("source_image_id");
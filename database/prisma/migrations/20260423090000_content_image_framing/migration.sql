@@ -0,0 +1,8 @@
+-- AlterTable
+ALTER TABLE "public"."content_image"
+ADD COLUMN IF NOT EXISTS "crop_x" DOUBLE PRECISION,
+ADD COLUMN IF NOT EXISTS "crop_y" DOUBLE PRECISION,
+ADD COLUMN IF NOT EXISTS "crop_width" DOUBLE PRECISION,
+ADD COLUMN IF NOT EXISTS "crop_height" DOUBLE PRECISION,
+ADD COLUMN IF NOT EXISTS "focal_x" DOUBLE PRECISION,
+ADD COLUMN IF NOT EXISTS "focal_y" DOUBLE PRECISION;
@@ -0,0 +1,30 @@
+-- AlterTable
+ALTER TABLE "public"."language"
+ADD COLUMN IF NOT EXISTS "code" VARCHAR(16),
+ADD COLUMN IF NOT EXISTS "aliases" VARCHAR(1000) NOT NULL DEFAULT '',
+ADD COLUMN IF NOT EXISTS "direction" VARCHAR(3) NOT NULL DEFAULT 'ltr',
+ADD COLUMN IF NOT EXISTS "enabled" BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN IF NOT EXISTS "site_locale" BOOLEAN NOT NULL DEFAULT false,
+ADD COLUMN IF NOT EXISTS "sort_order" INTEGER NOT NULL DEFAULT 0;
+
+-- CreateIndex
+CREATE UNIQUE INDEX IF NOT EXISTS "language_code_key" ON "public"."language"("code");
+
+-- Seed the languages that used to be compiled in. Row ids match the stable
+-- identifiers the translation cache already uses for each code.
+INSERT INTO "public"."language" ("id", "name", "code", "aliases", "direction", "enabled", "site_locale", "sort_order")
+VALUES
+    ('ed3e84a8-d69d-a294-1829-cdb56b6bf813', 'English', 'en', 'english', 'ltr', true, true, 0),
+    ('ec507cc2-8475-580f-9222-a233e88a6bad', 'Portuguese', 'pt', 'portuguese,portuguese (brazil),brazilian portuguese', 'ltr', true, true, 1),
+    ('89632258-d876-fb89-ee89-2e943a13d19f', 'Spanish', 'es', 'spanish', 'ltr', true, true, 2),
+    ('1ef10655-54ab-4001-cc84-e7a27f2602f2', 'French', 'fr', 'french', 'ltr', true, false, 3),
+    ('ceb5e090-fd70-b2dd-f72e-dad7ea295cce', 'German', 'de', 'german', 'ltr', true, false, 4),
+    ('e94aaf5f-232a-0c00-6c7c-2ba70f70265a', 'Italian', 'it', 'italian', 'ltr', true, false, 5)
+ON CONFLICT ("id") DO UPDATE SET
+    "code" = EXCLUDED."code",
+    "aliases" = EXCLUDED."aliases",
+    "direction" = EXCLUDED."direction",
+    "enabled" = EXCLUDED."enabled",
+    "site_locale" = EXCLUDED."site_locale",
+    "sort_order" = EXCLUDED."sort_order"
+WHERE "language"."code" IS NULL;
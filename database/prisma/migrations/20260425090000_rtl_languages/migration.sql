@@ -0,0 +1,7 @@
+-- Seed the right-to-left translation languages. Existing rows for these codes
+-- (e.g. added by hand through the admin registry) are left untouched.
+INSERT INTO "public"."language" ("id", "name", "code", "aliases", "direction", "enabled", "site_locale", "sort_order")
+VALUES
+    ('c6dfde90-ae3e-c1cb-2021-129663778bef', 'Arabic', 'ar', 'arabic', 'rtl', true, false, 6),
+    ('b05f85d5-c85d-4de7-3c36-238005d7cc4b', 'Hebrew', 'he', 'hebrew,iw', 'rtl', true, false, 7)
+ON CONFLICT DO NOTHING;
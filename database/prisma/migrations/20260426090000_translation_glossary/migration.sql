@@ -0,0 +1,15 @@
+-- CreateTable
+CREATE TABLE IF NOT EXISTS "public"."translation_glossary" (
+    "id" VARCHAR(36) NOT NULL,
+    "term" VARCHAR(200) NOT NULL,
+    "language_code" VARCHAR(16),
+    "translation" VARCHAR(200),
+    "created_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "translation_glossary_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "translation_glossary_language_code_idx"
+ON "public"."translation_glossary"("language_code");
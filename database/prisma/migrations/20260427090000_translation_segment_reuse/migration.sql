@@ -0,0 +1,4 @@
+-- AlterTable
+ALTER TABLE "public"."translation_job"
+ADD COLUMN IF NOT EXISTS "segments_total" INTEGER,
+ADD COLUMN IF NOT EXISTS "segments_reused" INTEGER;
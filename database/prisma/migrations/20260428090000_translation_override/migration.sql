@@ -0,0 +1,34 @@
+-- CreateTable
+CREATE TABLE IF NOT EXISTS "public"."translation_override" (
+    "id" VARCHAR(36) NOT NULL,
+    "article_id" VARCHAR(36) NOT NULL,
+    "language_code" VARCHAR(16) NOT NULL,
+    "field" VARCHAR(32) NOT NULL,
+    "source_hash" VARCHAR(100) NOT NULL,
+    "translation" TEXT NOT NULL,
+    "reviewed_by" VARCHAR(350) NOT NULL,
+    "stale" BOOLEAN NOT NULL DEFAULT false,
+    "created_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "translation_override_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX IF NOT EXISTS "translation_override_article_language_field_key"
+ON "public"."translation_override"("article_id", "language_code", "field");
+
+-- AddForeignKey
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1
+        FROM pg_constraint
+        WHERE conname = 'translation_override_article_id_fkey'
+    ) THEN
+        ALTER TABLE "public"."translation_override"
+        ADD CONSTRAINT "translation_override_article_id_fkey"
+        FOREIGN KEY ("article_id") REFERENCES "public"."content"("id")
+        ON DELETE CASCADE ON UPDATE NO ACTION;
+    END IF;
+END $$;
@@ -0,0 +1,3 @@
+-- AlterTable
+ALTER TABLE "public"."content_comment"
+ADD COLUMN IF NOT EXISTS "language_code" VARCHAR(16);
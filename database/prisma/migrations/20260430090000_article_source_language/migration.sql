@@ -0,0 +1,3 @@
+-- AlterTable
+ALTER TABLE "public"."article_job"
+ADD COLUMN IF NOT EXISTS "language_code" VARCHAR(16);
@@ -0,0 +1,19 @@
+-- CreateTable
+CREATE TABLE IF NOT EXISTS "public"."research_source" (
+    "url_hash" VARCHAR(64) NOT NULL,
+    "url" TEXT NOT NULL,
+    "title" VARCHAR(1000) NOT NULL,
+    "domain" VARCHAR(255) NOT NULL,
+    "snippet" TEXT NOT NULL,
+    "search_query" VARCHAR(1000) NOT NULL,
+    "content" TEXT NOT NULL,
+    "content_hash" VARCHAR(64) NOT NULL,
+    "fetched_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "ttl_seconds" INTEGER NOT NULL,
+
+    CONSTRAINT "research_source_pkey" PRIMARY KEY ("url_hash")
+);
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "research_source_search_query_fetched_at_idx"
+ON "public"."research_source"("search_query", "fetched_at");
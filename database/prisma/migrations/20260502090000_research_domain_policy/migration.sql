@@ -0,0 +1,33 @@
+-- CreateTable
+CREATE TABLE IF NOT EXISTS "public"."research_domain_policy" (
+    "id" VARCHAR(36) NOT NULL,
+    "pattern" VARCHAR(255) NOT NULL,
+    "action" VARCHAR(16) NOT NULL,
+    "score" INTEGER NOT NULL DEFAULT 10,
+    "leak_markers" VARCHAR(1000) NOT NULL DEFAULT '',
+    "created_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "research_domain_policy_pkey" PRIMARY KEY ("id")
+);
+
+-- CreateIndex
+CREATE UNIQUE INDEX IF NOT EXISTS "research_domain_policy_pattern_key"
+ON "public"."research_domain_policy"("pattern");
+
+-- Seed the domain preferences and publication names that used to be compiled in.
+INSERT INTO "public"."research_domain_policy" ("id", "pattern", "action", "score", "leak_markers")
+VALUES
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a01', '*.gov', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a02', '*.gov.*', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a03', '*parliament*', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a04', '*senate*', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a05', '*ministry*', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a06', '*.edu', 'boost', 30, ''),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a07', 'reuters.com', 'boost', 20, 'reuters'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a08', 'apnews.com', 'boost', 20, 'apnews,associated press'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a09', 'bbc.com', 'boost', 20, 'bbc'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0a', 'npr.org', 'boost', 20, 'npr'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0b', 'ft.com', 'boost', 20, 'financial times'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0c', 'economist.com', 'boost', 20, 'economist')
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,16 @@
+-- CreateTable
+CREATE TABLE IF NOT EXISTS "public"."research_trace" (
+    "job_id" VARCHAR(36) NOT NULL,
+    "search_provider" VARCHAR(32) NOT NULL,
+    "source_count" INTEGER NOT NULL DEFAULT 0,
+    "failed" BOOLEAN NOT NULL DEFAULT false,
+    "trace" TEXT NOT NULL,
+    "created_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    "updated_at" TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    CONSTRAINT "research_trace_pkey" PRIMARY KEY ("job_id")
+);
+
+-- CreateIndex
+CREATE INDEX IF NOT EXISTS "research_trace_updated_at_idx"
+ON "public"."research_trace"("updated_at");
@@ -0,0 +1,2 @@
+-- AlterTable
+ALTER TABLE "public"."research_source" ADD COLUMN IF NOT EXISTS "published_on" DATE;
@@ -0,0 +1,7 @@
+-- Cover the BBC hosts the compiled-in `contains("bbc")` check used to match.
+INSERT INTO "public"."research_domain_policy" ("id", "pattern", "action", "score", "leak_markers")
+VALUES
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0d', 'bbc.co.uk', 'boost', 20, 'bbc'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0e', 'bbc.*', 'boost', 20, 'bbc'),
+    ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0f', '*.bbc.*', 'boost', 20, 'bbc')
+ON CONFLICT DO NOTHING;
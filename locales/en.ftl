@@ -0,0 +1,414 @@
+# Site chrome strings for English, the fallback for every other locale.
+#
+# Syntax is Fluent (https://projectfluent.org/): `id = text`, indented
+# `.attribute = text` lines, `{ $variable }` placeables, and select expressions:
+#
+#     comment-count = { $count ->
+#         [one] { $count } comment
+#        *[other] { $count } comments
+#         }
+#
+# Numeric selectors match exact keys such as `[0]` first, then the locale's CLDR
+# plural category (`one`, `few`, `many`, `other`, ...). String selectors match
+# keys by name. Messages named `ui-<section>` are exposed to templates as
+# `ui.<section>.<attribute>`.
+# Adding `<code>.ftl` next to this file turns on the site UI for that locale;
+# missing messages fall back to English.
+
+## Language names
+
+language-en = English
+language-pt = Portuguese
+language-es = Spanish
+language-fr = French
+language-de = German
+language-it = Italian
+language-ar = Arabic
+language-he = Hebrew
+
+## Index
+
+index-meta-title = Latest Wibble News
+index-search-meta-title = Search results for { $search }
+index-meta-description = Dry official bulletins on civic confusion, institutional overreaction, and preventable emergencies.
+sort-label-newest = Newest
+sort-label-hot = Hot
+time-label-any = Any time
+time-label-week = This week
+time-label-month = This month
+
+## Create
+
+create-meta-title = Create a new article
+create-meta-description = Submit a brief and let The Wibble draft a straight-faced satirical report.
+create-preset-1-label = Policy Memo
+create-preset-1-prompt = A national transport ministry begins issuing emotional readiness bulletins alongside delay notices, and employers start asking staff to attach them to leave requests.
+create-preset-2-label = Civic Desk
+create-preset-2-prompt = A borough council opens a formal inquiry after one unusually competent pigeon is repeatedly observed directing pedestrian traffic more effectively than the current signage.
+create-preset-3-label = Sports Tribunal
+create-preset-3-prompt = A football federation releases a compliance review after every post-match interview starts sounding like a quarterly earnings call and supporters begin demanding clearer guidance.
+create-preset-4-label = Research Brief
+create-preset-4-prompt = A respected institute publishes a sober report concluding that the national mood is best classified as 'manageable, with nuggets', prompting immediate parliamentary interest.
+
+## Wait page
+
+wait-meta-title = Generating article
+wait-meta-description = The article is still being generated and this page auto-refreshes.
+wait-destination-title = { $destination ->
+    [draft] Destination: draft
+   *[public] Destination: public
+    }
+wait-destination-note = { $destination ->
+    [draft] Signed-in articles stay private until you review and publish them.
+   *[public] Anonymous articles publish immediately and are not tied to an editable owner account.
+    }
+wait-stage-title = { $phase ->
+    [queued] Queued for generation
+    [planning] Planning the outline
+    [researching] Researching the brief
+    [translating] Translating the draft
+    [awaiting_user_input] Waiting for clarification
+    [outline_review] Waiting for outline approval
+    [ready_for_review] Preparing review
+    [rendering_images] Rendering illustrations
+    [preparing] Preparing the article
+    [recovering_images] Recovering the image set
+    [finalizing] Finalizing the article
+   *[writing] Drafting the story
+    }
+wait-stage-note = { $phase ->
+    [queued] The prompt is waiting for a generation slot before drafting starts.
+    [planning] Headline options, the angle, and section beats are being outlined before drafting.
+    [researching] The job is gathering bounded context before the draft is written.
+    [translating] The article text is being transformed into a new language variant.
+    [awaiting_user_input] The draft is paused because the brief is still ambiguous enough to change the article materially.
+    [outline_review] The outline is ready. The author can pick a headline or adjust the beats before drafting continues.
+    [ready_for_review] The draft is being packaged for a final review pass.
+    [rendering_images] The story draft is ready and the image queue is actively rendering art.
+    [preparing] The story draft is ready and the page is being finalized.
+    [recovering_images] Some illustrations failed and the article is waiting on the remaining results.
+    [finalizing] The draft is complete and the page is about to go live.
+   *[writing] The headline, angle, and article body are still being assembled.
+    }
+wait-phase-label = { $phase ->
+    [queued] Queued
+    [awaiting_user_input] Clarify
+    [outline_review] Outline
+    [rendering_images] Images
+    [ready_for_review] Review
+   *[writing] Write
+    }
+wait-clarification-deadline-note = If nobody answers by { $deadline }, the job resumes with a conservative fallback.
+wait-outline-deadline-note = If the outline is not approved by { $deadline }, drafting continues with it as planned.
+
+## Errors
+
+server-error-title = Server error
+server-error-description = An unexpected server error occurred while loading this page.
+server-error-message = Oops! Something went wrong. Please try again later.
+not-found-title = Page not found
+not-found-description = The requested page could not be found.
+not-found-message = The page you are looking for does not exist.
+
+## Images
+
+image-gallery-meta-title = Generated image gallery
+image-gallery-meta-description = A browsable gallery of AI-generated images used in Wibble stories.
+image-info-description = Generation details for an image used in a Wibble article.
+image-status-label = { $status ->
+    [pending] Queued
+    [processing] Generating
+    [failed] Failed
+    [completed] Ready
+   *[other] Unknown
+    }
+image-status-note = { $status ->
+    [pending] A fresh render is queued from the saved prompt.
+    [processing] A fresh render is in progress from the saved prompt.
+    [failed] The last generation attempt failed. You can retry or upload a replacement.
+    [completed] Current stored image. You can upload a replacement or regenerate from the saved prompt.
+   *[other] Image status is available for this slot.
+    }
+
+## Edit
+
+edit-meta-title = Edit: { $title }
+edit-preview-meta-title = Agent edit preview: { $title }
+translation-review-meta-title = Review { $language } translation: { $title }
+
+## Article language and research
+
+article-language-browser-default = Browser default: { $language }
+article-language-original-edition = Original edition: { $language }
+article-language-automatic-label = Automatic
+article-language-original-label = Original ({ $language })
+article-language-original-note = Manual source edition
+article-language-requested-note = Requested; showing { $language } for now
+article-language-saved-note = Saved for this article
+article-language-selected-note = Selected edition
+article-language-open-when-available = Open when available
+article-language-requested-summary = Requested { $language }
+article-language-source-summary = { $source ->
+    [explicit] Manual selection
+    [route] URL locale
+    [cookie] Saved selection
+    [browser] Automatic
+   *[article_source] Original edition
+    }
+article-language-notice = { $requested } was requested. This page is currently showing the original { $source } edition while that translation is prepared.
+article-research-mode-label = { $mode ->
+    [manual] Requested research desk
+   *[automatic] Automatic research desk
+    }
+article-research-note = { $mode }. This filing was grounded against { $count ->
+    [one] { $count } public-source brief
+   *[other] { $count } public-source briefs
+    } before drafting. The source trace is kept off-page so the article body stays deadpan.
+
+## Comments
+
+comment-count = { $count ->
+    [one] { $count } comment
+   *[other] { $count } comments
+    }
+comment-page-label = Page { $current } / { $total }
+comment-translated-from = Translated from { $language }
+comment-translated = Translated
+
+## Template strings
+
+ui-base =
+    .brand_note = Daily bulletin of public affairs and avoidable concern
+    .create_article = Draft article
+    .discord = Discord
+    .admin = Admin
+    .login = Login
+    .logout = Logout
+    .footer = © The Wibble
+
+ui-index =
+    .front_page_eyebrow = Front Page
+    .search_eyebrow = Search Docket
+    .front_page_title = Latest bulletins, notices, and administrative disturbances
+    .search_summary = Review the current file of reports matching that term, then clear filters to return to the general state of concern.
+    .front_page_summary = A straight-faced record of civic confusion, institutional overreaction, and matters requiring no immediate improvement.
+    .search_placeholder = Search by department, incident, or headline
+    .search_button = Search
+    .clear_button = Clear
+    .toolbar_aria = Feed controls
+    .toolbar_order = Order
+    .toolbar_window = Window
+    .lead_report = Lead Report
+    .further_notices = Further Notices
+    .additional_reports = Additional reports from the desk
+    .load_more = Load more reports
+    .empty_search_title = No filed reports matched that search
+    .empty_search_body = Try a broader term, return to newest, or clear the current docket and resume normal monitoring.
+    .empty_home_title = No public bulletins have been issued yet
+    .empty_home_body = The front page is presently calm. File a report and correct that condition.
+    .empty_home_cta = Draft the first article
+    .reset_front_page = Reset front page
+
+ui-create =
+    .eyebrow = Prompt studio
+    .title = Issue a matter for review
+    .summary = Submit a scene, policy failure, administrative memo, or civic incident. The model works best when the premise is specific, official-sounding, and treated as entirely routine.
+    .logged_in_note = Signed-in filings open as private drafts under your account. You can revise the copy, replace images, and publish when ready.
+    .anonymous_note = Anonymous filings publish immediately if they succeed. Log in if you want an editable draft and explicit publish control.
+    .desk_quota = Desk quota:
+    .owner_editing_note = Owner editing is capped separately at %EDIT_HOURLY% edit-agent previews per hour.
+    .research_lane_note = Research-backed filings run on their own desk at %RESEARCH_HOURLY% per hour / %RESEARCH_DAILY% per day.
+    .translation_lane_note = Background translation refreshes stay on their own lane at %TRANSLATION_HOURLY% per hour.
+    .login_upsell = Login raises the standard desk to %STANDARD_HOURLY% per hour, opens a bounded research desk at %RESEARCH_HOURLY% per hour, keeps results private as drafts, and unlocks the edit desk.
+    .tip_one = Write it like a memo, not a punchline
+    .tip_two = One absurd policy is enough
+    .tip_three = Treat the impossible as procedure
+    .desk_mode = Desk mode
+    .mode_auto = Automatic
+    .mode_auto_note = Use the cheap drafting path unless the brief clearly needs public-source grounding and you are signed in.
+    .mode_standard = Standard
+    .mode_standard_note = No browsing, fastest queue, best for invented institutions and generic bureaucratic collapse.
+    .mode_research = Research desk
+    .mode_research_note = Bounded public-source lookup for real institutions, policies, organizations, and public figures.
+    .mode_research_quota = Separate quota: %RESEARCH_HOURLY% per hour / %RESEARCH_DAILY% per day.
+    .mode_research_login_prefix = Log in
+    .mode_research_login_suffix = to enable it.
+    .case_brief = Case brief
+    .case_brief_placeholder = Example: A metropolitan transit authority begins issuing formal emotional support updates alongside train delays, and commuters quickly start citing them in workplace absence forms.
+    .case_brief_help = Name the institution, the failure mode, and the public reaction.
+    .article_language = Article language
+    .article_language_help = The article is written in this language and translated for readers of other languages.
+    .draft_report = Draft report
+    .wait_time = Typical wait: a few seconds to a minute, depending on the image queue and the severity of the incident.
+    .result_private = Result: private draft with the same background translation fallback rules as public stories.
+    .result_public = Result: immediate public article. Login keeps the filing private until you approve it.
+    .sample_filings = Sample filings
+    .sample_filings_body = Tap a preset to load a straight-faced newsroom brief, then adjust the facts as required.
+
+ui-wait =
+    .eyebrow = Generation status
+    .working_on = Working on:
+    .clarification_needed = Clarification needed.
+    .loading = Loading...
+    .progress_aria = Generation progress
+    .images_planned = Images planned
+    .completed = Completed
+    .in_progress = In progress
+    .failed = Failed
+    .answer_label = Answer the missing details
+    .answer_skip_note = Leave a question blank to let the writer decide.
+    .outline_ready = Outline ready for review.
+    .outline_waiting = The author is reviewing the outline before drafting continues.
+    .headline_label = Headline
+    .angle_label = Angle
+    .beats_label = Section beats, one per line
+    .image_slots_label = Image slots
+    .approve_outline = Approve outline and start drafting
+    .resume = Resume drafting
+    .refresh = Refresh now
+    .open_article = Open article
+
+ui-error =
+    .image_alt = Error image
+
+ui-content =
+    .images = Images
+    .edit_article = Edit article
+    .unpublished_warning = This article is unpublished and hidden from the public feed and sitemap.
+    .filed_report = Filed Report
+    .article_score = Article score
+    .clear_upvote = Clear upvote
+    .upvote = Upvote
+    .login_to_upvote = Log in to upvote
+    .clear_downvote = Clear downvote
+    .downvote = Downvote
+    .login_to_downvote = Log in to downvote
+    .filed = Filed
+    .status = Status
+    .public = Public
+    .draft = Draft
+    .edition_desk = Edition Desk
+    .current_edition = Current edition
+    .edition_intro = Automatic service follows your browser only when a prepared edition exists. If a requested edition is still being prepared, the source article stays readable immediately and the translation continues in the background. Manual changes are filed for this article only.
+    .public_response = Public Response
+    .comments = Comments
+    .add_comment = Add a comment
+    .post_comment = Post comment
+    .join_discussion_prefix = Log in
+    .join_discussion_suffix = to join the discussion.
+    .comments_closed = Comments open on published articles.
+    .newer_comments = Newer comments
+    .older_comments = Older comments
+    .no_comments = No comments yet.
+    .translate_comments = Translate comments
+    .show_original_comments = Show originals
+    .translate_comment = Translate
+    .show_original_comment = Show original
+    .comment_translation_limited = Translation limit reached; the remaining comments are shown as written.
+    .comment_translation_deferred = Some comments are still shown as written.
+    .translate_more_comments = Translate more
+
+ui-images =
+    .eyebrow = Illustration archive
+    .title = Generated Image Gallery
+    .summary = Browse the visual side of Wibble stories and jump straight into the articles they belong to.
+    .used_in = Used in
+    .next_page = Next Page
+    .empty_title = No gallery images yet
+    .empty_body = Once articles finish rendering, their illustrations will show up here.
+
+ui-image_info =
+    .kicker = Image prompt
+    .metadata_aria = Image metadata
+    .status = Status
+    .created = Created
+    .model = Model
+    .last_error = Last error
+    .used_in = Used in
+
+ui-edit =
+    .eyebrow = Editorial desk
+    .title = Edit article
+    .jump_to_images = Jump to images
+    .view_article = View article
+    .workspace_note = This workspace is available to the article owner and admins. Signed-in authors can revise draft copy, replace images, and publish when ready.
+    .agent_edit = Agent edit
+    .agent_edit_body = Describe the change in plain language. The agent will draft a revision, summarize it, and show a diff before anything is applied.
+    .change_request = Change request
+    .change_request_placeholder = Example: tighten the opening, cut the third section by a third, and make the closing sound more like an official bulletin.
+    .owners_only = Owners and admins only. Nothing is applied until you approve the preview.
+    .research_change = Research the change first
+    .research_change_note = Searches a few public sources for the requested change before rewriting. Uses your research quota.
+    .max_chars = Max %MAX_CHARS% characters.
+    .preview_revision = Preview agent revision
+    .manual_editor = Manual editor
+    .title_label = Title
+    .description_label = Description
+    .content_label = Content (Markdown)
+    .save_changes = Save changes
+    .cancel = Cancel
+    .raw_markdown_note = Raw markdown editing remains available as the escape hatch if the agent preview is not precise enough.
+    .images_title = Images
+    .images_body = Upload a replacement in JPG, JPEG, or PNG format, up to 12 MB. The file is validated before it overwrites the stored image.
+    .status_prefix = Status:
+    .last_error_prefix = Last error:
+    .accepted_formats = Accepted formats: JPG, JPEG, PNG. Maximum size: 12 MB.
+    .replace_image = Replace image
+    .drop_hint = Or drop an image file or link onto the picture.
+    .image_url_label = Image URL
+    .replace_from_url = Fetch and replace
+    .image_url_note = Public http(s) JPG or PNG links only, up to 12 MB.
+    .framing_title = Framing
+    .framing_note = Percentages of the full image. The crop applies everywhere; thumbnails and social cards are centred on the focal point. Click the picture to set the focal point.
+    .crop_x_label = Crop left %
+    .crop_y_label = Crop top %
+    .crop_width_label = Crop width %
+    .crop_height_label = Crop height %
+    .focal_x_label = Focal X %
+    .focal_y_label = Focal Y %
+    .save_framing = Save framing
+    .regenerating = Regenerating…
+    .regenerate_prompt = Regenerate from prompt
+    .reused_image_note = This render is shared with an earlier article that used the same prompt. Regenerate for a fresh render of your own.
+    .alt_text_label = Alt text
+    .caption_label = Caption
+    .image_text_note = Changes here are translated with the article; the markdown is left untouched.
+    .save_image_text = Save image text
+    .translations_title = Translations
+    .translations_body = Check the machine translations side by side with the source and correct them by hand.
+
+ui-edit_preview =
+    .eyebrow = Editorial desk
+    .title = Agent edit preview
+    .back_to_editor = Back to editor
+    .view_article = View article
+    .requested_change = Requested change:
+    .agent_summary = Agent summary:
+    .research_sources = Research sources:
+    .prompt_version = Prompt version:
+    .field_preview = Field preview
+    .current = Current
+    .proposed = Proposed
+    .diff = Diff
+    .title_label = Title
+    .description_label = Description
+    .markdown_label = Markdown
+    .apply_revision = Apply agent revision
+    .discard_preview = Discard preview
+
+ui-translation_review =
+    .eyebrow = Editorial desk
+    .title = Review translation
+    .back_to_editor = Back to editor
+    .view_article = View article
+    .note = Saved fields replace the machine translation for readers. Editing the source later marks them as needing review. Clear a field to go back to the machine translation.
+    .source = Source
+    .translation = Translation
+    .title_label = Title
+    .description_label = Description
+    .markdown_label = Content (Markdown)
+    .reviewed_by = Reviewed by
+    .machine_translation = Machine translation
+    .not_translated = Not translated yet
+    .stale = The source changed since this field was reviewed. Your earlier translation:
+    .save = Save reviewed translation
@@ -0,0 +1,399 @@
+# Site chrome strings for Spanish. See en.ftl for the syntax; anything missing
+# here falls back to English.
+
+## Language names
+
+language-en = Inglés
+language-pt = Portugués
+language-es = Español
+language-fr = Francés
+language-de = Alemán
+language-it = Italiano
+language-ar = Árabe
+language-he = Hebreo
+
+## Index
+
+index-meta-title = Últimas noticias de Wibble
+index-search-meta-title = Resultados de búsqueda para { $search }
+index-meta-description = Boletines oficiales impasibles sobre confusión cívica, sobrerreacción institucional y emergencias evitables.
+sort-label-newest = Más recientes
+sort-label-hot = Destacados
+time-label-any = Cualquier fecha
+time-label-week = Esta semana
+time-label-month = Este mes
+
+## Create
+
+create-meta-title = Crear un artículo nuevo
+create-meta-description = Envía un encargo y deja que The Wibble redacte un reportaje satírico con total seriedad.
+create-preset-1-label = Memorando de política
+create-preset-1-prompt = Un ministerio nacional de transporte empieza a emitir boletines de preparación emocional junto con los avisos de retraso, y las empresas empiezan a pedir al personal que los adjunte a sus solicitudes de permiso.
+create-preset-2-label = Mesa cívica
+create-preset-2-prompt = Un ayuntamiento abre una investigación formal después de que se observe repetidamente a una paloma inusualmente competente dirigiendo el tráfico peatonal mejor que la señalización actual.
+create-preset-3-label = Tribunal deportivo
+create-preset-3-prompt = Una federación de fútbol publica una revisión de cumplimiento después de que todas las entrevistas posteriores a los partidos empiecen a sonar como presentaciones trimestrales de resultados y la afición empiece a exigir directrices más claras.
+create-preset-4-label = Informe de investigación
+create-preset-4-prompt = Un instituto respetado publica un informe sobrio que concluye que el estado de ánimo nacional se clasifica mejor como 'manejable, con nuggets', lo que despierta un interés parlamentario inmediato.
+
+## Wait page
+
+wait-meta-title = Generando artículo
+wait-meta-description = El artículo todavía se está generando y esta página se actualiza automáticamente.
+wait-destination-title = { $destination ->
+    [draft] Destino: borrador
+   *[public] Destino: público
+    }
+wait-destination-note = { $destination ->
+    [draft] Los artículos con sesión iniciada siguen siendo privados hasta que los revises y publiques.
+   *[public] Los artículos anónimos se publican de inmediato y no quedan vinculados a una cuenta editable.
+    }
+wait-stage-title = { $phase ->
+    [queued] En cola para generación
+    [planning] Planificando el esquema
+    [researching] Investigando el encargo
+    [translating] Traduciendo el borrador
+    [awaiting_user_input] Esperando una aclaración
+    [outline_review] Esperando la aprobación del esquema
+    [ready_for_review] Preparando la revisión
+    [rendering_images] Renderizando ilustraciones
+    [preparing] Preparando el artículo
+    [recovering_images] Recuperando el conjunto de imágenes
+    [finalizing] Finalizando el artículo
+   *[writing] Redactando la noticia
+    }
+wait-stage-note = { $phase ->
+    [queued] El encargo espera un turno de generación antes de empezar la redacción.
+    [planning] Se están esbozando las opciones de titular, el enfoque y las secciones antes de redactar.
+    [researching] El trabajo está reuniendo contexto acotado antes de escribir el borrador.
+    [translating] El texto del artículo se está convirtiendo en una nueva variante de idioma.
+    [awaiting_user_input] El borrador está en pausa porque el encargo sigue siendo lo bastante ambiguo como para cambiar el artículo de forma sustancial.
+    [outline_review] El esquema está listo. El autor puede elegir un titular o ajustar las secciones antes de seguir redactando.
+    [ready_for_review] El borrador se está preparando para una última revisión.
+    [rendering_images] El borrador de la noticia está listo y la cola de imágenes está renderizando las ilustraciones.
+    [preparing] El borrador de la noticia está listo y la página se está terminando.
+    [recovering_images] Algunas ilustraciones fallaron y el artículo espera los resultados restantes.
+    [finalizing] El borrador está completo y la página está a punto de publicarse.
+   *[writing] El titular, el enfoque y el cuerpo del artículo todavía se están montando.
+    }
+wait-phase-label = { $phase ->
+    [queued] Cola
+    [awaiting_user_input] Aclarar
+    [outline_review] Esquema
+    [rendering_images] Imágenes
+    [ready_for_review] Revisar
+   *[writing] Escribir
+    }
+wait-clarification-deadline-note = Si nadie responde antes de { $deadline }, el trabajo continúa con una alternativa conservadora.
+wait-outline-deadline-note = Si el esquema no se aprueba antes de { $deadline }, la redacción continúa con el plan original.
+
+## Errors
+
+server-error-title = Error del servidor
+server-error-description = Se produjo un error inesperado del servidor al cargar esta página.
+server-error-message = Algo salió mal. Inténtalo de nuevo más tarde.
+not-found-title = Página no encontrada
+not-found-description = No se encontró la página solicitada.
+not-found-message = La página que buscas no existe.
+
+## Images
+
+image-gallery-meta-title = Galería de imágenes generadas
+image-gallery-meta-description = Una galería navegable de imágenes generadas por IA usadas en las noticias de Wibble.
+image-info-description = Detalles de generación de una imagen usada en un artículo de Wibble.
+image-status-label = { $status ->
+    [pending] En cola
+    [processing] Generando
+    [failed] Falló
+    [completed] Lista
+   *[other] Desconocido
+    }
+image-status-note = { $status ->
+    [pending] Hay un nuevo renderizado en cola a partir del prompt guardado.
+    [processing] Hay un nuevo renderizado en curso a partir del prompt guardado.
+    [failed] El último intento de generación falló. Puedes reintentarlo o subir una sustitución.
+    [completed] Imagen guardada actualmente. Puedes subir una sustitución o regenerarla a partir del prompt guardado.
+   *[other] El estado de la imagen está disponible para este espacio.
+    }
+
+## Edit
+
+edit-meta-title = Editar: { $title }
+edit-preview-meta-title = Vista previa de la edición con agente: { $title }
+translation-review-meta-title = Revisar traducción al { $language }: { $title }
+
+## Article language and research
+
+article-language-browser-default = Idioma del navegador: { $language }
+article-language-original-edition = Edición original: { $language }
+article-language-automatic-label = Automático
+article-language-original-note = Edición fuente manual
+article-language-requested-note = Solicitado; mostrando { $language } por ahora
+article-language-saved-note = Guardado para este artículo
+article-language-selected-note = Edición seleccionada
+article-language-open-when-available = Abrir cuando esté disponible
+article-language-requested-summary = Solicitado: { $language }
+article-language-source-summary = { $source ->
+    [explicit] Selección manual
+    [route] Idioma de la URL
+    [cookie] Selección guardada
+    [browser] Automático
+   *[article_source] Edición original
+    }
+article-language-notice = Se solicitó { $requested }. Esta página muestra por ahora la edición original en { $source } mientras se prepara la traducción.
+article-research-mode-label = { $mode ->
+    [manual] Mesa de investigación solicitada
+   *[automatic] Mesa de investigación automática
+    }
+article-research-note = { $mode }. Este informe se contrastó con { $count ->
+    [one] { $count } resumen de fuentes públicas
+   *[other] { $count } resúmenes de fuentes públicas
+    } antes de redactarlo. El rastro de fuentes queda fuera de la página para que el texto mantenga su seriedad.
+
+## Comments
+
+comment-count = { $count ->
+    [one] { $count } comentario
+   *[other] { $count } comentarios
+    }
+comment-page-label = Página { $current } / { $total }
+comment-translated-from = Traducido; original en { $language }
+comment-translated = Traducido
+
+## Template strings
+
+ui-base =
+    .brand_note = Boletín diario de asuntos públicos y preocupaciones evitables
+    .create_article = Redactar artículo
+    .discord = Discord
+    .admin = Administración
+    .login = Iniciar sesión
+    .logout = Cerrar sesión
+    .footer = © The Wibble
+
+ui-index =
+    .front_page_eyebrow = Portada
+    .search_eyebrow = Expediente de búsqueda
+    .front_page_title = Últimos boletines, avisos y alteraciones administrativas
+    .search_summary = Revisa el archivo actual de informes que coinciden con ese término y luego quita los filtros para volver al estado general de preocupación.
+    .front_page_summary = Un registro impasible de confusión cívica, sobrerreacción institucional y asuntos que no requieren mejora inmediata.
+    .search_placeholder = Buscar por departamento, incidente o titular
+    .search_button = Buscar
+    .clear_button = Limpiar
+    .toolbar_aria = Controles del listado
+    .toolbar_order = Orden
+    .toolbar_window = Periodo
+    .lead_report = Informe principal
+    .further_notices = Otros avisos
+    .additional_reports = Más informes de la redacción
+    .load_more = Cargar más informes
+    .empty_search_title = Ningún informe archivado coincide con esa búsqueda
+    .empty_search_body = Prueba un término más amplio, vuelve a lo más reciente o limpia el expediente actual y reanuda la vigilancia normal.
+    .empty_home_title = Todavía no se ha emitido ningún boletín público
+    .empty_home_body = La portada está tranquila por ahora. Presenta un informe y corrige esa situación.
+    .empty_home_cta = Redacta el primer artículo
+    .reset_front_page = Restablecer portada
+
+ui-create =
+    .eyebrow = Estudio de encargos
+    .title = Presenta un asunto para revisión
+    .summary = Envía una escena, un fallo de política, un memorando administrativo o un incidente cívico. El modelo funciona mejor cuando la premisa es específica, suena oficial y se trata como algo completamente rutinario.
+    .logged_in_note = Los encargos con sesión iniciada se abren como borradores privados en tu cuenta. Puedes revisar el texto, sustituir imágenes y publicar cuando esté listo.
+    .anonymous_note = Los encargos anónimos se publican de inmediato si salen bien. Inicia sesión si quieres un borrador editable y control explícito de la publicación.
+    .desk_quota = Cupo de la mesa:
+    .owner_editing_note = La edición del propietario tiene un límite aparte de %EDIT_HOURLY% vistas previas del agente de edición por hora.
+    .research_lane_note = Los encargos con investigación van por su propia mesa a %RESEARCH_HOURLY% por hora / %RESEARCH_DAILY% por día.
+    .translation_lane_note = Las actualizaciones de traducción en segundo plano van por su propio carril a %TRANSLATION_HOURLY% por hora.
+    .login_upsell = Iniciar sesión eleva la mesa estándar a %STANDARD_HOURLY% por hora, abre una mesa de investigación acotada a %RESEARCH_HOURLY% por hora, mantiene los resultados privados como borradores y desbloquea la mesa de edición.
+    .tip_one = Escríbelo como un memorando, no como un chiste
+    .tip_two = Una sola política absurda es suficiente
+    .tip_three = Trata lo imposible como un trámite
+    .desk_mode = Modo de la mesa
+    .mode_auto = Automático
+    .mode_auto_note = Usa la vía de redacción económica salvo que el encargo necesite claramente apoyo en fuentes públicas y hayas iniciado sesión.
+    .mode_standard = Estándar
+    .mode_standard_note = Sin navegación, la cola más rápida, ideal para instituciones inventadas y colapsos burocráticos genéricos.
+    .mode_research = Mesa de investigación
+    .mode_research_note = Consulta acotada de fuentes públicas para instituciones, políticas, organizaciones y figuras públicas reales.
+    .mode_research_quota = Cupo aparte: %RESEARCH_HOURLY% por hora / %RESEARCH_DAILY% por día.
+    .mode_research_login_prefix = Inicia sesión
+    .mode_research_login_suffix = para activarla.
+    .case_brief = Resumen del caso
+    .case_brief_placeholder = Ejemplo: Una autoridad metropolitana de transporte empieza a emitir actualizaciones formales de apoyo emocional junto con los retrasos de los trenes, y los viajeros empiezan a citarlas en sus justificantes de ausencia laboral.
+    .case_brief_help = Indica la institución, el modo de fallo y la reacción pública.
+    .article_language = Idioma del artículo
+    .article_language_help = El artículo se escribe en este idioma y se traduce para los lectores de otros idiomas.
+    .draft_report = Redactar informe
+    .wait_time = Espera habitual: de unos segundos a un minuto, según la cola de imágenes y la gravedad del incidente.
+    .result_private = Resultado: borrador privado con las mismas reglas de traducción en segundo plano que las noticias públicas.
+    .result_public = Resultado: artículo público inmediato. Iniciar sesión mantiene el encargo privado hasta que lo apruebes.
+    .sample_filings = Encargos de ejemplo
+    .sample_filings_body = Toca una plantilla para cargar un encargo de redacción impasible y ajusta los hechos según sea necesario.
+
+ui-wait =
+    .eyebrow = Estado de la generación
+    .working_on = Trabajando en:
+    .clarification_needed = Se necesita una aclaración.
+    .loading = Cargando...
+    .progress_aria = Progreso de la generación
+    .images_planned = Imágenes previstas
+    .completed = Completadas
+    .in_progress = En curso
+    .failed = Fallidas
+    .answer_label = Responde los detalles que faltan
+    .answer_skip_note = Deja una pregunta en blanco para que decida el redactor.
+    .outline_ready = Esquema listo para revisar.
+    .outline_waiting = El autor está revisando el esquema antes de seguir redactando.
+    .headline_label = Titular
+    .angle_label = Enfoque
+    .beats_label = Secciones, una por línea
+    .image_slots_label = Espacios para imágenes
+    .approve_outline = Aprobar el esquema y empezar a redactar
+    .resume = Reanudar la redacción
+    .refresh = Actualizar ahora
+    .open_article = Abrir artículo
+
+ui-error =
+    .image_alt = Imagen de error
+
+ui-content =
+    .images = Imágenes
+    .edit_article = Editar artículo
+    .unpublished_warning = Este artículo no está publicado y se oculta del listado público y del mapa del sitio.
+    .filed_report = Informe archivado
+    .article_score = Puntuación del artículo
+    .clear_upvote = Quitar voto positivo
+    .upvote = Votar a favor
+    .login_to_upvote = Inicia sesión para votar a favor
+    .clear_downvote = Quitar voto negativo
+    .downvote = Votar en contra
+    .login_to_downvote = Inicia sesión para votar en contra
+    .filed = Archivado
+    .status = Estado
+    .public = Público
+    .draft = Borrador
+    .edition_desk = Mesa de ediciones
+    .current_edition = Edición actual
+    .edition_intro = El servicio automático sigue a tu navegador solo cuando existe una edición preparada. Si una edición solicitada todavía se está preparando, el artículo original sigue legible de inmediato y la traducción continúa en segundo plano. Los cambios manuales se guardan solo para este artículo.
+    .public_response = Respuesta del público
+    .comments = Comentarios
+    .add_comment = Añadir un comentario
+    .post_comment = Publicar comentario
+    .join_discussion_prefix = Inicia sesión
+    .join_discussion_suffix = para unirte a la conversación.
+    .comments_closed = Los comentarios se abren en los artículos publicados.
+    .newer_comments = Comentarios más recientes
+    .older_comments = Comentarios anteriores
+    .no_comments = Todavía no hay comentarios.
+    .translate_comments = Traducir comentarios
+    .show_original_comments = Mostrar originales
+    .translate_comment = Traducir
+    .show_original_comment = Mostrar original
+    .comment_translation_limited = Límite de traducciones alcanzado; los demás comentarios se muestran tal como se escribieron.
+    .comment_translation_deferred = Algunos comentarios aún se muestran tal como se escribieron.
+    .translate_more_comments = Traducir más
+
+ui-images =
+    .eyebrow = Archivo de ilustraciones
+    .title = Galería de imágenes generadas
+    .summary = Explora el lado visual de las noticias de Wibble y ve directamente a los artículos a los que pertenecen.
+    .used_in = Usada en
+    .next_page = Página siguiente
+    .empty_title = Todavía no hay imágenes en la galería
+    .empty_body = Cuando los artículos terminen de renderizarse, sus ilustraciones aparecerán aquí.
+
+ui-image_info =
+    .kicker = Prompt de la imagen
+    .metadata_aria = Metadatos de la imagen
+    .status = Estado
+    .created = Creada
+    .model = Modelo
+    .last_error = Último error
+    .used_in = Usada en
+
+ui-edit =
+    .eyebrow = Mesa editorial
+    .title = Editar artículo
+    .jump_to_images = Ir a las imágenes
+    .view_article = Ver artículo
+    .workspace_note = Este espacio de trabajo está disponible para el propietario del artículo y los administradores. Los autores con sesión iniciada pueden revisar el borrador, sustituir imágenes y publicar cuando esté listo.
+    .agent_edit = Edición con agente
+    .agent_edit_body = Describe el cambio en lenguaje sencillo. El agente redactará una revisión, la resumirá y mostrará las diferencias antes de aplicar nada.
+    .change_request = Solicitud de cambio
+    .change_request_placeholder = Ejemplo: ajusta el arranque, recorta un tercio de la tercera sección y haz que el cierre suene más a boletín oficial.
+    .owners_only = Solo propietarios y administradores. No se aplica nada hasta que apruebes la vista previa.
+    .research_change = Investigar el cambio primero
+    .research_change_note = Busca algunas fuentes públicas sobre el cambio solicitado antes de reescribir. Usa tu cuota de investigación.
+    .max_chars = Máximo %MAX_CHARS% caracteres.
+    .preview_revision = Previsualizar la revisión del agente
+    .manual_editor = Editor manual
+    .title_label = Título
+    .description_label = Descripción
+    .content_label = Contenido (Markdown)
+    .save_changes = Guardar cambios
+    .cancel = Cancelar
+    .raw_markdown_note = La edición directa del markdown sigue disponible como vía de escape si la vista previa del agente no es lo bastante precisa.
+    .images_title = Imágenes
+    .images_body = Sube una sustitución en formato JPG, JPEG o PNG, de hasta 12 MB. El archivo se valida antes de sobrescribir la imagen guardada.
+    .status_prefix = Estado:
+    .last_error_prefix = Último error:
+    .accepted_formats = Formatos aceptados: JPG, JPEG, PNG. Tamaño máximo: 12 MB.
+    .replace_image = Sustituir imagen
+    .drop_hint = O suelta un archivo de imagen o un enlace sobre la imagen.
+    .image_url_label = URL de la imagen
+    .replace_from_url = Descargar y sustituir
+    .image_url_note = Solo enlaces públicos http(s) a JPG o PNG, de hasta 12 MB.
+    .framing_title = Encuadre
+    .framing_note = Porcentajes de la imagen completa. El recorte se aplica en todas partes; las miniaturas y las tarjetas sociales se centran en el punto focal. Haz clic en la imagen para fijar el punto focal.
+    .crop_x_label = Recorte izquierdo %
+    .crop_y_label = Recorte superior %
+    .crop_width_label = Ancho del recorte %
+    .crop_height_label = Alto del recorte %
+    .focal_x_label = Foco X %
+    .focal_y_label = Foco Y %
+    .save_framing = Guardar encuadre
+    .regenerating = Regenerando…
+    .regenerate_prompt = Regenerar desde el prompt
+    .reused_image_note = Este renderizado se comparte con un artículo anterior que usó el mismo prompt. Regenéralo para obtener un renderizado propio.
+    .alt_text_label = Texto alternativo
+    .caption_label = Pie de foto
+    .image_text_note = Estos cambios se traducen con el artículo; el markdown no se modifica.
+    .save_image_text = Guardar texto de la imagen
+    .translations_title = Traducciones
+    .translations_body = Compara las traducciones automáticas con el original y corrígelas a mano.
+
+ui-edit_preview =
+    .eyebrow = Mesa editorial
+    .title = Vista previa de la edición con agente
+    .back_to_editor = Volver al editor
+    .view_article = Ver artículo
+    .requested_change = Cambio solicitado:
+    .agent_summary = Resumen del agente:
+    .research_sources = Fuentes de investigación:
+    .prompt_version = Versión del prompt:
+    .field_preview = Vista previa de los campos
+    .current = Actual
+    .proposed = Propuesto
+    .diff = Diferencias
+    .title_label = Título
+    .description_label = Descripción
+    .markdown_label = Markdown
+    .apply_revision = Aplicar la revisión del agente
+    .discard_preview = Descartar la vista previa
+
+ui-translation_review =
+    .eyebrow = Mesa editorial
+    .title = Revisar traducción
+    .back_to_editor = Volver al editor
+    .view_article = Ver artículo
+    .note = Los campos guardados sustituyen la traducción automática para los lectores. Si luego se edita el original, quedan marcados para revisión. Vacía un campo para volver a la traducción automática.
+    .source = Original
+    .translation = Traducción
+    .title_label = Título
+    .description_label = Descripción
+    .markdown_label = Contenido (Markdown)
+    .reviewed_by = Revisado por
+    .machine_translation = Traducción automática
+    .not_translated = Aún sin traducir
+    .stale = El original cambió desde que se revisó este campo. Tu traducción anterior:
+    .save = Guardar traducción revisada
@@ -0,0 +1,404 @@
+# Site chrome strings for Portuguese. See en.ftl for the syntax; anything missing
+# here falls back to English.
+#
+# Portuguese treats 0 as singular (`one`), so counts that should read as plural at
+# zero carry an explicit `[0]` variant.
+
+## Language names
+
+language-en = Inglês
+language-pt = Português
+language-es = Espanhol
+language-fr = Francês
+language-de = Alemão
+language-it = Italiano
+language-ar = Árabe
+language-he = Hebraico
+
+## Index
+
+index-meta-title = Últimas notícias do Wibble
+index-search-meta-title = Resultados da busca por { $search }
+index-meta-description = Boletins oficiais sisudos sobre confusão cívica, excesso institucional e emergências evitáveis.
+sort-label-newest = Mais recentes
+sort-label-hot = Em alta
+time-label-any = Qualquer período
+time-label-week = Esta semana
+time-label-month = Este mês
+
+## Create
+
+create-meta-title = Criar um novo artigo
+create-meta-description = Envie um briefing e deixe o The Wibble redigir uma reportagem satírica em tom sisudo.
+create-preset-1-label = Memorando de política
+create-preset-1-prompt = Um ministério nacional dos transportes começa a publicar boletins de prontidão emocional junto com avisos de atraso, e empregadores passam a exigir que funcionários os anexem aos pedidos de licença.
+create-preset-2-label = Mesa cívica
+create-preset-2-prompt = Um conselho municipal abre um inquérito formal depois que um pombo incomumente competente é visto repetidas vezes orientando o tráfego de pedestres melhor do que a sinalização atual.
+create-preset-3-label = Tribunal esportivo
+create-preset-3-prompt = Uma federação de futebol divulga uma revisão de conformidade depois que todas as entrevistas pós-jogo começam a soar como teleconferências trimestrais de resultados e torcedores passam a exigir orientações mais claras.
+create-preset-4-label = Briefing de pesquisa
+create-preset-4-prompt = Um instituto respeitado publica um relatório sóbrio concluindo que o humor nacional é melhor classificado como 'administrável, com pepitas', provocando interesse parlamentar imediato.
+
+## Wait page
+
+wait-meta-title = Gerando artigo
+wait-meta-description = O artigo ainda está sendo gerado e esta página se atualiza automaticamente.
+wait-destination-title = { $destination ->
+    [draft] Destino: rascunho
+   *[public] Destino: público
+    }
+wait-destination-note = { $destination ->
+    [draft] Artigos de usuários logados permanecem privados até você revisar e publicar.
+   *[public] Artigos anônimos são publicados imediatamente e não ficam vinculados a uma conta editável.
+    }
+wait-stage-title = { $phase ->
+    [queued] Na fila para geração
+    [planning] Planejando o esboço
+    [researching] Pesquisando o briefing
+    [translating] Traduzindo o rascunho
+    [awaiting_user_input] Aguardando esclarecimento
+    [outline_review] Aguardando a aprovação do esboço
+    [ready_for_review] Preparando revisão
+    [rendering_images] Renderizando ilustrações
+    [preparing] Preparando o artigo
+    [recovering_images] Recuperando o conjunto de imagens
+    [finalizing] Finalizando o artigo
+   *[writing] Redigindo a matéria
+    }
+wait-stage-note = { $phase ->
+    [queued] O prompt está aguardando uma vaga antes de a redação começar.
+    [planning] As opções de manchete, o ângulo e as seções estão sendo esboçados antes da redação.
+    [researching] O trabalho está reunindo contexto limitado antes de o rascunho ser escrito.
+    [translating] O texto do artigo está sendo convertido para uma nova variante de idioma.
+    [awaiting_user_input] O rascunho está pausado porque o briefing ainda é ambíguo o bastante para mudar materialmente o artigo.
+    [outline_review] O esboço está pronto. O autor pode escolher uma manchete ou ajustar as seções antes de a redação continuar.
+    [ready_for_review] O rascunho está sendo empacotado para uma última passada de revisão.
+    [rendering_images] O rascunho da matéria está pronto e a fila de imagens está renderizando a arte.
+    [preparing] O rascunho da matéria está pronto e a página está sendo finalizada.
+    [recovering_images] Algumas ilustrações falharam e o artigo está aguardando os resultados restantes.
+    [finalizing] O rascunho está completo e a página está prestes a entrar no ar.
+   *[writing] A manchete, o ângulo e o corpo do artigo ainda estão sendo montados.
+    }
+wait-phase-label = { $phase ->
+    [queued] Fila
+    [awaiting_user_input] Esclarecer
+    [outline_review] Esboço
+    [rendering_images] Imagens
+    [ready_for_review] Revisar
+   *[writing] Escrever
+    }
+wait-clarification-deadline-note = Se ninguém responder até { $deadline }, o trabalho continua com uma alternativa conservadora.
+wait-outline-deadline-note = Se o esboço não for aprovado até { $deadline }, a redação continua com o plano original.
+
+## Errors
+
+server-error-title = Erro do servidor
+server-error-description = Ocorreu um erro inesperado do servidor ao carregar esta página.
+server-error-message = Algo deu errado. Tente novamente mais tarde.
+not-found-title = Página não encontrada
+not-found-description = A página solicitada não foi encontrada.
+not-found-message = A página que você está procurando não existe.
+
+## Images
+
+image-gallery-meta-title = Galeria de imagens geradas
+image-gallery-meta-description = Uma galeria navegável de imagens geradas por IA usadas nas histórias do Wibble.
+image-info-description = Detalhes de geração de uma imagem usada em um artigo do Wibble.
+image-status-label = { $status ->
+    [pending] Na fila
+    [processing] Gerando
+    [failed] Falhou
+    [completed] Pronta
+   *[other] Desconhecido
+    }
+image-status-note = { $status ->
+    [pending] Uma nova renderização foi enfileirada a partir do prompt salvo.
+    [processing] Uma nova renderização está em andamento a partir do prompt salvo.
+    [failed] A última tentativa de geração falhou. Você pode tentar de novo ou enviar uma substituição.
+    [completed] Imagem atual armazenada. Você pode enviar uma substituição ou regenerar a partir do prompt salvo.
+   *[other] O status da imagem está disponível para este slot.
+    }
+
+## Edit
+
+edit-meta-title = Editar: { $title }
+edit-preview-meta-title = Prévia da edição com agente: { $title }
+translation-review-meta-title = Revisar tradução em { $language }: { $title }
+
+## Article language and research
+
+article-language-browser-default = Padrão do navegador: { $language }
+article-language-original-edition = Edição original: { $language }
+article-language-automatic-label = Automático
+article-language-original-note = Edição-fonte manual
+article-language-requested-note = Solicitado; exibindo { $language } por enquanto
+article-language-saved-note = Salvo para este artigo
+article-language-selected-note = Edição selecionada
+article-language-open-when-available = Abrir quando disponível
+article-language-requested-summary = Solicitado { $language }
+article-language-source-summary = { $source ->
+    [explicit] Seleção manual
+    [route] Idioma da URL
+    [cookie] Seleção salva
+    [browser] Automático
+   *[article_source] Edição original
+    }
+article-language-notice = { $requested } foi solicitado. Esta página está mostrando a edição original em { $source } enquanto a tradução é preparada.
+article-research-mode-label = { $mode ->
+    [manual] Mesa de pesquisa solicitada
+   *[automatic] Mesa de pesquisa automática
+    }
+article-research-note = { $mode }. Este registro foi fundamentado em { $count ->
+    [0] { $count } resumos de fonte pública
+    [one] { $count } resumo de fonte pública
+   *[other] { $count } resumos de fonte pública
+    } antes da redação. O rastro das fontes fica fora da página para preservar o tom sisudo.
+
+## Comments
+
+comment-count = { $count ->
+    [0] { $count } comentários
+    [one] { $count } comentário
+   *[other] { $count } comentários
+    }
+comment-page-label = Página { $current } / { $total }
+comment-translated-from = Traduzido; original em { $language }
+comment-translated = Traduzido
+
+## Template strings
+
+ui-base =
+    .brand_note = Boletim diário de assuntos públicos e preocupação evitável
+    .create_article = Rascunhar artigo
+    .discord = Discord
+    .admin = Admin
+    .login = Entrar
+    .logout = Sair
+    .footer = © The Wibble
+
+ui-index =
+    .front_page_eyebrow = Capa
+    .search_eyebrow = Arquivo de busca
+    .front_page_title = Últimos boletins, comunicados e perturbações administrativas
+    .search_summary = Revise o arquivo atual de relatórios que correspondem a esse termo e depois limpe os filtros para voltar ao estado geral de apreensão.
+    .front_page_summary = Um registro sisudo de confusão cívica, exagero institucional e assuntos que não exigem melhora imediata.
+    .search_placeholder = Busque por órgão, incidente ou manchete
+    .search_button = Buscar
+    .clear_button = Limpar
+    .toolbar_aria = Controles do feed
+    .toolbar_order = Ordem
+    .toolbar_window = Período
+    .lead_report = Relatório principal
+    .further_notices = Outros avisos
+    .additional_reports = Outros relatórios da redação
+    .load_more = Carregar mais relatórios
+    .empty_search_title = Nenhum relatório arquivado correspondeu a essa busca
+    .empty_search_body = Tente um termo mais amplo, volte para os mais recentes ou limpe o arquivo atual e retome o monitoramento normal.
+    .empty_home_title = Ainda não foram emitidos boletins públicos
+    .empty_home_body = A capa está calma no momento. Registre um relatório e corrija essa situação.
+    .empty_home_cta = Rascunhar o primeiro artigo
+    .reset_front_page = Redefinir capa
+
+ui-create =
+    .eyebrow = Estúdio de pautas
+    .title = Registrar um assunto para análise
+    .summary = Envie uma cena, falha de política, memorando administrativo ou incidente cívico. O modelo funciona melhor quando a premissa é específica, soa oficial e é tratada como totalmente rotineira.
+    .logged_in_note = Envios com login abrem como rascunhos privados na sua conta. Você pode revisar o texto, trocar imagens e publicar quando estiver pronto.
+    .anonymous_note = Envios anônimos são publicados imediatamente se derem certo. Entre se você quiser um rascunho editável e controle explícito de publicação.
+    .desk_quota = Cota da redação:
+    .owner_editing_note = A edição do autor tem limite separado de %EDIT_HOURLY% prévias do agente por hora.
+    .research_lane_note = Pautas com pesquisa rodam em uma mesa própria, com %RESEARCH_HOURLY% por hora / %RESEARCH_DAILY% por dia.
+    .translation_lane_note = As atualizações de tradução em segundo plano ficam em uma faixa separada de %TRANSLATION_HOURLY% por hora.
+    .login_upsell = Entrar aumenta a mesa padrão para %STANDARD_HOURLY% por hora, abre uma mesa de pesquisa limitada com %RESEARCH_HOURLY% por hora, mantém os resultados privados como rascunhos e libera a mesa de edição.
+    .tip_one = Escreva como memorando, não como piada
+    .tip_two = Uma política absurda já basta
+    .tip_three = Trate o impossível como procedimento
+    .desk_mode = Modo da redação
+    .mode_auto = Automático
+    .mode_auto_note = Use o caminho barato de redação, a menos que o briefing claramente exija base em fontes públicas e você esteja logado.
+    .mode_standard = Padrão
+    .mode_standard_note = Sem navegação, fila mais rápida, melhor para instituições inventadas e colapsos burocráticos genéricos.
+    .mode_research = Mesa de pesquisa
+    .mode_research_note = Busca limitada em fontes públicas para instituições, políticas, organizações e figuras públicas reais.
+    .mode_research_quota = Cota separada: %RESEARCH_HOURLY% por hora / %RESEARCH_DAILY% por dia.
+    .mode_research_login_prefix = Entre
+    .mode_research_login_suffix = para habilitar.
+    .case_brief = Resumo do caso
+    .case_brief_placeholder = Exemplo: uma autoridade metropolitana de transporte começa a publicar atualizações formais de apoio emocional junto com atrasos de trens, e passageiros passam a citá-las em justificativas de ausência no trabalho.
+    .case_brief_help = Nomeie a instituição, a falha e a reação pública.
+    .article_language = Idioma da matéria
+    .article_language_help = A matéria é escrita neste idioma e traduzida para leitores de outros idiomas.
+    .draft_report = Rascunhar relatório
+    .wait_time = Espera típica: de alguns segundos a um minuto, dependendo da fila de imagens e da gravidade do incidente.
+    .result_private = Resultado: rascunho privado com as mesmas regras de fallback de tradução em segundo plano das histórias públicas.
+    .result_public = Resultado: artigo público imediato. Entrar mantém o envio privado até sua aprovação.
+    .sample_filings = Exemplos de registros
+    .sample_filings_body = Toque em um modelo para carregar um briefing sisudo de redação e ajuste os fatos conforme necessário.
+
+ui-wait =
+    .eyebrow = Status da geração
+    .working_on = Trabalhando em:
+    .clarification_needed = Esclarecimento necessário.
+    .loading = Carregando...
+    .progress_aria = Progresso da geração
+    .images_planned = Imagens planejadas
+    .completed = Concluídas
+    .in_progress = Em andamento
+    .failed = Falharam
+    .answer_label = Responda os detalhes que faltam
+    .answer_skip_note = Deixe uma pergunta em branco para o redator decidir.
+    .outline_ready = Esboço pronto para revisão.
+    .outline_waiting = O autor está revisando o esboço antes de a redação continuar.
+    .headline_label = Manchete
+    .angle_label = Ângulo
+    .beats_label = Seções, uma por linha
+    .image_slots_label = Espaços para imagens
+    .approve_outline = Aprovar o esboço e começar a redigir
+    .resume = Retomar rascunho
+    .refresh = Atualizar agora
+    .open_article = Abrir artigo
+
+ui-error =
+    .image_alt = Imagem de erro
+
+ui-content =
+    .images = Imagens
+    .edit_article = Editar artigo
+    .unpublished_warning = Este artigo não está publicado e fica oculto do feed público e do sitemap.
+    .filed_report = Relatório protocolado
+    .article_score = Pontuação do artigo
+    .clear_upvote = Remover voto positivo
+    .upvote = Voto positivo
+    .login_to_upvote = Entre para votar positivo
+    .clear_downvote = Remover voto negativo
+    .downvote = Voto negativo
+    .login_to_downvote = Entre para votar negativo
+    .filed = Protocolado
+    .status = Status
+    .public = Público
+    .draft = Rascunho
+    .edition_desk = Mesa de edições
+    .current_edition = Edição atual
+    .edition_intro = O modo automático segue o navegador apenas quando já existe uma edição preparada. Se a edição solicitada ainda estiver sendo preparada, o artigo-fonte continua legível imediatamente e a tradução segue em segundo plano. Mudanças manuais valem apenas para este artigo.
+    .public_response = Resposta pública
+    .comments = Comentários
+    .add_comment = Adicionar comentário
+    .post_comment = Publicar comentário
+    .join_discussion_prefix = Entre
+    .join_discussion_suffix = para participar da discussão.
+    .comments_closed = Comentários ficam abertos em artigos publicados.
+    .newer_comments = Comentários mais novos
+    .older_comments = Comentários mais antigos
+    .no_comments = Ainda não há comentários.
+    .translate_comments = Traduzir comentários
+    .show_original_comments = Mostrar originais
+    .translate_comment = Traduzir
+    .show_original_comment = Mostrar original
+    .comment_translation_limited = Limite de traduções atingido; os demais comentários aparecem como foram escritos.
+    .comment_translation_deferred = Alguns comentários ainda aparecem como foram escritos.
+    .translate_more_comments = Traduzir mais
+
+ui-images =
+    .eyebrow = Arquivo de ilustrações
+    .title = Galeria de imagens geradas
+    .summary = Veja o lado visual das histórias do Wibble e vá direto aos artigos a que elas pertencem.
+    .used_in = Usada em
+    .next_page = Próxima página
+    .empty_title = Ainda não há imagens na galeria
+    .empty_body = Quando os artigos terminarem de renderizar, as ilustrações aparecerão aqui.
+
+ui-image_info =
+    .kicker = Prompt da imagem
+    .metadata_aria = Metadados da imagem
+    .status = Status
+    .created = Criada
+    .model = Modelo
+    .last_error = Último erro
+    .used_in = Usada em
+
+ui-edit =
+    .eyebrow = Mesa editorial
+    .title = Editar artigo
+    .jump_to_images = Ir para imagens
+    .view_article = Ver artigo
+    .workspace_note = Este espaço está disponível para o autor do artigo e para admins. Autores logados podem revisar o texto do rascunho, trocar imagens e publicar quando estiverem prontos.
+    .agent_edit = Edição com agente
+    .agent_edit_body = Descreva a mudança em linguagem simples. O agente vai redigir uma revisão, resumir o que mudou e mostrar um diff antes de qualquer aplicação.
+    .change_request = Pedido de alteração
+    .change_request_placeholder = Exemplo: deixe a abertura mais seca, corte o terceiro trecho em um terço e faça o encerramento soar mais como um boletim oficial.
+    .owners_only = Só para autores e admins. Nada é aplicado até você aprovar a prévia.
+    .research_change = Pesquisar a mudança antes
+    .research_change_note = Busca algumas fontes públicas sobre a mudança pedida antes de reescrever. Usa sua cota de pesquisa.
+    .max_chars = Máximo de %MAX_CHARS% caracteres.
+    .preview_revision = Pré-visualizar revisão do agente
+    .manual_editor = Editor manual
+    .title_label = Título
+    .description_label = Descrição
+    .content_label = Conteúdo (Markdown)
+    .save_changes = Salvar alterações
+    .cancel = Cancelar
+    .raw_markdown_note = A edição direta em markdown continua disponível como saída de emergência se a prévia do agente não ficar precisa o bastante.
+    .images_title = Imagens
+    .images_body = Envie uma substituição em JPG, JPEG ou PNG, com até 12 MB. O arquivo é validado antes de sobrescrever a imagem armazenada.
+    .status_prefix = Status:
+    .last_error_prefix = Último erro:
+    .accepted_formats = Formatos aceitos: JPG, JPEG, PNG. Tamanho máximo: 12 MB.
+    .replace_image = Substituir imagem
+    .drop_hint = Ou solte um arquivo ou link de imagem sobre a figura.
+    .image_url_label = URL da imagem
+    .replace_from_url = Baixar e substituir
+    .image_url_note = Apenas links públicos http(s) para JPG ou PNG, com até 12 MB.
+    .framing_title = Enquadramento
+    .framing_note = Porcentagens da imagem completa. O recorte vale em todo o site; miniaturas e cartões sociais são centralizados no ponto focal. Clique na figura para definir o ponto focal.
+    .crop_x_label = Recorte à esquerda %
+    .crop_y_label = Recorte no topo %
+    .crop_width_label = Largura do recorte %
+    .crop_height_label = Altura do recorte %
+    .focal_x_label = Foco X %
+    .focal_y_label = Foco Y %
+    .save_framing = Salvar enquadramento
+    .regenerating = Regenerando…
+    .regenerate_prompt = Regenerar a partir do prompt
+    .reused_image_note = Esta imagem é compartilhada com um artigo anterior que usou o mesmo prompt. Regenere para obter uma renderização própria.
+    .alt_text_label = Texto alternativo
+    .caption_label = Legenda
+    .image_text_note = Estas alterações são traduzidas com o artigo; o markdown não é alterado.
+    .save_image_text = Salvar texto da imagem
+    .translations_title = Traduções
+    .translations_body = Compare as traduções automáticas com o original e corrija-as à mão.
+
+ui-edit_preview =
+    .eyebrow = Mesa editorial
+    .title = Prévia da edição com agente
+    .back_to_editor = Voltar ao editor
+    .view_article = Ver artigo
+    .requested_change = Alteração solicitada:
+    .agent_summary = Resumo do agente:
+    .research_sources = Fontes da pesquisa:
+    .prompt_version = Versão do prompt:
+    .field_preview = Prévia dos campos
+    .current = Atual
+    .proposed = Proposto
+    .diff = Diff
+    .title_label = Título
+    .description_label = Descrição
+    .markdown_label = Markdown
+    .apply_revision = Aplicar revisão do agente
+    .discard_preview = Descartar prévia
+
+ui-translation_review =
+    .eyebrow = Mesa editorial
+    .title = Revisar tradução
+    .back_to_editor = Voltar ao editor
+    .view_article = Ver artigo
+    .note = Os campos salvos substituem a tradução automática para os leitores. Editar o original depois os marca como pendentes de revisão. Esvazie um campo para voltar à tradução automática.
+    .source = Original
+    .translation = Tradução
+    .title_label = Título
+    .description_label = Descrição
+    .markdown_label = Conteúdo (Markdown)
+    .reviewed_by = Revisado por
+    .machine_translation = Tradução automática
+    .not_translated = Ainda não traduzido
+    .stale = O original mudou desde a revisão deste campo. Sua tradução anterior:
+    .save = Salvar tradução revisada
@@ -11,10 +11,15 @@ use tokio::sync::{Mutex, Semaphore};
 
 use crate::auth::JwksClient;
 use crate::error::Error;
-use crate::image_generator::replicate::ReplicateImageGenerator;
-use crate::image_generator::ImageGenerator;
+use crate::image_generator::{AsyncImageProvider, ImageGenerator};
 use crate::llm::Llm;
 use crate::rate_limit::RateLimitState;
+use crate::repositories::glossary::load_translation_glossary;
+use crate::repositories::languages::load_language_registry;
+use crate::repositories::research_domains::load_research_domain_policy;
+use crate::search_provider::SearchProvider;
+use crate::services::site_catalog::init_site_catalogs;
+use crate::sitemap::SitemapCache;
 
 mod background_jobs;
 mod db;
@@ -25,8 +30,8 @@ mod schema_compat;
 use background_jobs::bootstrap_background_jobs;
 use db::connect_database;
 use providers::{
-    build_bust_dir, build_image_providers, detect_template_auto_reload, init_templates,
-    log_startup_configuration, log_static_dir_diagnostics, read_runtime_limits,
+    build_bust_dir, build_image_providers, build_search_provider, detect_template_auto_reload,
+    init_templates, log_startup_configuration, log_static_dir_diagnostics, read_runtime_limits,
 };
 use runtime::build_runtime_state;
 use schema_compat::{
@@ -106,19 +111,25 @@ impl AppState {
         let db = connect_database().await?;
         apply_startup_schema_compatibility(&db).await;
         validate_required_schema(&db).await?;
+        load_language_registry(&db).await?;
+        load_translation_glossary(&db).await?;
+        load_research_domain_policy(&db).await?;
 
         let runtime_limits = read_runtime_limits();
         let jwks_client = JwksClient::new();
         let tera = init_templates()?;
+        init_site_catalogs()?;
         let template_auto_reload = detect_template_auto_reload();
         let llm = Llm::init();
         let rate_limit_state = RateLimitState::new();
-        let image_providers = build_image_providers();
+        let image_providers = build_image_providers(&db);
+        let search_provider = build_search_provider()?;
         let runtime_state = build_runtime_state(tera, template_auto_reload, runtime_limits);
 
         log_startup_configuration(
             &image_providers.requested_mode,
             &image_providers.name,
+            search_provider.name(),
             runtime_limits,
             startup_schema_compatibility_mode(),
         );
@@ -130,7 +141,8 @@ impl AppState {
             llm,
             image_generator: image_providers.generator,
             image_generator_name: image_providers.name,
-            replicate_image_generator: image_providers.replicate,
+            image_provider: image_providers.provider,
+            search_provider,
             bust_dir: build_bust_dir()?,
             rate_limit_state,
             template_auto_reload: runtime_state.template_auto_reload,
@@ -143,6 +155,7 @@ impl AppState {
             dead_link_recovery_max_per_day: runtime_state.dead_link_recovery_max_per_day,
             dead_link_recovery_timestamps: runtime_state.dead_link_recovery_timestamps,
             jwks_client,
+            sitemap_cache: SitemapCache::default(),
         };
 
         bootstrap_background_jobs(state.clone());
@@ -158,7 +171,8 @@ pub struct AppState {
     pub llm: Llm,
     pub image_generator: Arc<dyn ImageGenerator>,
     pub image_generator_name: String,
-    pub replicate_image_generator: Option<Arc<ReplicateImageGenerator>>,
+    pub image_provider: Arc<dyn AsyncImageProvider>,
+    pub search_provider: Arc<dyn SearchProvider>,
     pub bust_dir: BustDir,
     pub rate_limit_state: RateLimitState,
     pub template_auto_reload: bool,
@@ -171,4 +185,5 @@ pub struct AppState {
     pub dead_link_recovery_max_per_day: usize,
     pub dead_link_recovery_timestamps: Arc<Mutex<Vec<Instant>>>,
     pub jwks_client: JwksClient,
+    pub sitemap_cache: SitemapCache,
 }
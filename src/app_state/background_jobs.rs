@@ -4,6 +4,9 @@ use sea_orm::{ConnectionTrait, DatabaseConnection};
 
 use crate::hot_score::update_hot_score_statement;
 use crate::image_jobs;
+use crate::repositories::glossary::load_translation_glossary;
+use crate::repositories::languages::load_language_registry;
+use crate::repositories::research_domains::load_research_domain_policy;
 use crate::services::article_jobs;
 use crate::translation_jobs;
 
@@ -11,6 +14,7 @@ use super::AppState;
 
 pub fn bootstrap_background_jobs(state: AppState) {
     spawn_hot_score_update_loop(state.db.clone());
+    spawn_admin_settings_reload_loop(state.db.clone());
     article_jobs::spawn_resume_loop(state.clone());
     image_jobs::spawn_resume_loop(state.clone());
     translation_jobs::spawn_resume_loop(state);
@@ -32,6 +36,37 @@ fn spawn_hot_score_update_loop(db: DatabaseConnection) {
     });
 }
 
+/// Admin saves reload these settings in the instance that handled them; this loop
+/// brings every other instance up to date.
+fn spawn_admin_settings_reload_loop(db: DatabaseConnection) {
+    let interval_seconds = admin_settings_reload_interval_seconds();
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(interval_seconds));
+        loop {
+            interval.tick().await;
+            if let Err(err) = load_language_registry(&db).await {
+                eprintln!("Error reloading language registry: {}", err);
+            }
+            if let Err(err) = load_translation_glossary(&db).await {
+                eprintln!("Error reloading translation glossary: {}", err);
+            }
+            if let Err(err) = load_research_domain_policy(&db).await {
+                eprintln!("Error reloading research domain policy: {}", err);
+            }
+        }
+    });
+}
+
+fn admin_settings_reload_interval_seconds() -> u64 {
+    env::var("ADMIN_SETTINGS_RELOAD_SECONDS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .filter(|value| *value > 0)
+        .unwrap_or(30)
+}
+
 fn hot_score_update_interval_seconds() -> u64 {
     env::var("HOT_SCORE_UPDATE_SECONDS")
         .ok()
@@ -2,6 +2,7 @@ use std::env;
 use std::sync::Arc;
 
 use bustdir::BustDir;
+use sea_orm::DatabaseConnection;
 use tera::Tera;
 
 use crate::error::Error;
@@ -9,7 +10,11 @@ use crate::image_generator::ai_horde::AiHordeImageGenerator;
 use crate::image_generator::huggingface::HuggingFaceImageGenerator;
 use crate::image_generator::replicate::ReplicateImageGenerator;
 use crate::image_generator::stability::StabilityImageGenerator;
-use crate::image_generator::ImageGenerator;
+use crate::image_generator::{AsyncImageProvider, ImageGenerator};
+use crate::search_provider::duckduckgo::DuckDuckGoSearchProvider;
+use crate::search_provider::local_index::LocalIndexSearchProvider;
+use crate::search_provider::searxng::SearxngSearchProvider;
+use crate::search_provider::SearchProvider;
 
 use super::runtime::RuntimeLimits;
 
@@ -17,7 +22,7 @@ pub struct ImageProviders {
     pub requested_mode: String,
     pub name: String,
     pub generator: Arc<dyn ImageGenerator>,
-    pub replicate: Option<Arc<ReplicateImageGenerator>>,
+    pub provider: Arc<dyn AsyncImageProvider>,
 }
 
 pub fn init_templates() -> Result<Tera, Error> {
@@ -43,36 +48,39 @@ pub fn read_runtime_limits() -> RuntimeLimits {
     }
 }
 
-pub fn build_image_providers() -> ImageProviders {
+pub fn build_image_providers(db: &DatabaseConnection) -> ImageProviders {
     let image_mode = env::var("IMAGE_MODE").unwrap_or_default();
 
     if image_mode == "sd3" {
         println!("Using SD3");
+        let stability = Arc::new(StabilityImageGenerator::new());
         return ImageProviders {
             requested_mode: image_mode,
             name: "sd3".to_string(),
-            generator: Arc::new(StabilityImageGenerator::new()),
-            replicate: None,
+            generator: stability.clone() as Arc<dyn ImageGenerator>,
+            provider: stability,
         };
     }
 
     if image_mode == "horde" {
         println!("Using Horde");
+        let horde = Arc::new(AiHordeImageGenerator::new().with_ledger(db.clone()));
         return ImageProviders {
             requested_mode: image_mode,
             name: "horde".to_string(),
-            generator: Arc::new(AiHordeImageGenerator::new()),
-            replicate: None,
+            generator: horde.clone() as Arc<dyn ImageGenerator>,
+            provider: horde,
         };
     }
 
     if image_mode == "huggingface" {
         println!("Using Hugging Face");
+        let huggingface = Arc::new(HuggingFaceImageGenerator::new());
         return ImageProviders {
             requested_mode: image_mode,
             name: "huggingface".to_string(),
-            generator: Arc::new(HuggingFaceImageGenerator::new()),
-            replicate: None,
+            generator: huggingface.clone() as Arc<dyn ImageGenerator>,
+            provider: huggingface,
         };
     }
 
@@ -82,10 +90,28 @@ pub fn build_image_providers() -> ImageProviders {
         requested_mode: image_mode,
         name: "replicate".to_string(),
         generator: replicate.clone() as Arc<dyn ImageGenerator>,
-        replicate: Some(replicate),
+        provider: replicate,
     }
 }
 
+pub fn build_search_provider() -> Result<Arc<dyn SearchProvider>, Error> {
+    let provider: Arc<dyn SearchProvider> = match env::var("RESEARCH_SEARCH_PROVIDER")
+        .unwrap_or_default()
+        .as_str()
+    {
+        "searxng" => Arc::new(SearxngSearchProvider::new()?),
+        "local" => Arc::new(LocalIndexSearchProvider::new()?),
+        "" | "duckduckgo" => Arc::new(DuckDuckGoSearchProvider::new()?),
+        other => {
+            return Err(Error::Llm(format!(
+                "Unknown RESEARCH_SEARCH_PROVIDER {}; expected duckduckgo, searxng or local",
+                other
+            )))
+        }
+    };
+    Ok(provider)
+}
+
 pub fn build_bust_dir() -> Result<BustDir, Error> {
     BustDir::new("static").map_err(|e| Error::Storage(format!("Failed to build bust dir: {}", e)))
 }
@@ -93,6 +119,7 @@ pub fn build_bust_dir() -> Result<BustDir, Error> {
 pub fn log_startup_configuration(
     requested_image_mode: &str,
     image_provider_name: &str,
+    search_provider_name: &str,
     runtime_limits: RuntimeLimits,
     schema_compatibility_mode: &str,
 ) {
@@ -103,6 +130,7 @@ pub fn log_startup_configuration(
     };
     println!("IMAGE_MODE={}", requested_image_mode);
     println!("Image provider: {}", image_provider_name);
+    println!("Research search provider: {}", search_provider_name);
     println!(
         "MAX_CONCURRENT_ARTICLE_GENERATIONS={}",
         runtime_limits.max_concurrent_article_generations
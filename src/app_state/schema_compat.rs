@@ -29,6 +29,145 @@ const ASYNC_IMAGE_JOB_COMPATIBILITY: &[&str] = &[
        ON "public"."content_image"("status", "created_at")"#,
 ];
 
+const IMAGE_TEXT_COMPATIBILITY: &[&str] = &[r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "caption" VARCHAR(1000)"#];
+
+const IMAGE_REUSE_COMPATIBILITY: &[&str] = &[
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "source_image_id" VARCHAR(100)"#,
+    r#"CREATE INDEX IF NOT EXISTS "content_image_prompt_hash_status_idx"
+       ON "public"."content_image"("prompt_hash", "status")"#,
+    r#"CREATE INDEX IF NOT EXISTS "content_image_source_image_id_idx"
+       ON "public"."content_image"("source_image_id")"#,
+];
+
+const LANGUAGE_REGISTRY_COMPATIBILITY: &[&str] = &[
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "code" VARCHAR(16)"#,
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "aliases" VARCHAR(1000) NOT NULL DEFAULT ''"#,
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "direction" VARCHAR(3) NOT NULL DEFAULT 'ltr'"#,
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "enabled" BOOLEAN NOT NULL DEFAULT false"#,
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "site_locale" BOOLEAN NOT NULL DEFAULT false"#,
+    r#"ALTER TABLE "public"."language"
+       ADD COLUMN IF NOT EXISTS "sort_order" INTEGER NOT NULL DEFAULT 0"#,
+    r#"CREATE UNIQUE INDEX IF NOT EXISTS "language_code_key"
+       ON "public"."language"("code")"#,
+];
+
+const TRANSLATION_GLOSSARY_COMPATIBILITY: &[&str] = &[
+    r#"CREATE TABLE IF NOT EXISTS "public"."translation_glossary" (
+        "id" VARCHAR(36) PRIMARY KEY,
+        "term" VARCHAR(200) NOT NULL,
+        "language_code" VARCHAR(16),
+        "translation" VARCHAR(200),
+        "created_at" TIMESTAMP(6) NOT NULL DEFAULT NOW(),
+        "updated_at" TIMESTAMP(6) NOT NULL DEFAULT NOW()
+    )"#,
+    r#"CREATE INDEX IF NOT EXISTS "translation_glossary_language_code_idx"
+       ON "public"."translation_glossary"("language_code")"#,
+];
+
+const RESEARCH_SOURCE_COMPATIBILITY: &[&str] = &[
+    r#"CREATE TABLE IF NOT EXISTS "public"."research_source" (
+        "url_hash" VARCHAR(64) PRIMARY KEY,
+        "url" TEXT NOT NULL,
+        "title" VARCHAR(1000) NOT NULL,
+        "domain" VARCHAR(255) NOT NULL,
+        "snippet" TEXT NOT NULL,
+        "search_query" VARCHAR(1000) NOT NULL,
+        "content" TEXT NOT NULL,
+        "content_hash" VARCHAR(64) NOT NULL,
+        "fetched_at" TIMESTAMP(6) NOT NULL DEFAULT NOW(),
+        "ttl_seconds" INTEGER NOT NULL
+    )"#,
+    r#"CREATE INDEX IF NOT EXISTS "research_source_search_query_fetched_at_idx"
+       ON "public"."research_source"("search_query", "fetched_at")"#,
+    r#"ALTER TABLE "public"."research_source"
+       ADD COLUMN IF NOT EXISTS "published_on" DATE"#,
+];
+
+const RESEARCH_DOMAIN_POLICY_COMPATIBILITY: &[&str] = &[
+    r#"CREATE TABLE IF NOT EXISTS "public"."research_domain_policy" (
+        "id" VARCHAR(36) PRIMARY KEY,
+        "pattern" VARCHAR(255) NOT NULL,
+        "action" VARCHAR(16) NOT NULL,
+        "score" INTEGER NOT NULL DEFAULT 10,
+        "leak_markers" VARCHAR(1000) NOT NULL DEFAULT '',
+        "created_at" TIMESTAMP(6) NOT NULL DEFAULT NOW(),
+        "updated_at" TIMESTAMP(6) NOT NULL DEFAULT NOW()
+    )"#,
+    r#"CREATE UNIQUE INDEX IF NOT EXISTS "research_domain_policy_pattern_key"
+       ON "public"."research_domain_policy"("pattern")"#,
+    // Same rows as the research_domain_policy migrations. Only an empty table is seeded, so
+    // rules an admin deletes stay deleted unless every rule is removed.
+    r#"INSERT INTO "public"."research_domain_policy" ("id", "pattern", "action", "score", "leak_markers")
+       SELECT * FROM (VALUES
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a01', '*.gov', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a02', '*.gov.*', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a03', '*parliament*', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a04', '*senate*', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a05', '*ministry*', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a06', '*.edu', 'boost', 30, ''),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a07', 'reuters.com', 'boost', 20, 'reuters'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a08', 'apnews.com', 'boost', 20, 'apnews,associated press'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a09', 'bbc.com', 'boost', 20, 'bbc'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0a', 'npr.org', 'boost', 20, 'npr'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0b', 'ft.com', 'boost', 20, 'financial times'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0c', 'economist.com', 'boost', 20, 'economist'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0d', 'bbc.co.uk', 'boost', 20, 'bbc'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0e', 'bbc.*', 'boost', 20, 'bbc'),
+           ('5b1f0c52-0d7e-4c1a-9f61-1d0c6a0e0a0f', '*.bbc.*', 'boost', 20, 'bbc')
+       ) AS seed ("id", "pattern", "action", "score", "leak_markers")
+       WHERE NOT EXISTS (SELECT 1 FROM "public"."research_domain_policy")
+       ON CONFLICT DO NOTHING"#,
+];
+
+const RESEARCH_TRACE_COMPATIBILITY: &[&str] = &[
+    r#"CREATE TABLE IF NOT EXISTS "public"."research_trace" (
+        "job_id" VARCHAR(36) PRIMARY KEY,
+        "search_provider" VARCHAR(32) NOT NULL,
+        "source_count" INTEGER NOT NULL DEFAULT 0,
+        "failed" BOOLEAN NOT NULL DEFAULT false,
+        "trace" TEXT NOT NULL,
+        "created_at" TIMESTAMP(6) NOT NULL DEFAULT NOW(),
+        "updated_at" TIMESTAMP(6) NOT NULL DEFAULT NOW()
+    )"#,
+    r#"CREATE INDEX IF NOT EXISTS "research_trace_updated_at_idx"
+       ON "public"."research_trace"("updated_at")"#,
+];
+
+const IMAGE_FRAMING_COMPATIBILITY: &[&str] = &[
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "crop_x" DOUBLE PRECISION"#,
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "crop_y" DOUBLE PRECISION"#,
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "crop_width" DOUBLE PRECISION"#,
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "crop_height" DOUBLE PRECISION"#,
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "focal_x" DOUBLE PRECISION"#,
+    r#"ALTER TABLE "public"."content_image"
+       ADD COLUMN IF NOT EXISTS "focal_y" DOUBLE PRECISION"#,
+];
+
+const HORDE_LOG_COMPATIBILITY: &[&str] = &[
+    r#"ALTER TABLE "public"."horde_log"
+       ADD COLUMN IF NOT EXISTS "event" VARCHAR(32) NOT NULL DEFAULT 'submission'"#,
+    r#"ALTER TABLE "public"."horde_log"
+       ADD COLUMN IF NOT EXISTS "request_id" VARCHAR(100)"#,
+    r#"ALTER TABLE "public"."horde_log"
+       ADD COLUMN IF NOT EXISTS "worker_model" VARCHAR(200)"#,
+    r#"ALTER TABLE "public"."horde_log"
+       ADD COLUMN IF NOT EXISTS "wait_seconds" INTEGER"#,
+    r#"CREATE INDEX IF NOT EXISTS "horde_log_event_created_at_idx"
+       ON "public"."horde_log"("event", "created_at")"#,
+];
+
 const AUTH_COMPATIBILITY: &[&str] = &[
     r#"ALTER TABLE "public"."content"
        ADD COLUMN IF NOT EXISTS "author_email" VARCHAR(350)"#,
@@ -63,6 +202,8 @@ const COMMENT_TABLE_COMPATIBILITY: &[&str] = &[
         "body" TEXT NOT NULL,
         "created_at" TIMESTAMP(6) DEFAULT NOW()
     )"#,
+    r#"ALTER TABLE "public"."content_comment"
+       ADD COLUMN IF NOT EXISTS "language_code" VARCHAR(16)"#,
     r#"CREATE INDEX IF NOT EXISTS "idx_content_comment_content_created_at"
        ON "public"."content_comment"("content_id", "created_at")"#,
     r#"CREATE INDEX IF NOT EXISTS "idx_content_comment_user_created_at"
@@ -102,6 +243,27 @@ const TRANSLATION_JOB_COMPATIBILITY: &[&str] = &[
        ON "public"."translation_job"("article_id")"#,
     r#"CREATE INDEX IF NOT EXISTS "idx_translation_job_status_priority_created_at"
        ON "public"."translation_job"("status", "priority", "created_at")"#,
+    r#"ALTER TABLE "public"."translation_job"
+       ADD COLUMN IF NOT EXISTS "segments_total" INTEGER"#,
+    r#"ALTER TABLE "public"."translation_job"
+       ADD COLUMN IF NOT EXISTS "segments_reused" INTEGER"#,
+];
+
+const TRANSLATION_OVERRIDE_COMPATIBILITY: &[&str] = &[
+    r#"CREATE TABLE IF NOT EXISTS "public"."translation_override" (
+        "id" VARCHAR(36) PRIMARY KEY,
+        "article_id" VARCHAR(36) NOT NULL REFERENCES "public"."content"("id") ON DELETE CASCADE ON UPDATE NO ACTION,
+        "language_code" VARCHAR(16) NOT NULL,
+        "field" VARCHAR(32) NOT NULL,
+        "source_hash" VARCHAR(100) NOT NULL,
+        "translation" TEXT NOT NULL,
+        "reviewed_by" VARCHAR(350) NOT NULL,
+        "stale" BOOLEAN NOT NULL DEFAULT false,
+        "created_at" TIMESTAMP(6) NOT NULL DEFAULT NOW(),
+        "updated_at" TIMESTAMP(6) NOT NULL DEFAULT NOW()
+    )"#,
+    r#"CREATE UNIQUE INDEX IF NOT EXISTS "translation_override_article_language_field_key"
+       ON "public"."translation_override"("article_id", "language_code", "field")"#,
 ];
 
 const ARTICLE_JOB_COMPATIBILITY: &[&str] = &[
@@ -130,6 +292,8 @@ const ARTICLE_JOB_COMPATIBILITY: &[&str] = &[
        ON "public"."article_job"("status", "phase", "created_at")"#,
     r#"CREATE INDEX IF NOT EXISTS "idx_article_job_requester_key_created_at"
        ON "public"."article_job"("requester_key", "created_at")"#,
+    r#"ALTER TABLE "public"."article_job"
+       ADD COLUMN IF NOT EXISTS "language_code" VARCHAR(16)"#,
 ];
 
 pub fn startup_schema_compatibility_mode() -> &'static str {
@@ -143,6 +307,60 @@ pub async fn apply_startup_schema_compatibility(db: &DatabaseConnection) {
         "async image job schema compatibility",
     )
     .await;
+    apply_compatibility_statements(
+        db,
+        IMAGE_TEXT_COMPATIBILITY,
+        "image text schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        IMAGE_REUSE_COMPATIBILITY,
+        "image reuse schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        IMAGE_FRAMING_COMPATIBILITY,
+        "image framing schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        LANGUAGE_REGISTRY_COMPATIBILITY,
+        "language registry schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        TRANSLATION_GLOSSARY_COMPATIBILITY,
+        "translation glossary schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        RESEARCH_SOURCE_COMPATIBILITY,
+        "research source schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        RESEARCH_DOMAIN_POLICY_COMPATIBILITY,
+        "research domain policy schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        RESEARCH_TRACE_COMPATIBILITY,
+        "research trace schema compatibility",
+    )
+    .await;
+    apply_compatibility_statements(
+        db,
+        HORDE_LOG_COMPATIBILITY,
+        "horde log schema compatibility",
+    )
+    .await;
     apply_compatibility_statements(db, AUTH_COMPATIBILITY, "auth schema compatibility").await;
     apply_compatibility_statements(
         db,
@@ -156,6 +374,12 @@ pub async fn apply_startup_schema_compatibility(db: &DatabaseConnection) {
         "translation job schema compatibility",
     )
     .await;
+    apply_compatibility_statements(
+        db,
+        TRANSLATION_OVERRIDE_COMPATIBILITY,
+        "translation override schema compatibility",
+    )
+    .await;
     apply_compatibility_statements(
         db,
         ARTICLE_JOB_COMPATIBILITY,
@@ -123,13 +123,13 @@ async fn main() {
 
         // Checa imagem principal (image_id) se houver
         if let Some(ref image_id) = c.image_id {
-            if !file_exists(&images_dir_path, image_id) {
+            if !file_exists(&images_dir_path, storage_key(images, image_id)) {
                 missing.push(image_id.clone());
             }
         }
         // Checa todas as content_image
         for img in images.iter() {
-            if !file_exists(&images_dir_path, &img.id) {
+            if !file_exists(&images_dir_path, storage_key(images, &img.id)) {
                 missing.push(img.id.clone());
             }
         }
@@ -252,6 +252,15 @@ async fn main() {
     );
 }
 
+// Imagens reaproveitadas de outro artigo (source_image_id) usam o arquivo da origem
+fn storage_key<'a>(images: &'a [content_image::Model], image_id: &'a str) -> &'a str {
+    images
+        .iter()
+        .find(|img| img.id == image_id)
+        .and_then(|img| img.source_image_id.as_deref())
+        .unwrap_or(image_id)
+}
+
 fn file_exists(base: &Path, id: &str) -> bool {
     let p = base.join(format!("{}.jpg", id));
     fs::metadata(&p).is_ok()
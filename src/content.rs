@@ -10,7 +10,7 @@ mod policy;
 mod query;
 mod render;
 
-pub use comments::{normalize_comment_body, normalize_comments_page};
+pub use comments::{normalize_comment_body, normalize_comments_page, CommentTranslationRequest};
 pub use policy::{article_accepts_public_interactions, can_view_article};
 pub use query::{find_article_by_slug, require_article_by_slug};
 
@@ -26,6 +26,7 @@ pub trait GetContent {
         slug: &str,
         source: Option<&str>,
         comments_page: Option<u64>,
+        translate_comments: Option<&str>,
         requested_language: Option<SupportedTranslationLanguage>,
     ) -> Result<Html<String>, Error>;
     async fn get_content_paged(
@@ -50,9 +51,18 @@ impl GetContent for WibbleRequest {
         slug: &str,
         source: Option<&str>,
         comments_page: Option<u64>,
+        translate_comments: Option<&str>,
         requested_language: Option<SupportedTranslationLanguage>,
     ) -> Result<Html<String>, Error> {
-        page::render_content_page(self, slug, source, comments_page, requested_language).await
+        page::render_content_page(
+            self,
+            slug,
+            source,
+            comments_page,
+            &CommentTranslationRequest::from_query(translate_comments),
+            requested_language,
+        )
+        .await
     }
 }
 
@@ -61,6 +71,7 @@ mod tests {
     use axum::response::Html;
     use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait};
 
+    use crate::article_id::canonical_article_id;
     use crate::entities::{
         article_job, content, prelude::ArticleJob, prelude::Content, prelude::TranslationJob,
     };
@@ -69,7 +80,10 @@ mod tests {
     use crate::services::article_jobs::{
         ArticleJobService, ARTICLE_JOB_PHASE_COMPLETED, ARTICLE_JOB_STATUS_COMPLETED,
     };
-    use crate::services::article_language::resolve_article_language;
+    use crate::services::article_language::{article_source_language, resolve_article_language};
+    use crate::services::article_translations::{
+        ensure_cached_article_translation, ArticleSourceText,
+    };
     use crate::services::site_text::{default_site_language, site_text};
     use crate::test_support::{preferred_language, TestContext};
     use crate::wibble_request::WibbleRequest;
@@ -112,6 +126,7 @@ mod tests {
             longview_count: ActiveValue::set(0),
             impression_count: ActiveValue::set(0),
             click_count: ActiveValue::set(0),
+            language_id: ActiveValue::set(None),
             author_email: ActiveValue::set(None),
             published: ActiveValue::set(true),
             recovered_from_dead_link: ActiveValue::set(false),
@@ -136,7 +151,7 @@ mod tests {
     fn article_language_href_uses_query_param_when_override_exists() {
         assert_eq!(
             article_language_href(
-                find_supported_translation_language("en").unwrap(),
+                &find_supported_translation_language("en").unwrap(),
                 "story-slug",
                 Some("pt"),
             ),
@@ -147,19 +162,20 @@ mod tests {
     #[test]
     fn automatic_language_option_is_active_without_explicit_override() {
         let selection = resolve_article_language(
+            &article_source_language(),
             None,
             None,
             None,
-            find_supported_translation_language("pt"),
+            find_supported_translation_language("pt").as_ref(),
             &[],
         );
 
         let options = build_article_language_options(
             "story-slug",
-            site_text(default_site_language()),
-            default_site_language(),
-            selection,
-            find_supported_translation_language("pt"),
+            &site_text(&default_site_language()),
+            &default_site_language(),
+            &selection,
+            find_supported_translation_language("pt").as_ref(),
         );
 
         assert!(options[0].active);
@@ -169,7 +185,8 @@ mod tests {
     #[test]
     fn requested_language_option_stays_active_while_falling_back() {
         let selection = resolve_article_language(
-            find_supported_translation_language("pt"),
+            &article_source_language(),
+            find_supported_translation_language("pt").as_ref(),
             None,
             None,
             None,
@@ -178,9 +195,9 @@ mod tests {
 
         let options = build_article_language_options(
             "story-slug",
-            site_text(default_site_language()),
-            default_site_language(),
-            selection,
+            &site_text(&default_site_language()),
+            &default_site_language(),
+            &selection,
             None,
         );
         let portuguese = options
@@ -195,19 +212,20 @@ mod tests {
     #[test]
     fn saved_language_option_is_marked_active_from_cookie_preference() {
         let selection = resolve_article_language(
+            &article_source_language(),
             None,
             None,
-            find_supported_translation_language("pt"),
-            find_supported_translation_language("fr"),
+            find_supported_translation_language("pt").as_ref(),
+            find_supported_translation_language("fr").as_ref(),
             &[],
         );
 
         let options = build_article_language_options(
             "story-slug",
-            site_text(default_site_language()),
-            default_site_language(),
-            selection,
-            find_supported_translation_language("fr"),
+            &site_text(&default_site_language()),
+            &default_site_language(),
+            &selection,
+            find_supported_translation_language("fr").as_ref(),
         );
         let portuguese = options
             .iter()
@@ -221,19 +239,20 @@ mod tests {
     #[test]
     fn saved_language_option_notes_saved_preference_when_translation_is_available() {
         let selection = resolve_article_language(
+            &article_source_language(),
             None,
             None,
-            find_supported_translation_language("pt"),
-            find_supported_translation_language("fr"),
+            find_supported_translation_language("pt").as_ref(),
+            find_supported_translation_language("fr").as_ref(),
             &[find_supported_translation_language("pt").unwrap()],
         );
 
         let options = build_article_language_options(
             "story-slug",
-            site_text(default_site_language()),
-            default_site_language(),
-            selection,
-            find_supported_translation_language("fr"),
+            &site_text(&default_site_language()),
+            &default_site_language(),
+            &selection,
+            find_supported_translation_language("fr").as_ref(),
         );
         let portuguese = options
             .iter()
@@ -247,7 +266,7 @@ mod tests {
     #[test]
     fn research_metadata_parses_manual_mode_and_source_count() {
         let metadata = parse_article_research_metadata(
-            site_text(default_site_language()),
+            &site_text(&default_site_language()),
             Some(r#"{"research":{"mode":"manual","source_count":3}}"#),
         )
         .unwrap();
@@ -259,7 +278,7 @@ mod tests {
     #[test]
     fn research_metadata_ignores_missing_sources() {
         assert!(parse_article_research_metadata(
-            site_text(default_site_language()),
+            &site_text(&default_site_language()),
             Some(r#"{"research":{"mode":"auto","source_count":0}}"#,)
         )
         .is_none());
@@ -292,6 +311,7 @@ mod tests {
             updated_at: ActiveValue::set(chrono::Utc::now().naive_utc()),
             started_at: ActiveValue::set(None),
             finished_at: ActiveValue::set(None),
+            language_code: ActiveValue::set(None),
         })
         .exec(&ctx.state.db)
         .await
@@ -312,6 +332,7 @@ mod tests {
                 "research-bulletin",
                 None,
                 None,
+                None,
                 Some(preferred_language("pt")),
             )
             .await
@@ -336,7 +357,7 @@ mod tests {
             .unwrap();
 
         let Html(html) = sample_request(ctx.state.clone(), "/en/content/stale-bulletin")
-            .get_content("stale-bulletin", None, None, None)
+            .get_content("stale-bulletin", None, None, None, None)
             .await
             .unwrap();
         let article = Content::find_by_id("story-2".to_string())
@@ -352,4 +373,70 @@ mod tests {
             "expected generating flag to clear after serving content"
         );
     }
+
+    struct ArabicTranslator;
+
+    impl crate::llm::translate::Translate for ArabicTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            _target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
+        ) -> Result<String, crate::error::Error> {
+            Ok(text
+                .split("\n\n")
+                .map(|block| {
+                    let sentence = format!(
+                        "قالت لجنة Harbor Authority إن المذكرة رقم {} إجرائية.",
+                        block.len()
+                    );
+                    match block.strip_prefix("## ") {
+                        Some(_) => format!("## {}", sentence),
+                        None => sentence,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"))
+        }
+    }
+
+    #[tokio::test]
+    async fn rtl_translation_renders_isolated_article_inside_ltr_page() {
+        let ctx = TestContext::new().await;
+        let article = sample_article("story-3", "harbor-bulletin", "Harbor Bulletin", false)
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        let article_id = canonical_article_id(&article.id);
+        let markdown = article.markdown.clone().unwrap();
+        ensure_cached_article_translation(
+            &ArabicTranslator,
+            &ctx.state.db,
+            &ArticleSourceText {
+                article_id: &article_id,
+                source_language: article_source_language(),
+                title: &article.title,
+                description: &article.description,
+                markdown: &markdown,
+                images: &[],
+            },
+            &preferred_language("ar"),
+        )
+        .await
+        .unwrap();
+
+        let Html(html) = sample_request(ctx.state.clone(), "/en/content/harbor-bulletin")
+            .get_content(
+                "harbor-bulletin",
+                None,
+                None,
+                None,
+                Some(preferred_language("ar")),
+            )
+            .await
+            .unwrap();
+
+        assert!(html.contains(r#"<html lang="en" dir="ltr">"#));
+        assert!(html.contains(r#"<div class="article-body" lang="ar" dir="rtl">"#));
+        assert!(html.contains("<bdi>Harbor Authority</bdi>"));
+    }
 }
@@ -1,3 +1,4 @@
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
     QuerySelect,
@@ -6,14 +7,70 @@ use serde::Serialize;
 
 use crate::entities::{content_comment, prelude::*};
 use crate::error::Error;
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, SupportedTranslationLanguage,
+};
+use crate::services::comment_translations::{
+    comment_needs_translation, detect_comment_language, translate_comments, CommentSourceText,
+};
+use crate::services::site_text::SiteText;
+use crate::wibble_request::WibbleRequest;
 
 const COMMENTS_PER_PAGE: u64 = 50;
+const TRANSLATE_ALL_COMMENTS: &str = "all";
 
 #[derive(Serialize)]
 pub struct CommentView {
+    id: String,
     user_name: String,
     body: String,
     created_at: String,
+    #[serde(skip)]
+    language_code: Option<String>,
+    translatable: bool,
+    translated_body: Option<String>,
+    translated_note: Option<String>,
+}
+
+impl CommentView {
+    pub fn is_translatable(&self) -> bool {
+        self.translatable
+    }
+
+    fn source_text(&self) -> CommentSourceText<'_> {
+        CommentSourceText {
+            comment_id: &self.id,
+            body: &self.body,
+            language_code: self.language_code.as_deref(),
+        }
+    }
+}
+
+/// Which comments the reader asked to see translated, from `?translate_comments=`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum CommentTranslationRequest {
+    #[default]
+    Originals,
+    All,
+    Comment(String),
+}
+
+impl CommentTranslationRequest {
+    pub fn from_query(raw: Option<&str>) -> Self {
+        match raw.map(str::trim) {
+            None | Some("") => Self::Originals,
+            Some(TRANSLATE_ALL_COMMENTS) => Self::All,
+            Some(comment_id) => Self::Comment(comment_id.to_string()),
+        }
+    }
+
+    fn includes(&self, comment_id: &str) -> bool {
+        match self {
+            Self::Originals => false,
+            Self::All => true,
+            Self::Comment(requested) => requested == comment_id,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -30,6 +87,10 @@ pub struct CommentPage {
     pub comments: Vec<CommentView>,
     pub comment_count: u64,
     pub pager: CommentPager,
+    /// Set when the translation rate limit left some requested comments untranslated.
+    pub translation_limited: bool,
+    /// Set when the per-request cap left some requested comments for a later request.
+    pub translation_deferred: bool,
 }
 
 impl CommentPage {
@@ -37,6 +98,8 @@ impl CommentPage {
         Self {
             comments: Vec::new(),
             comment_count: 0,
+            translation_limited: false,
+            translation_deferred: false,
             pager: CommentPager {
                 current_page: 1,
                 total_pages: 1,
@@ -97,12 +160,19 @@ pub async fn load_comment_page(
         comments: comments
             .into_iter()
             .map(|comment| CommentView {
+                id: comment.id,
                 user_name: comment.user_name,
                 body: comment.body,
                 created_at: comment.created_at.format("%F %R").to_string(),
+                language_code: comment.language_code,
+                translatable: false,
+                translated_body: None,
+                translated_note: None,
             })
             .collect(),
         comment_count,
+        translation_limited: false,
+        translation_deferred: false,
         pager: CommentPager {
             current_page,
             total_pages,
@@ -114,9 +184,95 @@ pub async fn load_comment_page(
     })
 }
 
+/// Stores the detected language of comments written before detection existed,
+/// so the lookup only runs once per comment.
+async fn backfill_comment_languages(
+    db: &DatabaseConnection,
+    comments: &mut [CommentView],
+) -> Result<(), Error> {
+    for comment in comments
+        .iter_mut()
+        .filter(|comment| comment.language_code.is_none())
+    {
+        let Some(language) = detect_comment_language(&comment.body) else {
+            continue;
+        };
+        ContentComment::update_many()
+            .col_expr(
+                content_comment::Column::LanguageCode,
+                Expr::value(language.code.as_ref()),
+            )
+            .filter(content_comment::Column::Id.eq(comment.id.clone()))
+            .exec(db)
+            .await
+            .map_err(|e| Error::Database(format!("Error storing comment language: {}", e)))?;
+        comment.language_code = Some(language.code.to_string());
+    }
+    Ok(())
+}
+
+fn comment_translated_note(text: &SiteText, language_code: Option<&str>) -> String {
+    match language_code.and_then(find_supported_translation_language) {
+        Some(language) => text.comment_translated_from(&text.translation_language_name(&language)),
+        None => text.comment_translated().to_string(),
+    }
+}
+
+/// Marks which comments can be translated into `language` and fills in the
+/// translations the reader asked for.
+pub async fn translate_comment_page(
+    request: &WibbleRequest,
+    page: &mut CommentPage,
+    translation_request: &CommentTranslationRequest,
+    language: &SupportedTranslationLanguage,
+) -> Result<(), Error> {
+    if page.comments.is_empty() {
+        return Ok(());
+    }
+    backfill_comment_languages(&request.state.db, &mut page.comments).await?;
+    for comment in &mut page.comments {
+        comment.translatable = comment_needs_translation(comment.source_text(), language);
+    }
+
+    let requested = page
+        .comments
+        .iter()
+        .filter(|comment| comment.translatable && translation_request.includes(&comment.id))
+        .map(CommentView::source_text)
+        .collect::<Vec<_>>();
+    if requested.is_empty() {
+        return Ok(());
+    }
+    let translations = translate_comments(
+        &request.state.llm,
+        &request.state.db,
+        &request.state.rate_limit_state,
+        &requested,
+        language,
+        request.requester_tier,
+        &request.rate_limit_key,
+    )
+    .await?;
+
+    let text = request.site_text();
+    page.translation_limited = translations.rate_limited;
+    page.translation_deferred = translations.deferred;
+    let mut texts = translations.texts;
+    for comment in &mut page.comments {
+        if let Some(translated) = texts.remove(&comment.id) {
+            comment.translated_note = Some(comment_translated_note(
+                &text,
+                comment.language_code.as_deref(),
+            ));
+            comment.translated_body = Some(translated);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{normalize_comment_body, normalize_comments_page};
+    use super::{normalize_comment_body, normalize_comments_page, CommentTranslationRequest};
 
     #[test]
     fn normalizes_comment_body() {
@@ -133,4 +289,20 @@ mod tests {
         assert_eq!(normalize_comments_page(Some(0)), 1);
         assert_eq!(normalize_comments_page(Some(3)), 3);
     }
+
+    #[test]
+    fn parses_comment_translation_requests() {
+        assert_eq!(
+            CommentTranslationRequest::from_query(None),
+            CommentTranslationRequest::Originals
+        );
+        assert_eq!(
+            CommentTranslationRequest::from_query(Some("all")),
+            CommentTranslationRequest::All
+        );
+        assert_eq!(
+            CommentTranslationRequest::from_query(Some("comment-1")),
+            CommentTranslationRequest::Comment("comment-1".to_string())
+        );
+    }
 }
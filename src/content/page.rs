@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use axum::response::Html;
 use serde::Serialize;
 use serde_json::Value;
@@ -5,13 +7,15 @@ use serde_json::Value;
 use crate::error::Error;
 use crate::llm::prompt_registry::{supported_translation_languages, SupportedTranslationLanguage};
 use crate::permissions::{can_edit_article, can_toggle_publish};
+use crate::repositories::images::find_article_images;
 use crate::services::article_jobs::ArticleJobService;
 use crate::services::article_language::{
-    resolve_article_language, ArticleLanguageSelection, PreferredLanguageSource,
-    ServedLanguageSource,
+    article_alternate_languages, article_source_language_for, resolve_article_language,
+    ArticleLanguageSelection, PreferredLanguageSource, ServedLanguageSource,
 };
 use crate::services::article_translations::{
-    cached_translation_languages, load_cached_article_translation, ArticleSourceText,
+    article_image_source_texts, cached_translation_languages, load_cached_article_translation,
+    ArticleSourceText, CachedArticleTranslation,
 };
 use crate::services::site_paths::detect_site_language_from_path;
 use crate::services::site_text::SiteText;
@@ -37,7 +41,7 @@ pub(super) struct ArticleResearchMetadata {
 }
 
 pub(super) fn article_language_href(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
     lang: Option<&str>,
 ) -> String {
@@ -49,15 +53,44 @@ pub(super) fn article_language_href(
     path
 }
 
+fn rendered_image_texts(
+    source: &ArticleSourceText<'_>,
+    translation: Option<&CachedArticleTranslation>,
+) -> HashMap<String, render::ArticleImageText> {
+    let mut texts = source
+        .images
+        .iter()
+        .map(|image| {
+            (
+                image.image_id.clone(),
+                render::ArticleImageText {
+                    alt_text: image.alt_text.clone(),
+                    caption: image.caption.clone(),
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    for image in translation.map_or(&[][..], |translation| &translation.images) {
+        texts.insert(
+            image.image_id.clone(),
+            render::ArticleImageText {
+                alt_text: image.alt_text.clone(),
+                caption: image.caption.clone(),
+            },
+        );
+    }
+    texts
+}
+
 pub(super) fn build_article_language_options(
     slug: &str,
-    text: SiteText,
-    site_language: SupportedTranslationLanguage,
-    selection: ArticleLanguageSelection,
-    browser_language: Option<SupportedTranslationLanguage>,
+    text: &SiteText,
+    site_language: &SupportedTranslationLanguage,
+    selection: &ArticleLanguageSelection,
+    browser_language: Option<&SupportedTranslationLanguage>,
 ) -> Vec<ArticleLanguageOption> {
     let automatic_note =
-        text.article_language_automatic_note(browser_language, selection.source_language);
+        text.article_language_automatic_note(browser_language, &selection.source_language);
     let route_selected_source =
         selection.preferred_language_source == PreferredLanguageSource::Route;
     let mut options = vec![
@@ -69,9 +102,9 @@ pub(super) fn build_article_language_options(
                 && !route_selected_source,
         },
         ArticleLanguageOption {
-            href: article_language_href(site_language, slug, Some(selection.source_language.code)),
+            href: article_language_href(site_language, slug, Some(&selection.source_language.code)),
             label: text.article_language_original_label(
-                text.translation_language_name(selection.source_language),
+                &text.translation_language_name(&selection.source_language),
             ),
             note: text.article_language_original_note().to_string(),
             active: (uses_manual_article_language_preference(selection.preferred_language_source)
@@ -83,7 +116,6 @@ pub(super) fn build_article_language_options(
     options.extend(
         supported_translation_languages()
             .iter()
-            .copied()
             .filter(|language| language.code != selection.source_language.code)
             .map(|language| {
                 let manually_selected =
@@ -95,7 +127,7 @@ pub(super) fn build_article_language_options(
                 let active = manually_selected || route_selected;
                 let note = if active && !selection.translation_available {
                     text.article_language_requested_note(
-                        text.translation_language_name(selection.served_language),
+                        &text.translation_language_name(&selection.served_language),
                     )
                 } else if manually_selected
                     && selection.preferred_language_source == PreferredLanguageSource::Cookie
@@ -108,7 +140,7 @@ pub(super) fn build_article_language_options(
                 };
 
                 ArticleLanguageOption {
-                    href: article_language_href(site_language, slug, Some(language.code)),
+                    href: article_language_href(site_language, slug, Some(&language.code)),
                     label: text.translation_language_name(language).to_string(),
                     note,
                     active,
@@ -127,7 +159,7 @@ pub(super) fn uses_manual_article_language_preference(source: PreferredLanguageS
 }
 
 pub(super) fn parse_article_research_metadata(
-    text: SiteText,
+    text: &SiteText,
     preview_payload: Option<&str>,
 ) -> Option<ArticleResearchMetadata> {
     let payload: Value = serde_json::from_str(preview_payload?).ok()?;
@@ -151,6 +183,7 @@ pub(super) async fn render_content_page(
     slug: &str,
     source: Option<&str>,
     comments_page: Option<u64>,
+    comment_translation: &comments::CommentTranslationRequest,
     requested_language: Option<SupportedTranslationLanguage>,
 ) -> Result<Html<String>, Error> {
     let text = request.site_text();
@@ -163,7 +196,7 @@ pub(super) async fn render_content_page(
         query::increment_click_count(&request.state.db, &article.id).await?;
     }
     let interactions_open = article_accepts_public_interactions(&article);
-    let comment_page = comments::load_comment_page(
+    let mut comment_page = comments::load_comment_page(
         &request.state.db,
         &article.id,
         comments_page,
@@ -187,28 +220,33 @@ pub(super) async fn render_content_page(
             "Markdown for content {} not found",
             article.id
         ))))?;
+    let image_sources =
+        article_image_source_texts(&find_article_images(&request.state.db, &article.id).await?);
     let source_article = ArticleSourceText {
         article_id: &article.id,
+        source_language: article_source_language_for(article.language_id.as_deref()),
         title: &article.title,
         description: &article.description,
         markdown,
+        images: &image_sources,
     };
     let mut available_translations =
-        cached_translation_languages(&request.state.db, source_article).await?;
+        cached_translation_languages(&request.state.db, &source_article).await?;
     let route_language = detect_site_language_from_path(&request.request_path);
     let mut language_selection = resolve_article_language(
-        requested_language,
-        route_language,
-        request.saved_article_language,
-        request.browser_translation_language,
+        &source_article.source_language,
+        requested_language.as_ref(),
+        route_language.as_ref(),
+        request.saved_article_language.as_ref(),
+        request.browser_translation_language.as_ref(),
         &available_translations,
     );
     let translated_article =
         if language_selection.served_language.code != language_selection.source_language.code {
             load_cached_article_translation(
                 &request.state.db,
-                source_article,
-                language_selection.served_language,
+                &source_article,
+                &language_selection.served_language,
             )
             .await?
         } else {
@@ -220,24 +258,45 @@ pub(super) async fn render_content_page(
         available_translations
             .retain(|language| language.code != language_selection.served_language.code);
         language_selection = resolve_article_language(
-            requested_language,
-            route_language,
-            request.saved_article_language,
-            request.browser_translation_language,
+            &source_article.source_language,
+            requested_language.as_ref(),
+            route_language.as_ref(),
+            request.saved_article_language.as_ref(),
+            request.browser_translation_language.as_ref(),
             &available_translations,
         );
     }
+    let image_text_missing = translated_article
+        .as_ref()
+        .is_some_and(|translation| translation.lacks_image_text(&source_article));
     if language_selection.translation_requested && !language_selection.translation_available {
         request_article_translation(
             request.state.clone(),
             article.id.clone(),
-            language_selection.preferred_language,
+            &language_selection.preferred_language,
+            request_source_from_preferred_language(language_selection.preferred_language_source),
+            request.requester_tier,
+            request.rate_limit_key.clone(),
+        )
+        .await;
+    } else if image_text_missing {
+        request_article_translation(
+            request.state.clone(),
+            article.id.clone(),
+            &language_selection.served_language,
             request_source_from_preferred_language(language_selection.preferred_language_source),
             request.requester_tier,
             request.rate_limit_key.clone(),
         )
         .await;
     }
+    comments::translate_comment_page(
+        request,
+        &mut comment_page,
+        comment_translation,
+        &language_selection.preferred_language,
+    )
+    .await?;
     let rendered_title = translated_article
         .as_ref()
         .map_or(article.title.as_str(), |translation| {
@@ -254,20 +313,37 @@ pub(super) async fn render_content_page(
     let rendered_body = render::markdown_to_html(
         &render::strip_leading_description(rendered_markdown, rendered_description),
         &request.locale_prefix(),
+        &rendered_image_texts(&source_article, translated_article.as_ref()),
+        language_selection.served_language.direction,
     );
     let language_options = build_article_language_options(
         &article.slug,
-        text,
-        request.site_language,
-        language_selection,
-        request.browser_translation_language,
+        &text,
+        &request.site_language,
+        &language_selection,
+        request.browser_translation_language.as_ref(),
     );
     let research_metadata = ArticleJobService::new(request.state.clone())
         .finalize_job_state_for_article(&article.id)
         .await?
-        .and_then(|job| parse_article_research_metadata(text, job.preview_payload.as_deref()));
+        .and_then(|job| parse_article_research_metadata(&text, job.preview_payload.as_deref()));
+    let article_path = format!("/content/{}", article.slug);
+    let alternate_languages =
+        article_alternate_languages(&source_article.source_language, &available_translations);
     let mut template = request.template("content").await;
     template
+        .insert(
+            "canonical_url",
+            &WibbleRequest::localized_url(&language_selection.served_language, &article_path),
+        )
+        .insert(
+            "alternate_locale_urls",
+            &WibbleRequest::language_alternates(
+                &article_path,
+                &alternate_languages,
+                &source_article.source_language,
+            ),
+        )
         .insert("id", &article.id)
         .insert("slug", &article.slug)
         .insert("created_at", &article.created_at.format("%F").to_string())
@@ -276,28 +352,32 @@ pub(super) async fn render_content_page(
         .insert("title", rendered_title)
         .insert("body", &rendered_body)
         .insert(
-            "page_language_code",
-            language_selection.served_language.code,
+            "article_language_code",
+            &language_selection.served_language.code,
+        )
+        .insert(
+            "article_direction",
+            language_selection.served_language.direction.as_str(),
         )
         .insert(
             "page_language_name",
-            text.translation_language_name(language_selection.served_language),
+            &text.translation_language_name(&language_selection.served_language),
         )
         .insert(
             "article_source_language_code",
-            language_selection.source_language.code,
+            &language_selection.source_language.code,
         )
         .insert(
             "article_source_language_name",
-            text.translation_language_name(language_selection.source_language),
+            &text.translation_language_name(&language_selection.source_language),
         )
         .insert(
             "preferred_article_language_code",
-            language_selection.preferred_language.code,
+            &language_selection.preferred_language.code,
         )
         .insert(
             "preferred_article_language_name",
-            text.translation_language_name(language_selection.preferred_language),
+            &text.translation_language_name(&language_selection.preferred_language),
         )
         .insert(
             "preferred_article_language_source",
@@ -331,14 +411,14 @@ pub(super) async fn render_content_page(
                 language_selection.preferred_language_source,
                 language_selection.translation_requested,
                 language_selection.translation_available,
-                text.translation_language_name(language_selection.preferred_language),
+                &text.translation_language_name(&language_selection.preferred_language),
             ),
         )
         .insert(
             "article_language_notice",
             &text.article_language_notice(
-                text.translation_language_name(language_selection.preferred_language),
-                text.translation_language_name(language_selection.source_language),
+                &text.translation_language_name(&language_selection.preferred_language),
+                &text.translation_language_name(&language_selection.source_language),
             ),
         )
         .insert("article_language_options", &language_options)
@@ -383,6 +463,25 @@ pub(super) async fn render_content_page(
             &(interactions_open && request.auth_user.is_some()),
         )
         .insert("comment_pager", &comment_page.pager)
+        .insert(
+            "comments_translatable",
+            &comment_page
+                .comments
+                .iter()
+                .any(|comment| comment.is_translatable()),
+        )
+        .insert(
+            "comments_translated_all",
+            &(*comment_translation == comments::CommentTranslationRequest::All),
+        )
+        .insert(
+            "comment_translation_limited",
+            &comment_page.translation_limited,
+        )
+        .insert(
+            "comment_translation_deferred",
+            &comment_page.translation_deferred,
+        )
         .insert(
             "comment_page_label",
             &text.comment_page_label(
@@ -403,7 +502,7 @@ pub(super) async fn render_content_page(
     if uses_manual_article_language_preference(language_selection.preferred_language_source) {
         template.insert(
             "article_language_override_code",
-            language_selection.preferred_language.code,
+            &language_selection.preferred_language.code,
         );
     }
     if !public_article {
@@ -55,6 +55,7 @@ mod tests {
             longview_count: 0,
             impression_count: 0,
             click_count: 0,
+            language_id: None,
             author_email: None,
             published: true,
             recovered_from_dead_link: false,
@@ -1,33 +1,164 @@
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use markdown::{to_html, to_html_with_options, Options};
 use regex::Regex;
 
+use crate::llm::prompt_registry::TextDirection;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ArticleImageText {
+    pub alt_text: String,
+    pub caption: Option<String>,
+}
+
 fn article_image_regex() -> &'static Regex {
     static ARTICLE_IMAGE_REGEX: OnceLock<Regex> = OnceLock::new();
     ARTICLE_IMAGE_REGEX.get_or_init(|| {
-        Regex::new(r#"<img src="(/image/([^"/?#]+))"([^>]*) />"#)
+        Regex::new(r#"(<p>)?<img src="(/image/([^"/?#]+))"([^>]*) />(</p>)?"#)
             .expect("article image regex must compile")
     })
 }
 
-fn link_article_images(html: &str, locale_prefix: &str) -> String {
+fn image_alt_regex() -> &'static Regex {
+    static IMAGE_ALT_REGEX: OnceLock<Regex> = OnceLock::new();
+    IMAGE_ALT_REGEX
+        .get_or_init(|| Regex::new(r#" alt="[^"]*""#).expect("image alt regex must compile"))
+}
+
+fn html_tag_regex() -> &'static Regex {
+    static HTML_TAG_REGEX: OnceLock<Regex> = OnceLock::new();
+    HTML_TAG_REGEX.get_or_init(|| {
+        Regex::new(r"<(/?)([A-Za-z][A-Za-z0-9]*)[^>]*>").expect("html tag regex must compile")
+    })
+}
+
+/// Matches an HTML entity (left alone) or a run of Latin-script words, so names
+/// like "Dr. Jane O'Neil" stay in one isolated left-to-right span.
+fn ltr_run_regex() -> &'static Regex {
+    static LTR_RUN_REGEX: OnceLock<Regex> = OnceLock::new();
+    LTR_RUN_REGEX.get_or_init(|| {
+        let word = r"[A-Za-z\x{00C0}-\x{024F}][A-Za-z0-9\x{00C0}-\x{024F}]*(?:['’.\-][A-Za-z0-9\x{00C0}-\x{024F}]+)*";
+        Regex::new(&format!(
+            r"(&#?[A-Za-z0-9]+;)|({word}(?:\.?[ \t]+(?:{word}|[0-9]+))*)",
+            word = word
+        ))
+        .expect("ltr run regex must compile")
+    })
+}
+
+fn isolate_ltr_text(text: &str) -> String {
+    ltr_run_regex()
+        .replace_all(text, |caps: &regex::Captures<'_>| match caps.get(2) {
+            Some(run) => format!("<bdi>{}</bdi>", run.as_str()),
+            None => caps[0].to_string(),
+        })
+        .into_owned()
+}
+
+/// Wraps embedded Latin-script runs of a right-to-left article in `<bdi>` so the
+/// bidi algorithm cannot reorder them against the surrounding text. Code blocks,
+/// inline code and existing isolates are left as they are.
+fn isolate_ltr_runs(html: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut skip_depth = 0usize;
+    let mut last = 0;
+    for tag in html_tag_regex().captures_iter(html) {
+        let whole = tag.get(0).expect("regex match has a whole group");
+        let text = &html[last..whole.start()];
+        if skip_depth == 0 {
+            output.push_str(&isolate_ltr_text(text));
+        } else {
+            output.push_str(text);
+        }
+        output.push_str(whole.as_str());
+        last = whole.end();
+
+        if matches!(
+            tag[2].to_ascii_lowercase().as_str(),
+            "code" | "pre" | "bdi" | "bdo"
+        ) {
+            if tag[1].is_empty() {
+                skip_depth += 1;
+            } else {
+                skip_depth = skip_depth.saturating_sub(1);
+            }
+        }
+    }
+    let rest = &html[last..];
+    if skip_depth == 0 {
+        output.push_str(&isolate_ltr_text(rest));
+    } else {
+        output.push_str(rest);
+    }
+    output
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn link_article_images(
+    html: &str,
+    locale_prefix: &str,
+    image_texts: &HashMap<String, ArticleImageText>,
+) -> String {
     article_image_regex()
         .replace_all(html, |caps: &regex::Captures<'_>| {
-            format!(
-                r#"<a href="{locale_prefix}/image_info/{id}" class="article-image-link">{img}</a>"#,
+            let id = &caps[3];
+            let image_text = image_texts.get(id);
+            let attributes = match image_text {
+                Some(image_text) => image_alt_regex()
+                    .replace(
+                        &caps[4],
+                        format!(r#" alt="{}""#, escape_html(&image_text.alt_text)).as_str(),
+                    )
+                    .into_owned(),
+                None => caps[4].to_string(),
+            };
+            let link = format!(
+                r#"<a href="{locale_prefix}/image_info/{id}" class="article-image-link"><img src="{src}"{attributes} /></a>"#,
                 locale_prefix = locale_prefix,
-                id = &caps[2],
-                img = &caps[0],
-            )
+                id = id,
+                src = &caps[2],
+                attributes = attributes,
+            );
+            let is_block = caps.get(1).is_some() && caps.get(5).is_some();
+            let caption = image_text.and_then(|image_text| image_text.caption.as_deref());
+            match (is_block, caption) {
+                (true, Some(caption)) => format!(
+                    r#"<figure class="article-figure">{link}<figcaption>{caption}</figcaption></figure>"#,
+                    link = link,
+                    caption = escape_html(caption),
+                ),
+                _ => format!(
+                    "{open}{link}{close}",
+                    open = caps.get(1).map_or("", |open| open.as_str()),
+                    link = link,
+                    close = caps.get(5).map_or("", |close| close.as_str()),
+                ),
+            }
         })
         .into_owned()
 }
 
-pub fn markdown_to_html(markdown_str: &str, locale_prefix: &str) -> String {
+pub fn markdown_to_html(
+    markdown_str: &str,
+    locale_prefix: &str,
+    image_texts: &HashMap<String, ArticleImageText>,
+    direction: TextDirection,
+) -> String {
     let html = to_html_with_options(markdown_str, &Options::gfm())
         .unwrap_or_else(|_| to_html(markdown_str));
-    link_article_images(&html, locale_prefix)
+    let html = match direction {
+        TextDirection::Rtl => isolate_ltr_runs(&html),
+        TextDirection::Ltr => html,
+    };
+    link_article_images(&html, locale_prefix, image_texts)
 }
 
 pub fn strip_leading_description(markdown: &str, description: &str) -> String {
@@ -48,7 +179,11 @@ pub fn strip_leading_description(markdown: &str, description: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{markdown_to_html, strip_leading_description};
+    use std::collections::HashMap;
+
+    use crate::llm::prompt_registry::TextDirection;
+
+    use super::{markdown_to_html, strip_leading_description, ArticleImageText};
 
     #[test]
     fn markdown_rendering_escapes_raw_html_and_wraps_article_images() {
@@ -58,6 +193,8 @@ mod tests {
 ![Alt text](/image/abc-123 "Prompt")
 "#,
             "/pt",
+            &HashMap::new(),
+            TextDirection::Ltr,
         );
 
         assert!(!rendered.contains("<script>"));
@@ -66,6 +203,51 @@ mod tests {
         assert!(rendered.contains(r#"src="/image/abc-123""#));
     }
 
+    #[test]
+    fn markdown_rendering_applies_image_text_and_captions() {
+        let image_texts = HashMap::from([(
+            "abc-123".to_string(),
+            ArticleImageText {
+                alt_text: "Texto \"alternativo\"".to_string(),
+                caption: Some("Legenda <b>editada</b>".to_string()),
+            },
+        )]);
+        let rendered = markdown_to_html(
+            "Intro\n\n![Alt text](/image/abc-123 \"Prompt\")\n\nInline ![Other](/image/def-456) image.",
+            "",
+            &image_texts,
+            TextDirection::Ltr,
+        );
+
+        assert!(rendered.contains(r#"alt="Texto &quot;alternativo&quot;""#));
+        assert!(!rendered.contains(r#"alt="Alt text""#));
+        assert!(rendered.contains(r#"<figure class="article-figure">"#));
+        assert!(rendered
+            .contains("<figcaption>Legenda &lt;b&gt;editada&lt;/b&gt;</figcaption></figure>"));
+        assert!(rendered.contains(r#"alt="Other""#));
+        assert_eq!(rendered.matches("<figure").count(), 1);
+    }
+
+    #[test]
+    fn rtl_markdown_isolates_embedded_latin_names() {
+        let rendered = markdown_to_html(
+            "قال Dr. Jane O'Neil من NASA إن 3 خطط &amp; `run_job()` لم تنجح.\n\n![صورة](/image/abc-123)",
+            "/ar",
+            &HashMap::new(),
+            TextDirection::Rtl,
+        );
+
+        assert!(rendered.contains("<bdi>Dr. Jane O'Neil</bdi>"));
+        assert!(rendered.contains("<bdi>NASA</bdi>"));
+        assert!(rendered.contains("&amp;"));
+        assert!(rendered.contains("<code>run_job()</code>"));
+        assert!(rendered.contains(r#"src="/image/abc-123""#));
+        assert!(rendered.contains(r#"href="/ar/image_info/abc-123""#));
+
+        let ltr = markdown_to_html("Dr. Jane O'Neil", "", &HashMap::new(), TextDirection::Ltr);
+        assert!(!ltr.contains("<bdi>"));
+    }
+
     #[test]
     fn strips_duplicate_standfirst_from_article_body() {
         let markdown = "Opening paragraph.\n\n## Section\n\nMore detail.";
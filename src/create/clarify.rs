@@ -2,60 +2,26 @@ use std::env;
 
 use chrono::{Duration, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use tracing::{event, Level};
 
 use crate::error::Error;
+use crate::llm::clarifier::{
+    propose_clarifying_questions, ClarificationProposal, ClarifyingQuestion,
+    MAX_CLARIFYING_QUESTIONS,
+};
+use crate::llm::Llm;
 
 const DEFAULT_CLARIFICATION_TIMEOUT_SECONDS: i64 = 5 * 60;
 const MAX_CLARIFICATION_ANSWER_CHARS: usize = 200;
 
-const INSTITUTION_MARKERS: &[&str] = &[
-    "ministry",
-    "department",
-    "council",
-    "committee",
-    "agency",
-    "office",
-    "authority",
-    "board",
-    "cabinet",
-    "parliament",
-    "court",
-    "police",
-    "hospital",
-    "school",
-    "university",
-    "federation",
-    "commission",
-    "tribunal",
-    "institute",
-    "company",
-    "union",
-];
-
-const RESPONSE_MARKERS: &[&str] = &[
-    "announces",
-    "announced",
-    "orders",
-    "ordered",
-    "launches",
-    "launched",
-    "opens",
-    "opened",
-    "issues",
-    "issued",
-    "publishes",
-    "published",
-    "review",
-    "inquiry",
-    "guidance",
-    "policy",
-    "memo",
-    "report",
-];
+const DEFAULT_FALLBACK_INSTRUCTION: &str = "If no answer arrives, choose the most plausible public-institution framing and continue with one clear official response.";
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ClarificationRequest {
+    /// The first question, kept for payloads written before questions became a list.
     pub question: String,
+    #[serde(default)]
+    pub questions: Vec<ClarifyingQuestion>,
     pub fallback_instruction: String,
     pub auto_resume_at: String,
     pub clarification_count: u32,
@@ -70,31 +36,54 @@ impl ClarificationRequest {
         self.auto_resume_at_datetime()
             .map(|value| value.format("%F %T").to_string())
     }
-}
 
-pub fn build_clarification_request(prompt: &str) -> Option<ClarificationRequest> {
-    if !should_request_clarification(prompt) {
-        return None;
+    pub fn questions(&self) -> Vec<ClarifyingQuestion> {
+        if self.questions.is_empty() {
+            return vec![ClarifyingQuestion {
+                question: self.question.clone(),
+                suggested_answers: Vec::new(),
+            }];
+        }
+        self.questions.clone()
     }
 
-    let normalized = prompt.to_ascii_lowercase();
-    let question = if !contains_any(&normalized, INSTITUTION_MARKERS) {
-        "Which institution, office, or organization should treat this as routine?"
-    } else {
-        "What specific policy decision or official response should the article center?"
-    };
-    let fallback_instruction = "If no answer arrives, choose the most plausible public-institution framing and continue with one clear official response.".to_string();
-    let auto_resume_at = (chrono::Utc::now().naive_local()
-        + Duration::seconds(clarification_timeout_seconds()))
-    .format("%F %T")
-    .to_string();
-
-    Some(ClarificationRequest {
-        question: question.to_string(),
-        fallback_instruction,
-        auto_resume_at,
-        clarification_count: 1,
-    })
+    pub fn from_proposal(proposal: ClarificationProposal) -> Option<Self> {
+        let question = proposal.questions.first()?.question.clone();
+        let auto_resume_at = (chrono::Utc::now().naive_local()
+            + Duration::seconds(clarification_timeout_seconds()))
+        .format("%F %T")
+        .to_string();
+
+        Some(Self {
+            question,
+            questions: proposal.questions,
+            fallback_instruction: proposal
+                .fallback_instruction
+                .unwrap_or_else(|| DEFAULT_FALLBACK_INSTRUCTION.to_string()),
+            auto_resume_at,
+            clarification_count: 1,
+        })
+    }
+}
+
+/// Lets the model decide whether the prompt is too ambiguous to write. Any failure means
+/// generation simply continues without asking.
+pub async fn build_clarification_request(
+    llm: &Llm,
+    model: &str,
+    prompt: &str,
+) -> Option<ClarificationRequest> {
+    match propose_clarifying_questions(llm, model, prompt, MAX_CLARIFYING_QUESTIONS).await {
+        Ok(proposal) => proposal.and_then(ClarificationRequest::from_proposal),
+        Err(err) => {
+            event!(
+                Level::WARN,
+                error = %err,
+                "Clarifying question step failed; continuing without asking"
+            );
+            None
+        }
+    }
 }
 
 pub fn parse_clarification_request(value: Option<&str>) -> Option<ClarificationRequest> {
@@ -117,8 +106,30 @@ pub fn normalize_clarification_answer(raw: &str) -> Result<String, Error> {
     Ok(answer.to_string())
 }
 
-pub fn append_clarification_answer(prompt: &str, question: &str, answer: &str) -> String {
-    format!("{prompt}\n\nClarification from requester:\nQuestion: {question}\nAnswer: {answer}")
+/// Like [`normalize_clarification_answer`], but a blank answer means the question was skipped.
+pub fn normalize_optional_clarification_answer(raw: &str) -> Result<Option<String>, Error> {
+    if raw.trim().is_empty() {
+        return Ok(None);
+    }
+    normalize_clarification_answer(raw).map(Some)
+}
+
+/// Appends every answered question in order; unanswered ones are left to the writer.
+pub fn append_clarification_answers(
+    prompt: &str,
+    questions: &[ClarifyingQuestion],
+    answers: &[Option<String>],
+) -> String {
+    let mut prompt = format!("{prompt}\n\nClarification from requester:");
+    for (question, answer) in questions.iter().zip(answers) {
+        if let Some(answer) = answer {
+            prompt.push_str(&format!(
+                "\nQuestion: {}\nAnswer: {}",
+                question.question, answer
+            ));
+        }
+    }
+    prompt
 }
 
 pub fn append_clarification_fallback(prompt: &str, fallback_instruction: &str) -> String {
@@ -133,56 +144,73 @@ pub fn clarification_timeout_seconds() -> i64 {
         .unwrap_or(DEFAULT_CLARIFICATION_TIMEOUT_SECONDS)
 }
 
-fn should_request_clarification(prompt: &str) -> bool {
-    let normalized = prompt.to_ascii_lowercase();
-    let word_count = prompt.split_whitespace().count();
-    let has_institution = contains_any(&normalized, INSTITUTION_MARKERS);
-    let has_response = contains_any(&normalized, RESPONSE_MARKERS);
-
-    word_count < 12 || !has_institution || !has_response
-}
-
-fn contains_any(text: &str, markers: &[&str]) -> bool {
-    markers.iter().any(|marker| text.contains(marker))
-}
-
 #[cfg(test)]
 mod tests {
     use super::{
-        append_clarification_answer, append_clarification_fallback, build_clarification_request,
-        normalize_clarification_answer,
+        append_clarification_answers, append_clarification_fallback,
+        normalize_clarification_answer, normalize_optional_clarification_answer,
+        parse_clarification_request, ClarificationRequest, DEFAULT_FALLBACK_INSTRUCTION,
     };
+    use crate::llm::clarifier::{ClarificationProposal, ClarifyingQuestion};
 
-    #[test]
-    fn clarification_request_triggers_for_materially_ambiguous_prompt() {
-        let request = build_clarification_request("A local office begins doing something odd")
-            .expect("expected clarification request");
+    fn question(text: &str) -> ClarifyingQuestion {
+        ClarifyingQuestion {
+            question: text.to_string(),
+            suggested_answers: vec!["The transport ministry".to_string()],
+        }
+    }
 
-        assert!(request.question.contains("policy"));
+    #[test]
+    fn clarification_request_keeps_model_questions_and_defaults_the_fallback() {
+        let request = ClarificationRequest::from_proposal(ClarificationProposal {
+            questions: vec![question("Which office?"), question("What did it announce?")],
+            fallback_instruction: None,
+        })
+        .expect("expected clarification request");
+
+        assert_eq!(request.question, "Which office?");
+        assert_eq!(request.questions().len(), 2);
+        assert_eq!(request.fallback_instruction, DEFAULT_FALLBACK_INSTRUCTION);
         assert_eq!(request.clarification_count, 1);
+        assert!(request.auto_resume_at_datetime().is_some());
     }
 
     #[test]
-    fn clarification_request_skips_specific_prompt() {
-        let request = build_clarification_request(
-            "A transport ministry issues a formal policy memo announcing emotional readiness bulletins for rail delays.",
-        );
-
-        assert!(request.is_none());
+    fn single_question_payloads_still_parse() {
+        let request = parse_clarification_request(Some(
+            r#"{"question":"Which office?","fallback_instruction":"Pick one","auto_resume_at":"2026-05-01 10:00:00","clarification_count":1}"#,
+        ))
+        .expect("expected stored request");
+
+        let questions = request.questions();
+        assert_eq!(questions.len(), 1);
+        assert_eq!(questions[0].question, "Which office?");
+        assert!(questions[0].suggested_answers.is_empty());
     }
 
     #[test]
     fn clarification_answer_validation_rejects_empty_values() {
         assert!(normalize_clarification_answer("   ").is_err());
+        assert_eq!(
+            normalize_optional_clarification_answer("   ").unwrap(),
+            None
+        );
+        assert_eq!(
+            normalize_optional_clarification_answer(" Lisbon ").unwrap(),
+            Some("Lisbon".to_string())
+        );
     }
 
     #[test]
-    fn clarification_answer_is_appended_to_prompt() {
-        let prompt =
-            append_clarification_answer("Original", "Which office?", "The transport ministry");
+    fn only_answered_questions_are_appended_to_prompt() {
+        let prompt = append_clarification_answers(
+            "Original",
+            &[question("Which office?"), question("Where?")],
+            &[None, Some("Lisbon".to_string())],
+        );
 
-        assert!(prompt.contains("Clarification from requester"));
-        assert!(prompt.contains("The transport ministry"));
+        assert!(!prompt.contains("Which office?"));
+        assert!(prompt.ends_with("Question: Where?\nAnswer: Lisbon"));
     }
 
     #[test]
@@ -1,6 +1,9 @@
 use serde::Deserialize;
 
 use crate::error::Error;
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, SupportedTranslationLanguage,
+};
 use crate::services::editorial_policy::enforce_generation_request_policy;
 
 use super::MAX_PROMPT_CHARS;
@@ -52,15 +55,34 @@ pub fn normalize_create_mode(raw: Option<&str>) -> Result<CreateModeSelection, E
     }
 }
 
+/// The language the article is written in. Forms without a choice keep the
+/// language of the page they were posted from.
+pub fn normalize_create_language(
+    raw: Option<&str>,
+    page_language: &SupportedTranslationLanguage,
+) -> Result<SupportedTranslationLanguage, Error> {
+    match raw.map(str::trim).filter(|value| !value.is_empty()) {
+        None => Ok(page_language.clone()),
+        Some(value) => find_supported_translation_language(value)
+            .ok_or_else(|| Error::BadRequest(format!("Unknown article language: {}", value))),
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct PostCreateData {
     pub prompt: String,
     pub mode: Option<String>,
+    pub language: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{normalize_create_mode, normalize_create_prompt, CreateModeSelection};
+    use crate::test_support::preferred_language;
+
+    use super::{
+        normalize_create_language, normalize_create_mode, normalize_create_prompt,
+        CreateModeSelection,
+    };
 
     #[test]
     fn create_prompt_validation_trims_and_rejects_empty_input() {
@@ -89,4 +111,21 @@ mod tests {
     fn create_mode_rejects_unknown_values() {
         assert!(normalize_create_mode(Some("expedition")).is_err());
     }
+
+    #[test]
+    fn create_language_defaults_to_the_page_language() {
+        let spanish = preferred_language("es");
+
+        assert_eq!(
+            normalize_create_language(None, &spanish).unwrap().code,
+            "es"
+        );
+        assert_eq!(
+            normalize_create_language(Some("pt"), &spanish)
+                .unwrap()
+                .code,
+            "pt"
+        );
+        assert!(normalize_create_language(Some("klingon"), &spanish).is_err());
+    }
 }
@@ -17,11 +17,14 @@ use crate::llm::article_generator::{
     create_article_attempt, create_article_using_placeholders, create_researched_article_attempt,
     ResearchModeSource,
 };
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::services::article_language::article_source_language;
 
 const MAX_PROMPT_CHARS: usize = 600;
 
 pub use input::{
-    normalize_create_mode, normalize_create_prompt, CreateModeSelection, PostCreateData,
+    normalize_create_language, normalize_create_mode, normalize_create_prompt, CreateModeSelection,
+    PostCreateData,
 };
 pub use orchestration::start_create_article;
 pub use page::{get_create, render_create_page};
@@ -34,6 +37,7 @@ pub(crate) async fn create_article(
     instructions: String,
     author_email: Option<String>,
     research_mode: Option<ResearchModeSource>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<(), Error> {
     debug!("Generating article for instructions: {}", instructions);
     let model = state
@@ -50,6 +54,7 @@ pub(crate) async fn create_article(
             model,
             author_email,
             research_mode,
+            language,
         )
         .await;
     }
@@ -68,7 +73,8 @@ pub(crate) async fn create_article(
 
     debug!("use_placeholders: {}", use_placeholders);
     debug!("can_use_examples: {}", can_use_examples);
-    let use_examples = can_use_examples;
+    // The stored examples are English articles, so they only guide English output.
+    let use_examples = can_use_examples && *language == article_source_language();
     debug!("single attempt use_examples {}", use_examples);
     if use_placeholders {
         create_article_using_placeholders(
@@ -78,9 +84,10 @@ pub(crate) async fn create_article(
             model,
             use_examples,
             author_email,
+            language,
         )
         .await
     } else {
-        create_article_attempt(state, id, instructions, model, author_email).await
+        create_article_attempt(state, id, instructions, model, author_email, language).await
     }
 }
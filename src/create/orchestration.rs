@@ -1,8 +1,11 @@
+use std::time::Duration;
+
 use tracing::{event, Level};
 
 use crate::app_state::AppState;
 use crate::error::Error;
 use crate::llm::article_generator::resolve_research_mode;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::rate_limit::RequesterTier;
 use crate::services::article_jobs::{ArticleJobRequest, ArticleJobService, ArticleJobTrace};
 
@@ -11,6 +14,10 @@ use super::{
     CreateModeSelection,
 };
 
+/// The clarification step holds a generation slot, so a slow model only delays drafting
+/// this long before the job carries on without asking.
+const CLARIFICATION_DEADLINE: Duration = Duration::from_secs(20);
+
 pub async fn start_create_article(
     state: AppState,
     prompt: String,
@@ -18,6 +25,7 @@ pub async fn start_create_article(
     requester_tier: RequesterTier,
     rate_limit_key: String,
     selected_mode: CreateModeSelection,
+    language: &SupportedTranslationLanguage,
 ) -> Result<String, Error> {
     let job_service = ArticleJobService::new(state.clone());
     let prompt = normalize_create_prompt(&prompt)?;
@@ -31,12 +39,7 @@ pub async fn start_create_article(
     } else {
         job_service.check_create_rate_limit(requester_tier, &rate_limit_key)?;
     }
-    let clarification = build_clarification_request(&prompt);
-    let permit = if clarification.is_none() {
-        Some(job_service.try_acquire_generation_slot("create")?)
-    } else {
-        None
-    };
+    let permit = job_service.try_acquire_generation_slot("create")?;
 
     let id = job_service.new_job_id();
     event!(Level::DEBUG, "Created id {}", &id);
@@ -48,29 +51,31 @@ pub async fn start_create_article(
                 prompt.clone(),
                 author_email.clone(),
                 requester_tier,
-                rate_limit_key,
+                rate_limit_key.clone(),
                 research_mode,
+                language,
             ),
         )
         .await?;
-    if let Some(clarification) = clarification {
-        let payload = serde_json::to_string(&clarification)
-            .map_err(|e| Error::Llm(format!("Failed to encode clarification request: {}", e)))?;
-        job_service.request_clarification(&id, payload).await?;
-        return Ok(return_id);
-    }
+    let language = language.clone();
     job_service
         .spawn_generation_job(
             id.clone(),
-            permit.unwrap(),
+            permit,
             ArticleJobTrace::create(research_mode),
             async move {
+                if pause_for_clarification(&state, &id, &prompt, requester_tier, &rate_limit_key)
+                    .await?
+                {
+                    return Ok(());
+                }
                 create_article(
                     &state,
                     id.clone(),
                     prompt.clone(),
                     author_email,
                     research_mode,
+                    &language,
                 )
                 .await
             },
@@ -78,3 +83,130 @@ pub async fn start_create_article(
         .await;
     Ok(return_id)
 }
+
+/// Lets the model judge whether the brief is too ambiguous to write and, if it asks,
+/// pauses the job for the requester. Only a question actually asked counts against the
+/// `ClarifyingQuestion` limit. Returns whether the job is now waiting for answers; resumed
+/// jobs go straight to drafting and never ask again.
+async fn pause_for_clarification(
+    state: &AppState,
+    id: &str,
+    prompt: &str,
+    requester_tier: RequesterTier,
+    rate_limit_key: &str,
+) -> Result<bool, Error> {
+    let Some(model) = state.llm.models.first() else {
+        return Ok(false);
+    };
+    let clarification = match tokio::time::timeout(
+        CLARIFICATION_DEADLINE,
+        build_clarification_request(&state.llm, model, prompt),
+    )
+    .await
+    {
+        Ok(clarification) => clarification,
+        Err(_) => {
+            event!(
+                Level::WARN,
+                job_id = %id,
+                "Clarifying question step timed out; continuing without asking"
+            );
+            None
+        }
+    };
+    let Some(clarification) = clarification else {
+        return Ok(false);
+    };
+    let job_service = ArticleJobService::new(state.clone());
+    if job_service
+        .check_clarifying_question_rate_limit(requester_tier, rate_limit_key)
+        .is_err()
+    {
+        return Ok(false);
+    }
+    let payload = serde_json::to_string(&clarification)
+        .map_err(|e| Error::Llm(format!("Failed to encode clarification request: {}", e)))?;
+    job_service.request_clarification(id, payload).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use crate::rate_limit::RequesterTier;
+    use crate::test_support::TestContext;
+
+    use super::pause_for_clarification;
+
+    async fn spawn_mock_llm_server(response_body: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 16 * 1024];
+                let _ = socket.read(&mut buffer).await.unwrap();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    response_body.len(),
+                    response_body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        format!("http://{address}/v1/chat/completions")
+    }
+
+    fn mock_no_questions_response() -> String {
+        serde_json::json!({
+            "choices": [{
+                "message": {
+                    "tool_calls": [{
+                        "function": {
+                            "arguments": serde_json::json!({
+                                "needs_clarification": false,
+                                "questions": [],
+                                "fallback_instruction": ""
+                            }).to_string()
+                        }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }],
+            "usage": {
+                "prompt_tokens": 1,
+                "completion_tokens": 1,
+                "total_tokens": 2
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn clarifying_question_limit_is_only_charged_when_a_question_is_asked() {
+        let mock_url = spawn_mock_llm_server(mock_no_questions_response()).await;
+        let ctx =
+            TestContext::new_with_overrides(&[("OPENROUTER_API_URL", mock_url.as_str())]).await;
+
+        for _ in 0..2 {
+            assert!(!pause_for_clarification(
+                &ctx.state,
+                "job-clear",
+                "The transport ministry asks buses to reflect",
+                RequesterTier::Anonymous,
+                "ip:203.0.113.7",
+            )
+            .await
+            .unwrap());
+        }
+
+        assert!(ctx
+            .state
+            .rate_limit_state
+            .check_clarifying_question_limit(RequesterTier::Anonymous, "ip:203.0.113.7")
+            .is_ok());
+    }
+}
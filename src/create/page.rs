@@ -1,7 +1,10 @@
+use std::borrow::Cow;
+
 use axum::response::Html;
 use serde::Serialize;
 
 use crate::error::Error;
+use crate::llm::prompt_registry::{supported_translation_languages, SupportedTranslationLanguage};
 use crate::rate_limit::{RateLimitCapability, RateLimitState, RequesterTier};
 use crate::wibble_request::WibbleRequest;
 
@@ -13,17 +16,31 @@ struct PromptPreset {
     prompt: &'static str,
 }
 
+#[derive(Serialize)]
+struct ArticleLanguageOption {
+    code: Cow<'static, str>,
+    name: Cow<'static, str>,
+}
+
 pub async fn render_create_page(
     wr: &WibbleRequest,
     prompt: &str,
     error_message: Option<&str>,
     selected_mode: CreateModeSelection,
+    selected_language: &SupportedTranslationLanguage,
 ) -> Result<Html<String>, Error> {
     let text = wr.site_text();
     let ui = text.template_strings();
     let presets = text
         .create_prompt_presets()
         .map(|(label, prompt)| PromptPreset { label, prompt });
+    let article_languages = supported_translation_languages()
+        .into_iter()
+        .map(|language| ArticleLanguageOption {
+            name: text.translation_language_name(&language),
+            code: language.code,
+        })
+        .collect::<Vec<_>>();
     let logged_in = wr.auth_user.is_some();
     let standard_quota = RateLimitState::quota_summary_for(
         RateLimitCapability::PlainArticleGeneration,
@@ -94,6 +111,8 @@ pub async fn render_create_page(
         .insert("prompt_presets", &presets)
         .insert("logged_in", &logged_in)
         .insert("selected_create_mode", selected_mode.as_str())
+        .insert("article_languages", &article_languages)
+        .insert("selected_article_language", &selected_language.code)
         .insert("owner_editing_note", &owner_editing_note)
         .insert("research_lane_note", &research_lane_note)
         .insert("translation_lane_note", &translation_lane_note)
@@ -121,5 +140,6 @@ pub async fn render_create_page(
 }
 
 pub async fn get_create(wr: WibbleRequest) -> Result<Html<String>, Error> {
-    render_create_page(&wr, "", None, CreateModeSelection::Auto).await
+    let language = wr.site_language.clone();
+    render_create_page(&wr, "", None, CreateModeSelection::Auto, &language).await
 }
@@ -6,6 +6,7 @@ use crate::entities::content;
 use crate::entities::prelude::*;
 use crate::error::Error;
 use crate::services::article_jobs::{ArticleJobRequest, ArticleJobService, ArticleJobTrace};
+use crate::services::article_language::article_source_language;
 
 use super::create_article;
 
@@ -81,6 +82,7 @@ pub async fn start_recover_article_for_slug(
         longview_count: 0,
         impression_count: 0,
         click_count: 0,
+        language_id: None,
         author_email: None,
         published: true,
         recovered_from_dead_link: true,
@@ -125,7 +127,15 @@ pub async fn start_recover_article_for_slug(
             permit,
             ArticleJobTrace::dead_link_recovery(slug.clone()),
             async move {
-                let result = create_article(&state, id.clone(), prompt, None, None).await;
+                let result = create_article(
+                    &state,
+                    id.clone(),
+                    prompt,
+                    None,
+                    None,
+                    &article_source_language(),
+                )
+                .await;
                 if result.is_err() {
                     let _ = Content::delete_by_id(id.clone()).exec(&state.db).await;
                 }
@@ -11,6 +11,9 @@ use crate::error::Error;
 use crate::image_status::{
     IMAGE_STATUS_COMPLETED, IMAGE_STATUS_FAILED, IMAGE_STATUS_PENDING, IMAGE_STATUS_PROCESSING,
 };
+use crate::llm::article_generator::parse_outline_payload;
+use crate::llm::clarifier::{ClarifyingQuestion, MAX_CLARIFYING_QUESTIONS};
+use crate::permissions::can_review_article_job;
 use crate::services::article_jobs::{
     is_in_progress_job_status, ArticleJobService, ARTICLE_JOB_PHASE_AWAITING_USER_INPUT,
     ARTICLE_JOB_PHASE_QUEUED, ARTICLE_JOB_PHASE_READY_FOR_REVIEW,
@@ -34,11 +37,32 @@ struct WaitSummary {
     image_processing: usize,
     image_failed: usize,
     clarification_question: Option<String>,
+    clarification_questions: Vec<WaitClarificationQuestion>,
     clarification_deadline: Option<String>,
     clarification_deadline_note: Option<String>,
+    outline: Option<WaitOutline>,
     phase_items: Vec<WaitPhaseItem>,
 }
 
+#[derive(Serialize)]
+struct WaitOutline {
+    headline_options: Vec<String>,
+    angle: String,
+    /// Beats joined one per line, as edited in the form.
+    beats: String,
+    image_slots: Vec<String>,
+    can_review: bool,
+    deadline_note: Option<String>,
+}
+
+#[derive(Serialize)]
+struct WaitClarificationQuestion {
+    /// Form field the answer is posted as.
+    field: String,
+    question: String,
+    suggested_answers: Vec<String>,
+}
+
 #[derive(Serialize)]
 struct WaitPhaseItem {
     label: String,
@@ -53,19 +77,21 @@ pub enum WaitResponse {
     NotFound,
 }
 
-fn publication_copy(text: SiteText, is_logged_in: bool) -> (String, String) {
+fn publication_copy(text: &SiteText, is_logged_in: bool) -> (String, String) {
     text.wait_publication_copy(is_logged_in)
 }
 
-fn queued_stage_copy(text: SiteText, phase: Option<&str>) -> (String, String) {
+fn queued_stage_copy(text: &SiteText, phase: Option<&str>) -> (String, String) {
     text.wait_stage_copy(phase)
 }
 
 fn build_wait_phase_items(
-    text: SiteText,
+    text: &SiteText,
     phase: Option<&str>,
     clarification_requested: bool,
+    outline_reviewed: bool,
 ) -> Vec<WaitPhaseItem> {
+    let input_requested = clarification_requested || outline_reviewed;
     let steps = [
         ARTICLE_JOB_PHASE_QUEUED,
         ARTICLE_JOB_PHASE_AWAITING_USER_INPUT,
@@ -79,21 +105,21 @@ fn build_wait_phase_items(
         ARTICLE_JOB_PHASE_WRITING
         | ARTICLE_JOB_PHASE_RESEARCHING
         | ARTICLE_JOB_PHASE_TRANSLATING => {
-            if clarification_requested {
+            if input_requested {
                 2
             } else {
                 1
             }
         }
         ARTICLE_JOB_PHASE_RENDERING_IMAGES => {
-            if clarification_requested {
+            if input_requested {
                 3
             } else {
                 2
             }
         }
         ARTICLE_JOB_PHASE_READY_FOR_REVIEW => {
-            if clarification_requested {
+            if input_requested {
                 4
             } else {
                 3
@@ -105,28 +131,28 @@ fn build_wait_phase_items(
     steps
         .into_iter()
         .filter(|step_phase| {
-            clarification_requested || *step_phase != ARTICLE_JOB_PHASE_AWAITING_USER_INPUT
+            input_requested || *step_phase != ARTICLE_JOB_PHASE_AWAITING_USER_INPUT
         })
         .map(|step_phase| {
             let step_rank = match step_phase {
                 ARTICLE_JOB_PHASE_QUEUED => 0,
                 ARTICLE_JOB_PHASE_AWAITING_USER_INPUT => 1,
                 ARTICLE_JOB_PHASE_WRITING => {
-                    if clarification_requested {
+                    if input_requested {
                         2
                     } else {
                         1
                     }
                 }
                 ARTICLE_JOB_PHASE_RENDERING_IMAGES => {
-                    if clarification_requested {
+                    if input_requested {
                         3
                     } else {
                         2
                     }
                 }
                 ARTICLE_JOB_PHASE_READY_FOR_REVIEW => {
-                    if clarification_requested {
+                    if input_requested {
                         4
                     } else {
                         3
@@ -141,32 +167,87 @@ fn build_wait_phase_items(
             } else {
                 "pending"
             };
+            let label_phase = if step_phase == ARTICLE_JOB_PHASE_AWAITING_USER_INPUT
+                && outline_reviewed
+                && !clarification_requested
+            {
+                "outline_review"
+            } else {
+                step_phase
+            };
             WaitPhaseItem {
-                label: text.wait_phase_label(step_phase).to_string(),
+                label: text.wait_phase_label(label_phase).to_string(),
                 state: state.to_string(),
             }
         })
         .collect()
 }
 
+fn wait_clarification_questions(
+    questions: &[ClarifyingQuestion],
+) -> Vec<WaitClarificationQuestion> {
+    questions
+        .iter()
+        .take(MAX_CLARIFYING_QUESTIONS)
+        .enumerate()
+        .map(|(index, question)| WaitClarificationQuestion {
+            field: match index {
+                0 => "answer".to_string(),
+                n => format!("answer_{}", n + 1),
+            },
+            question: question.question.clone(),
+            suggested_answers: question.suggested_answers.clone(),
+        })
+        .collect()
+}
+
 async fn build_wait_summary(
-    text: SiteText,
+    text: &SiteText,
     state: &AppState,
     id: &str,
     is_logged_in: bool,
+    can_review_outline: bool,
     job_phase: Option<&str>,
     job_preview_payload: Option<&str>,
 ) -> Result<WaitSummary, Error> {
     let fallback_publication_copy = publication_copy(text, is_logged_in);
     let clarification = parse_clarification_request(job_preview_payload);
     let clarification_question = clarification.as_ref().map(|value| value.question.clone());
+    let clarification_questions = clarification
+        .as_ref()
+        .map(|value| wait_clarification_questions(&value.questions()))
+        .unwrap_or_default();
     let clarification_deadline = clarification
         .as_ref()
         .and_then(|value| value.formatted_deadline());
     let clarification_deadline_note = clarification_deadline
         .as_ref()
         .map(|deadline| text.wait_clarification_deadline_note(deadline));
-    let phase_items = build_wait_phase_items(text, job_phase, clarification_question.is_some());
+    let stored_outline = parse_outline_payload(job_preview_payload);
+    let outline_reviewed = stored_outline
+        .as_ref()
+        .is_some_and(|outline| outline.review_deadline.is_some());
+    let outline = stored_outline
+        .filter(|outline| {
+            job_phase == Some(ARTICLE_JOB_PHASE_AWAITING_USER_INPUT) && outline.awaiting_review()
+        })
+        .map(|outline| WaitOutline {
+            deadline_note: outline
+                .review_deadline
+                .as_deref()
+                .map(|deadline| text.wait_outline_deadline_note(deadline)),
+            beats: outline.beats.join("\n"),
+            headline_options: outline.headline_options,
+            angle: outline.angle,
+            image_slots: outline.image_slots,
+            can_review: can_review_outline,
+        });
+    let phase_items = build_wait_phase_items(
+        text,
+        job_phase,
+        clarification_question.is_some(),
+        outline_reviewed,
+    );
     let article = Content::find()
         .filter(content::Column::Id.eq(id))
         .one(&state.db)
@@ -217,12 +298,19 @@ async fn build_wait_summary(
             image_processing,
             image_failed,
             clarification_question: None,
+            clarification_questions: Vec::new(),
             clarification_deadline: None,
             clarification_deadline_note: None,
+            outline: None,
             phase_items,
         })
     } else {
-        let (stage_title, stage_description) = queued_stage_copy(text, job_phase);
+        let stage_phase = if outline.is_some() {
+            Some("outline_review")
+        } else {
+            job_phase
+        };
+        let (stage_title, stage_description) = queued_stage_copy(text, stage_phase);
         Ok(WaitSummary {
             article_title: None,
             slug: None,
@@ -235,8 +323,10 @@ async fn build_wait_summary(
             image_processing: 0,
             image_failed: 0,
             clarification_question,
+            clarification_questions,
             clarification_deadline,
             clarification_deadline_note,
+            outline,
             phase_items,
         })
     }
@@ -247,11 +337,17 @@ pub async fn render_wait_page(wr: &WibbleRequest, id: &str) -> Result<Html<Strin
     let job = ArticleJobService::new(wr.state.clone())
         .load_job(id)
         .await?;
+    let can_review_outline = wr
+        .auth_user
+        .as_ref()
+        .zip(job.as_ref())
+        .is_some_and(|(auth_user, job)| can_review_article_job(auth_user, job));
     let wait_summary = build_wait_summary(
-        text,
+        &text,
         &wr.state,
         id,
         wr.auth_user.is_some(),
+        can_review_outline,
         job.as_ref().map(|job| job.phase.as_str()),
         job.as_ref().and_then(|job| job.preview_payload.as_deref()),
     )
@@ -264,7 +360,11 @@ pub async fn render_wait_page(wr: &WibbleRequest, id: &str) -> Result<Html<Strin
         .insert("robots", "noindex,nofollow")
         .insert(
             "wait_auto_refresh",
-            &wait_summary.clarification_question.is_none(),
+            &(wait_summary.clarification_question.is_none()
+                && !wait_summary
+                    .outline
+                    .as_ref()
+                    .is_some_and(|outline| outline.can_review)),
         )
         .insert("wait_summary", &wait_summary)
         .render()
@@ -300,18 +400,21 @@ pub async fn wait(wr: WibbleRequest, id: &str) -> WaitResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_wait_phase_items, queued_stage_copy};
+    use super::{build_wait_phase_items, queued_stage_copy, wait_clarification_questions};
+    use crate::llm::clarifier::ClarifyingQuestion;
     use crate::services::article_jobs::{
         ARTICLE_JOB_PHASE_AWAITING_USER_INPUT, ARTICLE_JOB_PHASE_RESEARCHING,
+        ARTICLE_JOB_PHASE_WRITING,
     };
     use crate::services::site_text::{default_site_language, site_text};
 
     #[test]
     fn wait_phase_items_include_clarify_step_when_question_is_pending() {
         let items = build_wait_phase_items(
-            site_text(default_site_language()),
+            &site_text(&default_site_language()),
             Some(ARTICLE_JOB_PHASE_AWAITING_USER_INPUT),
             true,
+            false,
         );
 
         assert_eq!(items.len(), 5);
@@ -319,10 +422,47 @@ mod tests {
         assert_eq!(items[1].state, "active");
     }
 
+    #[test]
+    fn wait_phase_items_label_the_input_step_for_outline_review() {
+        let items = build_wait_phase_items(
+            &site_text(&default_site_language()),
+            Some(ARTICLE_JOB_PHASE_WRITING),
+            false,
+            true,
+        );
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[1].label, "Outline");
+        assert_eq!(items[1].state, "done");
+        assert_eq!(items[2].state, "active");
+    }
+
+    #[test]
+    fn wait_clarification_questions_map_to_answer_fields() {
+        let question = |text: &str| ClarifyingQuestion {
+            question: text.to_string(),
+            suggested_answers: vec!["Lisbon".to_string()],
+        };
+        let items = wait_clarification_questions(&[
+            question("Which office?"),
+            question("Where?"),
+            question("When?"),
+            question("Why?"),
+        ]);
+
+        let fields = items
+            .iter()
+            .map(|item| item.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec!["answer", "answer_2", "answer_3"]);
+        assert_eq!(items[1].question, "Where?");
+        assert_eq!(items[1].suggested_answers, vec!["Lisbon"]);
+    }
+
     #[test]
     fn queued_stage_copy_describes_research_phase() {
         let (title, description) = queued_stage_copy(
-            site_text(default_site_language()),
+            &site_text(&default_site_language()),
             Some(ARTICLE_JOB_PHASE_RESEARCHING),
         );
 
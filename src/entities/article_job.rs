@@ -25,6 +25,7 @@ pub struct Model {
     pub updated_at: DateTime,
     pub started_at: Option<DateTime>,
     pub finished_at: Option<DateTime>,
+    pub language_code: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
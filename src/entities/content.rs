@@ -41,6 +41,7 @@ pub struct Model {
     // umami_view_count removed
     pub impression_count: i32,
     pub click_count: i32,
+    pub language_id: Option<String>,
     pub author_email: Option<String>,
     pub published: bool,
     pub recovered_from_dead_link: bool,
@@ -12,6 +12,7 @@ pub struct Model {
     pub user_name: String,
     #[sea_orm(column_type = "Text")]
     pub body: String,
+    pub language_code: Option<String>,
     pub created_at: DateTime,
 }
 
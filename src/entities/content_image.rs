@@ -2,7 +2,7 @@
 
 use sea_orm::entity::prelude::*;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "content_image")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -12,6 +12,7 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub prompt: String,
     pub alt_text: String,
+    pub caption: Option<String>,
     pub created_at: DateTime,
     pub flagged: bool,
     pub regenerate: bool,
@@ -29,6 +30,13 @@ pub struct Model {
     pub generation_finished_at: Option<DateTime>,
     pub provider_job_id: Option<String>,
     pub provider_job_url: Option<String>,
+    pub source_image_id: Option<String>,
+    pub crop_x: Option<f64>,
+    pub crop_y: Option<f64>,
+    pub crop_width: Option<f64>,
+    pub crop_height: Option<f64>,
+    pub focal_x: Option<f64>,
+    pub focal_y: Option<f64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -16,6 +16,10 @@ pub struct Model {
     pub error: Option<String>,
     pub kudos: i32,
     pub created_at: DateTime,
+    pub event: String,
+    pub request_id: Option<String>,
+    pub worker_model: Option<String>,
+    pub wait_seconds: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
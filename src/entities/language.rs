@@ -9,6 +9,13 @@ pub struct Model {
     pub id: String,
     #[sea_orm(unique)]
     pub name: String,
+    #[sea_orm(unique)]
+    pub code: Option<String>,
+    pub aliases: String,
+    pub direction: String,
+    pub enabled: bool,
+    pub site_locale: bool,
+    pub sort_order: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
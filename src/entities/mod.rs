@@ -16,6 +16,11 @@ pub mod history_generation_fail;
 pub mod horde_log;
 pub mod image_file;
 pub mod language;
+pub mod research_domain_policy;
+pub mod research_source;
+pub mod research_trace;
 pub mod search_history;
 pub mod translation;
+pub mod translation_glossary;
 pub mod translation_job;
+pub mod translation_override;
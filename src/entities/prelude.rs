@@ -15,6 +15,11 @@ pub use super::history_generation_fail::Entity as HistoryGenerationFail;
 pub use super::horde_log::Entity as HordeLog;
 pub use super::image_file::Entity as ImageFile;
 pub use super::language::Entity as Language;
+pub use super::research_domain_policy::Entity as ResearchDomainPolicy;
+pub use super::research_source::Entity as ResearchSource;
+pub use super::research_trace::Entity as ResearchTrace;
 pub use super::search_history::Entity as SearchHistory;
 pub use super::translation::Entity as Translation;
+pub use super::translation_glossary::Entity as TranslationGlossary;
 pub use super::translation_job::Entity as TranslationJob;
+pub use super::translation_override::Entity as TranslationOverride;
@@ -18,6 +18,8 @@ pub struct Model {
     pub started_at: Option<DateTime>,
     pub finished_at: Option<DateTime>,
     pub next_retry_at: Option<DateTime>,
+    pub segments_total: Option<i32>,
+    pub segments_reused: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
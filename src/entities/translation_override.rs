@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "translation_override")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub article_id: String,
+    pub language_code: String,
+    pub field: String,
+    pub source_hash: String,
+    #[sea_orm(column_type = "Text")]
+    pub translation: String,
+    pub reviewed_by: String,
+    pub stale: bool,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::content::Entity",
+        from = "Column::ArticleId",
+        to = "super::content::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Content,
+}
+
+impl Related<super::content::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Content.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -17,6 +17,7 @@ pub enum Error {
     Template(tera::Error),
     Storage(String),
     Auth(String),
+    Fetch(String),
 }
 
 impl fmt::Display for Error {
@@ -36,6 +37,7 @@ impl fmt::Display for Error {
             Error::Template(err) => write!(f, "Template error: {}", err),
             Error::Storage(msg) => write!(f, "Storage error: {}", msg),
             Error::Auth(msg) => write!(f, "Auth error: {}", msg),
+            Error::Fetch(msg) => write!(f, "Fetch error: {}", msg),
         }
     }
 }
@@ -8,6 +8,10 @@ use crate::entities::prelude::*;
 use crate::entities::{content, content_image};
 use crate::error::Error;
 use crate::image_status::IMAGE_STATUS_COMPLETED;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::services::article_translations::{
+    article_image_source_text, load_cached_image_text_translations, ImageTextSource,
+};
 use crate::wibble_request::WibbleRequest;
 
 const IMAGES_PER_PAGE: u64 = 24;
@@ -22,6 +26,7 @@ pub struct GetImagesParams {
 struct ImageGalleryItem {
     id: String,
     alt_text: String,
+    caption: Option<String>,
     article_slug: String,
     article_title: String,
 }
@@ -39,6 +44,7 @@ fn build_next_page_url(after_id: Option<&str>) -> Option<String> {
 async fn get_gallery_page(
     db: &DatabaseConnection,
     after_id: Option<String>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<ImageGalleryPage, Error> {
     let cursor = match after_id {
         Some(after_id) => ContentImage::find_by_id(after_id)
@@ -79,15 +85,33 @@ async fn get_gallery_page(
         rows.truncate(IMAGES_PER_PAGE as usize);
     }
 
+    let rows = rows
+        .into_iter()
+        .filter_map(|(image, article)| article.map(|article| (image, article)))
+        .collect::<Vec<_>>();
+    let sources = rows
+        .iter()
+        .map(|(image, article)| ImageTextSource {
+            article_id: article.id.clone(),
+            image: article_image_source_text(image),
+        })
+        .collect::<Vec<_>>();
+    let mut translations = load_cached_image_text_translations(db, &sources, language).await?;
     let items = rows
         .into_iter()
-        .filter_map(|(image, article)| {
-            article.map(|article| ImageGalleryItem {
+        .zip(sources)
+        .map(|((image, article), source)| {
+            let (alt_text, caption) = match translations.remove(&image.id) {
+                Some(translation) => (translation.alt_text, translation.caption),
+                None => (source.image.alt_text, source.image.caption),
+            };
+            ImageGalleryItem {
                 id: image.id,
-                alt_text: image.alt_text,
+                alt_text,
+                caption,
                 article_slug: article.slug,
                 article_title: article.title,
-            })
+            }
         })
         .collect::<Vec<_>>();
 
@@ -108,7 +132,8 @@ pub async fn get_images(
     Query(params): Query<GetImagesParams>,
 ) -> Result<Html<String>, Error> {
     let text = wr.site_text();
-    let gallery_page = get_gallery_page(&wr.state.db, params.after_id.clone()).await?;
+    let gallery_page =
+        get_gallery_page(&wr.state.db, params.after_id.clone(), &wr.site_language).await?;
     let next_page_url = build_next_page_url(gallery_page.next_after_id.as_deref());
     let mut template = wr.template("images").await;
     template
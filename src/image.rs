@@ -1,6 +1,4 @@
-use std::env;
-use std::path::PathBuf;
-use std::{fs, io};
+use std::io;
 
 use image::ImageError;
 use sea_orm::EntityTrait;
@@ -10,25 +8,22 @@ use crate::auth::AuthUser;
 use crate::content::can_view_article;
 use crate::entities::prelude::*;
 use crate::error::{Error, Result};
+use crate::image_framing::{
+    needs_render, render_image_variant, rendition_tag, ImageFraming, ImageVariant,
+};
 use crate::image_jobs::spawn_image_generation;
 use crate::image_status::{is_pending_status, IMAGE_STATUS_FAILED};
-use crate::s3;
+use crate::repositories::images::{image_storage_key, read_image_file, store_image_file};
+
+/// Rendered images are named by their ETag, so clients may keep them for a while and
+/// revalidate cheaply; a reframe shows up once this expires.
+const RENDERED_IMAGE_CACHE_CONTROL: &str = "public, max-age=3600";
 
 pub struct ImagePayload {
     pub bytes: Vec<u8>,
     pub content_type: &'static str,
     pub cache_control: &'static str,
-}
-
-async fn read_stored_image(id: &str) -> Result<Vec<u8>> {
-    let storage_type = env::var("STORAGE_TYPE").unwrap_or_else(|_| "local".to_string());
-    if storage_type.eq_ignore_ascii_case("s3") {
-        s3::download_image(id).await
-    } else {
-        let images_dir = env::var("IMAGES_DIR").expect("IMAGES_DIR is not set");
-        let image_path = PathBuf::from(images_dir).join(format!("{}.jpg", id));
-        fs::read(&image_path).map_err(|e| Error::Image(ImageError::IoError(e)))
-    }
+    pub etag: Option<String>,
 }
 
 fn escape_xml(value: &str) -> String {
@@ -78,6 +73,7 @@ fn placeholder_svg(status: &str, alt_text: &str) -> Vec<u8> {
 pub async fn get_image(
     state: &AppState,
     id: &str,
+    variant: ImageVariant,
     auth_user: Option<&AuthUser>,
 ) -> Result<ImagePayload> {
     let (image, article) = ContentImage::find_by_id(id.to_string())
@@ -101,11 +97,15 @@ pub async fn get_image(
         return Err(Error::NotFound(Some(format!("Image {} not found", id))));
     }
 
-    if let Ok(bytes) = read_stored_image(id).await {
+    if let Ok(original) = read_image_file(image_storage_key(&image)).await {
+        let framing = ImageFraming::from_model(&image);
+        let tag = rendition_tag(&original, &framing, variant);
+        let bytes = rendered_variant(original, &framing, variant, &tag).await?;
         return Ok(ImagePayload {
             bytes,
             content_type: "image/jpeg",
-            cache_control: "public, max-age=0, must-revalidate",
+            cache_control: RENDERED_IMAGE_CACHE_CONTROL,
+            etag: Some(format!("\"{}\"", tag)),
         });
     }
 
@@ -122,5 +122,28 @@ pub async fn get_image(
         bytes: placeholder_svg(placeholder_status, &image.alt_text),
         content_type: "image/svg+xml",
         cache_control: "no-store",
+        etag: None,
     })
 }
+
+/// Crops and resizes are stored under their rendition tag, so each one is encoded once
+/// per file and framing instead of on every request.
+async fn rendered_variant(
+    original: Vec<u8>,
+    framing: &ImageFraming,
+    variant: ImageVariant,
+    tag: &str,
+) -> Result<Vec<u8>> {
+    if !needs_render(framing, variant) {
+        return Ok(original);
+    }
+    let key = format!("variants/{}", tag);
+    if let Ok(bytes) = read_image_file(&key).await {
+        return Ok(bytes);
+    }
+    let bytes = render_image_variant(original, framing, variant)?;
+    if let Err(err) = store_image_file(&key, bytes.clone()).await {
+        eprintln!("Failed to cache image variant {}: {}", key, err);
+    }
+    Ok(bytes)
+}
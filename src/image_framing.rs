@@ -0,0 +1,287 @@
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use sha2::{Digest, Sha256};
+
+use crate::entities::content_image;
+use crate::error::Error;
+
+/// Rendered shapes of a stored image. `Original` honours the crop box, `Full` ignores
+/// framing so editors can pick points on the whole file, and the rest have fixed sizes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageVariant {
+    Original,
+    Full,
+    Card,
+    Thumb,
+}
+
+impl ImageVariant {
+    pub fn parse(value: Option<&str>) -> Self {
+        match value.map(str::trim) {
+            Some("full") => Self::Full,
+            Some("card") => Self::Card,
+            Some("thumb") => Self::Thumb,
+            _ => Self::Original,
+        }
+    }
+
+    fn target_size(self) -> Option<(u32, u32)> {
+        match self {
+            Self::Original | Self::Full => None,
+            Self::Card => Some((1200, 630)),
+            Self::Thumb => Some((800, 450)),
+        }
+    }
+}
+
+/// Crop box and focal point as fractions of the stored image, both in `0.0..=1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageFraming {
+    pub crop: Option<CropBox>,
+    pub focal: Option<(f64, f64)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PixelRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl CropBox {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Result<Self, Error> {
+        let in_range = |value: f64| (0.0..=1.0).contains(&value);
+        if !(in_range(x) && in_range(y) && in_range(width) && in_range(height))
+            || width <= 0.0
+            || height <= 0.0
+            || x + width > 1.0 + f64::EPSILON
+            || y + height > 1.0 + f64::EPSILON
+        {
+            return Err(Error::BadRequest(
+                "The crop box must stay inside the image".to_string(),
+            ));
+        }
+        Ok(Self {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+}
+
+impl ImageFraming {
+    pub fn from_model(image: &content_image::Model) -> Self {
+        let crop = match (
+            image.crop_x,
+            image.crop_y,
+            image.crop_width,
+            image.crop_height,
+        ) {
+            (Some(x), Some(y), Some(width), Some(height)) => CropBox::new(x, y, width, height).ok(),
+            _ => None,
+        };
+        let focal = image
+            .focal_x
+            .zip(image.focal_y)
+            .filter(|(x, y)| (0.0..=1.0).contains(x) && (0.0..=1.0).contains(y));
+        Self { crop, focal }
+    }
+
+    /// Picks the region to keep: the crop box, narrowed to `aspect` (width / height)
+    /// around the focal point when a fixed-shape variant is requested.
+    pub fn region(&self, image_width: u32, image_height: u32, aspect: Option<f64>) -> PixelRect {
+        let (width, height) = (f64::from(image_width), f64::from(image_height));
+        let crop = self.crop.unwrap_or(CropBox {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+        });
+        let base_x = crop.x * width;
+        let base_y = crop.y * height;
+        let base_width = (crop.width * width).max(1.0);
+        let base_height = (crop.height * height).max(1.0);
+
+        let (region_width, region_height) = match aspect {
+            Some(aspect) if base_width / base_height > aspect => {
+                (base_height * aspect, base_height)
+            }
+            Some(aspect) => (base_width, base_width / aspect),
+            None => (base_width, base_height),
+        };
+        let (focal_x, focal_y) = self
+            .focal
+            .map(|(x, y)| (x * width, y * height))
+            .unwrap_or((base_x + base_width / 2.0, base_y + base_height / 2.0));
+        let region_x =
+            (focal_x - region_width / 2.0).clamp(base_x, base_x + base_width - region_width);
+        let region_y =
+            (focal_y - region_height / 2.0).clamp(base_y, base_y + base_height - region_height);
+
+        let x = (region_x.round() as u32).min(image_width.saturating_sub(1));
+        let y = (region_y.round() as u32).min(image_height.saturating_sub(1));
+        PixelRect {
+            x,
+            y,
+            width: (region_width.round() as u32).clamp(1, image_width - x),
+            height: (region_height.round() as u32).clamp(1, image_height - y),
+        }
+    }
+}
+
+/// Whether `variant` differs from the stored file and has to be decoded and re-encoded.
+pub fn needs_render(framing: &ImageFraming, variant: ImageVariant) -> bool {
+    variant != ImageVariant::Full && (variant.target_size().is_some() || framing.crop.is_some())
+}
+
+/// Names one rendition of one stored file: it changes when the file is replaced, the
+/// framing moves or a different variant is asked for. Used as ETag and cache key.
+pub fn rendition_tag(original: &[u8], framing: &ImageFraming, variant: ImageVariant) -> String {
+    let mut digest = Sha256::new();
+    digest.update(original);
+    digest.update(format!("{:?}", variant).as_bytes());
+    if needs_render(framing, variant) {
+        if let Some(crop) = framing.crop {
+            for value in [crop.x, crop.y, crop.width, crop.height] {
+                digest.update(value.to_bits().to_be_bytes());
+            }
+        }
+        if let Some((x, y)) = framing.focal {
+            digest.update(b"focal");
+            digest.update(x.to_bits().to_be_bytes());
+            digest.update(y.to_bits().to_be_bytes());
+        }
+    }
+    format!("{:x}", digest.finalize())
+}
+
+/// Applies the stored framing and variant size; unframed originals are passed through.
+pub fn render_image_variant(
+    bytes: Vec<u8>,
+    framing: &ImageFraming,
+    variant: ImageVariant,
+) -> Result<Vec<u8>, Error> {
+    let target = variant.target_size();
+    if !needs_render(framing, variant) {
+        return Ok(bytes);
+    }
+
+    let decoded = image::load_from_memory(&bytes).map_err(Error::Image)?;
+    let aspect = target.map(|(width, height)| f64::from(width) / f64::from(height));
+    let rect = framing.region(decoded.width(), decoded.height(), aspect);
+    let mut framed = decoded.crop_imm(rect.x, rect.y, rect.width, rect.height);
+    if let Some((width, height)) = target {
+        framed = framed.resize_exact(width, height, FilterType::Triangle);
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    JpegEncoder::new_with_quality(&mut output, 85)
+        .encode_image(&DynamicImage::ImageRgb8(framed.to_rgb8()))
+        .map_err(Error::Image)?;
+    Ok(output.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rendition_tag, CropBox, ImageFraming, ImageVariant, PixelRect};
+
+    #[test]
+    fn image_variant_defaults_to_original() {
+        assert_eq!(ImageVariant::parse(Some("card")), ImageVariant::Card);
+        assert_eq!(ImageVariant::parse(Some("full")), ImageVariant::Full);
+        assert_eq!(ImageVariant::parse(Some("thumb")), ImageVariant::Thumb);
+        assert_eq!(ImageVariant::parse(Some("poster")), ImageVariant::Original);
+        assert_eq!(ImageVariant::parse(None), ImageVariant::Original);
+    }
+
+    #[test]
+    fn crop_box_rejects_regions_outside_the_image() {
+        assert!(CropBox::new(0.5, 0.0, 0.6, 1.0).is_err());
+        assert!(CropBox::new(0.0, 0.0, 0.0, 1.0).is_err());
+        assert!(CropBox::new(0.25, 0.25, 0.5, 0.75).is_ok());
+    }
+
+    #[test]
+    fn region_centers_on_the_image_without_framing() {
+        let rect = ImageFraming::default().region(1000, 1000, Some(2.0));
+        assert_eq!(
+            rect,
+            PixelRect {
+                x: 0,
+                y: 250,
+                width: 1000,
+                height: 500
+            }
+        );
+    }
+
+    #[test]
+    fn region_follows_the_focal_point_and_stays_inside_the_crop() {
+        let framing = ImageFraming {
+            crop: Some(CropBox::new(0.0, 0.0, 1.0, 0.8).unwrap()),
+            focal: Some((0.5, 0.95)),
+        };
+        let rect = framing.region(1000, 1000, Some(2.0));
+        assert_eq!(
+            rect,
+            PixelRect {
+                x: 0,
+                y: 300,
+                width: 1000,
+                height: 500
+            }
+        );
+    }
+
+    #[test]
+    fn region_keeps_the_crop_box_for_originals() {
+        let framing = ImageFraming {
+            crop: Some(CropBox::new(0.1, 0.2, 0.5, 0.5).unwrap()),
+            focal: None,
+        };
+        assert_eq!(
+            framing.region(200, 100, None),
+            PixelRect {
+                x: 20,
+                y: 20,
+                width: 100,
+                height: 50
+            }
+        );
+    }
+
+    #[test]
+    fn rendition_tag_tracks_file_framing_and_variant() {
+        let framed = ImageFraming {
+            crop: Some(CropBox::new(0.1, 0.1, 0.5, 0.5).unwrap()),
+            focal: None,
+        };
+        let card = rendition_tag(b"jpeg", &framed, ImageVariant::Card);
+
+        assert_eq!(card, rendition_tag(b"jpeg", &framed, ImageVariant::Card));
+        assert_ne!(card, rendition_tag(b"other", &framed, ImageVariant::Card));
+        assert_ne!(card, rendition_tag(b"jpeg", &framed, ImageVariant::Thumb));
+        assert_ne!(
+            card,
+            rendition_tag(b"jpeg", &ImageFraming::default(), ImageVariant::Card)
+        );
+        // The full variant ignores framing, so moving the crop keeps it cached.
+        assert_eq!(
+            rendition_tag(b"jpeg", &framed, ImageVariant::Full),
+            rendition_tag(b"jpeg", &ImageFraming::default(), ImageVariant::Full)
+        );
+    }
+}
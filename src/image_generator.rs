@@ -2,9 +2,10 @@ use futures::future::BoxFuture;
 use futures::stream::{self, StreamExt};
 use std::env;
 use std::fmt::Debug;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::app_state::AppState;
+use tokio::time::sleep;
 use tracing::{event, Level};
 
 use crate::error::Error;
@@ -24,6 +25,119 @@ pub trait ImageGenerator: Debug + Send + Sync {
     fn create_image(&self, prompt: String) -> BoxFuture<'_, Result<CreatedImage, Error>>;
 }
 
+/// A render queued with a provider, persisted on `content_image` so it can be resumed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProviderJob {
+    pub id: Option<String>,
+    pub url: String,
+    pub parameters: String,
+    pub submitted_at: Option<chrono::NaiveDateTime>,
+}
+
+pub enum ImageSubmission {
+    Queued(ProviderJob),
+    /// Synchronous backends (Stability, Hugging Face) render during submission and have no
+    /// provider job to persist; a render cut off by a restart fails instead of resubmitting.
+    Finished(CreatedImage),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProviderJobStatus {
+    Pending,
+    Ready { output_url: String },
+}
+
+pub type ProviderSlot = Box<dyn Send + Sync>;
+
+/// Image backends split into submit → poll → fetch so `image_jobs` can pick up an
+/// in-flight render after a restart instead of paying for a second one. Backends whose API
+/// only renders synchronously return [`ImageSubmission::Finished`] from `submit` and keep the
+/// default `poll`/`fetch`; their renders cannot be resumed and are never submitted twice.
+pub trait AsyncImageProvider: Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Model and style settings that shape the output; part of the prompt-reuse hash.
+    fn render_signature(&self) -> String {
+        self.name().to_string()
+    }
+
+    fn acquire_slot(
+        &self,
+        _prompt_len: usize,
+    ) -> BoxFuture<'_, Result<Option<ProviderSlot>, Error>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn submit(&self, prompt: String) -> BoxFuture<'_, Result<ImageSubmission, Error>>;
+
+    fn poll<'a>(
+        &'a self,
+        _job: &'a ProviderJob,
+    ) -> BoxFuture<'a, Result<ProviderJobStatus, Error>> {
+        Box::pin(async move {
+            Err(Error::ImageGeneration(format!(
+                "Image provider {} does not queue jobs",
+                self.name()
+            )))
+        })
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        _job: &'a ProviderJob,
+        _output_url: &'a str,
+    ) -> BoxFuture<'a, Result<CreatedImage, Error>> {
+        Box::pin(async move {
+            Err(Error::ImageGeneration(format!(
+                "Image provider {} does not queue jobs",
+                self.name()
+            )))
+        })
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_millis(1_500)
+    }
+
+    fn poll_timeout(&self) -> Duration {
+        Duration::from_secs(3_600)
+    }
+}
+
+pub async fn await_provider_job(
+    provider: &dyn AsyncImageProvider,
+    job: &ProviderJob,
+) -> Result<CreatedImage, Error> {
+    let poll_started = Instant::now();
+    loop {
+        if poll_started.elapsed() > provider.poll_timeout() {
+            return Err(Error::ImageGeneration(format!(
+                "{} job {} timed out after {}s",
+                provider.name(),
+                job.id.as_deref().unwrap_or("unknown"),
+                provider.poll_timeout().as_secs()
+            )));
+        }
+        match provider.poll(job).await? {
+            ProviderJobStatus::Pending => sleep(provider.poll_interval()).await,
+            ProviderJobStatus::Ready { output_url } => {
+                return provider.fetch(job, &output_url).await;
+            }
+        }
+    }
+}
+
+pub async fn create_image_with_provider(
+    provider: &dyn AsyncImageProvider,
+    prompt: String,
+) -> Result<CreatedImage, Error> {
+    let _slot = provider.acquire_slot(prompt.len()).await?;
+    match provider.submit(prompt).await? {
+        ImageSubmission::Finished(created) => Ok(created),
+        ImageSubmission::Queued(job) => await_provider_job(provider, &job).await,
+    }
+}
+
 #[derive(Clone)]
 pub struct ImageToCreate {
     pub id: String,
@@ -11,11 +11,19 @@ use futures::future::BoxFuture;
 use http::StatusCode;
 use image::ImageFormat;
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use sea_orm::DatabaseConnection;
 use serde_json::{json, Value};
 use tracing::{error, event, Level};
 
 use crate::error::Error;
-use crate::image_generator::{CreatedImage, ImageGenerator};
+use crate::image_generator::{
+    create_image_with_provider, AsyncImageProvider, CreatedImage, ImageGenerator, ImageSubmission,
+    ProviderJob, ProviderJobStatus,
+};
+use crate::repositories::horde_log::{
+    record_horde_log, HordeLogEntry, HORDE_EVENT_CENSORED, HORDE_EVENT_COMPLETED,
+    HORDE_EVENT_FAILED, HORDE_EVENT_SUBMISSION,
+};
 
 #[derive(Debug, Clone)]
 struct GenerateImageResponse {
@@ -25,11 +33,20 @@ struct GenerateImageResponse {
     parameters: String,
 }
 
+#[derive(Debug, Clone)]
+struct GenerationStatus {
+    img: String,
+    kudos: i32,
+    worker_model: Option<String>,
+    response: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct AiHordeImageGenerator {
     client: reqwest::Client,
     headers: HeaderMap,
     style: Option<Value>,
+    ledger: Option<DatabaseConnection>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -55,6 +72,8 @@ static BASE_URL: &str = "https://aihorde.net/api/v2";
 
 static TIMEOUT: Duration = Duration::from_secs(120);
 
+static POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 trait AndAwait<T, E> {
     async fn and_await(self) -> Result<T, E>;
 }
@@ -89,8 +108,15 @@ impl AiHordeImageGenerator {
             client: reqwest::Client::new(),
             style,
             headers,
+            ledger: None,
         }
     }
+
+    /// Records every submission and poll outcome in `horde_log`.
+    pub fn with_ledger(mut self, db: DatabaseConnection) -> Self {
+        self.ledger = Some(db);
+        self
+    }
 }
 
 impl Default for AiHordeImageGenerator {
@@ -99,7 +125,23 @@ impl Default for AiHordeImageGenerator {
     }
 }
 
+fn kudos_from(value: &Value) -> i32 {
+    value
+        .as_f64()
+        .map(|kudos| kudos.round() as i32)
+        .unwrap_or(0)
+}
+
 impl AiHordeImageGenerator {
+    async fn record(&self, entry: HordeLogEntry) {
+        let Some(db) = &self.ledger else {
+            return;
+        };
+        if let Err(err) = record_horde_log(db, entry).await {
+            event!(Level::WARN, error = %err, "Failed to record AI Horde ledger entry");
+        }
+    }
+
     fn check_status_code(status: StatusCode) -> Result<(), HordeError> {
         if status.is_success() {
             return Ok(());
@@ -204,16 +246,37 @@ impl AiHordeImageGenerator {
             body["models"] = json!([model]);
         }
 
-        let res = self.post("/generate/async", body.clone()).await?;
-        let id = res
-            .get("id")
-            .and_then(|id| id.as_str())
-            .ok_or(HordeError::Unexpected(
-                "AI horde response without id".into(),
-            ))?
-            .to_string();
-
         let parameters_str = serde_json::to_string(&body).unwrap();
+        let res = match self.post("/generate/async", body.clone()).await {
+            Ok(res) => res,
+            Err(err) => {
+                self.record(HordeLogEntry {
+                    event: HORDE_EVENT_SUBMISSION,
+                    message: parameters_str.clone(),
+                    error: Some(err.to_string()),
+                    ..HordeLogEntry::default()
+                })
+                .await;
+                return Err(err);
+            }
+        };
+        let id = res.get("id").and_then(|id| id.as_str()).map(str::to_string);
+        self.record(HordeLogEntry {
+            event: HORDE_EVENT_SUBMISSION,
+            request_id: id.clone(),
+            message: parameters_str.clone(),
+            response: Some(res.to_string()),
+            error: id
+                .is_none()
+                .then(|| "AI horde response without id".to_string()),
+            kudos: kudos_from(&res["kudos"]),
+            ..HordeLogEntry::default()
+        })
+        .await;
+        let id = id.ok_or(HordeError::Unexpected(
+            "AI horde response without id".into(),
+        ))?;
+
         let generated_image_response = GenerateImageResponse {
             id,
             url: None,
@@ -223,7 +286,7 @@ impl AiHordeImageGenerator {
         Ok(generated_image_response)
     }
 
-    async fn get_status(&self, id: &str) -> Result<String, HordeError> {
+    async fn get_status(&self, id: &str) -> Result<GenerationStatus, HordeError> {
         let j = self.get(&format!("/generate/status/{}", id)).await?;
         if j["generations"][0]["censored"].as_bool() == Some(true) {
             return Err(HordeError::ImageCensored);
@@ -234,12 +297,18 @@ impl AiHordeImageGenerator {
 
         if j["done"].as_bool() == Some(true) {
             let gen = &j["generations"][0];
-            Ok(gen["img"]
+            let img = gen["img"]
                 .as_str()
                 .ok_or(HordeError::ServerError(
                     "Missing img from AI horde success message".into(),
                 ))?
-                .to_string())
+                .to_string();
+            Ok(GenerationStatus {
+                img,
+                kudos: kudos_from(&j["kudos"]),
+                worker_model: gen["model"].as_str().map(str::to_string),
+                response: j.to_string(),
+            })
         } else {
             Err(HordeError::Pending)
         }
@@ -266,37 +335,66 @@ impl AiHordeImageGenerator {
         }
     }
 
-    async fn generate_image(&self, prompt: &str) -> Result<GenerateImageResponse, HordeError> {
+    fn wait_seconds_since(submitted_at: Option<chrono::NaiveDateTime>) -> Option<i32> {
+        submitted_at.and_then(|submitted_at| {
+            i32::try_from((chrono::Utc::now().naive_local() - submitted_at).num_seconds()).ok()
+        })
+    }
+
+    async fn submit_job(&self, prompt: &str) -> Result<ProviderJob, HordeError> {
         let gen_response = self.generate_with_backoff(prompt.to_string()).await?;
-        let id = gen_response.id;
-        retry(
-            ExponentialBackoff {
-                max_elapsed_time: Some(TIMEOUT),
-                ..ExponentialBackoff::default()
-            },
-            || async {
-                let status = self.get_status(&id).await;
-                let r: Result<String, backoff::Error<HordeError>> = match status {
-                    Ok(s) => Ok(s),
-                    Err(e) => match e {
-                        HordeError::ServerError(_)
-                        | HordeError::RateLimited
-                        | HordeError::Pending => Err(backoff::Error::Transient {
-                            err: e,
-                            retry_after: None,
-                        }),
-                        _ => Err(backoff::Error::Permanent(e)),
+        Ok(ProviderJob {
+            url: format!("{}/generate/status/{}", BASE_URL, gen_response.id),
+            id: Some(gen_response.id),
+            parameters: gen_response.parameters,
+            submitted_at: Some(chrono::Utc::now().naive_local()),
+        })
+    }
+
+    async fn poll_job(&self, job: &ProviderJob) -> Result<ProviderJobStatus, HordeError> {
+        let id = job
+            .id
+            .clone()
+            .ok_or_else(|| HordeError::Unexpected("AI horde job without id".into()))?;
+        match self.get_status(&id).await {
+            Ok(status) => {
+                self.record(HordeLogEntry {
+                    event: HORDE_EVENT_COMPLETED,
+                    request_id: Some(id),
+                    message: job.parameters.clone(),
+                    response: Some(status.response),
+                    kudos: status.kudos,
+                    worker_model: status.worker_model,
+                    wait_seconds: Self::wait_seconds_since(job.submitted_at),
+                    ..HordeLogEntry::default()
+                })
+                .await;
+                Ok(ProviderJobStatus::Ready {
+                    output_url: status.img,
+                })
+            }
+            Err(HordeError::ServerError(_) | HordeError::RateLimited | HordeError::Pending) => {
+                Ok(ProviderJobStatus::Pending)
+            }
+            Err(err) => {
+                let censored = matches!(err, HordeError::ImageCensored);
+                self.record(HordeLogEntry {
+                    event: if censored {
+                        HORDE_EVENT_CENSORED
+                    } else {
+                        HORDE_EVENT_FAILED
                     },
-                };
-                r.map(|s| GenerateImageResponse {
-                    id: id.clone(),
-                    url: Some(s),
-                    model: gen_response.model.clone(),
-                    parameters: gen_response.parameters.clone(),
+                    request_id: Some(id),
+                    message: job.parameters.clone(),
+                    error: Some(err.to_string()),
+                    wait_seconds: Self::wait_seconds_since(job.submitted_at),
+                    flagged: censored,
+                    ..HordeLogEntry::default()
                 })
-            },
-        )
-        .await
+                .await;
+                Err(err)
+            }
+        }
     }
 
     async fn and_await<T, FT, E>(o: Result<FT, E>) -> Result<T, E>
@@ -309,10 +407,13 @@ impl AiHordeImageGenerator {
         }
     }
 
-    async fn create_image(&self, prompt: String) -> Result<CreatedImage, HordeError> {
-        let generate_response = self.generate_image(&prompt).await?;
+    async fn fetch_image(
+        &self,
+        job: &ProviderJob,
+        output_url: &str,
+    ) -> Result<CreatedImage, HordeError> {
         let response = retry(ExponentialBackoff::default(), || async {
-            Ok(reqwest::get(generate_response.url.clone().unwrap()).await?)
+            Ok(reqwest::get(output_url).await?)
         })
         .await
         .map_err(|e| {
@@ -332,28 +433,76 @@ impl AiHordeImageGenerator {
             })?;
         Ok(CreatedImage {
             data: buffer.into_inner(),
-            parameters: generate_response.parameters,
+            parameters: job.parameters.clone(),
         })
     }
 }
 
-impl ImageGenerator for AiHordeImageGenerator {
-    fn create_image(&self, prompt: String) -> BoxFuture<'_, Result<CreatedImage, Error>> {
+fn map_horde_error(he: HordeError) -> Error {
+    match he {
+        HordeError::ImageCensored => {
+            error!("Image censored");
+            Error::ImageCensored
+        }
+        HordeError::RateLimited => {
+            error!("Rate limited");
+            Error::RateLimited
+        }
+        _ => {
+            error!("{}", he.to_string());
+            Error::ImageGeneration(he.to_string())
+        }
+    }
+}
+
+impl AsyncImageProvider for AiHordeImageGenerator {
+    fn name(&self) -> &'static str {
+        "horde"
+    }
+
+    fn render_signature(&self) -> String {
+        match &self.style {
+            Some(style) => format!("horde:{}", style),
+            None => "horde".to_string(),
+        }
+    }
+
+    fn submit(&self, prompt: String) -> BoxFuture<'_, Result<ImageSubmission, Error>> {
         Box::pin(async move {
-            self.create_image(prompt).await.map_err(|he| match he {
-                HordeError::ImageCensored => {
-                    error!("Image censored");
-                    Error::ImageCensored
-                }
-                HordeError::RateLimited => {
-                    error!("Rate limited");
-                    Error::RateLimited
-                }
-                _ => {
-                    error!("{}", he.to_string());
-                    Error::ImageGeneration(he.to_string())
-                }
-            })
+            self.submit_job(&prompt)
+                .await
+                .map(ImageSubmission::Queued)
+                .map_err(map_horde_error)
+        })
+    }
+
+    fn poll<'a>(&'a self, job: &'a ProviderJob) -> BoxFuture<'a, Result<ProviderJobStatus, Error>> {
+        Box::pin(async move { self.poll_job(job).await.map_err(map_horde_error) })
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        job: &'a ProviderJob,
+        output_url: &'a str,
+    ) -> BoxFuture<'a, Result<CreatedImage, Error>> {
+        Box::pin(async move {
+            self.fetch_image(job, output_url)
+                .await
+                .map_err(map_horde_error)
         })
     }
+
+    fn poll_interval(&self) -> Duration {
+        POLL_INTERVAL
+    }
+
+    fn poll_timeout(&self) -> Duration {
+        TIMEOUT
+    }
+}
+
+impl ImageGenerator for AiHordeImageGenerator {
+    fn create_image(&self, prompt: String) -> BoxFuture<'_, Result<CreatedImage, Error>> {
+        Box::pin(create_image_with_provider(self, prompt))
+    }
 }
@@ -6,7 +6,7 @@ use tokio::time::{sleep, Duration};
 use tracing::{event, Level};
 
 use crate::error::Error;
-use crate::image_generator::{CreatedImage, ImageGenerator};
+use crate::image_generator::{AsyncImageProvider, CreatedImage, ImageGenerator, ImageSubmission};
 
 #[derive(Debug, Clone)]
 pub struct HuggingFaceImageGenerator {
@@ -146,3 +146,22 @@ impl ImageGenerator for HuggingFaceImageGenerator {
         })
     }
 }
+
+impl AsyncImageProvider for HuggingFaceImageGenerator {
+    fn name(&self) -> &'static str {
+        "huggingface"
+    }
+
+    fn render_signature(&self) -> String {
+        format!("huggingface:{}", self.api_url)
+    }
+
+    /// The inference API answers with the finished image and issues no job id to resume.
+    fn submit(&self, prompt: String) -> BoxFuture<'_, Result<ImageSubmission, Error>> {
+        Box::pin(async move {
+            self.create_image(prompt)
+                .await
+                .map(ImageSubmission::Finished)
+        })
+    }
+}
@@ -6,11 +6,14 @@ use std::time::{Duration, Instant};
 use futures::future::BoxFuture;
 use serde_json::{json, Value};
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
-use tokio::time::{sleep, timeout};
+use tokio::time::timeout;
 use tracing::{event, Level};
 
 use crate::error::Error;
-use crate::image_generator::{CreatedImage, ImageGenerator};
+use crate::image_generator::{
+    create_image_with_provider, AsyncImageProvider, CreatedImage, ImageGenerator, ImageSubmission,
+    ProviderJob, ProviderJobStatus, ProviderSlot,
+};
 
 #[derive(Debug, Clone)]
 pub struct ReplicateImageGenerator {
@@ -36,13 +39,6 @@ pub struct ReplicateGenerationPermit {
     _inflight_guard: InflightGuard,
 }
 
-#[derive(Debug, Clone)]
-pub struct ReplicatePrediction {
-    pub id: Option<String>,
-    pub poll_url: String,
-    pub parameters: String,
-}
-
 impl InflightGuard {
     fn new(inflight_predictions: Arc<AtomicUsize>) -> Self {
         inflight_predictions.fetch_add(1, Ordering::SeqCst);
@@ -221,7 +217,7 @@ impl ReplicateImageGenerator {
         })
     }
 
-    pub async fn create_prediction(&self, prompt: &str) -> Result<ReplicatePrediction, Error> {
+    pub async fn create_prediction(&self, prompt: &str) -> Result<ProviderJob, Error> {
         let params = json!({
             "input": { "prompt": prompt }
         });
@@ -287,74 +283,60 @@ impl ReplicateImageGenerator {
             prediction_id = %prediction_id.as_deref().unwrap_or("unknown"),
             "Replicate prediction created"
         );
-        Ok(ReplicatePrediction {
+        Ok(ProviderJob {
             id: prediction_id,
-            poll_url,
+            url: poll_url,
             parameters: params.to_string(),
+            submitted_at: Some(chrono::Utc::now().naive_local()),
         })
     }
 
-    pub async fn await_prediction(
-        &self,
-        prediction_id: Option<&str>,
-        poll_url: &str,
-        parameters: String,
-        request_started: Instant,
-    ) -> Result<CreatedImage, Error> {
-        let poll_started = Instant::now();
-        let mut last_status = String::new();
-        let prediction_json = loop {
-            if poll_started.elapsed() > self.poll_timeout {
-                return Err(Error::ImageGeneration(format!(
-                    "Replicate prediction {} timed out after {}s",
-                    prediction_id.unwrap_or("unknown"),
-                    self.poll_timeout.as_secs(),
-                )));
-            }
-
-            let prediction_json = self.fetch_prediction_json(prediction_id, poll_url).await?;
-            let status = prediction_json
-                .get("status")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown");
-            let status_normalized = normalize_status(status);
-            if status_normalized != last_status {
-                event!(
-                    Level::INFO,
-                    prediction_id = %prediction_id.unwrap_or("unknown"),
-                    status = %status,
-                    "Replicate prediction status update"
-                );
-                last_status = status_normalized.clone();
-            }
-
-            if is_completed_status(&status_normalized) {
-                break prediction_json;
-            }
-            if is_failed_status(&status_normalized) {
-                let err_msg = prediction_json
-                    .get("error")
-                    .map(|v| v.to_string())
-                    .unwrap_or_else(|| "unknown error".to_string());
-                return Err(Error::ImageGeneration(format!(
-                    "Replicate prediction {} failed with status '{}': {}",
-                    prediction_id.unwrap_or("unknown"),
-                    status,
-                    err_msg
-                )));
-            }
+    async fn poll_prediction(&self, job: &ProviderJob) -> Result<ProviderJobStatus, Error> {
+        let prediction_id = job.id.as_deref();
+        let prediction_json = self.fetch_prediction_json(prediction_id, &job.url).await?;
+        let status = prediction_json
+            .get("status")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown");
+        let status_normalized = normalize_status(status);
+        event!(
+            Level::DEBUG,
+            prediction_id = %prediction_id.unwrap_or("unknown"),
+            status = %status,
+            "Replicate prediction status"
+        );
 
-            sleep(self.poll_interval).await;
-        };
+        if is_failed_status(&status_normalized) {
+            let err_msg = prediction_json
+                .get("error")
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "unknown error".to_string());
+            return Err(Error::ImageGeneration(format!(
+                "Replicate prediction {} failed with status '{}': {}",
+                prediction_id.unwrap_or("unknown"),
+                status,
+                err_msg
+            )));
+        }
+        if !is_completed_status(&status_normalized) {
+            return Ok(ProviderJobStatus::Pending);
+        }
 
-        let url = extract_output_url(prediction_json.get("output")).ok_or_else(|| {
+        let output_url = extract_output_url(prediction_json.get("output")).ok_or_else(|| {
             Error::ImageGeneration(format!(
                 "Replicate prediction {} completed but has no usable output URL",
                 prediction_id.unwrap_or("unknown")
             ))
         })?;
+        Ok(ProviderJobStatus::Ready { output_url })
+    }
 
-        let img_resp = self.reqwest.get(url).send().await.map_err(|e| {
+    async fn download_output(
+        &self,
+        job: &ProviderJob,
+        output_url: &str,
+    ) -> Result<CreatedImage, Error> {
+        let img_resp = self.reqwest.get(output_url).send().await.map_err(|e| {
             Error::ImageGeneration(format!(
                 "Failed to download image from Replicate output URL: {}",
                 e
@@ -375,32 +357,67 @@ impl ReplicateImageGenerator {
         })?;
         event!(
             Level::INFO,
-            prediction_id = %prediction_id.unwrap_or("unknown"),
-            elapsed_ms = request_started.elapsed().as_millis(),
-            poll_elapsed_ms = poll_started.elapsed().as_millis(),
+            prediction_id = %job.id.as_deref().unwrap_or("unknown"),
             in_flight_predictions = self.inflight_predictions.load(Ordering::SeqCst),
             "Replicate image generation completed"
         );
         Ok(CreatedImage {
             data: img_bytes.to_vec(),
-            parameters,
+            parameters: job.parameters.clone(),
         })
     }
 }
 
-impl ImageGenerator for ReplicateImageGenerator {
-    fn create_image(&self, prompt: String) -> BoxFuture<'_, Result<CreatedImage, Error>> {
+impl AsyncImageProvider for ReplicateImageGenerator {
+    fn name(&self) -> &'static str {
+        "replicate"
+    }
+
+    fn render_signature(&self) -> String {
+        format!("replicate:{}", self.api_url)
+    }
+
+    fn acquire_slot(
+        &self,
+        prompt_len: usize,
+    ) -> BoxFuture<'_, Result<Option<ProviderSlot>, Error>> {
         Box::pin(async move {
-            let request_started = Instant::now();
-            let _permit = self.acquire_generation_slot(prompt.len()).await?;
-            let prediction = self.create_prediction(&prompt).await?;
-            self.await_prediction(
-                prediction.id.as_deref(),
-                &prediction.poll_url,
-                prediction.parameters,
-                request_started,
-            )
-            .await
+            let permit = self.acquire_generation_slot(prompt_len).await?;
+            Ok(Some(Box::new(permit) as ProviderSlot))
+        })
+    }
+
+    fn submit(&self, prompt: String) -> BoxFuture<'_, Result<ImageSubmission, Error>> {
+        Box::pin(async move {
+            self.create_prediction(&prompt)
+                .await
+                .map(ImageSubmission::Queued)
         })
     }
+
+    fn poll<'a>(&'a self, job: &'a ProviderJob) -> BoxFuture<'a, Result<ProviderJobStatus, Error>> {
+        Box::pin(self.poll_prediction(job))
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        job: &'a ProviderJob,
+        output_url: &'a str,
+    ) -> BoxFuture<'a, Result<CreatedImage, Error>> {
+        Box::pin(self.download_output(job, output_url))
+    }
+
+    fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    fn poll_timeout(&self) -> Duration {
+        self.poll_timeout
+    }
+}
+
+impl ImageGenerator for ReplicateImageGenerator {
+    fn create_image(&self, prompt: String) -> BoxFuture<'_, Result<CreatedImage, Error>> {
+        Box::pin(create_image_with_provider(self, prompt))
+    }
 }
@@ -11,7 +11,7 @@ use futures::future::BoxFuture;
 use tracing::{event, Level};
 
 use crate::error::Error;
-use crate::image_generator::{CreatedImage, ImageGenerator};
+use crate::image_generator::{AsyncImageProvider, CreatedImage, ImageGenerator, ImageSubmission};
 
 #[derive(Debug, Clone)]
 pub struct StabilityImageGenerator {
@@ -79,3 +79,19 @@ impl ImageGenerator for StabilityImageGenerator {
         })
     }
 }
+
+impl AsyncImageProvider for StabilityImageGenerator {
+    fn name(&self) -> &'static str {
+        "sd3"
+    }
+
+    /// The v2beta text-to-image endpoints answer with the finished image and issue no job
+    /// id; Stability's results polling only covers upscale and video, so nothing is resumable.
+    fn submit(&self, prompt: String) -> BoxFuture<'_, Result<ImageSubmission, Error>> {
+        Box::pin(async move {
+            self.create_image(prompt)
+                .await
+                .map(ImageSubmission::Finished)
+        })
+    }
+}
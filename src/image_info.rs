@@ -5,6 +5,9 @@ use sea_orm::EntityTrait;
 use crate::content::can_view_article;
 use crate::entities::prelude::*;
 use crate::error::Error;
+use crate::services::article_translations::{
+    article_image_source_text, load_cached_image_text_translations, ImageTextSource,
+};
 use crate::wibble_request::WibbleRequest;
 
 pub async fn get_image_info_handler(
@@ -19,11 +22,11 @@ pub async fn get_image_info_handler(
         .await
         .map_err(|e| Error::Database(format!("Database error reading image info: {}", e)))?
         .ok_or(Error::NotFound(Some(format!("Image {} not found", id))))?;
-    let (slug, article_title) = if let Some(article) = article {
+    let (article_id, slug, article_title) = if let Some(article) = article {
         if !can_view_article(wr.auth_user.as_ref(), &article) {
             return Err(Error::NotFound(Some(format!("Image {} not found", id))));
         }
-        (article.slug, article.title)
+        (article.id, article.slug, article.title)
     } else {
         return Err(Error::NotFound(Some(format!(
             "Article for image {} not found",
@@ -31,10 +34,27 @@ pub async fn get_image_info_handler(
         ))));
     };
 
+    let source = ImageTextSource {
+        article_id,
+        image: article_image_source_text(&info),
+    };
+    let (alt_text, caption) = match load_cached_image_text_translations(
+        db,
+        std::slice::from_ref(&source),
+        &wr.site_language,
+    )
+    .await?
+    .remove(&id)
+    {
+        Some(translation) => (translation.alt_text, translation.caption),
+        None => (source.image.alt_text, source.image.caption),
+    };
+
     wr.template("image_info")
         .await
         .insert("image_id", &id)
-        .insert("title", &info.alt_text)
+        .insert("title", &alt_text)
+        .insert("caption", &caption)
         .insert("created_at", &info.created_at.format("%F").to_string())
         .insert("slug", &slug)
         .insert("content_title", &article_title)
@@ -1,5 +1,5 @@
 use std::env;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
@@ -9,12 +9,15 @@ use tracing::{event, Level};
 use crate::app_state::AppState;
 use crate::entities::{content_image, prelude::*};
 use crate::error::Error;
-use crate::image_generator::replicate::ReplicatePrediction;
+use crate::image_generator::{await_provider_job, CreatedImage, ImageSubmission, ProviderJob};
 use crate::image_status::{
     is_pending_status, IMAGE_STATUS_COMPLETED, IMAGE_STATUS_FAILED, IMAGE_STATUS_PENDING,
     IMAGE_STATUS_PROCESSING,
 };
-use crate::repositories::images::store_image_file;
+use crate::repositories::images::{
+    available_prompt_hash, detach_shared_image, find_reusable_image, image_prompt_hash,
+    image_storage_key, store_image_file,
+};
 use crate::services::article_jobs::ArticleJobService;
 
 fn pending_resume_interval_seconds() -> u64 {
@@ -33,13 +36,82 @@ fn pending_resume_batch_size() -> u64 {
         .unwrap_or(100)
 }
 
-fn replicate_parameters(prompt: &str) -> String {
+/// How long a render submitted without a provider job may still be running in another
+/// worker before a restart is assumed to have abandoned it.
+const SUBMITTED_RENDER_GRACE_MINUTES: i64 = 15;
+
+#[derive(Debug, Eq, PartialEq)]
+enum InterruptedSubmission {
+    StillRendering,
+    Abandoned,
+}
+
+/// `mark_processing` stamps the row before `submit`, so a processing row without a job URL
+/// marks a submission that may already be paid for: a synchronous render (Stability, Hugging
+/// Face) or a queued job whose id was never recorded. Neither can be resumed, and submitting
+/// again would pay twice.
+fn interrupted_submission(
+    image: &content_image::Model,
+    now: chrono::NaiveDateTime,
+) -> Option<InterruptedSubmission> {
+    if image.status != IMAGE_STATUS_PROCESSING || image.provider_job_url.is_some() {
+        return None;
+    }
+    match image.generation_started_at {
+        Some(started_at)
+            if now - started_at < chrono::Duration::minutes(SUBMITTED_RENDER_GRACE_MINUTES) =>
+        {
+            Some(InterruptedSubmission::StillRendering)
+        }
+        _ => Some(InterruptedSubmission::Abandoned),
+    }
+}
+
+/// Reusing finished renders for identical prompts across articles is opt-in.
+fn prompt_reuse_enabled() -> bool {
+    env::var("IMAGE_PROMPT_REUSE")
+        .map(|value| {
+            matches!(
+                value.trim().to_lowercase().as_str(),
+                "1" | "true" | "yes" | "on"
+            )
+        })
+        .unwrap_or(false)
+}
+
+/// Rows queued before provider jobs were generic only came from Replicate and carry
+/// neither `generator` nor `parameters`.
+fn legacy_replicate_parameters(prompt: &str) -> String {
     serde_json::json!({
         "input": { "prompt": prompt }
     })
     .to_string()
 }
 
+fn resumable_job(image: &content_image::Model, provider_name: &str) -> Option<ProviderJob> {
+    let url = image.provider_job_url.clone()?;
+    let generator = image.generator.as_deref().unwrap_or("replicate");
+    if generator != provider_name {
+        event!(
+            Level::WARN,
+            image_id = %image.id,
+            job_provider = %generator,
+            provider = %provider_name,
+            "Discarding provider job queued with a different image provider"
+        );
+        return None;
+    }
+    Some(ProviderJob {
+        id: image.provider_job_id.clone(),
+        url,
+        parameters: image
+            .parameters
+            .clone()
+            .unwrap_or_else(|| legacy_replicate_parameters(&image.prompt)),
+        submitted_at: image.generation_started_at,
+    })
+}
+
 async fn load_content_image(
     state: &AppState,
     image_id: &str,
@@ -53,16 +125,22 @@ async fn load_content_image(
 async fn mark_processing(
     state: &AppState,
     image: content_image::Model,
-    provider_job_id: Option<String>,
-    provider_job_url: Option<String>,
+    job: Option<&ProviderJob>,
 ) -> Result<content_image::Model, Error> {
     let mut active = content_image::ActiveModel::from(image);
     active.status = ActiveValue::set(IMAGE_STATUS_PROCESSING.to_string());
     active.last_error = ActiveValue::set(None);
-    active.generation_started_at = ActiveValue::set(Some(chrono::Utc::now().naive_local()));
+    active.generator = ActiveValue::set(Some(state.image_provider.name().to_string()));
+    active.generation_started_at = ActiveValue::set(Some(
+        job.and_then(|job| job.submitted_at)
+            .unwrap_or_else(|| chrono::Utc::now().naive_local()),
+    ));
     active.generation_finished_at = ActiveValue::set(None);
-    active.provider_job_id = ActiveValue::set(provider_job_id);
-    active.provider_job_url = ActiveValue::set(provider_job_url);
+    active.provider_job_id = ActiveValue::set(job.and_then(|job| job.id.clone()));
+    active.provider_job_url = ActiveValue::set(job.map(|job| job.url.clone()));
+    if let Some(job) = job {
+        active.parameters = ActiveValue::set(Some(job.parameters.clone()));
+    }
     active
         .update(&state.db)
         .await
@@ -91,12 +169,16 @@ async fn mark_completed(
     state: &AppState,
     image: content_image::Model,
     parameters: String,
+    prompt_hash: String,
 ) -> Result<(), Error> {
+    let prompt_hash = available_prompt_hash(&state.db, &image, prompt_hash).await?;
     let mut active = content_image::ActiveModel::from(image);
     active.status = ActiveValue::set(IMAGE_STATUS_COMPLETED.to_string());
     active.regenerate = ActiveValue::set(false);
     active.last_error = ActiveValue::set(None);
     active.parameters = ActiveValue::set(Some(parameters));
+    active.prompt_hash = ActiveValue::set(prompt_hash);
+    active.source_image_id = ActiveValue::set(None);
     active.generation_finished_at = ActiveValue::set(Some(chrono::Utc::now().naive_local()));
     active
         .update(&state.db)
@@ -105,16 +187,54 @@ async fn mark_completed(
     Ok(())
 }
 
-async fn process_generic_image(state: &AppState, image: content_image::Model) -> Result<(), Error> {
-    let image = mark_processing(state, image, None, None).await?;
-    match state
-        .image_generator
-        .create_image(image.prompt.clone())
+async fn mark_reused(
+    state: &AppState,
+    image: content_image::Model,
+    source: &content_image::Model,
+    prompt_hash: String,
+) -> Result<(), Error> {
+    let prompt_hash = available_prompt_hash(&state.db, &image, prompt_hash).await?;
+    let storage_key = image_storage_key(source).to_string();
+    event!(
+        Level::INFO,
+        image_id = %image.id,
+        source_image_id = %storage_key,
+        "Reusing cached image render"
+    );
+    let now = chrono::Utc::now().naive_local();
+    let mut active = content_image::ActiveModel::from(image);
+    active.status = ActiveValue::set(IMAGE_STATUS_COMPLETED.to_string());
+    active.regenerate = ActiveValue::set(false);
+    active.last_error = ActiveValue::set(None);
+    active.generator = ActiveValue::set(source.generator.clone());
+    active.parameters = ActiveValue::set(source.parameters.clone());
+    active.prompt_hash = ActiveValue::set(prompt_hash);
+    active.source_image_id = ActiveValue::set(Some(storage_key));
+    active.generation_started_at = ActiveValue::set(Some(now));
+    active.generation_finished_at = ActiveValue::set(Some(now));
+    active.provider_job_id = ActiveValue::set(None);
+    active.provider_job_url = ActiveValue::set(None);
+    active
+        .update(&state.db)
         .await
-    {
+        .map_err(|e| Error::Database(format!("Error marking image as reused: {}", e)))?;
+    Ok(())
+}
+
+async fn finish_image(
+    state: &AppState,
+    image_id: &str,
+    prompt_hash: String,
+    result: Result<CreatedImage, Error>,
+) -> Result<(), Error> {
+    let image = load_content_image(state, image_id)
+        .await?
+        .ok_or_else(|| Error::NotFound(Some(format!("Image {} not found", image_id))))?;
+    match result {
         Ok(created) => {
+            detach_shared_image(&state.db, &image.id).await?;
             store_image_file(&image.id, created.data).await?;
-            mark_completed(state, image, created.parameters).await
+            mark_completed(state, image, created.parameters, prompt_hash).await
         }
         Err(err) => {
             mark_failed(state, image, &err).await?;
@@ -123,64 +243,62 @@ async fn process_generic_image(state: &AppState, image: content_image::Model) ->
     }
 }
 
-async fn process_replicate_image(
+async fn process_provider_image(
     state: &AppState,
     image: content_image::Model,
 ) -> Result<(), Error> {
-    let replicate = state
-        .replicate_image_generator
-        .as_ref()
-        .ok_or_else(|| Error::ImageGeneration("Replicate generator is not configured".into()))?;
-    let request_started = Instant::now();
-    let _permit = replicate
-        .acquire_generation_slot(image.prompt.len())
-        .await?;
-    let image_id = image.id.clone();
-
-    let prediction = if let Some(poll_url) = image.provider_job_url.clone() {
-        let provider_job_id = image.provider_job_id.clone();
-        let image = mark_processing(state, image, provider_job_id, Some(poll_url.clone())).await?;
-        ReplicatePrediction {
-            id: image.provider_job_id.clone(),
-            poll_url,
-            parameters: replicate_parameters(&image.prompt),
+    let provider = state.image_provider.clone();
+    let prompt_hash = image_prompt_hash(&image.prompt, &provider.render_signature());
+    // An explicit regeneration asks for a fresh render, and a queued job is already paid for.
+    if prompt_reuse_enabled() && !image.regenerate && image.provider_job_url.is_none() {
+        if let Some(source) = find_reusable_image(&state.db, &image, &prompt_hash).await? {
+            return mark_reused(state, image, &source, prompt_hash).await;
         }
-    } else {
-        let prediction = replicate.create_prediction(&image.prompt).await?;
-        let _ = mark_processing(
-            state,
-            image,
-            prediction.id.clone(),
-            Some(prediction.poll_url.clone()),
-        )
-        .await?;
-        prediction
-    };
+    }
 
-    match replicate
-        .await_prediction(
-            prediction.id.as_deref(),
-            &prediction.poll_url,
-            prediction.parameters,
-            request_started,
-        )
-        .await
-    {
-        Ok(created) => {
-            let image = load_content_image(state, &image_id)
-                .await?
-                .ok_or_else(|| Error::NotFound(Some(format!("Image {} not found", image_id))))?;
-            store_image_file(&image.id, created.data).await?;
-            mark_completed(state, image, created.parameters).await
-        }
-        Err(err) => {
-            let image = load_content_image(state, &image_id)
-                .await?
-                .ok_or_else(|| Error::NotFound(Some(format!("Image {} not found", image_id))))?;
+    match interrupted_submission(&image, chrono::Utc::now().naive_local()) {
+        Some(InterruptedSubmission::StillRendering) => return Ok(()),
+        Some(InterruptedSubmission::Abandoned) => {
+            let err = Error::ImageGeneration(
+                "The render was interrupted before the provider answered; regenerate the image to submit it again".to_string(),
+            );
             mark_failed(state, image, &err).await?;
-            Err(err)
+            return Err(err);
         }
+        None => {}
     }
+
+    let _slot = provider.acquire_slot(image.prompt.len()).await?;
+    let image_id = image.id.clone();
+
+    let job = if let Some(job) = resumable_job(&image, provider.name()) {
+        event!(
+            Level::INFO,
+            image_id = %image_id,
+            provider = %provider.name(),
+            job_id = %job.id.as_deref().unwrap_or("unknown"),
+            "Resuming provider image job"
+        );
+        mark_processing(state, image, Some(&job)).await?;
+        job
+    } else {
+        let image = mark_processing(state, image, None).await?;
+        match provider.submit(image.prompt.clone()).await {
+            Ok(ImageSubmission::Queued(job)) => {
+                mark_processing(state, image, Some(&job)).await?;
+                job
+            }
+            // Synchronous providers leave no job URL; a restart before this point leaves
+            // the row to `interrupted_submission` instead of submitting the prompt again.
+            Ok(ImageSubmission::Finished(created)) => {
+                return finish_image(state, &image_id, prompt_hash, Ok(created)).await;
+            }
+            Err(err) => return finish_image(state, &image_id, prompt_hash, Err(err)).await,
+        }
+    };
+
+    let result = await_provider_job(provider.as_ref(), &job).await;
+    finish_image(state, &image_id, prompt_hash, result).await
 }
 
 async fn process_image_generation(state: &AppState, image_id: &str) -> Result<(), Error> {
@@ -190,11 +308,7 @@ async fn process_image_generation(state: &AppState, image_id: &str) -> Result<()
     if image.status == IMAGE_STATUS_COMPLETED || image.status == IMAGE_STATUS_FAILED {
         return Ok(());
     }
-    if state.replicate_image_generator.is_some() {
-        process_replicate_image(state, image).await
-    } else {
-        process_generic_image(state, image).await
-    }
+    process_provider_image(state, image).await
 }
 
 pub fn spawn_image_generation(state: AppState, image_id: String) {
@@ -271,3 +385,201 @@ pub fn spawn_resume_loop(state: AppState) {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use sea_orm::{ActiveModelTrait, ActiveValue};
+
+    use super::{
+        interrupted_submission, load_content_image, mark_reused, resumable_job,
+        InterruptedSubmission,
+    };
+    use crate::entities::content_image;
+    use crate::image_generator::ImageToCreate;
+    use crate::image_status::{
+        IMAGE_STATUS_COMPLETED, IMAGE_STATUS_PENDING, IMAGE_STATUS_PROCESSING,
+    };
+    use crate::repositories::articles::{save_pending_article, PendingArticle};
+    use crate::repositories::images::{
+        detach_shared_image, find_reusable_image, image_prompt_hash, read_image_file,
+        store_image_file,
+    };
+    use crate::services::article_language::article_source_language;
+    use crate::test_support::TestContext;
+
+    fn queued_image(generator: Option<&str>) -> content_image::Model {
+        content_image::Model {
+            id: "image-1".to_string(),
+            content_id: "article-1".to_string(),
+            prompt_hash: None,
+            prompt: "a lighthouse".to_string(),
+            alt_text: "lighthouse".to_string(),
+            caption: None,
+            created_at: chrono::Utc::now().naive_local(),
+            flagged: false,
+            regenerate: false,
+            fail_count: 0,
+            generator: generator.map(str::to_string),
+            model: None,
+            seed: None,
+            parameters: None,
+            view_count: 0,
+            status: IMAGE_STATUS_PROCESSING.to_string(),
+            last_error: None,
+            generation_started_at: None,
+            generation_finished_at: None,
+            provider_job_id: Some("job-1".to_string()),
+            provider_job_url: Some("https://provider.test/jobs/job-1".to_string()),
+            source_image_id: None,
+            crop_x: None,
+            crop_y: None,
+            crop_width: None,
+            crop_height: None,
+            focal_x: None,
+            focal_y: None,
+        }
+    }
+
+    #[test]
+    fn resumable_job_resumes_jobs_from_the_same_provider() {
+        let mut image = queued_image(Some("horde"));
+        image.parameters = Some("{\"steps\":30}".to_string());
+
+        let job = resumable_job(&image, "horde").expect("job should resume");
+        assert_eq!(job.id.as_deref(), Some("job-1"));
+        assert_eq!(job.url, "https://provider.test/jobs/job-1");
+        assert_eq!(job.parameters, "{\"steps\":30}");
+    }
+
+    #[test]
+    fn resumable_job_skips_jobs_from_another_provider() {
+        assert!(resumable_job(&queued_image(Some("horde")), "replicate").is_none());
+    }
+
+    #[test]
+    fn resumable_job_treats_untagged_rows_as_replicate() {
+        let job = resumable_job(&queued_image(None), "replicate").expect("legacy job resumes");
+        assert!(job.parameters.contains("a lighthouse"));
+        assert!(resumable_job(&queued_image(None), "horde").is_none());
+    }
+
+    #[test]
+    fn submissions_without_a_job_are_never_sent_again() {
+        let now = chrono::Utc::now().naive_local();
+        let mut image = queued_image(Some("sd3"));
+        assert_eq!(interrupted_submission(&image, now), None);
+
+        image.provider_job_id = None;
+        image.provider_job_url = None;
+        image.generation_started_at = Some(now - chrono::Duration::minutes(1));
+        assert_eq!(
+            interrupted_submission(&image, now),
+            Some(InterruptedSubmission::StillRendering)
+        );
+
+        image.generation_started_at = Some(now - chrono::Duration::hours(1));
+        assert_eq!(
+            interrupted_submission(&image, now),
+            Some(InterruptedSubmission::Abandoned)
+        );
+
+        image.status = IMAGE_STATUS_PENDING.to_string();
+        assert_eq!(interrupted_submission(&image, now), None);
+    }
+
+    #[test]
+    fn resumable_job_requires_a_job_url() {
+        let mut image = queued_image(Some("replicate"));
+        image.provider_job_url = None;
+        assert!(resumable_job(&image, "replicate").is_none());
+    }
+
+    fn pending_article(id: &str, image_id: &str) -> PendingArticle {
+        PendingArticle {
+            id: id.to_string(),
+            title: format!("Title {}", id),
+            markdown: "# Draft".to_string(),
+            prompt_version: 1,
+            instructions: "prompt".to_string(),
+            start_time: chrono::Utc::now().naive_local(),
+            model: "test-model".to_string(),
+            description: "desc".to_string(),
+            images: vec![ImageToCreate {
+                id: image_id.to_string(),
+                caption: "Caption".to_string(),
+                prompt: "A lighthouse at dusk".to_string(),
+            }],
+            image_generator: "replicate".to_string(),
+            author_email: Some("author@example.com".to_string()),
+            source_language: article_source_language(),
+        }
+    }
+
+    #[tokio::test]
+    async fn reused_images_share_the_source_file_until_it_is_overwritten() {
+        let images_dir = std::env::temp_dir().join(format!(
+            "wibble-reused-images-{}",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+        fs::create_dir_all(&images_dir).unwrap();
+        let images_dir_value = images_dir.to_string_lossy().to_string();
+        let ctx =
+            TestContext::new_with_overrides(&[("IMAGES_DIR", images_dir_value.as_str())]).await;
+        let db = &ctx.state.db;
+        save_pending_article(db, pending_article("article-1", "source-image"))
+            .await
+            .unwrap();
+        save_pending_article(db, pending_article("article-2", "reused-image"))
+            .await
+            .unwrap();
+
+        let prompt_hash = image_prompt_hash("a lighthouse  at dusk", "replicate:test");
+        let source = load_content_image(&ctx.state, "source-image")
+            .await
+            .unwrap()
+            .unwrap();
+        let mut active = content_image::ActiveModel::from(source);
+        active.status = ActiveValue::set(IMAGE_STATUS_COMPLETED.to_string());
+        active.prompt_hash = ActiveValue::set(Some(prompt_hash.clone()));
+        active.update(db).await.unwrap();
+        store_image_file("source-image", vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        let pending = load_content_image(&ctx.state, "reused-image")
+            .await
+            .unwrap()
+            .unwrap();
+        let source = find_reusable_image(db, &pending, &prompt_hash)
+            .await
+            .unwrap()
+            .expect("completed render in another article should be reusable");
+        mark_reused(&ctx.state, pending, &source, prompt_hash)
+            .await
+            .unwrap();
+
+        let reused = load_content_image(&ctx.state, "reused-image")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reused.status, IMAGE_STATUS_COMPLETED);
+        assert_eq!(reused.source_image_id.as_deref(), Some("source-image"));
+        assert!(reused.prompt_hash.is_some());
+
+        detach_shared_image(db, "source-image").await.unwrap();
+        store_image_file("source-image", vec![9, 9]).await.unwrap();
+
+        let detached = load_content_image(&ctx.state, "reused-image")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(detached.source_image_id, None);
+        assert_eq!(
+            read_image_file("reused-image").await.unwrap(),
+            vec![1, 2, 3]
+        );
+        let _ = fs::remove_dir_all(images_dir);
+    }
+}
@@ -9,6 +9,7 @@ pub mod error;
 pub mod get_images;
 pub mod hot_score;
 pub mod image;
+pub mod image_framing;
 pub mod image_generator;
 pub mod image_info;
 pub mod image_jobs;
@@ -16,13 +17,16 @@ pub mod image_status;
 pub mod llm;
 pub mod newslist;
 pub mod permissions;
+pub mod public_address;
 pub mod rate_limit;
 pub mod repositories;
 pub mod routes;
 pub mod s3;
+pub mod search_provider;
 pub mod server;
 pub mod services;
 pub mod sitemap;
+pub mod source_fetch;
 pub mod translation_jobs;
 pub mod wibble_request;
 
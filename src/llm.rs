@@ -10,8 +10,11 @@ use crate::error::Error;
 use crate::llm::function_definition::FunctionDefinition;
 
 pub mod article_generator;
+pub mod clarifier;
+pub mod domain_policy;
 pub mod edit_agent;
 mod function_definition;
+pub mod glossary;
 pub mod prompt_registry;
 pub mod translate;
 
@@ -28,7 +31,7 @@ pub struct Llm {
     pub models: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Message {
     System(String),
     User(String),
@@ -1,17 +1,24 @@
 mod draft;
 mod image_briefs;
+mod outline;
 mod planning;
 mod prompt_builder;
 mod research;
+mod research_freshness;
+mod research_queries;
+mod research_trace;
 mod runtime;
 mod validation;
 
+use tracing::{event, Level};
+
 use crate::app_state::AppState;
 use crate::error::Error;
 use crate::image_generator::generate_images;
 use crate::image_jobs::enqueue_pending_images;
 use crate::llm::prompt_registry::{
     article_generation_prompt, placeholder_generation_prompt, research_article_generation_prompt,
+    SupportedTranslationLanguage,
 };
 use crate::repositories::{
     articles::{save_article, save_pending_article, Article, PendingArticle},
@@ -24,15 +31,24 @@ use draft::{
     generate_placeholder_article_draft, request_article_draft, request_researched_article_draft,
 };
 use image_briefs::{generate_image_briefs, replace_placeholder_tags_with_markdown};
+use outline::{apply_outline_headline, plan_article_outline};
+pub use outline::{parse_outline_payload, ArticleOutline};
 use planning::{compose_article_markdown, leading_paragraph};
-use research::gather_research_packet;
-pub use research::{prompt_requires_research, resolve_research_mode, ResearchModeSource};
-use runtime::{BoundedGenerationRuntime, GenerationTool};
+pub use research::{
+    gather_research_packet, gather_research_packet_within, prompt_requires_research,
+    resolve_research_mode, ResearchModeSource, ResearchPacket,
+};
+pub use research_trace::{CandidateOutcome, ResearchTrace, TraceCandidate};
+pub use runtime::{BoundedGenerationRuntime, GenerationTool};
 use validation::{
     ensure_generated_images_present, ensure_image_briefs_present,
-    ensure_placeholder_images_present, parse_titled_markdown, validate_researched_article_output,
+    ensure_placeholder_images_present, parse_titled_markdown,
+};
+pub use validation::{
+    ensure_minimum_paragraph_count, ensure_no_internal_source_domain_mentions,
+    ensure_no_prompt_leakage, ensure_no_research_citation_scaffolding, split_paragraphs,
+    validate_article_output, validate_researched_article_output,
 };
-pub use validation::{ensure_minimum_paragraph_count, split_paragraphs, validate_article_output};
 
 pub async fn create_article_using_placeholders(
     state: &AppState,
@@ -41,13 +57,25 @@ pub async fn create_article_using_placeholders(
     model: &str,
     use_examples: bool,
     author_email: Option<String>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<(), Error> {
     let llm = &state.llm;
     let mut runtime =
         BoundedGenerationRuntime::new(state.clone(), id.clone(), &instructions).await?;
-    runtime
-        .begin_tool(GenerationTool::ArticlePlanning, false)
-        .await?;
+    let PlanningOutcome::Write(outline) = plan_article(
+        state,
+        &mut runtime,
+        &id,
+        &instructions,
+        model,
+        author_email.is_some(),
+        language,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+    let draft_instructions = outlined_instructions(&instructions, outline.as_ref());
     let examples = if use_examples {
         Some(get_examples(&state.db).await?)
     } else {
@@ -57,7 +85,10 @@ pub async fn create_article_using_placeholders(
     runtime
         .begin_tool(GenerationTool::DraftWriter, true)
         .await?;
-    let article = generate_placeholder_article_draft(llm, &instructions, model, examples).await?;
+    let article =
+        generate_placeholder_article_draft(llm, &draft_instructions, model, examples, language)
+            .await?;
+    let title = apply_outline_headline(article.title, outline.as_ref());
     runtime
         .begin_tool(GenerationTool::ImageBriefPlanner, false)
         .await?;
@@ -67,7 +98,7 @@ pub async fn create_article_using_placeholders(
         .begin_tool(GenerationTool::PolicyCheck, false)
         .await?;
     validate_article_output(
-        &article.title,
+        &title,
         &placeholder_images.markdown,
         placeholder_images.images.len(),
     )?;
@@ -84,7 +115,7 @@ pub async fn create_article_using_placeholders(
         &state.db,
         PendingArticle {
             id,
-            title: article.title,
+            title,
             markdown: placeholder_images.markdown,
             prompt_version: placeholder_generation_prompt().version,
             instructions,
@@ -94,6 +125,7 @@ pub async fn create_article_using_placeholders(
             images: placeholder_images.images,
             image_generator: state.image_generator_name.clone(),
             author_email,
+            source_language: language.clone(),
         },
     )
     .await?;
@@ -108,25 +140,43 @@ pub async fn create_article_attempt(
     instructions: String,
     model: &str,
     author_email: Option<String>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<(), Error> {
     let db = &state.db;
     let start_time = chrono::Utc::now().naive_local();
     let mut runtime =
         BoundedGenerationRuntime::new(state.clone(), id.clone(), &instructions).await?;
-    runtime
-        .begin_tool(GenerationTool::ArticlePlanning, false)
-        .await?;
+    let PlanningOutcome::Write(outline) = plan_article(
+        state,
+        &mut runtime,
+        &id,
+        &instructions,
+        model,
+        author_email.is_some(),
+        language,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
     runtime
         .begin_tool(GenerationTool::DraftWriter, true)
         .await?;
-    let article = request_article_draft(&state.llm, &instructions, model).await?;
+    let article = request_article_draft(
+        &state.llm,
+        &outlined_instructions(&instructions, outline.as_ref()),
+        model,
+        language,
+    )
+    .await?;
     let article = parse_titled_markdown(&article)?;
+    let title = apply_outline_headline(article.title, outline.as_ref());
     let paragraphs = split_paragraphs(&article.body);
     ensure_minimum_paragraph_count(&paragraphs)?;
     runtime
         .begin_tool(GenerationTool::ImageBriefPlanner, true)
         .await?;
-    let image_briefs = generate_image_briefs(&state.llm, &article.body, model).await?;
+    let image_briefs = generate_image_briefs(&state.llm, &article.body, model, language).await?;
     ensure_image_briefs_present(&image_briefs)?;
     let images = generate_images(state, image_briefs).await?;
     ensure_generated_images_present(&images)?;
@@ -135,7 +185,7 @@ pub async fn create_article_attempt(
     runtime
         .begin_tool(GenerationTool::PolicyCheck, false)
         .await?;
-    validate_article_output(&article.title, &markdown, images.len())?;
+    validate_article_output(&title, &markdown, images.len())?;
     runtime.mark_ready_for_review().await?;
     runtime.ensure_not_cancelled().await?;
     let description = leading_paragraph(&markdown);
@@ -144,7 +194,7 @@ pub async fn create_article_attempt(
         db,
         Article {
             id,
-            title: article.title,
+            title,
             markdown,
             prompt_version: article_generation_prompt().version,
             instructions,
@@ -153,6 +203,7 @@ pub async fn create_article_attempt(
             description,
             images,
             author_email,
+            source_language: language.clone(),
         },
     )
     .await?;
@@ -166,30 +217,48 @@ pub async fn create_researched_article_attempt(
     model: &str,
     author_email: Option<String>,
     mode_source: ResearchModeSource,
+    language: &SupportedTranslationLanguage,
 ) -> Result<(), Error> {
     let db = &state.db;
     let start_time = chrono::Utc::now().naive_local();
     let mut runtime =
         BoundedGenerationRuntime::new_research(state.clone(), id.clone(), &instructions).await?;
-    runtime
-        .begin_tool(GenerationTool::ArticlePlanning, false)
-        .await?;
-    let research = gather_research_packet(state, &mut runtime, &instructions, mode_source).await?;
+    let PlanningOutcome::Write(outline) = plan_article(
+        state,
+        &mut runtime,
+        &id,
+        &instructions,
+        model,
+        author_email.is_some(),
+        language,
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+    let research =
+        gather_research_packet(state, &mut runtime, &instructions, model, mode_source).await?;
     ArticleJobService::new(state.clone())
         .merge_preview_payload(&id, research.preview_payload())
         .await?;
     runtime
         .begin_tool(GenerationTool::DraftWriter, true)
         .await?;
-    let researched_prompt = research.prompt_context(&instructions);
-    let article = request_researched_article_draft(&state.llm, &researched_prompt, model).await?;
+    // The outline was planned before the sources were read, so its headline is only a
+    // suggestion here; the writer titles the draft from what the research found.
+    let outline = outline.map(|outline| outline.with_suggested_headline());
+    let researched_prompt =
+        research.prompt_context(&outlined_instructions(&instructions, outline.as_ref()));
+    let article =
+        request_researched_article_draft(&state.llm, &researched_prompt, model, language).await?;
     let article = parse_titled_markdown(&article)?;
+    let title = article.title;
     let paragraphs = split_paragraphs(&article.body);
     ensure_minimum_paragraph_count(&paragraphs)?;
     runtime
         .begin_tool(GenerationTool::ImageBriefPlanner, true)
         .await?;
-    let image_briefs = generate_image_briefs(&state.llm, &article.body, model).await?;
+    let image_briefs = generate_image_briefs(&state.llm, &article.body, model, language).await?;
     ensure_image_briefs_present(&image_briefs)?;
     let images = generate_images(state, image_briefs).await?;
     ensure_generated_images_present(&images)?;
@@ -198,7 +267,7 @@ pub async fn create_researched_article_attempt(
     runtime
         .begin_tool(GenerationTool::PolicyCheck, false)
         .await?;
-    validate_researched_article_output(&article.title, &markdown, images.len(), &research.sources)?;
+    validate_researched_article_output(&title, &markdown, images.len(), &research.sources)?;
     runtime.mark_ready_for_review().await?;
     runtime.ensure_not_cancelled().await?;
     let description = leading_paragraph(&markdown);
@@ -207,7 +276,7 @@ pub async fn create_researched_article_attempt(
         db,
         Article {
             id,
-            title: article.title,
+            title,
             markdown,
             prompt_version: research_article_generation_prompt().version,
             instructions,
@@ -216,8 +285,72 @@ pub async fn create_researched_article_attempt(
             description,
             images,
             author_email,
+            source_language: language.clone(),
         },
     )
     .await?;
     Ok(())
 }
+
+enum PlanningOutcome {
+    /// Carry on drafting; the outline is missing only if planning itself failed.
+    Write(Option<ArticleOutline>),
+    /// The author has been asked to review the outline and the job is paused.
+    AwaitingAuthorReview,
+}
+
+/// Plans the outline, or picks up the one approved before the job was resumed. Authored jobs
+/// pause for review; anonymous and recovery jobs write straight from the plan.
+async fn plan_article(
+    state: &AppState,
+    runtime: &mut BoundedGenerationRuntime,
+    id: &str,
+    instructions: &str,
+    model: &str,
+    review_with_author: bool,
+    language: &SupportedTranslationLanguage,
+) -> Result<PlanningOutcome, Error> {
+    let job_service = ArticleJobService::new(state.clone());
+    let approved = job_service
+        .load_job(id)
+        .await?
+        .and_then(|job| parse_outline_payload(job.preview_payload.as_deref()))
+        .filter(|outline| outline.approved);
+    if approved.is_some() {
+        runtime
+            .begin_tool(GenerationTool::ArticlePlanning, false)
+            .await?;
+        return Ok(PlanningOutcome::Write(approved));
+    }
+
+    runtime
+        .begin_tool(GenerationTool::ArticlePlanning, true)
+        .await?;
+    let outline = match plan_article_outline(&state.llm, instructions, model, language).await {
+        Ok(outline) => outline,
+        Err(err) => {
+            event!(
+                Level::WARN,
+                job_id = %id,
+                error = %err,
+                "Article outline planning failed; drafting from the brief"
+            );
+            return Ok(PlanningOutcome::Write(None));
+        }
+    };
+    if review_with_author {
+        job_service.request_outline_review(id, outline).await?;
+        return Ok(PlanningOutcome::AwaitingAuthorReview);
+    }
+    job_service
+        .merge_preview_payload(id, outline.preview_payload())
+        .await?;
+    Ok(PlanningOutcome::Write(Some(outline)))
+}
+
+fn outlined_instructions(instructions: &str, outline: Option<&ArticleOutline>) -> String {
+    match outline {
+        Some(outline) => outline.prompt_context(instructions),
+        None => instructions.to_string(),
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::Error;
 use crate::image_generator::ImageToCreate;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::llm::Llm;
 
 use super::image_briefs::generate_image_briefs;
@@ -22,12 +23,13 @@ pub async fn generate_article_parts(
     _examples: Vec<(String, String)>,
     instructions: &str,
     model: &str,
+    language: &SupportedTranslationLanguage,
 ) -> Result<ArticleData, Error> {
-    let article = request_article_draft(llm, instructions, model).await?;
+    let article = request_article_draft(llm, instructions, model, language).await?;
     let article = parse_titled_markdown(&article)?;
     let paragraphs = split_paragraphs(&article.body);
     ensure_minimum_paragraph_count(&paragraphs)?;
-    let images = generate_image_briefs(llm, &article.body, model).await?;
+    let images = generate_image_briefs(llm, &article.body, model, language).await?;
     ensure_image_briefs_present(&images)?;
 
     Ok(ArticleData {
@@ -42,9 +44,13 @@ pub async fn generate_placeholder_article_draft(
     instructions: &str,
     model: &str,
     examples: Option<Vec<(String, String)>>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<ParsedArticleDraft, Error> {
     let article = llm
-        .request_chat(build_placeholder_messages(examples, instructions), model)
+        .request_chat(
+            build_placeholder_messages(examples, instructions, language),
+            model,
+        )
         .await?
         .trim()
         .to_string();
@@ -56,8 +62,9 @@ pub async fn request_article_draft(
     llm: &Llm,
     instructions: &str,
     model: &str,
+    language: &SupportedTranslationLanguage,
 ) -> Result<String, Error> {
-    llm.request_chat(build_article_messages(instructions), model)
+    llm.request_chat(build_article_messages(instructions, language), model)
         .await
         .map(|article| article.trim().to_string())
 }
@@ -66,8 +73,12 @@ pub async fn request_researched_article_draft(
     llm: &Llm,
     instructions: &str,
     model: &str,
+    language: &SupportedTranslationLanguage,
 ) -> Result<String, Error> {
-    llm.request_chat(build_research_article_messages(instructions), model)
-        .await
-        .map(|article| article.trim().to_string())
+    llm.request_chat(
+        build_research_article_messages(instructions, language),
+        model,
+    )
+    .await
+    .map(|article| article.trim().to_string())
 }
@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::error::Error;
 use crate::image_generator::ImageToCreate;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::llm::Llm;
 
 use super::prompt_builder::build_illustrator_messages;
@@ -17,9 +18,10 @@ pub async fn generate_image_briefs(
     llm: &Llm,
     article: &str,
     model: &str,
+    language: &SupportedTranslationLanguage,
 ) -> Result<Vec<ImageToCreate>, Error> {
     let response = llm
-        .request_chat(build_illustrator_messages(article), model)
+        .request_chat(build_illustrator_messages(article, language), model)
         .await?;
     Ok(parse_image_brief_lines(
         &response,
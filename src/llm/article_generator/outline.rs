@@ -0,0 +1,352 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::llm::function_definition::FunctionDefinition;
+use crate::llm::prompt_registry::{
+    article_outline_prompt, generation_prompt_for_language, SupportedTranslationLanguage,
+};
+use crate::llm::{function_definition, Llm, Message};
+
+const MAX_HEADLINE_OPTIONS: usize = 3;
+const MAX_OUTLINE_BEATS: usize = 6;
+const MAX_IMAGE_SLOTS: usize = 4;
+const MAX_HEADLINE_CHARS: usize = 160;
+const MAX_OUTLINE_LINE_CHARS: usize = 300;
+
+/// The planning editor's structure for an article, stored under `outline` in the job's
+/// preview payload so the author can review it before the draft is written.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ArticleOutline {
+    pub headline_options: Vec<String>,
+    pub angle: String,
+    pub beats: Vec<String>,
+    #[serde(default)]
+    pub image_slots: Vec<String>,
+    /// Headline the author picked; the draft is published under it verbatim.
+    #[serde(default)]
+    pub chosen_headline: Option<String>,
+    /// Set once the author approves or the review deadline passes; writing then proceeds.
+    #[serde(default)]
+    pub approved: bool,
+    /// When an unanswered review resumes with the outline as planned, as `%F %T`.
+    #[serde(default)]
+    pub review_deadline: Option<String>,
+}
+
+impl ArticleOutline {
+    pub fn preview_payload(&self) -> Value {
+        serde_json::json!({ "outline": self })
+    }
+
+    pub fn awaiting_review(&self) -> bool {
+        !self.approved && self.review_deadline.is_some()
+    }
+
+    /// Applies the author's headline pick and beat edits. `beats` holds one beat per line.
+    pub fn with_author_choice(&self, headline: &str, beats: &str) -> Result<Self, Error> {
+        let headline = clean_line(headline, MAX_HEADLINE_CHARS);
+        if !self.headline_options.contains(&headline) {
+            return Err(Error::BadRequest(
+                "Pick one of the proposed headlines.".to_string(),
+            ));
+        }
+        let beats = beats
+            .lines()
+            .map(|beat| beat.trim().trim_start_matches(['-', '*']).trim())
+            .filter(|beat| !beat.is_empty())
+            .collect::<Vec<_>>();
+        if beats.is_empty() {
+            return Err(Error::BadRequest(
+                "Keep at least one section beat in the outline.".to_string(),
+            ));
+        }
+        if beats.len() > MAX_OUTLINE_BEATS {
+            return Err(Error::BadRequest(format!(
+                "Outlines can have at most {} section beats.",
+                MAX_OUTLINE_BEATS
+            )));
+        }
+        if beats
+            .iter()
+            .any(|beat| beat.chars().count() > MAX_OUTLINE_LINE_CHARS)
+        {
+            return Err(Error::BadRequest(format!(
+                "Section beats must stay under {} characters.",
+                MAX_OUTLINE_LINE_CHARS
+            )));
+        }
+
+        Ok(Self {
+            beats: beats
+                .into_iter()
+                .map(|beat| clean_line(beat, MAX_OUTLINE_LINE_CHARS))
+                .collect(),
+            chosen_headline: Some(headline),
+            approved: true,
+            ..self.clone()
+        })
+    }
+
+    /// Research drafts are headlined by the writer after reading the sources, so the chosen
+    /// headline is offered first among the options instead of being imposed.
+    pub fn with_suggested_headline(&self) -> Self {
+        let mut outline = self.clone();
+        if let Some(headline) = outline.chosen_headline.take() {
+            outline
+                .headline_options
+                .retain(|option| *option != headline);
+            outline.headline_options.insert(0, headline);
+        }
+        outline
+    }
+
+    /// The outline as the draft writer sees it, appended to the brief.
+    pub fn prompt_context(&self, instructions: &str) -> String {
+        let mut context = format!("{instructions}\n\nArticle outline:\n");
+        match &self.chosen_headline {
+            Some(headline) => {
+                context.push_str(&format!("Headline (use exactly): {headline}\n"));
+            }
+            None => {
+                context.push_str("Headline options (pick one or tighten it):\n");
+                for headline in &self.headline_options {
+                    context.push_str(&format!("- {headline}\n"));
+                }
+            }
+        }
+        context.push_str(&format!(
+            "Angle: {}\nSection beats, in order:\n",
+            self.angle
+        ));
+        for (index, beat) in self.beats.iter().enumerate() {
+            context.push_str(&format!("{}. {}\n", index + 1, beat));
+        }
+        if !self.image_slots.is_empty() {
+            context.push_str("Image slots:\n");
+            for slot in &self.image_slots {
+                context.push_str(&format!("- {slot}\n"));
+            }
+        }
+        context.trim_end().to_string()
+    }
+}
+
+pub fn parse_outline_payload(preview_payload: Option<&str>) -> Option<ArticleOutline> {
+    let payload = serde_json::from_str::<Value>(preview_payload?).ok()?;
+    serde_json::from_value(payload.get("outline")?.clone()).ok()
+}
+
+/// The author's headline wins over whatever the writer put on the first line.
+pub fn apply_outline_headline(title: String, outline: Option<&ArticleOutline>) -> String {
+    outline
+        .and_then(|outline| outline.chosen_headline.clone())
+        .unwrap_or(title)
+}
+
+fn submit_article_outline() -> FunctionDefinition {
+    let mut f = function_definition::def_function(
+        "submit_article_outline",
+        "Submit the structured outline for the article",
+    );
+    f.parameters
+        .add_arr_str("headline_options", true, "Candidate headlines, best first");
+    f.parameters
+        .add_str("angle", true, "The institutional angle the article takes");
+    f.parameters
+        .add_arr_str("beats", true, "Section beats in reading order");
+    f.parameters.add_arr_str(
+        "image_slots",
+        true,
+        "Where supporting images go and what they show",
+    );
+    f
+}
+
+pub async fn plan_article_outline(
+    llm: &Llm,
+    instructions: &str,
+    model: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<ArticleOutline, Error> {
+    let system = generation_prompt_for_language(article_outline_prompt(), language)
+        .replace("{max_headlines}", &MAX_HEADLINE_OPTIONS.to_string())
+        .replace("{max_beats}", &MAX_OUTLINE_BEATS.to_string())
+        .replace("{max_image_slots}", &MAX_IMAGE_SLOTS.to_string());
+    let messages = vec![
+        Message::System(system),
+        Message::User(instructions.to_string()),
+    ];
+    let response = llm
+        .request_tool(submit_article_outline(), messages, model)
+        .await?;
+    parse_article_outline(&response)
+}
+
+fn parse_article_outline(response: &str) -> Result<ArticleOutline, Error> {
+    let value: Value = serde_json::from_str(response)
+        .map_err(|e| Error::Llm(format!("Failed to parse article outline response: {}", e)))?;
+    let headline_options = string_list(
+        &value["headline_options"],
+        MAX_HEADLINE_OPTIONS,
+        MAX_HEADLINE_CHARS,
+    )
+    .into_iter()
+    .map(|headline| {
+        headline
+            .trim_start_matches('#')
+            .trim()
+            .trim_end_matches('.')
+            .to_string()
+    })
+    .filter(|headline| !headline.is_empty())
+    .collect::<Vec<_>>();
+    let beats = string_list(&value["beats"], MAX_OUTLINE_BEATS, MAX_OUTLINE_LINE_CHARS);
+    if headline_options.is_empty() || beats.is_empty() {
+        return Err(Error::Llm(
+            "Article outline needs at least one headline option and one beat".to_string(),
+        ));
+    }
+
+    Ok(ArticleOutline {
+        headline_options,
+        angle: clean_line(
+            value["angle"].as_str().unwrap_or_default(),
+            MAX_OUTLINE_LINE_CHARS,
+        ),
+        beats,
+        image_slots: string_list(
+            &value["image_slots"],
+            MAX_IMAGE_SLOTS,
+            MAX_OUTLINE_LINE_CHARS,
+        ),
+        chosen_headline: None,
+        approved: false,
+        review_deadline: None,
+    })
+}
+
+fn string_list(value: &Value, max_items: usize, max_chars: usize) -> Vec<String> {
+    let mut items = Vec::new();
+    for item in value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(|item| clean_line(item, max_chars))
+    {
+        if !item.is_empty() && !items.contains(&item) {
+            items.push(item);
+        }
+    }
+    items.truncate(max_items);
+    items
+}
+
+fn clean_line(text: &str, max_chars: usize) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(max_chars)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_outline_headline, parse_article_outline, parse_outline_payload};
+
+    fn sample_response() -> String {
+        serde_json::json!({
+            "headline_options": [
+                "# Transport Ministry Schedules Bus Feelings.",
+                "Ministry Issues Emotional Readiness Memo",
+                "Ministry Issues Emotional Readiness Memo",
+                "Buses Asked To Reflect",
+                "Fourth Option"
+            ],
+            "angle": "The transport ministry treats  bus morale as routine compliance.",
+            "beats": ["The memo lands", "Union response", ""],
+            "image_slots": ["Minister at a lectern"]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn outline_response_is_cleaned_deduplicated_and_capped() {
+        let outline = parse_article_outline(&sample_response()).unwrap();
+
+        assert_eq!(
+            outline.headline_options,
+            vec![
+                "Transport Ministry Schedules Bus Feelings",
+                "Ministry Issues Emotional Readiness Memo",
+                "Buses Asked To Reflect",
+            ]
+        );
+        assert_eq!(
+            outline.angle,
+            "The transport ministry treats bus morale as routine compliance."
+        );
+        assert_eq!(outline.beats, vec!["The memo lands", "Union response"]);
+        assert!(!outline.approved);
+        assert!(parse_article_outline(r#"{"headline_options":[],"beats":["x"]}"#).is_err());
+    }
+
+    #[test]
+    fn author_choice_requires_a_proposed_headline_and_rewrites_beats() {
+        let outline = parse_article_outline(&sample_response()).unwrap();
+
+        assert!(outline
+            .with_author_choice("A headline nobody proposed", "One beat")
+            .is_err());
+        assert!(outline
+            .with_author_choice("Buses Asked To Reflect", " \n ")
+            .is_err());
+
+        let approved = outline
+            .with_author_choice(
+                "Buses Asked To Reflect",
+                "- The memo lands\n\n- Drivers ask for clarity\n",
+            )
+            .unwrap();
+        assert!(approved.approved);
+        assert_eq!(
+            approved.beats,
+            vec!["The memo lands", "Drivers ask for clarity"]
+        );
+        assert_eq!(
+            apply_outline_headline("Writer headline".to_string(), Some(&approved)),
+            "Buses Asked To Reflect"
+        );
+        assert_eq!(
+            apply_outline_headline("Writer headline".to_string(), Some(&outline)),
+            "Writer headline"
+        );
+    }
+
+    #[test]
+    fn prompt_context_lists_the_outline_and_payload_round_trips() {
+        let outline = parse_article_outline(&sample_response())
+            .unwrap()
+            .with_author_choice("Buses Asked To Reflect", "The memo lands\nUnion response")
+            .unwrap();
+
+        let context = outline.prompt_context("Write about buses");
+        assert!(context.starts_with("Write about buses\n\nArticle outline:\n"));
+        assert!(context.contains("Headline (use exactly): Buses Asked To Reflect"));
+        assert!(context.contains("1. The memo lands\n2. Union response"));
+        assert!(context.ends_with("- Minister at a lectern"));
+
+        let suggested = outline
+            .with_suggested_headline()
+            .prompt_context("Write about buses");
+        assert!(suggested
+            .contains("Headline options (pick one or tighten it):\n- Buses Asked To Reflect\n"));
+        assert!(!suggested.contains("use exactly"));
+
+        let stored = outline.preview_payload().to_string();
+        assert_eq!(parse_outline_payload(Some(&stored)), Some(outline));
+        assert_eq!(parse_outline_payload(Some(r#"{"sources":[]}"#)), None);
+    }
+}
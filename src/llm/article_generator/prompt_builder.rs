@@ -1,39 +1,72 @@
 use crate::llm::prompt_registry::{
-    article_generation_prompt, image_brief_generation_prompt, placeholder_generation_prompt,
-    research_article_generation_prompt,
+    article_generation_prompt, generation_prompt_for_language, image_brief_generation_prompt,
+    placeholder_generation_prompt, research_article_generation_prompt,
+    research_query_planning_prompt, SupportedTranslationLanguage,
 };
 use crate::llm::Message;
 
-pub fn build_article_messages(instructions: &str) -> Vec<Message> {
-    build_messages(article_generation_prompt().body, None, instructions)
+pub fn build_article_messages(
+    instructions: &str,
+    language: &SupportedTranslationLanguage,
+) -> Vec<Message> {
+    build_messages(
+        generation_prompt_for_language(article_generation_prompt(), language),
+        None,
+        instructions,
+    )
 }
 
 pub fn build_placeholder_messages(
     examples: Option<Vec<(String, String)>>,
     instructions: &str,
+    language: &SupportedTranslationLanguage,
 ) -> Vec<Message> {
-    build_messages(placeholder_generation_prompt().body, examples, instructions)
+    build_messages(
+        generation_prompt_for_language(placeholder_generation_prompt(), language),
+        examples,
+        instructions,
+    )
 }
 
-pub fn build_research_article_messages(instructions: &str) -> Vec<Message> {
+pub fn build_research_article_messages(
+    instructions: &str,
+    language: &SupportedTranslationLanguage,
+) -> Vec<Message> {
     build_messages(
-        research_article_generation_prompt().body,
+        generation_prompt_for_language(research_article_generation_prompt(), language),
         None,
         instructions,
     )
 }
 
-pub fn build_illustrator_messages(article: &str) -> Vec<Message> {
-    build_messages(image_brief_generation_prompt().body, None, article)
+pub fn build_research_query_messages(brief: &str, max_queries: usize) -> Vec<Message> {
+    build_messages(
+        research_query_planning_prompt()
+            .body
+            .replace("{max_queries}", &max_queries.to_string()),
+        None,
+        brief,
+    )
+}
+
+pub fn build_illustrator_messages(
+    article: &str,
+    language: &SupportedTranslationLanguage,
+) -> Vec<Message> {
+    build_messages(
+        generation_prompt_for_language(image_brief_generation_prompt(), language),
+        None,
+        article,
+    )
 }
 
 fn build_messages(
-    system_message: &str,
+    system_message: String,
     examples: Option<Vec<(String, String)>>,
     instructions: &str,
 ) -> Vec<Message> {
     let mut messages = Vec::<Message>::new();
-    messages.push(Message::System(system_message.to_string()));
+    messages.push(Message::System(system_message));
 
     if let Some(examples) = examples {
         for (prompt, article) in examples {
@@ -53,6 +86,8 @@ mod tests {
         research_article_generation_prompt,
     };
     use crate::llm::Message;
+    use crate::services::article_language::article_source_language;
+    use crate::test_support::preferred_language;
 
     use super::{
         build_article_messages, build_illustrator_messages, build_placeholder_messages,
@@ -61,7 +96,7 @@ mod tests {
 
     #[test]
     fn build_article_messages_uses_registered_prompt_and_user_input() {
-        let messages = build_article_messages("Write about the audit");
+        let messages = build_article_messages("Write about the audit", &article_source_language());
 
         assert!(matches!(
             &messages[0],
@@ -81,6 +116,7 @@ mod tests {
                 ("Prompt two".to_string(), "# Article two".to_string()),
             ]),
             "Main request",
+            &article_source_language(),
         );
 
         assert!(matches!(
@@ -96,7 +132,7 @@ mod tests {
 
     #[test]
     fn build_illustrator_messages_uses_registered_prompt() {
-        let messages = build_illustrator_messages("Full article body");
+        let messages = build_illustrator_messages("Full article body", &article_source_language());
 
         assert!(matches!(
             &messages[0],
@@ -110,7 +146,8 @@ mod tests {
 
     #[test]
     fn build_research_article_messages_uses_research_prompt() {
-        let messages = build_research_article_messages("Research-backed request");
+        let messages =
+            build_research_article_messages("Research-backed request", &article_source_language());
 
         assert!(matches!(
             &messages[0],
@@ -121,4 +158,17 @@ mod tests {
             Message::User(body) if body == "Research-backed request"
         ));
     }
+
+    #[test]
+    fn build_article_messages_asks_for_the_source_language() {
+        let messages =
+            build_article_messages("Escreva sobre a auditoria", &preferred_language("pt"));
+
+        assert!(matches!(
+            &messages[0],
+            Message::System(body)
+                if body.starts_with(article_generation_prompt().body.trim_end())
+                    && body.contains("whole article in Portuguese")
+        ));
+    }
 }
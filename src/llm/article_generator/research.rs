@@ -1,21 +1,38 @@
-use regex::Regex;
+use std::collections::HashSet;
+use std::env;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
 use sea_orm::{ActiveValue, EntityTrait};
 use serde::Serialize;
 use serde_json::Value;
-use url::Url;
+use tracing::{event, Level};
 use uuid::Uuid;
 
 use crate::app_state::AppState;
-use crate::entities::{prelude::*, search_history};
+use crate::entities::{prelude::*, research_source, search_history};
 use crate::error::Error;
+use crate::llm::domain_policy::{research_domain_policy, ResearchDomainPolicy};
 use crate::llm::prompt_registry::research_article_generation_prompt;
 use crate::rate_limit::RequesterTier;
-
+use crate::repositories::research_sources::{
+    find_fresh_research_source, find_fresh_research_sources_for_query, save_research_source,
+    CachedSource,
+};
+use crate::repositories::research_traces::save_research_trace;
+use crate::search_provider::{SearchHit, SearchProvider};
+use crate::source_fetch::SourceFetcher;
+
+use super::research_freshness::{ResearchFreshness, SourceFreshness};
+use super::research_queries::plan_research_queries;
+use super::research_trace::{elapsed_ms, CandidateOutcome, ResearchTrace, TraceCandidate};
 use super::runtime::{BoundedGenerationRuntime, GenerationTool};
 
 const MAX_RESEARCH_RESULTS: usize = 5;
 const MAX_RESEARCH_SOURCES: usize = 3;
 const MAX_SOURCE_CONTEXT_CHARS: usize = 900;
+const DEFAULT_RESEARCH_SOURCE_TTL_MINUTES: i64 = 60;
+const RESEARCH_CACHE_PROVIDER: &str = "cache";
 const PUBLIC_TOPIC_MARKERS: &[&str] = &[
     "minister",
     "ministry",
@@ -65,12 +82,16 @@ pub struct ResearchSource {
     pub domain: String,
     pub snippet: String,
     pub context: String,
+    pub published: Option<NaiveDate>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ResearchPacket {
     pub mode_source: ResearchModeSource,
+    pub search_provider: String,
     pub sources: Vec<ResearchSource>,
+    /// The date research ran, so the writer can place source dates on a timeline.
+    pub today: NaiveDate,
 }
 
 impl ResearchPacket {
@@ -82,18 +103,22 @@ impl ResearchPacket {
             .enumerate()
             .map(|(index, source)| {
                 format!(
-                    "{}. {} ({})\nContext: {}",
+                    "{}. {} ({})\nPublished: {}\nContext: {}",
                     index + 1,
                     source.title,
                     source.domain,
+                    source
+                        .published
+                        .map(|date| date.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
                     source.context
                 )
             })
             .collect::<Vec<_>>()
             .join("\n\n");
         sections.push(format!(
-            "Research file:\n{}\n\nUse only this file to ground real institutions, timelines, and named public actors. Do not cite or mention the file directly.",
-            source_lines
+            "Today's date: {}\n\nResearch file:\n{}\n\nUse only this file to ground real institutions, timelines, and named public actors. Treat each source as current only as of its published date, and do not present older events as happening now. Do not cite or mention the file directly.",
+            self.today, source_lines
         ));
         sections.join("\n\n")
     }
@@ -102,6 +127,7 @@ impl ResearchPacket {
         serde_json::json!({
             "research": {
                 "mode": self.mode_source.as_str(),
+                "search_provider": self.search_provider,
                 "source_count": self.sources.len(),
                 "prompt_version": research_article_generation_prompt().version,
                 "sources": self.sources.iter().map(|source| {
@@ -109,6 +135,7 @@ impl ResearchPacket {
                         "title": source.title,
                         "url": source.url,
                         "domain": source.domain,
+                        "published": source.published.map(|date| date.to_string()),
                     })
                 }).collect::<Vec<_>>(),
             }
@@ -159,92 +186,380 @@ pub async fn gather_research_packet(
     state: &AppState,
     runtime: &mut BoundedGenerationRuntime,
     prompt: &str,
+    model: &str,
+    mode_source: ResearchModeSource,
+) -> Result<ResearchPacket, Error> {
+    traced_research_packet(state, runtime, prompt, model, mode_source, None).await
+}
+
+/// Like [`gather_research_packet`], but gives up once `deadline` has elapsed. The trace is
+/// still stored, recording the timeout as its error.
+pub async fn gather_research_packet_within(
+    state: &AppState,
+    runtime: &mut BoundedGenerationRuntime,
+    prompt: &str,
+    model: &str,
+    mode_source: ResearchModeSource,
+    deadline: Duration,
+) -> Result<ResearchPacket, Error> {
+    traced_research_packet(state, runtime, prompt, model, mode_source, Some(deadline)).await
+}
+
+async fn traced_research_packet(
+    state: &AppState,
+    runtime: &mut BoundedGenerationRuntime,
+    prompt: &str,
+    model: &str,
     mode_source: ResearchModeSource,
+    deadline: Option<Duration>,
 ) -> Result<ResearchPacket, Error> {
+    let started = Instant::now();
+    let mut trace = ResearchTrace::new(mode_source.as_str());
+    let collect = collect_research_packet(state, runtime, prompt, model, mode_source, &mut trace);
+    let packet = match deadline {
+        Some(deadline) => tokio::time::timeout(deadline, collect)
+            .await
+            .unwrap_or_else(|_| {
+                Err(Error::Fetch(format!(
+                    "Research timed out after {} seconds",
+                    deadline.as_secs()
+                )))
+            }),
+        None => collect.await,
+    };
+    match &packet {
+        Ok(packet) => trace.prompt_context = packet.prompt_context(prompt),
+        Err(err) => trace.error = Some(err.to_string()),
+    }
+    trace.total_ms = elapsed_ms(started);
+    if let Err(err) = save_research_trace(&state.db, runtime.job_id(), &trace).await {
+        event!(
+            Level::WARN,
+            job_id = runtime.job_id(),
+            error = %err,
+            "Failed to store research trace"
+        );
+    }
+    packet
+}
+
+async fn collect_research_packet(
+    state: &AppState,
+    runtime: &mut BoundedGenerationRuntime,
+    prompt: &str,
+    model: &str,
+    mode_source: ResearchModeSource,
+    trace: &mut ResearchTrace,
+) -> Result<ResearchPacket, Error> {
+    // Query planning is the research step's one model call; with the default budget of a
+    // single search the planner just picks the best query for the brief.
     runtime
-        .begin_tool(GenerationTool::LimitedWebResearch, false)
+        .begin_tool(GenerationTool::LimitedWebResearch, true)
         .await?;
-    runtime.record_search().await?;
-
-    let client = reqwest::Client::builder()
-        .user_agent("WibbleResearch/1.0")
-        .build()
-        .map_err(|e| Error::Llm(format!("Failed to build research HTTP client: {}", e)))?;
-    let results = search_public_web(&client, prompt).await?;
-    persist_search_history(&state.db, prompt, results.len() as i32).await?;
-
-    let mut sources = Vec::new();
-    for result in results {
-        if sources.len() >= MAX_RESEARCH_SOURCES {
+    let queries = plan_research_queries(
+        &state.llm,
+        prompt,
+        model,
+        runtime.remaining_searches() as usize,
+    )
+    .await;
+    runtime.record_planned_queries(&queries).await?;
+    trace.planned_queries = queries.clone();
+
+    let policy = research_domain_policy();
+    let provider = state.search_provider.as_ref();
+    let fetcher = SourceFetcher::new();
+    let ttl = research_source_ttl();
+    let today = chrono::Utc::now().date_naive();
+    let mut selection = SourceSelection::new(ResearchFreshness::from_env(), today);
+    let mut seen_urls = HashSet::new();
+    let mut searched_live = false;
+    let mut last_search_error = None;
+    for query in &queries {
+        if selection.is_full() {
             break;
         }
-        if let Ok(context) = fetch_source_context(&client, &result.url).await {
-            runtime.record_source().await?;
+        let started = Instant::now();
+        let cached = find_fresh_research_sources_for_query(&state.db, query)
+            .await?
+            .into_iter()
+            .filter(|row| policy.permits(&row.domain))
+            .collect::<Vec<_>>();
+        if !cached.is_empty() {
+            runtime.record_search_cache_hit().await?;
+            trace.record_query(query, RESEARCH_CACHE_PROVIDER, cached.len(), started);
+            for row in cached {
+                let mut candidate =
+                    trace_candidate(&row.title, &row.url, &row.domain, policy.score(&row.domain));
+                if !seen_urls.insert(row.url.clone()) {
+                    candidate.outcome = CandidateOutcome::Duplicate;
+                } else if selection.is_full() {
+                    candidate.outcome = CandidateOutcome::NotNeeded;
+                } else {
+                    selection
+                        .offer(runtime, trace, candidate, cached_research_source(row), true)
+                        .await?;
+                    continue;
+                }
+                trace.candidates.push(candidate);
+            }
+            continue;
+        }
+
+        runtime.record_search(query).await?;
+        searched_live = true;
+        let results = match search_public_web(provider, &policy, query, trace).await {
+            Ok(results) => results,
+            Err(err) => {
+                trace.record_failed_query(query, provider.name(), &err, started);
+                last_search_error = Some(err);
+                continue;
+            }
+        };
+        trace.record_query(query, provider.name(), results.len(), started);
+        persist_search_history(&state.db, query, results.len() as i32).await?;
+
+        for result in results {
+            let mut candidate =
+                trace_candidate(&result.title, &result.url, &result.domain, result.score);
+            if !seen_urls.insert(result.url.clone()) {
+                candidate.outcome = CandidateOutcome::Duplicate;
+                trace.candidates.push(candidate);
+                continue;
+            }
+            if selection.is_full() {
+                candidate.outcome = CandidateOutcome::NotNeeded;
+                trace.candidates.push(candidate);
+                continue;
+            }
+            if let Some(row) = find_fresh_research_source(&state.db, &result.url).await? {
+                selection
+                    .offer(runtime, trace, candidate, cached_research_source(row), true)
+                    .await?;
+                continue;
+            }
+            // Stale pages held back as fallbacks still spend the fetch budget, so stop
+            // fetching before another page could overrun it.
+            if runtime.remaining_fetched_content_chars() < MAX_SOURCE_CONTEXT_CHARS {
+                candidate.outcome = CandidateOutcome::NotNeeded;
+                trace.candidates.push(candidate);
+                continue;
+            }
+            let started = Instant::now();
+            let fetched = fetch_source_context(&fetcher, &result.url).await;
+            candidate.elapsed_ms = elapsed_ms(started);
+            let (context, published) = match fetched {
+                Ok(fetched) => fetched,
+                Err(err) => {
+                    candidate.outcome = CandidateOutcome::FetchFailed;
+                    candidate.error = Some(err.to_string());
+                    trace.candidates.push(candidate);
+                    continue;
+                }
+            };
             runtime
                 .record_fetched_content(context.chars().count())
                 .await?;
-            sources.push(ResearchSource {
+            save_research_source(
+                &state.db,
+                CachedSource {
+                    url: &result.url,
+                    title: &result.title,
+                    domain: &result.domain,
+                    snippet: &result.snippet,
+                    search_query: query,
+                    content: &context,
+                    published_on: published,
+                },
+                ttl,
+            )
+            .await?;
+            candidate.outcome = CandidateOutcome::Fetched;
+            let source = ResearchSource {
                 title: result.title,
                 url: result.url,
                 domain: result.domain,
                 snippet: result.snippet,
                 context,
-            });
+                published,
+            };
+            selection
+                .offer(runtime, trace, candidate, source, false)
+                .await?;
         }
     }
 
+    let sources = selection.finish(runtime, trace).await?;
+    let search_provider = if searched_live {
+        provider.name()
+    } else {
+        RESEARCH_CACHE_PROVIDER
+    };
+    trace.search_provider = search_provider.to_string();
     if sources.is_empty() {
-        return Err(Error::Llm(
-            "Research mode could not gather any usable public-source context".to_string(),
-        ));
+        return Err(last_search_error.unwrap_or_else(|| {
+            Error::Llm(
+                "Research mode could not gather any usable public-source context".to_string(),
+            )
+        }));
     }
 
     Ok(ResearchPacket {
         mode_source,
+        search_provider: search_provider.to_string(),
         sources,
+        today,
     })
 }
 
+/// Sources chosen for the research file. Recent sources take slots as they arrive; under
+/// `prefer`, older or undated ones wait as fallbacks and only fill slots left at the end.
+struct SourceSelection {
+    freshness: ResearchFreshness,
+    today: NaiveDate,
+    selected: Vec<ResearchSource>,
+    fallbacks: Vec<(ResearchSource, bool, usize)>,
+}
+
+impl SourceSelection {
+    fn new(freshness: ResearchFreshness, today: NaiveDate) -> Self {
+        Self {
+            freshness,
+            today,
+            selected: Vec::new(),
+            fallbacks: Vec::new(),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.selected.len() >= MAX_RESEARCH_SOURCES
+    }
+
+    async fn offer(
+        &mut self,
+        runtime: &mut BoundedGenerationRuntime,
+        trace: &mut ResearchTrace,
+        mut candidate: TraceCandidate,
+        source: ResearchSource,
+        cached: bool,
+    ) -> Result<(), Error> {
+        candidate.published = source.published.map(|date| date.to_string());
+        candidate.context_chars = source.context.chars().count();
+        match self.freshness.classify(source.published, self.today) {
+            SourceFreshness::Recent => {
+                accept_source(runtime, &source, cached).await?;
+                self.selected.push(source);
+            }
+            SourceFreshness::Fallback => {
+                self.fallbacks
+                    .push((source, cached, trace.candidates.len()));
+            }
+            SourceFreshness::Rejected => candidate.outcome = CandidateOutcome::Stale,
+        }
+        trace.candidates.push(candidate);
+        Ok(())
+    }
+
+    async fn finish(
+        mut self,
+        runtime: &mut BoundedGenerationRuntime,
+        trace: &mut ResearchTrace,
+    ) -> Result<Vec<ResearchSource>, Error> {
+        for (source, cached, index) in std::mem::take(&mut self.fallbacks) {
+            if self.is_full() {
+                trace.candidates[index].outcome = CandidateOutcome::Stale;
+                continue;
+            }
+            accept_source(runtime, &source, cached).await?;
+            self.selected.push(source);
+        }
+        Ok(self.selected)
+    }
+}
+
+async fn accept_source(
+    runtime: &mut BoundedGenerationRuntime,
+    source: &ResearchSource,
+    cached: bool,
+) -> Result<(), Error> {
+    runtime.record_source().await?;
+    if cached {
+        runtime
+            .record_source_cache_hit(source.context.chars().count())
+            .await?;
+    }
+    Ok(())
+}
+
+/// Starts a trace entry as a cache hit; callers overwrite the outcome for other paths.
+fn trace_candidate(title: &str, url: &str, domain: &str, score: i32) -> TraceCandidate {
+    TraceCandidate {
+        title: title.to_string(),
+        url: url.to_string(),
+        domain: domain.to_string(),
+        score,
+        outcome: CandidateOutcome::Cached,
+        published: None,
+        context_chars: 0,
+        elapsed_ms: 0,
+        error: None,
+    }
+}
+
+fn cached_research_source(row: research_source::Model) -> ResearchSource {
+    ResearchSource {
+        title: row.title,
+        url: row.url,
+        domain: row.domain,
+        snippet: row.snippet,
+        context: row.content,
+        published: row.published_on,
+    }
+}
+
+fn research_source_ttl() -> chrono::Duration {
+    let minutes = env::var("RESEARCH_SOURCE_TTL_MINUTES")
+        .ok()
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .filter(|minutes| *minutes >= 0)
+        .unwrap_or(DEFAULT_RESEARCH_SOURCE_TTL_MINUTES);
+    chrono::Duration::minutes(minutes)
+}
+
 async fn search_public_web(
-    client: &reqwest::Client,
+    provider: &dyn SearchProvider,
+    policy: &ResearchDomainPolicy,
     query: &str,
+    trace: &mut ResearchTrace,
 ) -> Result<Vec<SearchResult>, Error> {
-    let response = client
-        .get("https://duckduckgo.com/html/")
-        .query(&[("q", query)])
-        .send()
-        .await
-        .map_err(|e| Error::Llm(format!("Failed to search public web: {}", e)))?
-        .text()
-        .await
-        .map_err(|e| Error::Llm(format!("Failed to read search response: {}", e)))?;
-
-    let entry_re = Regex::new(
-        r#"(?s)result__a" href="(?P<href>[^"]+)".*?>(?P<title>.*?)</a>.*?result__snippet[^>]*>(?P<snippet>.*?)</"#,
-    )
-    .expect("search result regex must compile");
-    let tag_re = Regex::new(r"<[^>]+>").expect("tag stripping regex must compile");
-
-    let mut results = entry_re
-        .captures_iter(&response)
-        .filter_map(|capture| {
-            let href = capture.name("href")?.as_str();
-            let url = resolve_duckduckgo_url(href)?;
-            let domain = Url::parse(&url)
-                .ok()
-                .and_then(|value| value.domain().map(str::to_string))
-                .unwrap_or_default();
-            Some(SearchResult {
-                title: decode_html_entities(
-                    &tag_re.replace_all(capture.name("title")?.as_str(), ""),
-                ),
-                url: url.clone(),
-                domain: domain.clone(),
-                snippet: decode_html_entities(
-                    &tag_re.replace_all(capture.name("snippet")?.as_str(), ""),
-                ),
-                score: source_domain_score(&domain),
-            })
+    let hits = provider.search(query, MAX_RESEARCH_RESULTS * 2).await?;
+    Ok(rank_search_hits(hits, policy, trace))
+}
+
+/// Drops domains the admin policy blocks (or leaves off an allowlist) and ranks the rest
+/// by their policy score. Every dropped hit is recorded in the trace with its reason.
+fn rank_search_hits(
+    hits: Vec<SearchHit>,
+    policy: &ResearchDomainPolicy,
+    trace: &mut ResearchTrace,
+) -> Vec<SearchResult> {
+    let (permitted, blocked): (Vec<_>, Vec<_>) = hits
+        .into_iter()
+        .partition(|hit| policy.permits(&hit.domain));
+    trace
+        .candidates
+        .extend(blocked.iter().map(|hit| TraceCandidate {
+            outcome: CandidateOutcome::Blocked,
+            ..trace_candidate(&hit.title, &hit.url, &hit.domain, 0)
+        }));
+    let mut results = permitted
+        .into_iter()
+        .map(|hit| SearchResult {
+            score: policy.score(&hit.domain),
+            title: hit.title,
+            url: hit.url,
+            domain: hit.domain,
+            snippet: hit.snippet,
         })
         .collect::<Vec<_>>();
 
@@ -255,35 +570,41 @@ async fn search_public_web(
             .then_with(|| left.title.cmp(&right.title))
     });
 
-    let mut seen = std::collections::HashSet::new();
-    results.retain(|result| seen.insert(result.url.clone()));
-    results.truncate(MAX_RESEARCH_RESULTS);
-    Ok(results)
+    let mut seen = HashSet::new();
+    let (mut results, duplicates): (Vec<_>, Vec<_>) = results
+        .into_iter()
+        .partition(|result| seen.insert(result.url.clone()));
+    let below_cutoff = results.split_off(results.len().min(MAX_RESEARCH_RESULTS));
+    for (dropped, outcome) in duplicates
+        .iter()
+        .map(|result| (result, CandidateOutcome::Duplicate))
+        .chain(
+            below_cutoff
+                .iter()
+                .map(|result| (result, CandidateOutcome::BelowCutoff)),
+        )
+    {
+        trace.candidates.push(TraceCandidate {
+            outcome,
+            ..trace_candidate(&dropped.title, &dropped.url, &dropped.domain, dropped.score)
+        });
+    }
+    results
 }
 
-async fn fetch_source_context(client: &reqwest::Client, url: &str) -> Result<String, Error> {
-    let stripped = url
-        .trim_start_matches("https://")
-        .trim_start_matches("http://");
-    let mirror_url = format!("https://r.jina.ai/http://{}", stripped);
-    let response = client
-        .get(&mirror_url)
-        .send()
-        .await
-        .map_err(|e| Error::Llm(format!("Failed to fetch source text: {}", e)))?
-        .text()
-        .await
-        .map_err(|e| Error::Llm(format!("Failed to read source text: {}", e)))?;
-
-    let body = response
-        .split_once("Markdown Content:")
-        .map(|(_, body)| body)
-        .unwrap_or(response.as_str());
-    let collapsed = collapse_whitespace(body);
+async fn fetch_source_context(
+    fetcher: &SourceFetcher,
+    url: &str,
+) -> Result<(String, Option<NaiveDate>), Error> {
+    let page = fetcher.fetch_readable(url).await?;
+    let collapsed = collapse_whitespace(&page.text);
     if collapsed.is_empty() {
-        return Err(Error::Llm("Fetched source text was empty".to_string()));
+        return Err(Error::Fetch("Fetched source text was empty".to_string()));
     }
-    Ok(truncate_chars(&collapsed, MAX_SOURCE_CONTEXT_CHARS))
+    Ok((
+        truncate_chars(&collapsed, MAX_SOURCE_CONTEXT_CHARS),
+        page.published,
+    ))
 }
 
 async fn persist_search_history(
@@ -303,50 +624,6 @@ async fn persist_search_history(
     Ok(())
 }
 
-fn resolve_duckduckgo_url(raw: &str) -> Option<String> {
-    let href = if raw.starts_with("//") {
-        format!("https:{}", raw)
-    } else {
-        raw.to_string()
-    };
-    let parsed = Url::parse(&href).ok()?;
-    let uddg = parsed
-        .query_pairs()
-        .find(|(key, _)| key == "uddg")
-        .map(|(_, value)| value.to_string())?;
-    if uddg.starts_with("http://") || uddg.starts_with("https://") {
-        Some(uddg)
-    } else {
-        None
-    }
-}
-
-fn source_domain_score(domain: &str) -> i32 {
-    if domain.ends_with(".gov")
-        || domain.contains(".gov.")
-        || domain.contains("parliament")
-        || domain.contains("senate")
-        || domain.contains("ministry")
-        || domain.ends_with(".edu")
-    {
-        30
-    } else if [
-        "reuters.com",
-        "apnews.com",
-        "bbc.com",
-        "npr.org",
-        "ft.com",
-        "economist.com",
-    ]
-    .iter()
-    .any(|candidate| domain.contains(candidate))
-    {
-        20
-    } else {
-        10
-    }
-}
-
 fn looks_like_named_public_entity(text: &str) -> bool {
     let mut previous_capitalized = false;
     for token in text.split_whitespace() {
@@ -377,24 +654,33 @@ fn truncate_chars(text: &str, max_chars: usize) -> String {
     text.chars().take(max_chars).collect()
 }
 
-fn decode_html_entities(input: &str) -> String {
-    input
-        .replace("&amp;", "&")
-        .replace("&quot;", "\"")
-        .replace("&#x27;", "'")
-        .replace("&#39;", "'")
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{error::Error, rate_limit::RequesterTier};
 
+    use crate::llm::domain_policy::{DomainAction, DomainRule, ResearchDomainPolicy};
+    use crate::search_provider::local_index::{LocalIndexDocument, LocalIndexSearchProvider};
+
+    use chrono::NaiveDate;
+
     use super::{
-        prompt_requires_research, resolve_duckduckgo_url, resolve_research_mode,
-        source_domain_score, ResearchModeSource,
+        prompt_requires_research, resolve_research_mode, search_public_web, ResearchModeSource,
+        ResearchPacket, ResearchSource,
     };
+    use crate::llm::article_generator::research_trace::{CandidateOutcome, ResearchTrace};
+
+    fn sample_policy() -> ResearchDomainPolicy {
+        let rule = |pattern: &str, action, score| DomainRule {
+            pattern: pattern.to_string(),
+            action,
+            score,
+            leak_markers: Vec::new(),
+        };
+        ResearchDomainPolicy::new(vec![
+            rule("*.gov", DomainAction::Boost, 30),
+            rule("content-farm.example", DomainAction::Block, 0),
+        ])
+    }
 
     #[test]
     fn research_detection_triggers_for_public_policy_topics() {
@@ -410,19 +696,11 @@ mod tests {
         ));
     }
 
-    #[test]
-    fn duckduckgo_redirect_url_is_decoded() {
-        let decoded = resolve_duckduckgo_url(
-            "//duckduckgo.com/l/?uddg=https%3A%2F%2Fexample.gov%2Fmemo&rut=test",
-        )
-        .unwrap();
-
-        assert_eq!(decoded, "https://example.gov/memo");
-    }
-
     #[test]
     fn primary_domains_rank_above_generic_results() {
-        assert!(source_domain_score("transport.gov") > source_domain_score("example.com"));
+        let policy = sample_policy();
+
+        assert!(policy.score("transport.gov") > policy.score("example.com"));
     }
 
     #[test]
@@ -448,4 +726,91 @@ mod tests {
 
         assert_eq!(mode, Some(ResearchModeSource::Auto));
     }
+
+    #[tokio::test]
+    async fn provider_hits_are_filtered_ranked_by_domain_policy_and_deduplicated() {
+        let document = |title: &str, url: &str| LocalIndexDocument {
+            title: title.to_string(),
+            url: url.to_string(),
+            snippet: "Emissions policy review".to_string(),
+            body: String::new(),
+        };
+        let provider = LocalIndexSearchProvider::from_documents(vec![
+            document("Emissions blog", "https://example.com/emissions"),
+            document("Emissions policy", "https://transport.gov/emissions"),
+            document("Emissions policy mirror", "https://transport.gov/emissions"),
+            document(
+                "Emissions policy digest",
+                "https://content-farm.example/emissions",
+            ),
+        ]);
+
+        let mut trace = ResearchTrace::new("manual");
+        let results =
+            search_public_web(&provider, &sample_policy(), "emissions policy", &mut trace)
+                .await
+                .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].domain, "transport.gov");
+        assert_eq!(results[1].domain, "example.com");
+        let dropped = trace
+            .candidates
+            .iter()
+            .map(|candidate| (candidate.domain.as_str(), candidate.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dropped,
+            vec![
+                ("content-farm.example", CandidateOutcome::Blocked),
+                ("transport.gov", CandidateOutcome::Duplicate),
+            ]
+        );
+    }
+
+    #[test]
+    fn preview_payload_records_answering_search_provider() {
+        let packet = ResearchPacket {
+            mode_source: ResearchModeSource::Manual,
+            search_provider: "searxng".to_string(),
+            sources: Vec::new(),
+            today: NaiveDate::from_ymd_opt(2026, 5, 4).unwrap(),
+        };
+
+        let payload = packet.preview_payload();
+
+        assert_eq!(payload["research"]["search_provider"], "searxng");
+        assert_eq!(payload["research"]["mode"], "manual");
+    }
+
+    #[test]
+    fn prompt_context_dates_the_research_file_and_each_source() {
+        let source = |title: &str, published| ResearchSource {
+            title: title.to_string(),
+            url: "https://transport.gov/memo".to_string(),
+            domain: "transport.gov".to_string(),
+            snippet: String::new(),
+            context: "New emissions targets.".to_string(),
+            published,
+        };
+        let packet = ResearchPacket {
+            mode_source: ResearchModeSource::Manual,
+            search_provider: "searxng".to_string(),
+            sources: vec![
+                source("Memo", NaiveDate::from_ymd_opt(2026, 4, 30)),
+                source("Archive", None),
+            ],
+            today: NaiveDate::from_ymd_opt(2026, 5, 4).unwrap(),
+        };
+
+        let context = packet.prompt_context("Brief");
+
+        assert!(context.contains("Today's date: 2026-05-04"));
+        assert!(context.contains("1. Memo (transport.gov)\nPublished: 2026-04-30"));
+        assert!(context.contains("2. Archive (transport.gov)\nPublished: unknown"));
+        assert_eq!(
+            packet.preview_payload()["research"]["sources"][0]["published"],
+            "2026-04-30"
+        );
+    }
 }
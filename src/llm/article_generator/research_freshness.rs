@@ -0,0 +1,99 @@
+use std::env;
+
+use chrono::NaiveDate;
+
+const DEFAULT_MAX_SOURCE_AGE_DAYS: i64 = 30;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FreshnessMode {
+    /// Publication dates are reported to the writer but do not affect selection.
+    Any,
+    /// Recent sources are used first; older or undated ones only fill remaining slots.
+    Prefer,
+    /// Only sources with a publication date inside the window are used.
+    Require,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SourceFreshness {
+    Recent,
+    Fallback,
+    Rejected,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResearchFreshness {
+    pub mode: FreshnessMode,
+    pub max_age_days: i64,
+}
+
+impl ResearchFreshness {
+    /// Reads `RESEARCH_FRESHNESS` (`any`, `prefer` or `require`; default `prefer`) and
+    /// `RESEARCH_MAX_SOURCE_AGE_DAYS` (default 30).
+    pub fn from_env() -> Self {
+        let mode = match env::var("RESEARCH_FRESHNESS")
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "any" | "off" => FreshnessMode::Any,
+            "require" => FreshnessMode::Require,
+            _ => FreshnessMode::Prefer,
+        };
+        let max_age_days = env::var("RESEARCH_MAX_SOURCE_AGE_DAYS")
+            .ok()
+            .and_then(|value| value.trim().parse::<i64>().ok())
+            .filter(|days| *days > 0)
+            .unwrap_or(DEFAULT_MAX_SOURCE_AGE_DAYS);
+        Self { mode, max_age_days }
+    }
+
+    pub fn is_recent(&self, published: Option<NaiveDate>, today: NaiveDate) -> bool {
+        published.is_some_and(|published| (today - published).num_days() <= self.max_age_days)
+    }
+
+    pub fn classify(&self, published: Option<NaiveDate>, today: NaiveDate) -> SourceFreshness {
+        if self.mode == FreshnessMode::Any || self.is_recent(published, today) {
+            return SourceFreshness::Recent;
+        }
+        match self.mode {
+            FreshnessMode::Require => SourceFreshness::Rejected,
+            _ => SourceFreshness::Fallback,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{FreshnessMode, ResearchFreshness, SourceFreshness};
+
+    fn freshness(mode: FreshnessMode) -> ResearchFreshness {
+        ResearchFreshness {
+            mode,
+            max_age_days: 30,
+        }
+    }
+
+    #[test]
+    fn sources_are_classified_by_age_and_mode() {
+        let today = NaiveDate::from_ymd_opt(2026, 5, 4).unwrap();
+        let recent = NaiveDate::from_ymd_opt(2026, 4, 20);
+        let old = NaiveDate::from_ymd_opt(2024, 4, 20);
+
+        let prefer = freshness(FreshnessMode::Prefer);
+        assert_eq!(prefer.classify(recent, today), SourceFreshness::Recent);
+        assert_eq!(prefer.classify(old, today), SourceFreshness::Fallback);
+        assert_eq!(prefer.classify(None, today), SourceFreshness::Fallback);
+
+        let require = freshness(FreshnessMode::Require);
+        assert_eq!(require.classify(recent, today), SourceFreshness::Recent);
+        assert_eq!(require.classify(old, today), SourceFreshness::Rejected);
+        assert_eq!(require.classify(None, today), SourceFreshness::Rejected);
+
+        let any = freshness(FreshnessMode::Any);
+        assert_eq!(any.classify(None, today), SourceFreshness::Recent);
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use tracing::{event, Level};
+
+use crate::llm::Llm;
+use crate::repositories::research_sources::normalize_research_query;
+
+use super::prompt_builder::build_research_query_messages;
+
+pub const MAX_RESEARCH_QUERIES: usize = 3;
+const MAX_QUERY_CHARS: usize = 200;
+
+/// Asks the model for up to `max_queries` targeted searches for the brief. The raw brief is
+/// the fallback whenever planning fails or yields nothing usable, so research still runs.
+pub async fn plan_research_queries(
+    llm: &Llm,
+    brief: &str,
+    model: &str,
+    max_queries: usize,
+) -> Vec<String> {
+    let max_queries = max_queries.clamp(1, MAX_RESEARCH_QUERIES);
+    let planned = match llm
+        .request_chat(build_research_query_messages(brief, max_queries), model)
+        .await
+    {
+        Ok(response) => parse_research_queries(&response, max_queries),
+        Err(err) => {
+            event!(
+                Level::WARN,
+                error = %err,
+                "Research query planning failed; searching for the brief instead"
+            );
+            Vec::new()
+        }
+    };
+    if planned.is_empty() {
+        return vec![fallback_query(brief)];
+    }
+    planned
+}
+
+fn parse_research_queries(response: &str, max_queries: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    response
+        .lines()
+        .map(clean_query_line)
+        .filter(|query| !query.is_empty())
+        .filter(|query| seen.insert(normalize_research_query(query)))
+        .take(max_queries)
+        .collect()
+}
+
+fn clean_query_line(line: &str) -> String {
+    let line = line.trim().trim_start_matches(['-', '*', '•']).trim_start();
+    let line = strip_numbering(line);
+    let line = line.trim().trim_matches(['"', '\'', '`', '“', '”']).trim();
+    line.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_QUERY_CHARS)
+        .collect()
+}
+
+fn strip_numbering(line: &str) -> &str {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return line;
+    }
+    match line[digits..].strip_prefix(['.', ')', ':']) {
+        Some(rest) => rest,
+        None => line,
+    }
+}
+
+fn fallback_query(brief: &str) -> String {
+    brief
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_QUERY_CHARS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fallback_query, parse_research_queries};
+
+    #[test]
+    fn planned_queries_are_cleaned_deduplicated_and_capped() {
+        let response = "1. transport ministry bus policy\n\n- \"Regional bus timetable\"\n2) Transport  Ministry bus policy\nvehicle inspection rules\nextra query";
+
+        assert_eq!(
+            parse_research_queries(response, 3),
+            vec![
+                "transport ministry bus policy",
+                "Regional bus timetable",
+                "vehicle inspection rules",
+            ]
+        );
+    }
+
+    #[test]
+    fn fallback_query_collapses_the_brief() {
+        assert_eq!(
+            fallback_query("  The transport\nministry  memo "),
+            "The transport ministry memo"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandidateOutcome {
+    /// Fetched live and handed to the model.
+    Fetched,
+    /// Served from the research source cache and handed to the model.
+    Cached,
+    FetchFailed,
+    /// Removed by the research domain policy before ranking.
+    Blocked,
+    Duplicate,
+    /// Ranked below the result cutoff.
+    BelowCutoff,
+    /// Ranked, but the source budget was already spent.
+    NotNeeded,
+    /// Older than the freshness window (or undated) and not used.
+    Stale,
+}
+
+impl CandidateOutcome {
+    pub fn used(self) -> bool {
+        matches!(self, Self::Fetched | Self::Cached)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TraceQuery {
+    pub query: String,
+    pub provider: String,
+    pub result_count: usize,
+    pub elapsed_ms: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TraceCandidate {
+    pub title: String,
+    pub url: String,
+    pub domain: String,
+    pub score: i32,
+    pub outcome: CandidateOutcome,
+    /// Publication date found on the page, as `YYYY-MM-DD`.
+    #[serde(default)]
+    pub published: Option<String>,
+    #[serde(default)]
+    pub context_chars: usize,
+    #[serde(default)]
+    pub elapsed_ms: u64,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Everything one research run did, kept for editorial review on the admin trace page.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ResearchTrace {
+    pub mode: String,
+    pub search_provider: String,
+    /// Queries proposed by the research query planner, in the order they were run.
+    #[serde(default)]
+    pub planned_queries: Vec<String>,
+    pub queries: Vec<TraceQuery>,
+    pub candidates: Vec<TraceCandidate>,
+    /// The research file exactly as it was given to the draft writer.
+    #[serde(default)]
+    pub prompt_context: String,
+    #[serde(default)]
+    pub error: Option<String>,
+    pub total_ms: u64,
+}
+
+impl ResearchTrace {
+    pub fn new(mode: &str) -> Self {
+        Self {
+            mode: mode.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn record_query(
+        &mut self,
+        query: &str,
+        provider: &str,
+        result_count: usize,
+        started: Instant,
+    ) {
+        self.queries.push(TraceQuery {
+            query: query.to_string(),
+            provider: provider.to_string(),
+            result_count,
+            elapsed_ms: elapsed_ms(started),
+            error: None,
+        });
+    }
+
+    pub fn record_failed_query(
+        &mut self,
+        query: &str,
+        provider: &str,
+        error: &Error,
+        started: Instant,
+    ) {
+        self.queries.push(TraceQuery {
+            query: query.to_string(),
+            provider: provider.to_string(),
+            result_count: 0,
+            elapsed_ms: elapsed_ms(started),
+            error: Some(error.to_string()),
+        });
+    }
+
+    pub fn used_source_count(&self) -> usize {
+        self.candidates
+            .iter()
+            .filter(|candidate| candidate.outcome.used())
+            .count()
+    }
+}
+
+pub fn elapsed_ms(started: Instant) -> u64 {
+    u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CandidateOutcome, ResearchTrace, TraceCandidate};
+
+    #[test]
+    fn trace_round_trips_and_counts_used_sources() {
+        let candidate = |outcome| TraceCandidate {
+            title: "Memo".to_string(),
+            url: "https://transport.gov/memo".to_string(),
+            domain: "transport.gov".to_string(),
+            score: 30,
+            outcome,
+            published: None,
+            context_chars: 0,
+            elapsed_ms: 0,
+            error: None,
+        };
+        let mut trace = ResearchTrace::new("manual");
+        trace.candidates = vec![
+            candidate(CandidateOutcome::Fetched),
+            candidate(CandidateOutcome::Cached),
+            candidate(CandidateOutcome::Blocked),
+        ];
+
+        let stored = serde_json::to_string(&trace).unwrap();
+        assert!(stored.contains("\"outcome\":\"blocked\""));
+        let loaded = serde_json::from_str::<ResearchTrace>(&stored).unwrap();
+
+        assert_eq!(loaded, trace);
+        assert_eq!(loaded.used_source_count(), 2);
+    }
+}
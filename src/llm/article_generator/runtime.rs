@@ -1,3 +1,5 @@
+use std::env;
+
 use serde_json::json;
 use serde_json::Value;
 use tracing::{event, Level};
@@ -9,13 +11,16 @@ use crate::services::article_jobs::{
     ARTICLE_JOB_PHASE_READY_FOR_REVIEW, ARTICLE_JOB_PHASE_RESEARCHING, ARTICLE_JOB_PHASE_WRITING,
 };
 
+use super::research_queries::MAX_RESEARCH_QUERIES;
+
 const DEFAULT_MAX_PROMPT_CHARS: usize = 600;
 const DEFAULT_MAX_AGENT_STEPS: u32 = 6;
-const DEFAULT_MAX_MODEL_CALLS: u32 = 3;
+const DEFAULT_MAX_MODEL_CALLS: u32 = 4;
 const DEFAULT_MAX_TOOL_CALLS: u32 = 4;
 const DEFAULT_MAX_FETCHED_CONTENT_CHARS: usize = 0;
 const DEFAULT_MAX_SEARCHES: u32 = 0;
 const DEFAULT_MAX_SOURCES: u32 = 0;
+const DEFAULT_RESEARCH_MAX_SEARCHES: u32 = 1;
 
 pub const GENERATION_TOOL_REGISTRY: [GenerationTool; 5] = [
     GenerationTool::ArticlePlanning,
@@ -49,7 +54,7 @@ impl GenerationTool {
         match self {
             Self::ArticlePlanning => "Outline the article angle and structural plan",
             Self::LimitedWebResearch => {
-                "Run the bounded public-web search and fetch flow for grounded briefs"
+                "Plan targeted queries, then run the bounded public-web search and fetch flow for grounded briefs"
             }
             Self::DraftWriter => "Produce the article draft from the selected prompt layer",
             Self::ImageBriefPlanner => "Generate illustration briefs for the article body",
@@ -102,15 +107,26 @@ impl GenerationExecutionPolicy {
             max_model_calls: DEFAULT_MAX_MODEL_CALLS,
             max_tool_calls: DEFAULT_MAX_TOOL_CALLS + 1,
             max_fetched_content_chars: 12_000,
-            max_searches: 1,
+            max_searches: research_max_searches(),
             max_sources: 3,
         }
     }
 }
 
+/// Live searches per research job, one per planned query. Defaults to a single search for
+/// the planner's best query; `RESEARCH_MAX_SEARCHES` raises it up to the planner's limit.
+fn research_max_searches() -> u32 {
+    env::var("RESEARCH_MAX_SEARCHES")
+        .ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .unwrap_or(DEFAULT_RESEARCH_MAX_SEARCHES)
+        .clamp(1, MAX_RESEARCH_QUERIES as u32)
+}
+
 pub struct BoundedGenerationRuntime {
     job_id: String,
-    job_service: ArticleJobService,
+    /// `None` for runs outside an article job, which have no row to persist or cancel.
+    job_service: Option<ArticleJobService>,
     policy: GenerationExecutionPolicy,
     prompt_chars: usize,
     agent_steps: u32,
@@ -119,13 +135,17 @@ pub struct BoundedGenerationRuntime {
     searches: u32,
     sources: u32,
     fetched_content_chars: usize,
+    search_cache_hits: u32,
+    source_cache_hits: u32,
+    cached_content_chars: usize,
     current_tool: &'static str,
+    tool_steps: Vec<Value>,
 }
 
 impl BoundedGenerationRuntime {
     pub async fn new(state: AppState, job_id: String, prompt: &str) -> Result<Self, Error> {
         Self::new_with_policy(
-            state,
+            Some(ArticleJobService::new(state)),
             job_id,
             prompt,
             GenerationExecutionPolicy::standard_non_research(),
@@ -139,7 +159,7 @@ impl BoundedGenerationRuntime {
         prompt: &str,
     ) -> Result<Self, Error> {
         Self::new_with_policy(
-            state,
+            Some(ArticleJobService::new(state)),
             job_id,
             prompt,
             GenerationExecutionPolicy::research_mode(),
@@ -147,8 +167,20 @@ impl BoundedGenerationRuntime {
         .await
     }
 
+    /// Research budgets for work that is not an article job, such as agent edits. The
+    /// limits apply as usual; `run_id` only labels log lines and the research trace.
+    pub async fn new_detached_research(run_id: String, prompt: &str) -> Result<Self, Error> {
+        Self::new_with_policy(
+            None,
+            run_id,
+            prompt,
+            GenerationExecutionPolicy::research_mode(),
+        )
+        .await
+    }
+
     async fn new_with_policy(
-        state: AppState,
+        job_service: Option<ArticleJobService>,
         job_id: String,
         prompt: &str,
         policy: GenerationExecutionPolicy,
@@ -163,7 +195,7 @@ impl BoundedGenerationRuntime {
 
         let runtime = Self {
             job_id,
-            job_service: ArticleJobService::new(state),
+            job_service,
             policy,
             prompt_chars,
             agent_steps: 0,
@@ -172,12 +204,20 @@ impl BoundedGenerationRuntime {
             searches: 0,
             sources: 0,
             fetched_content_chars: 0,
+            search_cache_hits: 0,
+            source_cache_hits: 0,
+            cached_content_chars: 0,
             current_tool: "queued",
+            tool_steps: Vec::new(),
         };
         runtime.persist(ARTICLE_JOB_PHASE_QUEUED).await?;
         Ok(runtime)
     }
 
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
     pub async fn begin_tool(
         &mut self,
         tool: GenerationTool,
@@ -211,6 +251,7 @@ impl BoundedGenerationRuntime {
         }
 
         self.current_tool = tool.as_str();
+        self.tool_steps.push(json!({ "tool": tool.as_str() }));
         event!(
             Level::INFO,
             job_id = %self.job_id,
@@ -235,7 +276,7 @@ impl BoundedGenerationRuntime {
         self.persist(ARTICLE_JOB_PHASE_PLANNING).await
     }
 
-    pub async fn record_search(&mut self) -> Result<(), Error> {
+    pub async fn record_search(&mut self, query: &str) -> Result<(), Error> {
         self.ensure_not_cancelled().await?;
         self.searches = self.searches.saturating_add(1);
         if self.searches > self.policy.max_searches {
@@ -244,9 +285,43 @@ impl BoundedGenerationRuntime {
                 self.policy.max_searches
             )));
         }
+        self.append_to_current_step("searches", query);
         self.persist(ARTICLE_JOB_PHASE_PLANNING).await
     }
 
+    pub fn remaining_searches(&self) -> u32 {
+        self.policy.max_searches.saturating_sub(self.searches)
+    }
+
+    pub fn remaining_fetched_content_chars(&self) -> usize {
+        self.policy
+            .max_fetched_content_chars
+            .saturating_sub(self.fetched_content_chars)
+    }
+
+    /// Records the planner's queries on its tool step so the usage snapshot shows them.
+    pub async fn record_planned_queries(&mut self, queries: &[String]) -> Result<(), Error> {
+        self.ensure_not_cancelled().await?;
+        for query in queries {
+            self.append_to_current_step("queries", query);
+        }
+        self.persist(ARTICLE_JOB_PHASE_RESEARCHING).await
+    }
+
+    fn append_to_current_step(&mut self, key: &str, value: &str) {
+        let Some(step) = self
+            .tool_steps
+            .last_mut()
+            .and_then(|step| step.as_object_mut())
+        else {
+            return;
+        };
+        let entries = step.entry(key).or_insert_with(|| json!([]));
+        if let Some(entries) = entries.as_array_mut() {
+            entries.push(Value::from(value));
+        }
+    }
+
     pub async fn record_source(&mut self) -> Result<(), Error> {
         self.ensure_not_cancelled().await?;
         self.sources = self.sources.saturating_add(1);
@@ -259,6 +334,21 @@ impl BoundedGenerationRuntime {
         self.persist(ARTICLE_JOB_PHASE_PLANNING).await
     }
 
+    /// A search answered from the research source cache; does not spend the search budget.
+    pub async fn record_search_cache_hit(&mut self) -> Result<(), Error> {
+        self.ensure_not_cancelled().await?;
+        self.search_cache_hits = self.search_cache_hits.saturating_add(1);
+        self.persist(ARTICLE_JOB_PHASE_PLANNING).await
+    }
+
+    /// Source text reused from the cache; tracked apart from the fetched-content budget.
+    pub async fn record_source_cache_hit(&mut self, chars: usize) -> Result<(), Error> {
+        self.ensure_not_cancelled().await?;
+        self.source_cache_hits = self.source_cache_hits.saturating_add(1);
+        self.cached_content_chars = self.cached_content_chars.saturating_add(chars);
+        self.persist(ARTICLE_JOB_PHASE_PLANNING).await
+    }
+
     pub async fn mark_ready_for_review(&mut self) -> Result<(), Error> {
         self.ensure_not_cancelled().await?;
         self.current_tool = GenerationTool::PolicyCheck.as_str();
@@ -266,7 +356,10 @@ impl BoundedGenerationRuntime {
     }
 
     pub async fn ensure_not_cancelled(&self) -> Result<(), Error> {
-        if self.job_service.is_job_cancelled(&self.job_id).await? {
+        let Some(job_service) = &self.job_service else {
+            return Ok(());
+        };
+        if job_service.is_job_cancelled(&self.job_id).await? {
             return Err(Error::BadRequest(format!(
                 "Article job {} was cancelled",
                 self.job_id
@@ -276,7 +369,10 @@ impl BoundedGenerationRuntime {
     }
 
     async fn persist(&self, phase: &str) -> Result<(), Error> {
-        self.job_service
+        let Some(job_service) = &self.job_service else {
+            return Ok(());
+        };
+        job_service
             .record_usage_snapshot(&self.job_id, phase, self.snapshot())
             .await
     }
@@ -290,7 +386,11 @@ impl BoundedGenerationRuntime {
             "searches": self.searches,
             "sources": self.sources,
             "fetched_content_chars": self.fetched_content_chars,
+            "search_cache_hits": self.search_cache_hits,
+            "source_cache_hits": self.source_cache_hits,
+            "cached_content_chars": self.cached_content_chars,
             "current_tool": self.current_tool,
+            "tool_steps": self.tool_steps,
             "limits": {
                 "max_prompt_chars": self.policy.max_prompt_chars,
                 "max_agent_steps": self.policy.max_agent_steps,
@@ -306,7 +406,7 @@ impl BoundedGenerationRuntime {
 
 #[cfg(test)]
 mod tests {
-    use super::{GenerationExecutionPolicy, GenerationTool};
+    use super::{BoundedGenerationRuntime, GenerationExecutionPolicy, GenerationTool};
     use crate::services::article_jobs::{
         ARTICLE_JOB_PHASE_PLANNING, ARTICLE_JOB_PHASE_READY_FOR_REVIEW,
         ARTICLE_JOB_PHASE_RESEARCHING, ARTICLE_JOB_PHASE_WRITING,
@@ -354,6 +454,19 @@ mod tests {
         assert!(policy.max_fetched_content_chars > 0);
     }
 
+    #[tokio::test]
+    async fn detached_research_runtime_enforces_budgets_without_a_job() {
+        let mut runtime =
+            BoundedGenerationRuntime::new_detached_research("edit-run".to_string(), "brief")
+                .await
+                .unwrap();
+
+        runtime.record_search("one").await.unwrap();
+
+        assert_eq!(runtime.remaining_searches(), 0);
+        assert!(runtime.record_search("two").await.is_err());
+    }
+
     #[test]
     fn generation_tool_registry_covers_structured_runtime_steps() {
         let names = GenerationTool::registry()
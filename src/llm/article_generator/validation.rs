@@ -1,5 +1,6 @@
 use crate::error::Error;
 use crate::image_generator::{ImageGenerated, ImageToCreate};
+use crate::llm::domain_policy::{research_domain_policy, ResearchDomainPolicy};
 use crate::services::editorial_policy::enforce_article_output_policy;
 
 use super::research::ResearchSource;
@@ -146,11 +147,19 @@ pub fn ensure_no_research_citation_scaffolding(text: &str) -> Result<(), Error>
 pub fn ensure_no_internal_source_domain_mentions(
     text: &str,
     sources: &[ResearchSource],
+) -> Result<(), Error> {
+    ensure_no_source_domain_leaks(text, sources, &research_domain_policy())
+}
+
+/// Rejects output naming a source's domain or any publication name the policy lists for it.
+fn ensure_no_source_domain_leaks(
+    text: &str,
+    sources: &[ResearchSource],
+    policy: &ResearchDomainPolicy,
 ) -> Result<(), Error> {
     let normalized = text.to_ascii_lowercase();
     for source in sources {
-        let domain = source.domain.trim().to_ascii_lowercase();
-        for marker in source_leak_markers(&domain) {
+        for marker in policy.leak_markers(&source.domain) {
             if normalized.contains(&marker) {
                 return Err(Error::Llm(format!(
                     "Generated researched article cited an internal source domain: {}",
@@ -162,31 +171,6 @@ pub fn ensure_no_internal_source_domain_mentions(
     Ok(())
 }
 
-fn source_leak_markers(domain: &str) -> Vec<String> {
-    if domain.is_empty() {
-        return Vec::new();
-    }
-    let mut markers = vec![domain.to_string()];
-    for publisher in [
-        "reuters",
-        "apnews",
-        "associated press",
-        "bbc",
-        "npr",
-        "economist",
-    ] {
-        if domain.contains(publisher) {
-            markers.push(publisher.to_string());
-        }
-    }
-    if domain.contains("ft.com") {
-        markers.push("financial times".to_string());
-    }
-    markers.sort();
-    markers.dedup();
-    markers
-}
-
 pub fn ensure_deadpan_tone(title: &str, markdown: &str) -> Result<(), Error> {
     let normalized = format!("{}\n{}", title, markdown).to_ascii_lowercase();
     for tone_break in ["haha", "lol", "lmao", "this is satire", "this is parody"] {
@@ -242,12 +226,23 @@ pub fn validate_researched_article_output(
 #[cfg(test)]
 mod tests {
     use super::{
-        ensure_minimum_paragraph_count, ensure_no_forbidden_markup,
-        ensure_no_internal_source_domain_mentions, ensure_no_prompt_leakage,
-        ensure_no_research_citation_scaffolding, parse_titled_markdown, source_leak_markers,
-        split_paragraphs, validate_article_output,
+        ensure_minimum_paragraph_count, ensure_no_forbidden_markup, ensure_no_prompt_leakage,
+        ensure_no_research_citation_scaffolding, ensure_no_source_domain_leaks,
+        parse_titled_markdown, split_paragraphs, validate_article_output,
     };
     use crate::llm::article_generator::research::ResearchSource;
+    use crate::llm::domain_policy::{
+        parse_leak_markers, DomainAction, DomainRule, ResearchDomainPolicy,
+    };
+
+    fn wire_policy() -> ResearchDomainPolicy {
+        ResearchDomainPolicy::new(vec![DomainRule {
+            pattern: "reuters.com".to_string(),
+            action: DomainAction::Boost,
+            score: 20,
+            leak_markers: parse_leak_markers("reuters"),
+        }])
+    }
 
     #[test]
     fn parse_titled_markdown_extracts_heading_and_body() {
@@ -320,7 +315,7 @@ mod tests {
 
     #[test]
     fn researched_output_rejects_internal_source_domains() {
-        let err = ensure_no_internal_source_domain_mentions(
+        let err = ensure_no_source_domain_leaks(
             "Officials said Reuters had framed the matter carefully.",
             &[ResearchSource {
                 title: "Wire update".to_string(),
@@ -328,7 +323,9 @@ mod tests {
                 domain: "reuters.com".to_string(),
                 snippet: String::new(),
                 context: String::new(),
+                published: None,
             }],
+            &wire_policy(),
         )
         .unwrap_err()
         .to_string();
@@ -337,10 +334,10 @@ mod tests {
     }
 
     #[test]
-    fn source_leak_markers_include_known_publication_names() {
-        let markers = source_leak_markers("reuters.com");
+    fn source_leak_markers_include_policy_publication_names() {
+        let markers = wire_policy().leak_markers("www.reuters.com");
 
-        assert!(markers.contains(&"reuters.com".to_string()));
+        assert!(markers.contains(&"www.reuters.com".to_string()));
         assert!(markers.contains(&"reuters".to_string()));
     }
 }
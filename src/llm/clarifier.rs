@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::llm::function_definition::FunctionDefinition;
+use crate::llm::prompt_registry::clarifying_question_prompt;
+use crate::llm::{function_definition, Llm, Message};
+
+pub const MAX_CLARIFYING_QUESTIONS: usize = 3;
+const MAX_SUGGESTED_ANSWERS: usize = 3;
+const MAX_QUESTION_CHARS: usize = 200;
+const MAX_SUGGESTED_ANSWER_CHARS: usize = 80;
+const MAX_FALLBACK_CHARS: usize = 400;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ClarifyingQuestion {
+    pub question: String,
+    #[serde(default)]
+    pub suggested_answers: Vec<String>,
+}
+
+/// What the intake editor wants to ask before a brief goes to the writer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClarificationProposal {
+    pub questions: Vec<ClarifyingQuestion>,
+    /// What the writer should assume if the requester never answers.
+    pub fallback_instruction: Option<String>,
+}
+
+fn propose_clarifying_questions_tool() -> FunctionDefinition {
+    let mut f = function_definition::def_function(
+        "propose_clarifying_questions",
+        "Decide whether the brief needs clarification and propose targeted questions",
+    );
+    f.parameters.add_bool(
+        "needs_clarification",
+        true,
+        "Whether a missing detail would materially change the article",
+    );
+    f.parameters.add_arr_obj(
+        "questions",
+        true,
+        "Questions for the requester, most important first",
+        |question| {
+            question.add_str("question", true, "One short question for the requester");
+            question.add_arr_str(
+                "suggested_answers",
+                true,
+                "Short plausible answers the requester can pick",
+            );
+        },
+    );
+    f.parameters.add_str(
+        "fallback_instruction",
+        true,
+        "What the writer should assume if the requester never answers",
+    );
+    f
+}
+
+/// Asks the model whether `prompt` is too ambiguous to write, returning `None` when it is
+/// specific enough. At most `max_questions` questions are kept.
+pub async fn propose_clarifying_questions(
+    llm: &Llm,
+    model: &str,
+    prompt: &str,
+    max_questions: usize,
+) -> Result<Option<ClarificationProposal>, Error> {
+    let max_questions = max_questions.clamp(1, MAX_CLARIFYING_QUESTIONS);
+    let messages = vec![
+        Message::System(
+            clarifying_question_prompt()
+                .body
+                .replace("{max_questions}", &max_questions.to_string()),
+        ),
+        Message::User(prompt.to_string()),
+    ];
+    let response = llm
+        .request_tool(propose_clarifying_questions_tool(), messages, model)
+        .await?;
+    parse_clarification_proposal(&response, max_questions)
+}
+
+fn parse_clarification_proposal(
+    response: &str,
+    max_questions: usize,
+) -> Result<Option<ClarificationProposal>, Error> {
+    let value: Value = serde_json::from_str(response).map_err(|e| {
+        Error::Llm(format!(
+            "Failed to parse clarifying question response: {}",
+            e
+        ))
+    })?;
+    if !value["needs_clarification"].as_bool().unwrap_or(false) {
+        return Ok(None);
+    }
+
+    let questions = value["questions"]
+        .as_array()
+        .map(|questions| {
+            questions
+                .iter()
+                .filter_map(parse_question)
+                .take(max_questions)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if questions.is_empty() {
+        return Ok(None);
+    }
+
+    let fallback_instruction = value["fallback_instruction"]
+        .as_str()
+        .map(|fallback| clean_text(fallback, MAX_FALLBACK_CHARS))
+        .filter(|fallback| !fallback.is_empty());
+
+    Ok(Some(ClarificationProposal {
+        questions,
+        fallback_instruction,
+    }))
+}
+
+fn parse_question(value: &Value) -> Option<ClarifyingQuestion> {
+    let question = clean_text(value["question"].as_str()?, MAX_QUESTION_CHARS);
+    if question.is_empty() {
+        return None;
+    }
+    let mut suggested_answers = Vec::new();
+    for answer in value["suggested_answers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(|answer| clean_text(answer, MAX_SUGGESTED_ANSWER_CHARS))
+    {
+        if !answer.is_empty() && !suggested_answers.contains(&answer) {
+            suggested_answers.push(answer);
+        }
+    }
+    suggested_answers.truncate(MAX_SUGGESTED_ANSWERS);
+
+    Some(ClarifyingQuestion {
+        question,
+        suggested_answers,
+    })
+}
+
+fn clean_text(text: &str, max_chars: usize) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(max_chars)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_clarification_proposal;
+
+    #[test]
+    fn proposal_keeps_capped_cleaned_questions_and_suggestions() {
+        let response = r#"{
+            "needs_clarification": true,
+            "questions": [
+                {"question": "  Which   office? ", "suggested_answers": ["Transport ministry", "Transport ministry", "", "City council", "Port authority", "Tax office"]},
+                {"question": "", "suggested_answers": ["ignored"]},
+                {"question": "What did they announce?", "suggested_answers": []},
+                {"question": "Where?", "suggested_answers": ["Lisbon"]}
+            ],
+            "fallback_instruction": "Assume the transport ministry issued a memo."
+        }"#;
+
+        let proposal = parse_clarification_proposal(response, 2)
+            .unwrap()
+            .expect("expected a proposal");
+
+        assert_eq!(proposal.questions.len(), 2);
+        assert_eq!(proposal.questions[0].question, "Which office?");
+        assert_eq!(
+            proposal.questions[0].suggested_answers,
+            vec!["Transport ministry", "City council", "Port authority"]
+        );
+        assert_eq!(proposal.questions[1].question, "What did they announce?");
+        assert_eq!(
+            proposal.fallback_instruction.as_deref(),
+            Some("Assume the transport ministry issued a memo.")
+        );
+    }
+
+    #[test]
+    fn proposal_is_skipped_when_the_brief_is_clear_or_questions_are_empty() {
+        let clear = r#"{"needs_clarification": false, "questions": [{"question": "Which office?", "suggested_answers": []}], "fallback_instruction": ""}"#;
+        let empty = r#"{"needs_clarification": true, "questions": [], "fallback_instruction": ""}"#;
+
+        assert!(parse_clarification_proposal(clear, 3).unwrap().is_none());
+        assert!(parse_clarification_proposal(empty, 3).unwrap().is_none());
+        assert!(parse_clarification_proposal("not json", 3).is_err());
+    }
+}
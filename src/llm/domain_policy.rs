@@ -0,0 +1,247 @@
+use std::sync::{Arc, RwLock};
+
+pub const DEFAULT_DOMAIN_SCORE: i32 = 10;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DomainAction {
+    /// Listed domains are trusted; once any allow rule exists, research only uses allowed domains.
+    Allow,
+    /// Results from the domain are discarded before ranking.
+    Block,
+    /// The domain ranks by its score without restricting other domains.
+    Boost,
+}
+
+impl DomainAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Block => "block",
+            Self::Boost => "boost",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "allow" => Some(Self::Allow),
+            "block" => Some(Self::Block),
+            "boost" => Some(Self::Boost),
+            _ => None,
+        }
+    }
+}
+
+/// One admin rule for research sources. A pattern without `*` matches the domain and
+/// its subdomains; `*` matches any run of characters (`*.gov`, `*parliament*`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainRule {
+    pub pattern: String,
+    pub action: DomainAction,
+    pub score: i32,
+    pub leak_markers: Vec<String>,
+}
+
+impl DomainRule {
+    pub fn matches(&self, domain: &str) -> bool {
+        domain_pattern_matches(&self.pattern, domain)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResearchDomainPolicy {
+    rules: Vec<DomainRule>,
+}
+
+static RESEARCH_DOMAIN_POLICY: RwLock<Option<Arc<ResearchDomainPolicy>>> = RwLock::new(None);
+
+impl ResearchDomainPolicy {
+    pub fn new(rules: Vec<DomainRule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn rules(&self) -> &[DomainRule] {
+        &self.rules
+    }
+
+    fn matching<'a>(&'a self, domain: &'a str) -> impl Iterator<Item = &'a DomainRule> + 'a {
+        let domain = domain.trim();
+        self.rules.iter().filter(move |rule| rule.matches(domain))
+    }
+
+    /// Blocked domains never qualify; with an allowlist only allowed domains do.
+    pub fn permits(&self, domain: &str) -> bool {
+        let domain = domain.to_ascii_lowercase();
+        if self
+            .matching(&domain)
+            .any(|rule| rule.action == DomainAction::Block)
+        {
+            return false;
+        }
+        let has_allowlist = self
+            .rules
+            .iter()
+            .any(|rule| rule.action == DomainAction::Allow);
+        !has_allowlist
+            || self
+                .matching(&domain)
+                .any(|rule| rule.action == DomainAction::Allow)
+    }
+
+    pub fn score(&self, domain: &str) -> i32 {
+        let domain = domain.to_ascii_lowercase();
+        self.matching(&domain)
+            .filter(|rule| rule.action != DomainAction::Block)
+            .map(|rule| rule.score)
+            .max()
+            .unwrap_or(DEFAULT_DOMAIN_SCORE)
+    }
+
+    /// The domain itself plus every publication name its rules forbid in output.
+    pub fn leak_markers(&self, domain: &str) -> Vec<String> {
+        let domain = domain.trim().to_ascii_lowercase();
+        if domain.is_empty() {
+            return Vec::new();
+        }
+        let mut markers = vec![domain.clone()];
+        markers.extend(
+            self.matching(&domain)
+                .flat_map(|rule| rule.leak_markers.iter().cloned()),
+        );
+        markers.sort();
+        markers.dedup();
+        markers
+    }
+}
+
+pub fn domain_pattern_matches(pattern: &str, domain: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    let domain = domain.trim().to_ascii_lowercase();
+    if pattern.is_empty() || domain.is_empty() {
+        return false;
+    }
+    if !pattern.contains('*') {
+        return domain == pattern || domain.ends_with(&format!(".{}", pattern));
+    }
+
+    let pieces = pattern.split('*').collect::<Vec<_>>();
+    let (first, rest) = pieces
+        .split_first()
+        .expect("split yields at least one piece");
+    let Some(mut remaining) = domain.strip_prefix(first) else {
+        return false;
+    };
+    let Some((last, middle)) = rest.split_last() else {
+        return remaining.is_empty();
+    };
+    for piece in middle {
+        match remaining.find(piece) {
+            Some(position) => remaining = &remaining[position + piece.len()..],
+            None => return false,
+        }
+    }
+    remaining.ends_with(last)
+}
+
+/// Comma-separated admin input; markers are matched case-insensitively in output.
+pub fn parse_leak_markers(raw: &str) -> Vec<String> {
+    let mut markers = raw
+        .split(',')
+        .map(|marker| marker.trim().to_ascii_lowercase())
+        .filter(|marker| !marker.is_empty())
+        .collect::<Vec<_>>();
+    markers.sort();
+    markers.dedup();
+    markers
+}
+
+pub fn install_research_domain_policy(rules: Vec<DomainRule>) {
+    *RESEARCH_DOMAIN_POLICY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Arc::new(ResearchDomainPolicy::new(rules)));
+}
+
+#[cfg(test)]
+pub(crate) fn reset_research_domain_policy() {
+    *RESEARCH_DOMAIN_POLICY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+pub fn research_domain_policy() -> Arc<ResearchDomainPolicy> {
+    RESEARCH_DOMAIN_POLICY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        domain_pattern_matches, parse_leak_markers, DomainAction, DomainRule, ResearchDomainPolicy,
+        DEFAULT_DOMAIN_SCORE,
+    };
+
+    fn rule(pattern: &str, action: DomainAction, score: i32, markers: &str) -> DomainRule {
+        DomainRule {
+            pattern: pattern.to_string(),
+            action,
+            score,
+            leak_markers: parse_leak_markers(markers),
+        }
+    }
+
+    #[test]
+    fn patterns_match_subdomains_and_wildcards() {
+        assert!(domain_pattern_matches("reuters.com", "www.reuters.com"));
+        assert!(domain_pattern_matches("reuters.com", "reuters.com"));
+        assert!(!domain_pattern_matches("reuters.com", "notreuters.com"));
+        assert!(domain_pattern_matches("*.gov", "transport.gov"));
+        assert!(!domain_pattern_matches("*.gov", "gov.example.com"));
+        assert!(domain_pattern_matches("*.gov.*", "health.gov.uk"));
+        assert!(domain_pattern_matches("*parliament*", "www.parliament.uk"));
+    }
+
+    #[test]
+    fn boosts_rank_domains_and_blocks_remove_them() {
+        let policy = ResearchDomainPolicy::new(vec![
+            rule("*.gov", DomainAction::Boost, 30, ""),
+            rule("reuters.com", DomainAction::Boost, 20, "reuters"),
+            rule("content-farm.example", DomainAction::Block, 0, ""),
+        ]);
+
+        assert_eq!(policy.score("transport.gov"), 30);
+        assert_eq!(policy.score("www.reuters.com"), 20);
+        assert_eq!(policy.score("example.com"), DEFAULT_DOMAIN_SCORE);
+        assert!(policy.permits("example.com"));
+        assert!(!policy.permits("news.content-farm.example"));
+    }
+
+    #[test]
+    fn allow_rules_turn_the_policy_into_an_allowlist() {
+        let policy = ResearchDomainPolicy::new(vec![
+            rule("*.gov", DomainAction::Allow, 30, ""),
+            rule("reuters.com", DomainAction::Boost, 20, ""),
+        ]);
+
+        assert!(policy.permits("transport.gov"));
+        assert!(!policy.permits("reuters.com"));
+    }
+
+    #[test]
+    fn leak_markers_include_domain_and_rule_markers() {
+        let policy = ResearchDomainPolicy::new(vec![rule(
+            "apnews.com",
+            DomainAction::Boost,
+            20,
+            "Associated Press, apnews,",
+        )]);
+
+        assert_eq!(
+            policy.leak_markers("APNews.com"),
+            vec!["apnews", "apnews.com", "associated press"]
+        );
+        assert!(policy.leak_markers("").is_empty());
+    }
+}
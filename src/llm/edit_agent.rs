@@ -1,17 +1,35 @@
+use std::time::Duration;
+
 use serde_json::Value;
+use uuid::Uuid;
 
+use crate::app_state::AppState;
 use crate::error::Error;
+use crate::llm::article_generator::{
+    gather_research_packet_within, BoundedGenerationRuntime, ResearchModeSource, ResearchPacket,
+};
 use crate::llm::function_definition::FunctionDefinition;
 use crate::llm::prompt_registry::edit_rewrite_prompt;
 use crate::llm::{function_definition, Llm, Message};
 
-pub const EDIT_TOOL_REGISTRY: [EditTool; 4] = [
+pub const EDIT_TOOL_REGISTRY: [EditTool; 5] = [
     EditTool::LoadArticle,
+    EditTool::ResearchRequestedChange,
     EditTool::ApplyRequestedChange,
     EditTool::SummarizeDiff,
     EditTool::SubmitEditProposal,
 ];
 
+/// Research runs while the editor waits on the preview request, so it gets a wall-clock
+/// limit on top of the research-mode budgets.
+const EDIT_RESEARCH_DEADLINE: Duration = Duration::from_secs(60);
+
+/// Research gathered for an agent edit, with the id its trace was stored under.
+pub struct EditResearch {
+    pub trace_id: String,
+    pub packet: ResearchPacket,
+}
+
 pub struct EditProposal {
     pub title: String,
     pub description: String,
@@ -23,6 +41,7 @@ pub struct EditProposal {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum EditTool {
     LoadArticle,
+    ResearchRequestedChange,
     ApplyRequestedChange,
     SummarizeDiff,
     SubmitEditProposal,
@@ -32,6 +51,7 @@ impl EditTool {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::LoadArticle => "load_article",
+            Self::ResearchRequestedChange => "research_requested_change",
             Self::ApplyRequestedChange => "apply_requested_change",
             Self::SummarizeDiff => "summarize_diff",
             Self::SubmitEditProposal => "submit_edit_proposal",
@@ -41,6 +61,9 @@ impl EditTool {
     pub fn description(self) -> &'static str {
         match self {
             Self::LoadArticle => "Load the current article title, description, and markdown",
+            Self::ResearchRequestedChange => {
+                "Optionally run the bounded public-web research flow for the requested change"
+            }
             Self::ApplyRequestedChange => {
                 "Rewrite the article according to the user's change request"
             }
@@ -71,6 +94,35 @@ fn submit_edit_proposal() -> FunctionDefinition {
     f
 }
 
+/// Runs the research desk for a change request under the research-mode budgets and
+/// [`EDIT_RESEARCH_DEADLINE`]. Edits have no article job, so the runtime is detached and the
+/// trace is stored under a fresh id that callers record alongside the edit.
+pub async fn research_edit_request(
+    state: &AppState,
+    model: &str,
+    title: &str,
+    change_request: &str,
+) -> Result<EditResearch, Error> {
+    let brief = edit_research_brief(title, change_request);
+    let trace_id = Uuid::new_v4().to_string();
+    let mut runtime =
+        BoundedGenerationRuntime::new_detached_research(trace_id.clone(), change_request).await?;
+    let packet = gather_research_packet_within(
+        state,
+        &mut runtime,
+        &brief,
+        model,
+        ResearchModeSource::Manual,
+        EDIT_RESEARCH_DEADLINE,
+    )
+    .await?;
+    Ok(EditResearch { trace_id, packet })
+}
+
+pub fn edit_research_brief(title: &str, change_request: &str) -> String {
+    format!("{}\n\nArticle: {}", change_request.trim(), title.trim())
+}
+
 pub async fn generate_edit_proposal(
     llm: &Llm,
     model: &str,
@@ -78,14 +130,22 @@ pub async fn generate_edit_proposal(
     description: &str,
     markdown: &str,
     change_request: &str,
+    research: Option<&ResearchPacket>,
 ) -> Result<EditProposal, Error> {
     let prompt = edit_rewrite_prompt();
+    let mut user_message = format!(
+        "Current title:\n{}\n\nCurrent description:\n{}\n\nCurrent markdown:\n{}\n\nRequested change:\n{}",
+        title, description, markdown, change_request
+    );
+    if let Some(research) = research {
+        user_message.push_str(&format!(
+            "\n\nResearch for the requested change:\n{}",
+            research.prompt_context(&edit_research_brief(title, change_request))
+        ));
+    }
     let messages = vec![
         Message::System(prompt.body.to_string()),
-        Message::User(format!(
-            "Current title:\n{}\n\nCurrent description:\n{}\n\nCurrent markdown:\n{}\n\nRequested change:\n{}",
-            title, description, markdown, change_request
-        )),
+        Message::User(user_message),
     ];
     let response = llm
         .request_tool(submit_edit_proposal(), messages, model)
@@ -123,6 +183,7 @@ mod tests {
             names,
             vec![
                 "load_article",
+                "research_requested_change",
                 "apply_requested_change",
                 "summarize_diff",
                 "submit_edit_proposal",
@@ -0,0 +1,297 @@
+use std::sync::{Arc, RwLock};
+
+use sha2::{Digest, Sha256};
+
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+
+/// A recurring name the translator must handle consistently. Entries without a
+/// translation are protected and must come back verbatim; entries without a
+/// language code apply to every target language.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlossaryEntry {
+    pub term: String,
+    pub language_code: Option<String>,
+    pub translation: Option<String>,
+}
+
+impl GlossaryEntry {
+    pub fn is_protected(&self) -> bool {
+        self.translation.is_none()
+    }
+
+    fn applies_to(&self, language: &SupportedTranslationLanguage) -> bool {
+        self.language_code
+            .as_deref()
+            .is_none_or(|code| code == language.code)
+    }
+
+    fn expected_text(&self) -> &str {
+        self.translation.as_deref().unwrap_or(&self.term)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GlossaryViolation {
+    pub term: String,
+    pub expected: String,
+    pub protected: bool,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TranslationGlossary {
+    entries: Vec<GlossaryEntry>,
+    version: String,
+}
+
+static TRANSLATION_GLOSSARY: RwLock<Option<Arc<TranslationGlossary>>> = RwLock::new(None);
+
+impl TranslationGlossary {
+    pub fn new(mut entries: Vec<GlossaryEntry>) -> Self {
+        entries.sort_by(|left, right| {
+            left.term
+                .cmp(&right.term)
+                .then(left.language_code.cmp(&right.language_code))
+        });
+        entries.dedup_by(|right, left| {
+            left.term == right.term && left.language_code == right.language_code
+        });
+        let version = entries_digest(&entries);
+        Self { entries, version }
+    }
+
+    pub fn entries(&self) -> &[GlossaryEntry] {
+        &self.entries
+    }
+
+    /// Changes whenever any entry changes; empty while the glossary is empty.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Version of the entries whose term appears in `text`, in any language. Cache
+    /// keys use this so a new term only invalidates translations of text that
+    /// mentions it; text no entry matches keeps the pre-glossary (empty) version.
+    pub fn version_for(&self, text: &str) -> String {
+        entries_digest(
+            self.entries
+                .iter()
+                .filter(|entry| text.contains(&entry.term)),
+        )
+    }
+
+    /// Entries whose term appears in `text`. A language-specific entry wins over a
+    /// global one for the same term.
+    pub fn entries_for(
+        &self,
+        text: &str,
+        language: &SupportedTranslationLanguage,
+    ) -> Vec<&GlossaryEntry> {
+        let mut matched: Vec<&GlossaryEntry> = Vec::new();
+        for entry in self
+            .entries
+            .iter()
+            .filter(|entry| entry.applies_to(language) && text.contains(&entry.term))
+        {
+            match matched.iter_mut().find(|seen| seen.term == entry.term) {
+                Some(seen) if entry.language_code.is_some() => *seen = entry,
+                Some(_) => {}
+                None => matched.push(entry),
+            }
+        }
+        matched
+    }
+
+    pub fn prompt_section(
+        &self,
+        text: &str,
+        language: &SupportedTranslationLanguage,
+    ) -> Option<String> {
+        let entries = self.entries_for(text, language);
+        if entries.is_empty() {
+            return None;
+        }
+
+        let mut section = String::from("Glossary (mandatory):\n");
+        for entry in entries {
+            match entry.translation.as_deref() {
+                Some(translation) => section.push_str(&format!(
+                    "- Translate \"{}\" as \"{}\".\n",
+                    entry.term, translation
+                )),
+                None => section.push_str(&format!(
+                    "- Keep \"{}\" exactly as written; do not translate or transliterate it.\n",
+                    entry.term
+                )),
+            }
+        }
+        Some(section)
+    }
+
+    /// Glossary entries present in the source whose expected rendering is missing
+    /// from the translation. Mapped terms are compared case-insensitively so a
+    /// sentence-initial capital does not count as a violation.
+    pub fn violations(
+        &self,
+        source: &str,
+        translated: &str,
+        language: &SupportedTranslationLanguage,
+    ) -> Vec<GlossaryViolation> {
+        let translated_lower = translated.to_lowercase();
+        self.entries_for(source, language)
+            .into_iter()
+            .filter(|entry| {
+                if entry.is_protected() {
+                    !translated.contains(&entry.term)
+                } else {
+                    !translated_lower.contains(&entry.expected_text().to_lowercase())
+                }
+            })
+            .map(|entry| GlossaryViolation {
+                term: entry.term.clone(),
+                expected: entry.expected_text().to_string(),
+                protected: entry.is_protected(),
+            })
+            .collect()
+    }
+}
+
+fn entries_digest<'a>(entries: impl IntoIterator<Item = &'a GlossaryEntry>) -> String {
+    let mut entries = entries.into_iter().peekable();
+    if entries.peek().is_none() {
+        return String::new();
+    }
+    let mut digest = Sha256::new();
+    for entry in entries {
+        digest.update(entry.term.as_bytes());
+        digest.update([0]);
+        digest.update(entry.language_code.as_deref().unwrap_or("*").as_bytes());
+        digest.update([0]);
+        digest.update(entry.translation.as_deref().unwrap_or("").as_bytes());
+        digest.update([0xff]);
+    }
+    format!("{:x}", digest.finalize())[..16].to_string()
+}
+
+pub fn install_translation_glossary(entries: Vec<GlossaryEntry>) {
+    *TRANSLATION_GLOSSARY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+        Some(Arc::new(TranslationGlossary::new(entries)));
+}
+
+#[cfg(test)]
+pub(crate) fn reset_translation_glossary() {
+    *TRANSLATION_GLOSSARY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+pub fn translation_glossary() -> Arc<TranslationGlossary> {
+    TRANSLATION_GLOSSARY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::llm::prompt_registry::find_supported_translation_language;
+
+    use super::{GlossaryEntry, TranslationGlossary};
+
+    fn entry(term: &str, language_code: Option<&str>, translation: Option<&str>) -> GlossaryEntry {
+        GlossaryEntry {
+            term: term.to_string(),
+            language_code: language_code.map(str::to_string),
+            translation: translation.map(str::to_string),
+        }
+    }
+
+    fn sample_glossary() -> TranslationGlossary {
+        TranslationGlossary::new(vec![
+            entry("Wibble", None, None),
+            entry("Ministry of Queues", None, None),
+            entry(
+                "Ministry of Queues",
+                Some("pt"),
+                Some("Ministério das Filas"),
+            ),
+        ])
+    }
+
+    #[test]
+    fn empty_glossary_has_no_version() {
+        assert_eq!(TranslationGlossary::new(Vec::new()).version(), "");
+        assert_eq!(sample_glossary().version().len(), 16);
+        assert_ne!(
+            sample_glossary().version(),
+            TranslationGlossary::new(vec![entry("Wibble", None, None)]).version()
+        );
+    }
+
+    #[test]
+    fn text_version_only_covers_terms_the_text_mentions() {
+        let glossary = sample_glossary();
+        let extended = TranslationGlossary::new(
+            glossary
+                .entries()
+                .iter()
+                .cloned()
+                .chain([entry("Queue Tsar", Some("pt"), Some("Czar das Filas"))])
+                .collect(),
+        );
+        let text = "The Wibble reports that the Ministry of Queues has closed.";
+
+        assert_eq!(glossary.version_for(text), extended.version_for(text));
+        assert_eq!(glossary.version_for("Nothing to see"), "");
+        assert_ne!(
+            glossary.version_for(text),
+            extended.version_for("The Queue Tsar resigned at the Ministry of Queues.")
+        );
+    }
+
+    #[test]
+    fn language_specific_entries_override_global_ones() {
+        let glossary = sample_glossary();
+        let portuguese = find_supported_translation_language("pt").unwrap();
+        let french = find_supported_translation_language("fr").unwrap();
+        let text = "The Wibble reports that the Ministry of Queues has closed.";
+
+        let prompt = glossary.prompt_section(text, &portuguese).unwrap();
+        assert!(prompt.contains("Keep \"Wibble\" exactly as written"));
+        assert!(prompt.contains("Translate \"Ministry of Queues\" as \"Ministério das Filas\""));
+
+        let prompt = glossary.prompt_section(text, &french).unwrap();
+        assert!(prompt.contains("Keep \"Ministry of Queues\" exactly as written"));
+        assert!(glossary.prompt_section("Nothing to see", &french).is_none());
+    }
+
+    #[test]
+    fn violations_flag_altered_protected_terms_and_missing_mappings() {
+        let glossary = sample_glossary();
+        let portuguese = find_supported_translation_language("pt").unwrap();
+        let source = "The Wibble reports that the Ministry of Queues has closed.";
+
+        assert!(glossary
+            .violations(
+                source,
+                "O Wibble informa que o ministério das filas fechou.",
+                &portuguese
+            )
+            .is_empty());
+
+        let violations = glossary.violations(
+            source,
+            "O Vibble informa que o Ministério de Filas fechou.",
+            &portuguese,
+        );
+        assert_eq!(violations.len(), 2);
+        assert!(violations
+            .iter()
+            .any(|violation| violation.term == "Wibble" && violation.protected));
+        assert!(violations
+            .iter()
+            .any(|violation| violation.expected == "Ministério das Filas" && !violation.protected));
+    }
+}
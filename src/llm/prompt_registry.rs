@@ -5,11 +5,43 @@ pub struct PromptDefinition {
     pub body: &'static str,
 }
 
+use std::borrow::Cow;
+use std::sync::RwLock;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+impl TextDirection {
+    pub fn parse(value: &str) -> Self {
+        if value.trim().eq_ignore_ascii_case("rtl") {
+            Self::Rtl
+        } else {
+            Self::Ltr
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+/// One entry of the language registry. Entries are loaded from the `language`
+/// table at startup and whenever an admin edits them; built-in entries borrow their
+/// strings and loaded ones own them.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SupportedTranslationLanguage {
-    pub code: &'static str,
-    pub name: &'static str,
-    pub aliases: &'static [&'static str],
+    pub code: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub aliases: Cow<'static, [Cow<'static, str>]>,
+    pub direction: TextDirection,
+    pub enabled: bool,
+    pub site_locale: bool,
 }
 
 const ARTICLE_GENERATION_PROMPT: PromptDefinition = PromptDefinition {
@@ -24,6 +56,24 @@ const RESEARCH_ARTICLE_GENERATION_PROMPT: PromptDefinition = PromptDefinition {
     body: include_str!("../../prompts/system_article_research.txt"),
 };
 
+const RESEARCH_QUERY_PLANNING_PROMPT: PromptDefinition = PromptDefinition {
+    key: "research_query_planning",
+    version: 1,
+    body: include_str!("../../prompts/research_query_planner.txt"),
+};
+
+const CLARIFYING_QUESTION_PROMPT: PromptDefinition = PromptDefinition {
+    key: "clarifying_questions",
+    version: 1,
+    body: include_str!("../../prompts/clarifying_questions.txt"),
+};
+
+const ARTICLE_OUTLINE_PROMPT: PromptDefinition = PromptDefinition {
+    key: "article_outline",
+    version: 1,
+    body: include_str!("../../prompts/article_outline.txt"),
+};
+
 const PLACEHOLDER_GENERATION_PROMPT: PromptDefinition = PromptDefinition {
     key: "placeholder_generation",
     version: 2,
@@ -48,39 +98,88 @@ const TRANSLATION_PROMPT: PromptDefinition = PromptDefinition {
     body: include_str!("../../prompts/translation_system.txt"),
 };
 
-const SUPPORTED_TRANSLATION_LANGUAGES: &[SupportedTranslationLanguage] = &[
-    SupportedTranslationLanguage {
-        code: "en",
-        name: "English",
-        aliases: &["english"],
-    },
-    SupportedTranslationLanguage {
-        code: "pt",
-        name: "Portuguese",
-        aliases: &["portuguese", "portuguese (brazil)", "brazilian portuguese"],
-    },
-    SupportedTranslationLanguage {
-        code: "es",
-        name: "Spanish",
-        aliases: &["spanish"],
-    },
-    SupportedTranslationLanguage {
-        code: "fr",
-        name: "French",
-        aliases: &["french"],
-    },
-    SupportedTranslationLanguage {
-        code: "de",
-        name: "German",
-        aliases: &["german"],
-    },
-    SupportedTranslationLanguage {
-        code: "it",
-        name: "Italian",
-        aliases: &["italian"],
-    },
+const BUILT_IN_TRANSLATION_LANGUAGES: &[SupportedTranslationLanguage] = &[
+    built_in_language(
+        "en",
+        "English",
+        &[Cow::Borrowed("english")],
+        TextDirection::Ltr,
+        true,
+    ),
+    built_in_language(
+        "pt",
+        "Portuguese",
+        &[
+            Cow::Borrowed("portuguese"),
+            Cow::Borrowed("portuguese (brazil)"),
+            Cow::Borrowed("brazilian portuguese"),
+        ],
+        TextDirection::Ltr,
+        true,
+    ),
+    built_in_language(
+        "es",
+        "Spanish",
+        &[Cow::Borrowed("spanish")],
+        TextDirection::Ltr,
+        true,
+    ),
+    built_in_language(
+        "fr",
+        "French",
+        &[Cow::Borrowed("french")],
+        TextDirection::Ltr,
+        false,
+    ),
+    built_in_language(
+        "de",
+        "German",
+        &[Cow::Borrowed("german")],
+        TextDirection::Ltr,
+        false,
+    ),
+    built_in_language(
+        "it",
+        "Italian",
+        &[Cow::Borrowed("italian")],
+        TextDirection::Ltr,
+        false,
+    ),
+    built_in_language(
+        "ar",
+        "Arabic",
+        &[Cow::Borrowed("arabic")],
+        TextDirection::Rtl,
+        false,
+    ),
+    built_in_language(
+        "he",
+        "Hebrew",
+        &[Cow::Borrowed("hebrew"), Cow::Borrowed("iw")],
+        TextDirection::Rtl,
+        false,
+    ),
 ];
 
+static TRANSLATION_LANGUAGES: RwLock<Vec<SupportedTranslationLanguage>> = RwLock::new(Vec::new());
+
+const fn built_in_language(
+    code: &'static str,
+    name: &'static str,
+    aliases: &'static [Cow<'static, str>],
+    direction: TextDirection,
+    site_locale: bool,
+) -> SupportedTranslationLanguage {
+    SupportedTranslationLanguage {
+        code: Cow::Borrowed(code),
+        name: Cow::Borrowed(name),
+        aliases: Cow::Borrowed(aliases),
+        direction,
+        enabled: true,
+        site_locale,
+    }
+}
+
 pub fn article_generation_prompt() -> PromptDefinition {
     ARTICLE_GENERATION_PROMPT
 }
@@ -93,10 +192,60 @@ pub fn research_article_generation_prompt() -> PromptDefinition {
     RESEARCH_ARTICLE_GENERATION_PROMPT
 }
 
+pub fn research_query_planning_prompt() -> PromptDefinition {
+    RESEARCH_QUERY_PLANNING_PROMPT
+}
+
+pub fn article_outline_prompt() -> PromptDefinition {
+    ARTICLE_OUTLINE_PROMPT
+}
+
+pub fn clarifying_question_prompt() -> PromptDefinition {
+    CLARIFYING_QUESTION_PROMPT
+}
+
 pub fn image_brief_generation_prompt() -> PromptDefinition {
     IMAGE_BRIEF_GENERATION_PROMPT
 }
 
+/// The generation prompts are written for English articles. Other source
+/// languages get the same prompt plus a closing instruction to write in that
+/// language; image descriptions stay in English for the image generator.
+pub fn generation_prompt_for_language(
+    prompt: PromptDefinition,
+    language: &SupportedTranslationLanguage,
+) -> String {
+    if language.code == "en" {
+        return prompt.body.to_string();
+    }
+    let instruction = if prompt.key == IMAGE_BRIEF_GENERATION_PROMPT.key {
+        format!(
+            "Write every caption in {}. Keep every detailed description in English.",
+            language.name
+        )
+    } else if prompt.key == ARTICLE_OUTLINE_PROMPT.key {
+        format!(
+            "Write the headline options, angle, beats and image slots in {}.",
+            language.name
+        )
+    } else if prompt.key == PLACEHOLDER_GENERATION_PROMPT.key {
+        format!(
+            "Write the headline, the article and every \"alt\" caption in {}. Keep every \"prompt\" attribute in English.",
+            language.name
+        )
+    } else {
+        format!(
+            "Write the headline and the whole article in {}.",
+            language.name
+        )
+    };
+    format!(
+        "{}\n\nLanguage:\n\n* {}\n",
+        prompt.body.trim_end(),
+        instruction
+    )
+}
+
 pub fn edit_rewrite_prompt() -> PromptDefinition {
     EDIT_REWRITE_PROMPT
 }
@@ -105,15 +254,55 @@ pub fn translation_prompt() -> PromptDefinition {
     TRANSLATION_PROMPT
 }
 
-pub fn supported_translation_languages() -> &'static [SupportedTranslationLanguage] {
-    SUPPORTED_TRANSLATION_LANGUAGES
+/// The languages seeded into the `language` table, used until the registry is loaded.
+pub fn built_in_translation_languages() -> &'static [SupportedTranslationLanguage] {
+    BUILT_IN_TRANSLATION_LANGUAGES
+}
+
+/// Every registered language, including disabled ones, in display order.
+pub fn registered_translation_languages() -> Vec<SupportedTranslationLanguage> {
+    let languages = TRANSLATION_LANGUAGES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if languages.is_empty() {
+        BUILT_IN_TRANSLATION_LANGUAGES.to_vec()
+    } else {
+        languages.clone()
+    }
+}
+
+pub fn supported_translation_languages() -> Vec<SupportedTranslationLanguage> {
+    registered_translation_languages()
+        .into_iter()
+        .filter(|language| language.enabled)
+        .collect()
+}
+
+/// Replaces the registry. English stays enabled because it is the source and
+/// fallback language for articles and site chrome.
+pub fn install_translation_languages(mut languages: Vec<SupportedTranslationLanguage>) {
+    match languages.iter_mut().find(|language| language.code == "en") {
+        Some(english) => english.enabled = true,
+        None => languages.insert(0, BUILT_IN_TRANSLATION_LANGUAGES[0].clone()),
+    }
+    *TRANSLATION_LANGUAGES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = languages;
+}
+
+/// Drops the installed registry so the built-in languages apply again.
+#[cfg(test)]
+pub(crate) fn reset_translation_languages() {
+    TRANSLATION_LANGUAGES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
 }
 
 pub fn find_supported_translation_language(value: &str) -> Option<SupportedTranslationLanguage> {
     let normalized = value.trim().to_ascii_lowercase();
-    SUPPORTED_TRANSLATION_LANGUAGES
-        .iter()
-        .copied()
+    supported_translation_languages()
+        .into_iter()
         .find(|language| {
             language.code == normalized
                 || language.name.eq_ignore_ascii_case(&normalized)
@@ -127,9 +316,10 @@ pub fn find_supported_translation_language(value: &str) -> Option<SupportedTrans
 #[cfg(test)]
 mod tests {
     use super::{
-        article_generation_prompt, edit_rewrite_prompt, find_supported_translation_language,
+        article_generation_prompt, article_outline_prompt, clarifying_question_prompt,
+        edit_rewrite_prompt, find_supported_translation_language, generation_prompt_for_language,
         image_brief_generation_prompt, placeholder_generation_prompt,
-        research_article_generation_prompt, translation_prompt,
+        research_article_generation_prompt, research_query_planning_prompt, translation_prompt,
     };
 
     #[test]
@@ -158,6 +348,36 @@ mod tests {
         assert!(prompt.contains("stay general instead of fabricating specifics"));
     }
 
+    #[test]
+    fn research_query_planning_prompt_contains_line_format_contract() {
+        let prompt = research_query_planning_prompt().body;
+
+        assert!(prompt.contains("up to {max_queries} queries, one per line"));
+        assert!(prompt.contains("Never search for the absurd premise itself"));
+    }
+
+    #[test]
+    fn article_outline_prompt_asks_for_every_outline_part_in_the_target_language() {
+        let spanish = find_supported_translation_language("es").unwrap();
+        let prompt = generation_prompt_for_language(article_outline_prompt(), &spanish);
+
+        assert!(prompt.contains("submit_article_outline"));
+        assert!(prompt.contains("{max_headlines} headline options"));
+        assert!(prompt.contains("{max_image_slots} image slots"));
+        assert!(
+            prompt.contains("Write the headline options, angle, beats and image slots in Spanish.")
+        );
+    }
+
+    #[test]
+    fn clarifying_question_prompt_names_its_tool_and_question_budget() {
+        let prompt = clarifying_question_prompt().body;
+
+        assert!(prompt.contains("Ask up to {max_questions} questions"));
+        assert!(prompt.contains("propose_clarifying_questions"));
+        assert!(prompt.contains("fallback instruction"));
+    }
+
     #[test]
     fn placeholder_prompt_contains_generated_image_contract() {
         let prompt = placeholder_generation_prompt().body;
@@ -194,6 +414,24 @@ mod tests {
         assert!(prompt.contains("Return only the translated text through the tool."));
     }
 
+    #[test]
+    fn generation_prompts_gain_a_language_instruction_outside_english() {
+        let english = find_supported_translation_language("en").unwrap();
+        let portuguese = find_supported_translation_language("pt").unwrap();
+
+        assert_eq!(
+            generation_prompt_for_language(article_generation_prompt(), &english),
+            article_generation_prompt().body
+        );
+        let prompt = generation_prompt_for_language(article_generation_prompt(), &portuguese);
+        assert!(prompt.starts_with(article_generation_prompt().body.trim_end()));
+        assert!(prompt.ends_with("Write the headline and the whole article in Portuguese.\n"));
+        assert!(
+            generation_prompt_for_language(image_brief_generation_prompt(), &portuguese)
+                .contains("Keep every detailed description in English.")
+        );
+    }
+
     #[test]
     fn translation_language_lookup_accepts_codes_and_aliases() {
         assert_eq!(
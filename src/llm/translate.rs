@@ -1,7 +1,9 @@
 use serde_json::Value;
+use tracing::{event, Level};
 
 use crate::error::Error;
 use crate::llm::function_definition::FunctionDefinition;
+use crate::llm::glossary::{translation_glossary, GlossaryViolation};
 use crate::llm::prompt_registry::{
     find_supported_translation_language, supported_translation_languages, translation_prompt,
     SupportedTranslationLanguage,
@@ -9,13 +11,14 @@ use crate::llm::prompt_registry::{
 use crate::llm::{function_definition, Llm, Message};
 
 const ENGLISH_LANGUAGE_CODE: &str = "en";
+const MAX_GLOSSARY_ATTEMPTS: usize = 2;
 
 #[allow(async_fn_in_trait)]
 pub trait Translate {
     async fn translate(
         &self,
         text: &str,
-        target_language: SupportedTranslationLanguage,
+        target_language: &SupportedTranslationLanguage,
     ) -> Result<String, Error>;
 }
 
@@ -62,10 +65,10 @@ pub fn detect_browser_translation_language(
 }
 
 pub fn default_translation_fallback_language(
-    source_language: SupportedTranslationLanguage,
+    source_language: &SupportedTranslationLanguage,
 ) -> SupportedTranslationLanguage {
     if source_language.code == ENGLISH_LANGUAGE_CODE {
-        source_language
+        source_language.clone()
     } else {
         find_supported_translation_language(ENGLISH_LANGUAGE_CODE)
             .expect("English must remain in the supported translation whitelist")
@@ -73,47 +76,120 @@ pub fn default_translation_fallback_language(
 }
 
 impl<'a> TranslationService<'a> {
-    pub fn supported_languages(&self) -> &'static [SupportedTranslationLanguage] {
+    pub fn supported_languages(&self) -> Vec<SupportedTranslationLanguage> {
         supported_translation_languages()
     }
 
     pub async fn translate_text(
         &self,
         text: &str,
-        target_language: SupportedTranslationLanguage,
+        target_language: &SupportedTranslationLanguage,
     ) -> Result<TranslationResult, Error> {
         let prompt = translation_prompt();
-        let messages = vec![
-            Message::System(prompt.body.to_string()),
+        let glossary = translation_glossary();
+        let system_prompt = match glossary.prompt_section(text, target_language) {
+            Some(section) => format!("{}\n\n{}", prompt.body, section),
+            None => prompt.body.to_string(),
+        };
+        let mut messages = vec![
+            Message::System(system_prompt),
             Message::User(format!(
                 "Translate the following text to {} ({}):\n\n{}",
                 target_language.name, target_language.code, text
             )),
         ];
+
+        let mut attempt = 1;
+        loop {
+            let translated = self.request_translation(messages.clone()).await?;
+            let violations = glossary.violations(text, &translated, target_language);
+            if violations.is_empty() {
+                return Ok(TranslationResult {
+                    text: translated,
+                    target_language: target_language.clone(),
+                    prompt_version: prompt.version,
+                });
+            }
+            if attempt < MAX_GLOSSARY_ATTEMPTS {
+                event!(
+                    Level::INFO,
+                    language = %target_language.code,
+                    violations = violations.len(),
+                    "Retrying translation that broke the glossary"
+                );
+                messages.push(Message::Assistant(translated));
+                messages.push(Message::User(glossary_correction_message(&violations)));
+                attempt += 1;
+                continue;
+            }
+            if violations.iter().any(|violation| violation.protected) {
+                return Err(Error::Llm(format!(
+                    "Translation to {} altered protected terms: {}",
+                    target_language.code,
+                    violation_terms(&violations)
+                )));
+            }
+            event!(
+                Level::WARN,
+                language = %target_language.code,
+                terms = %violation_terms(&violations),
+                "Accepting translation without preferred glossary terms"
+            );
+            return Ok(TranslationResult {
+                text: translated,
+                target_language: target_language.clone(),
+                prompt_version: prompt.version,
+            });
+        }
+    }
+
+    async fn request_translation(&self, messages: Vec<Message>) -> Result<String, Error> {
         let translation = self
             .llm
             .request_tool(send_translated_text(), messages, &self.llm.models[0])
             .await?;
         let value: Value = serde_json::from_str(&translation)
             .map_err(|e| Error::Llm(format!("Failed to parse translation response: {}", e)))?;
-        let text = value["text"]
+        Ok(value["text"]
             .as_str()
             .ok_or(Error::Llm("Translation response missing text".into()))?
-            .to_string();
+            .to_string())
+    }
+}
 
-        Ok(TranslationResult {
-            text,
-            target_language,
-            prompt_version: prompt.version,
-        })
+fn glossary_correction_message(violations: &[GlossaryViolation]) -> String {
+    let mut message = String::from(
+        "That translation did not follow the glossary. Send the full corrected translation, fixing only these terms:\n",
+    );
+    for violation in violations {
+        if violation.protected {
+            message.push_str(&format!(
+                "- \"{}\" must appear exactly as written.\n",
+                violation.term
+            ));
+        } else {
+            message.push_str(&format!(
+                "- \"{}\" must be translated as \"{}\".\n",
+                violation.term, violation.expected
+            ));
+        }
     }
+    message
+}
+
+fn violation_terms(violations: &[GlossaryViolation]) -> String {
+    violations
+        .iter()
+        .map(|violation| violation.term.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl Translate for Llm {
     async fn translate(
         &self,
         text: &str,
-        target_language: SupportedTranslationLanguage,
+        target_language: &SupportedTranslationLanguage,
     ) -> Result<String, Error> {
         translation_service(self)
             .translate_text(text, target_language)
@@ -174,9 +250,11 @@ fn parse_quality_value(value: &str) -> Option<u16> {
 mod tests {
     use crate::llm::prompt_registry::translation_prompt;
 
+    use crate::llm::glossary::GlossaryViolation;
+
     use super::{
         default_translation_fallback_language, detect_browser_translation_language,
-        translation_service,
+        glossary_correction_message, translation_service,
     };
 
     #[test]
@@ -228,7 +306,7 @@ mod tests {
         let source =
             crate::llm::prompt_registry::find_supported_translation_language("pt").unwrap();
 
-        let fallback = default_translation_fallback_language(source);
+        let fallback = default_translation_fallback_language(&source);
 
         assert_eq!(fallback.code, "en");
     }
@@ -238,8 +316,28 @@ mod tests {
         let source =
             crate::llm::prompt_registry::find_supported_translation_language("en").unwrap();
 
-        let fallback = default_translation_fallback_language(source);
+        let fallback = default_translation_fallback_language(&source);
 
         assert_eq!(fallback.code, "en");
     }
+
+    #[test]
+    fn glossary_correction_message_lists_each_violation() {
+        let message = glossary_correction_message(&[
+            GlossaryViolation {
+                term: "Wibble".to_string(),
+                expected: "Wibble".to_string(),
+                protected: true,
+            },
+            GlossaryViolation {
+                term: "Ministry of Queues".to_string(),
+                expected: "Ministério das Filas".to_string(),
+                protected: false,
+            },
+        ]);
+
+        assert!(message.contains("\"Wibble\" must appear exactly as written"));
+        assert!(message
+            .contains("\"Ministry of Queues\" must be translated as \"Ministério das Filas\""));
+    }
 }
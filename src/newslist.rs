@@ -17,7 +17,7 @@ use crate::entities::{content, prelude::*};
 use crate::error::Error;
 use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::llm::translate::Translate;
-use crate::services::article_language::article_source_language;
+use crate::services::article_language::article_source_language_for;
 use crate::services::article_translations::{
     ensure_cached_article_summary_translation, load_cached_article_summary_translations,
     OwnedArticleSummarySourceText,
@@ -45,6 +45,7 @@ pub struct Headline {
     pub description: String,
     pub image_id: Option<String>,
     pub title: String,
+    pub language_id: Option<String>,
 }
 
 fn public_sort_column(sort: Option<&str>) -> content::Column {
@@ -177,16 +178,22 @@ async fn localize_headlines_with_translator<T>(
     translator: T,
     db: DatabaseConnection,
     items: Vec<Headline>,
-    target_language: SupportedTranslationLanguage,
+    target_language: &SupportedTranslationLanguage,
 ) -> Result<Vec<FormattedHeadline>, Error>
 where
     T: Translate + Clone + Send + Sync + 'static,
 {
-    if items.is_empty() || target_language.code == article_source_language().code {
+    let sources = items
+        .iter()
+        .filter(|headline| {
+            article_source_language_for(headline.language_id.as_deref()).code
+                != target_language.code
+        })
+        .map(summary_source_text)
+        .collect::<Vec<_>>();
+    if sources.is_empty() {
         return Ok(items.into_iter().map(format_headline).collect());
     }
-
-    let sources = items.iter().map(summary_source_text).collect::<Vec<_>>();
     let mut translations =
         load_cached_article_summary_translations(&db, &sources, target_language).await?;
     let missing_sources = sources
@@ -222,7 +229,7 @@ where
             Err(err) => {
                 warn!(
                     article_id = article_id,
-                    target_language = target_language.code,
+                    target_language = %target_language.code,
                     error = %err,
                     "front-page summary translation failed"
                 );
@@ -276,7 +283,7 @@ fn build_index_url(
     }
 }
 
-fn sort_options(params: &ContentListParams, text: SiteText, root_path: &str) -> [FilterOption; 2] {
+fn sort_options(params: &ContentListParams, text: &SiteText, root_path: &str) -> [FilterOption; 2] {
     let search = params.search.as_deref();
     let t = params.t.as_deref();
     let current_sort = params.sort.as_deref().unwrap_or("new");
@@ -294,7 +301,7 @@ fn sort_options(params: &ContentListParams, text: SiteText, root_path: &str) ->
     ]
 }
 
-fn time_options(params: &ContentListParams, text: SiteText, root_path: &str) -> [FilterOption; 3] {
+fn time_options(params: &ContentListParams, text: &SiteText, root_path: &str) -> [FilterOption; 3] {
     let search = params.search.as_deref();
     let sort = params.sort.as_deref();
     let current_time = params.t.as_deref().unwrap_or("");
@@ -353,7 +360,7 @@ impl NewsList for WibbleRequest {
             self.state.llm.clone(),
             self.state.db.clone(),
             items,
-            self.site_language,
+            &self.site_language,
         )
         .await?;
         let has_results = !items.is_empty();
@@ -373,8 +380,8 @@ impl NewsList for WibbleRequest {
             params.sort.as_deref(),
             next_after_id.as_deref(),
         );
-        let sort_options = sort_options(&params, text, &root_path);
-        let time_options = time_options(&params, text, &root_path);
+        let sort_options = sort_options(&params, &text, &root_path);
+        let time_options = time_options(&params, &text, &root_path);
         let search_heading = match params
             .search
             .as_deref()
@@ -440,7 +447,7 @@ mod tests {
         async fn translate(
             &self,
             text: &str,
-            target_language: crate::llm::prompt_registry::SupportedTranslationLanguage,
+            target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
         ) -> Result<String, Error> {
             Ok(format!("[{}] {}", target_language.code, text))
         }
@@ -490,13 +497,14 @@ mod tests {
             description: "Brief summary".to_string(),
             image_id: None,
             title: "Report".to_string(),
+            language_id: None,
         }];
 
         let localized = localize_headlines_with_translator(
             FakeTranslator,
             ctx.state.db.clone(),
             items,
-            preferred_language("es"),
+            &preferred_language("es"),
         )
         .await
         .unwrap();
@@ -511,7 +519,7 @@ mod tests {
                 title: "Report".to_string(),
                 description: "Brief summary".to_string(),
             }],
-            preferred_language("es"),
+            &preferred_language("es"),
         )
         .await
         .unwrap();
@@ -1,5 +1,5 @@
 use crate::auth::AuthUser;
-use crate::entities::content;
+use crate::entities::{article_job, content};
 
 pub fn can_edit_article(auth_user: &AuthUser, article: &content::Model) -> bool {
     auth_user.is_admin() || article.author_email.as_deref() == Some(&auth_user.email)
@@ -13,11 +13,15 @@ pub fn can_toggle_publish(auth_user: &AuthUser, article: &content::Model) -> boo
     article.author_email.as_deref() == Some(&auth_user.email)
 }
 
+pub fn can_review_article_job(auth_user: &AuthUser, job: &article_job::Model) -> bool {
+    auth_user.is_admin() || job.author_email.as_deref() == Some(&auth_user.email)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{can_edit_article, can_toggle_publish};
+    use super::{can_edit_article, can_review_article_job, can_toggle_publish};
     use crate::auth::AuthUser;
-    use crate::entities::content;
+    use crate::entities::{article_job, content};
 
     fn sample_user(email: &str) -> AuthUser {
         AuthUser {
@@ -59,6 +63,7 @@ mod tests {
             longview_count: 0,
             impression_count: 0,
             click_count: 0,
+            language_id: None,
             author_email: author_email.map(str::to_string),
             published: false,
             recovered_from_dead_link: false,
@@ -92,4 +97,41 @@ mod tests {
             &article
         ));
     }
+
+    #[test]
+    fn only_the_job_author_can_review_its_outline() {
+        let created_at = chrono::NaiveDate::from_ymd_opt(2026, 4, 19)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let job = article_job::Model {
+            id: "job".to_string(),
+            article_id: None,
+            requester_key: "user:author@example.com".to_string(),
+            requester_tier: "AUTHENTICATED".to_string(),
+            author_email: Some("author@example.com".to_string()),
+            prompt: "prompt".to_string(),
+            feature_type: "create".to_string(),
+            phase: "awaiting_user_input".to_string(),
+            status: "processing".to_string(),
+            usage_counters: None,
+            preview_payload: None,
+            error_summary: None,
+            fail_count: 0,
+            created_at,
+            updated_at: created_at,
+            started_at: None,
+            finished_at: None,
+            language_code: None,
+        };
+
+        assert!(can_review_article_job(
+            &sample_user("author@example.com"),
+            &job
+        ));
+        assert!(!can_review_article_job(
+            &sample_user("reader@example.com"),
+            &job
+        ));
+    }
 }
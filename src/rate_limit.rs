@@ -417,6 +417,18 @@ impl RateLimitState {
             })
     }
 
+    pub fn check_clarifying_question_limit(
+        &self,
+        tier: RequesterTier,
+        key: &str,
+    ) -> Result<(), ArticleRateLimit> {
+        self.check_capability_limit(RateLimitCapability::ClarifyingQuestion, tier, key)
+            .map_err(|window| match window {
+                LimitWindow::Hourly => ArticleRateLimit::Hourly,
+                LimitWindow::Daily => ArticleRateLimit::Daily,
+            })
+    }
+
     pub fn check_translation_generation_limit(
         &self,
         tier: RequesterTier,
@@ -5,6 +5,7 @@ use crate::entities::{content_image, prelude::*};
 use crate::error::Error;
 use crate::image_generator::{ImageGenerated, ImageToCreate};
 use crate::image_status::IMAGE_STATUS_PENDING;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::services::article_persistence::{
     prepare_content_upsert, replace_content_images, upsert_prepared_content, SaveContentRequest,
 };
@@ -22,6 +23,7 @@ pub struct Article {
     pub description: String,
     pub images: Vec<ImageGenerated>,
     pub author_email: Option<String>,
+    pub source_language: SupportedTranslationLanguage,
 }
 
 pub struct PendingArticle {
@@ -36,6 +38,7 @@ pub struct PendingArticle {
     pub images: Vec<ImageToCreate>,
     pub image_generator: String,
     pub author_email: Option<String>,
+    pub source_language: SupportedTranslationLanguage,
 }
 
 pub async fn save_pending_article(
@@ -54,6 +57,7 @@ pub async fn save_pending_article(
         images,
         image_generator,
         author_email,
+        source_language,
     } = article;
     let now = chrono::Utc::now().naive_local();
     let first_image_id = images
@@ -74,6 +78,7 @@ pub async fn save_pending_article(
             title,
             instructions,
             author_email,
+            source_language,
         },
     )
     .await?;
@@ -89,6 +94,7 @@ pub async fn save_pending_article(
                     prompt_hash: None,
                     prompt: image.prompt,
                     alt_text: image.caption,
+                    caption: None,
                     created_at: now,
                     flagged: false,
                     regenerate: false,
@@ -104,6 +110,13 @@ pub async fn save_pending_article(
                     generation_finished_at: None,
                     provider_job_id: None,
                     provider_job_url: None,
+                    source_image_id: None,
+                    crop_x: None,
+                    crop_y: None,
+                    crop_width: None,
+                    crop_height: None,
+                    focal_x: None,
+                    focal_y: None,
                 };
                 ContentImage::insert(content_image::ActiveModel::from(pending_image))
                     .exec(tx)
@@ -132,6 +145,7 @@ pub async fn save_article(db: &DatabaseConnection, article: Article) -> Result<(
         description,
         images,
         author_email,
+        source_language,
     } = article;
     let first_image_id = images
         .first()
@@ -151,6 +165,7 @@ pub async fn save_article(db: &DatabaseConnection, article: Article) -> Result<(
             title,
             instructions,
             author_email,
+            source_language,
         },
     )
     .await?;
@@ -186,6 +201,7 @@ mod tests {
     use sea_orm::{sea_query::Expr, ColumnTrait, EntityTrait, QueryFilter};
 
     use crate::entities::{content, content_image};
+    use crate::services::article_language::article_source_language;
     use crate::test_support::TestContext;
 
     use super::*;
@@ -224,6 +240,7 @@ mod tests {
                 }],
                 image_generator: "test-generator".to_string(),
                 author_email: Some("author@example.com".to_string()),
+                source_language: article_source_language(),
             },
         )
         .await
@@ -247,6 +264,7 @@ mod tests {
                 }],
                 image_generator: "test-generator".to_string(),
                 author_email: Some("author@example.com".to_string()),
+                source_language: article_source_language(),
             },
         )
         .await
@@ -298,6 +316,7 @@ mod tests {
                 }],
                 image_generator: "test-generator".to_string(),
                 author_email: Some("author@example.com".to_string()),
+                source_language: article_source_language(),
             },
         )
         .await
@@ -327,6 +346,7 @@ mod tests {
                     "New Caption",
                 )],
                 author_email: Some("author@example.com".to_string()),
+                source_language: article_source_language(),
             },
         )
         .await
@@ -0,0 +1,180 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::entities::{prelude::*, translation_glossary};
+use crate::error::Error;
+use crate::llm::glossary::{install_translation_glossary, GlossaryEntry};
+use crate::llm::prompt_registry::registered_translation_languages;
+
+const MAX_GLOSSARY_TERM_CHARS: usize = 200;
+
+pub async fn list_glossary_rows(
+    db: &DatabaseConnection,
+) -> Result<Vec<translation_glossary::Model>, Error> {
+    TranslationGlossary::find()
+        .order_by_asc(translation_glossary::Column::Term)
+        .order_by_asc(translation_glossary::Column::LanguageCode)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading translation glossary: {}", e)))
+}
+
+/// Installs the stored glossary for translation prompts, verification and cache keys.
+pub async fn load_translation_glossary(db: &DatabaseConnection) -> Result<(), Error> {
+    let entries = list_glossary_rows(db)
+        .await?
+        .into_iter()
+        .map(|row| GlossaryEntry {
+            term: row.term,
+            language_code: row.language_code,
+            translation: row.translation,
+        })
+        .collect();
+    install_translation_glossary(entries);
+    Ok(())
+}
+
+/// Adds or replaces the entry for `term` in `language_code` (all languages when
+/// `None`). A missing translation marks the term as protected.
+pub async fn save_glossary_entry(
+    db: &DatabaseConnection,
+    entry: GlossaryEntry,
+) -> Result<translation_glossary::Model, Error> {
+    let entry = normalize_glossary_entry(entry)?;
+    let now = chrono::Utc::now().naive_utc();
+    let existing = TranslationGlossary::find()
+        .filter(translation_glossary::Column::Term.eq(entry.term.as_str()))
+        .filter(match entry.language_code.as_deref() {
+            Some(code) => translation_glossary::Column::LanguageCode.eq(code),
+            None => translation_glossary::Column::LanguageCode.is_null(),
+        })
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading glossary term: {}", e)))?;
+
+    let saved = match existing {
+        Some(row) => {
+            let mut active = translation_glossary::ActiveModel::from(row);
+            active.translation = ActiveValue::set(entry.translation);
+            active.updated_at = ActiveValue::set(now);
+            active.update(db).await
+        }
+        None => {
+            translation_glossary::ActiveModel {
+                id: ActiveValue::set(Uuid::new_v4().to_string()),
+                term: ActiveValue::set(entry.term),
+                language_code: ActiveValue::set(entry.language_code),
+                translation: ActiveValue::set(entry.translation),
+                created_at: ActiveValue::set(now),
+                updated_at: ActiveValue::set(now),
+            }
+            .insert(db)
+            .await
+        }
+    }
+    .map_err(|e| Error::Database(format!("Error saving glossary term: {}", e)))?;
+
+    load_translation_glossary(db).await?;
+    Ok(saved)
+}
+
+pub async fn delete_glossary_entry(
+    db: &DatabaseConnection,
+    id: &str,
+) -> Result<translation_glossary::Model, Error> {
+    let row = TranslationGlossary::find_by_id(id.to_string())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading glossary term: {}", e)))?
+        .ok_or(Error::NotFound(Some("Glossary term not found".to_string())))?;
+    TranslationGlossary::delete_by_id(id.to_string())
+        .exec(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error deleting glossary term: {}", e)))?;
+
+    load_translation_glossary(db).await?;
+    Ok(row)
+}
+
+fn normalize_glossary_entry(entry: GlossaryEntry) -> Result<GlossaryEntry, Error> {
+    let term = entry.term.trim().to_string();
+    if term.is_empty() || term.chars().count() > MAX_GLOSSARY_TERM_CHARS {
+        return Err(Error::BadRequest(format!(
+            "Glossary terms must be between 1 and {} characters",
+            MAX_GLOSSARY_TERM_CHARS
+        )));
+    }
+    let language_code = entry
+        .language_code
+        .map(|code| code.trim().to_ascii_lowercase())
+        .filter(|code| !code.is_empty());
+    if let Some(code) = language_code.as_deref() {
+        if !registered_translation_languages()
+            .iter()
+            .any(|language| language.code == code)
+        {
+            return Err(Error::BadRequest(format!("Unknown language code {}", code)));
+        }
+    }
+    let translation = entry
+        .translation
+        .map(|translation| translation.trim().to_string())
+        .filter(|translation| !translation.is_empty());
+    if translation
+        .as_deref()
+        .is_some_and(|translation| translation.chars().count() > MAX_GLOSSARY_TERM_CHARS)
+    {
+        return Err(Error::BadRequest(format!(
+            "Glossary translations must be at most {} characters",
+            MAX_GLOSSARY_TERM_CHARS
+        )));
+    }
+    if translation.is_some() && language_code.is_none() {
+        return Err(Error::BadRequest(
+            "Translated glossary terms need a target language".to_string(),
+        ));
+    }
+
+    Ok(GlossaryEntry {
+        term,
+        language_code,
+        translation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::llm::glossary::GlossaryEntry;
+
+    use super::normalize_glossary_entry;
+
+    fn entry(term: &str, language_code: Option<&str>, translation: Option<&str>) -> GlossaryEntry {
+        GlossaryEntry {
+            term: term.to_string(),
+            language_code: language_code.map(str::to_string),
+            translation: translation.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn normalize_glossary_entry_trims_and_treats_blank_translation_as_protected() {
+        let normalized = normalize_glossary_entry(entry(" Wibble ", Some(" "), Some(" "))).unwrap();
+
+        assert_eq!(normalized, entry("Wibble", None, None));
+        assert!(normalized.is_protected());
+    }
+
+    #[test]
+    fn normalize_glossary_entry_rejects_unknown_languages_and_unscoped_translations() {
+        assert!(normalize_glossary_entry(entry("Wibble", Some("xx"), None)).is_err());
+        assert!(normalize_glossary_entry(entry("Ministry", None, Some("Ministério"))).is_err());
+        assert!(normalize_glossary_entry(entry("", None, None)).is_err());
+        assert_eq!(
+            normalize_glossary_entry(entry("Ministry", Some("PT"), Some("Ministério"))).unwrap(),
+            entry("Ministry", Some("pt"), Some("Ministério"))
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DerivePartialModel, EntityTrait, FromQueryResult, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+use crate::entities::{horde_log, prelude::*};
+use crate::error::Error;
+
+pub const HORDE_EVENT_SUBMISSION: &str = "submission";
+pub const HORDE_EVENT_COMPLETED: &str = "completed";
+pub const HORDE_EVENT_FAILED: &str = "failed";
+pub const HORDE_EVENT_CENSORED: &str = "censored";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HordeLogEntry {
+    pub event: &'static str,
+    pub request_id: Option<String>,
+    pub message: String,
+    pub response: Option<String>,
+    pub error: Option<String>,
+    pub kudos: i32,
+    pub worker_model: Option<String>,
+    pub wait_seconds: Option<i32>,
+    pub flagged: bool,
+}
+
+/// The columns the provider panel aggregates; prompts and response bodies are left behind.
+#[derive(Clone, Debug, DerivePartialModel, FromQueryResult, Eq, PartialEq)]
+#[sea_orm(entity = "HordeLog")]
+pub struct HordeLogStat {
+    pub created_at: NaiveDateTime,
+    pub event: String,
+    pub kudos: i32,
+    pub wait_seconds: Option<i32>,
+}
+
+pub async fn record_horde_log(db: &DatabaseConnection, entry: HordeLogEntry) -> Result<(), Error> {
+    let log = horde_log::Model {
+        id: Uuid::new_v4().to_string(),
+        message: entry.message,
+        flagged: entry.flagged,
+        response: entry.response,
+        error: entry.error,
+        kudos: entry.kudos,
+        created_at: chrono::Utc::now().naive_local(),
+        event: entry.event.to_string(),
+        request_id: entry.request_id,
+        worker_model: entry.worker_model,
+        wait_seconds: entry.wait_seconds,
+    };
+
+    HordeLog::insert(horde_log::ActiveModel::from(log))
+        .exec(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error inserting horde log: {}", e)))?;
+
+    Ok(())
+}
+
+pub async fn load_horde_log_stats_since(
+    db: &DatabaseConnection,
+    since: NaiveDateTime,
+) -> Result<Vec<HordeLogStat>, Error> {
+    HordeLog::find()
+        .filter(horde_log::Column::CreatedAt.gte(since))
+        .into_partial_model::<HordeLogStat>()
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading horde log stats: {}", e)))
+}
+
+pub async fn load_recent_horde_logs(
+    db: &DatabaseConnection,
+    limit: u64,
+) -> Result<Vec<horde_log::Model>, Error> {
+    HordeLog::find()
+        .order_by_desc(horde_log::Column::CreatedAt)
+        .limit(limit)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading horde logs: {}", e)))
+}
@@ -3,7 +3,11 @@ use std::{env, fs};
 
 use image::codecs::jpeg::JpegEncoder;
 use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
-use sea_orm::{ConnectionTrait, EntityTrait};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use sha2::{Digest, Sha256};
 
 use crate::entities::{content_image, prelude::*};
 use crate::error::Error;
@@ -52,6 +56,19 @@ fn flatten_image_for_jpeg(image: DynamicImage) -> RgbImage {
     rgb
 }
 
+pub async fn find_article_images(
+    db: &impl ConnectionTrait,
+    article_id: &str,
+) -> Result<Vec<content_image::Model>, Error> {
+    ContentImage::find()
+        .filter(content_image::Column::ContentId.eq(article_id))
+        .order_by_asc(content_image::Column::CreatedAt)
+        .order_by_asc(content_image::Column::Id)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading article images: {}", e)))
+}
+
 pub async fn store_image_file(id: &str, img: Vec<u8>) -> Result<(), Error> {
     let storage_type = env::var("STORAGE_TYPE").unwrap_or_else(|_| "local".to_string());
     if storage_type.eq_ignore_ascii_case("s3") {
@@ -67,6 +84,96 @@ pub async fn store_image_file(id: &str, img: Vec<u8>) -> Result<(), Error> {
     Ok(())
 }
 
+pub async fn read_image_file(id: &str) -> Result<Vec<u8>, Error> {
+    let storage_type = env::var("STORAGE_TYPE").unwrap_or_else(|_| "local".to_string());
+    if storage_type.eq_ignore_ascii_case("s3") {
+        s3::download_image(id).await
+    } else {
+        let images_dir = env::var("IMAGES_DIR").expect("IMAGES_DIR is not set");
+        let image_path = PathBuf::from(images_dir).join(format!("{}.jpg", id));
+        fs::read(&image_path).map_err(|e| Error::Image(image::ImageError::IoError(e)))
+    }
+}
+
+/// Reused images point at the row whose file they share instead of owning a copy.
+pub fn image_storage_key(image: &content_image::Model) -> &str {
+    image.source_image_id.as_deref().unwrap_or(&image.id)
+}
+
+/// Identifies a render by its prompt and by the provider model/style that produced it,
+/// ignoring case and whitespace differences in the prompt.
+pub fn image_prompt_hash(prompt: &str, render_signature: &str) -> String {
+    let normalized = prompt
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    format!(
+        "{:x}",
+        Sha256::digest(format!("{}\n{}", render_signature, normalized).as_bytes())
+    )
+}
+
+pub async fn find_reusable_image(
+    db: &impl ConnectionTrait,
+    image: &content_image::Model,
+    prompt_hash: &str,
+) -> Result<Option<content_image::Model>, Error> {
+    ContentImage::find()
+        .filter(content_image::Column::PromptHash.eq(prompt_hash))
+        .filter(content_image::Column::Status.eq(IMAGE_STATUS_COMPLETED))
+        .filter(content_image::Column::Flagged.eq(false))
+        .filter(content_image::Column::ContentId.ne(image.content_id.as_str()))
+        .order_by_asc(content_image::Column::CreatedAt)
+        .order_by_asc(content_image::Column::Id)
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error looking up reusable image: {}", e)))
+}
+
+/// `content_image` keeps one hash per article, so a repeated prompt inside the same
+/// article stays unhashed rather than tripping `(content_id, prompt_hash)`.
+pub async fn available_prompt_hash(
+    db: &impl ConnectionTrait,
+    image: &content_image::Model,
+    prompt_hash: String,
+) -> Result<Option<String>, Error> {
+    let taken = ContentImage::find()
+        .filter(content_image::Column::ContentId.eq(image.content_id.as_str()))
+        .filter(content_image::Column::PromptHash.eq(prompt_hash.as_str()))
+        .filter(content_image::Column::Id.ne(image.id.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error checking image prompt hash: {}", e)))?
+        .is_some();
+    Ok((!taken).then_some(prompt_hash))
+}
+
+/// Gives every image reusing `image_id`'s file its own copy before that file is
+/// overwritten, so a regeneration or upload only changes the article it belongs to.
+pub async fn detach_shared_image(db: &impl ConnectionTrait, image_id: &str) -> Result<(), Error> {
+    let dependents = ContentImage::find()
+        .filter(content_image::Column::SourceImageId.eq(image_id))
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading shared images: {}", e)))?;
+    if dependents.is_empty() {
+        return Ok(());
+    }
+
+    let data = read_image_file(image_id).await?;
+    for dependent in dependents {
+        store_image_file(&dependent.id, data.clone()).await?;
+        let mut active = content_image::ActiveModel::from(dependent);
+        active.source_image_id = ActiveValue::set(None);
+        active
+            .update(db)
+            .await
+            .map_err(|e| Error::Database(format!("Error detaching shared image: {}", e)))?;
+    }
+    Ok(())
+}
+
 pub(super) async fn save_generated_image(
     article_id: String,
     prompt: String,
@@ -82,6 +189,7 @@ pub(super) async fn save_generated_image(
         content_id: article_id,
         prompt: prompt.clone(),
         alt_text,
+        caption: None,
         created_at: now,
         model: None,
         fail_count: 0,
@@ -98,6 +206,13 @@ pub(super) async fn save_generated_image(
         generation_finished_at: Some(now),
         provider_job_id: None,
         provider_job_url: None,
+        source_image_id: None,
+        crop_x: None,
+        crop_y: None,
+        crop_width: None,
+        crop_height: None,
+        focal_x: None,
+        focal_y: None,
     };
     ContentImage::insert(content_image::ActiveModel::from(content_image))
         .exec(db)
@@ -115,7 +230,7 @@ mod tests {
 
     use crate::error::Error;
 
-    use super::normalize_uploaded_image;
+    use super::{image_prompt_hash, normalize_uploaded_image};
 
     fn encode_test_image(format: ImageFormat) -> Vec<u8> {
         let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([16, 32, 64, 128])));
@@ -142,4 +257,20 @@ mod tests {
         assert!(matches!(err, Error::BadRequest(_)));
         assert!(err.to_string().contains("Upload a JPG, JPEG, or PNG image"));
     }
+
+    #[test]
+    fn image_prompt_hash_ignores_case_and_whitespace() {
+        assert_eq!(
+            image_prompt_hash("A  lighthouse\nat dusk ", "replicate:flux"),
+            image_prompt_hash("a lighthouse at dusk", "replicate:flux")
+        );
+    }
+
+    #[test]
+    fn image_prompt_hash_depends_on_render_signature() {
+        assert_ne!(
+            image_prompt_hash("a lighthouse at dusk", "replicate:flux"),
+            image_prompt_hash("a lighthouse at dusk", "horde:{}")
+        );
+    }
 }
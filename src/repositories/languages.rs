@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::entities::{language, prelude::*};
+use crate::error::Error;
+use crate::llm::prompt_registry::{
+    install_translation_languages, SupportedTranslationLanguage, TextDirection,
+};
+use crate::repositories::translations::language_row_id;
+
+const ENGLISH_LANGUAGE_CODE: &str = "en";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LanguageSettings {
+    pub code: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub direction: TextDirection,
+    pub enabled: bool,
+    pub site_locale: bool,
+}
+
+pub async fn list_language_rows(db: &DatabaseConnection) -> Result<Vec<language::Model>, Error> {
+    Language::find()
+        .order_by_asc(language::Column::SortOrder)
+        .order_by_asc(language::Column::Name)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading languages: {}", e)))
+}
+
+/// Installs the `language` rows that carry a code as the runtime registry. A table
+/// that has not been seeded yet keeps the built-in defaults.
+pub async fn load_language_registry(db: &DatabaseConnection) -> Result<(), Error> {
+    let languages = list_language_rows(db)
+        .await?
+        .iter()
+        .filter_map(registry_language)
+        .collect::<Vec<_>>();
+    if !languages.is_empty() {
+        install_translation_languages(languages);
+    }
+    Ok(())
+}
+
+fn registry_language(row: &language::Model) -> Option<SupportedTranslationLanguage> {
+    let code = row
+        .code
+        .as_deref()
+        .map(str::trim)
+        .filter(|code| !code.is_empty())?;
+    Some(SupportedTranslationLanguage {
+        code: Cow::Owned(code.to_string()),
+        name: Cow::Owned(row.name.trim().to_string()),
+        aliases: parse_language_aliases(&row.aliases)
+            .into_iter()
+            .map(Cow::Owned)
+            .collect(),
+        direction: TextDirection::parse(&row.direction),
+        enabled: row.enabled,
+        site_locale: row.site_locale,
+    })
+}
+
+pub fn parse_language_aliases(value: &str) -> Vec<String> {
+    let mut aliases = Vec::new();
+    for alias in value.split(',').map(|alias| alias.trim().to_lowercase()) {
+        if !alias.is_empty() && !aliases.contains(&alias) {
+            aliases.push(alias);
+        }
+    }
+    aliases
+}
+
+/// Accepts a primary language subtag with an optional region, e.g. `nl` or `pt-br`.
+pub fn normalize_language_code(value: &str) -> Result<String, Error> {
+    let code = value.trim().to_ascii_lowercase().replace('_', "-");
+    let mut parts = code.split('-');
+    let primary_ok = parts.next().is_some_and(|part| {
+        (2..=3).contains(&part.len()) && part.chars().all(|c| c.is_ascii_lowercase())
+    });
+    let rest_ok = parts.all(|part| {
+        (2..=4).contains(&part.len()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+    if !primary_ok || !rest_ok || code.len() > 16 {
+        return Err(Error::BadRequest(
+            "Language codes look like `nl` or `pt-br`".to_string(),
+        ));
+    }
+    Ok(code)
+}
+
+pub async fn save_language(
+    db: &DatabaseConnection,
+    settings: LanguageSettings,
+) -> Result<language::Model, Error> {
+    let code = normalize_language_code(&settings.code)?;
+    let name = settings.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > 500 {
+        return Err(Error::BadRequest(
+            "Language names must be between 1 and 500 characters".to_string(),
+        ));
+    }
+    if code == ENGLISH_LANGUAGE_CODE && !settings.enabled {
+        return Err(Error::BadRequest(
+            "English is the source and fallback language and cannot be disabled".to_string(),
+        ));
+    }
+
+    let rows = list_language_rows(db).await?;
+    if rows.iter().any(|row| {
+        row.name.eq_ignore_ascii_case(&name) && row.code.as_deref() != Some(code.as_str())
+    }) {
+        return Err(Error::BadRequest(format!(
+            "Another language is already named {}",
+            name
+        )));
+    }
+    let aliases = settings
+        .aliases
+        .iter()
+        .map(|alias| alias.trim().to_lowercase())
+        .filter(|alias| !alias.is_empty())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let existing = Language::find()
+        .filter(language::Column::Code.eq(code.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading language {}: {}", code, e)))?;
+    let saved = match existing {
+        Some(row) => {
+            let mut active = language::ActiveModel::from(row);
+            active.name = ActiveValue::set(name);
+            active.aliases = ActiveValue::set(aliases);
+            active.direction = ActiveValue::set(settings.direction.as_str().to_string());
+            active.enabled = ActiveValue::set(settings.enabled);
+            active.site_locale = ActiveValue::set(settings.site_locale);
+            active.update(db).await
+        }
+        None => {
+            let sort_order = Language::find()
+                .count(db)
+                .await
+                .map_err(|e| Error::Database(format!("Error counting languages: {}", e)))?;
+            language::ActiveModel {
+                id: ActiveValue::set(language_row_id(&code)),
+                name: ActiveValue::set(name),
+                code: ActiveValue::set(Some(code.clone())),
+                aliases: ActiveValue::set(aliases),
+                direction: ActiveValue::set(settings.direction.as_str().to_string()),
+                enabled: ActiveValue::set(settings.enabled),
+                site_locale: ActiveValue::set(settings.site_locale),
+                sort_order: ActiveValue::set(i32::try_from(sort_order).unwrap_or(i32::MAX)),
+            }
+            .insert(db)
+            .await
+        }
+    }
+    .map_err(|e| Error::Database(format!("Error saving language {}: {}", code, e)))?;
+
+    load_language_registry(db).await?;
+    Ok(saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_language_code, parse_language_aliases};
+
+    #[test]
+    fn normalize_language_code_accepts_primary_and_region_subtags() {
+        assert_eq!(normalize_language_code(" NL ").unwrap(), "nl");
+        assert_eq!(normalize_language_code("pt_BR").unwrap(), "pt-br");
+        assert!(normalize_language_code("dutch").is_err());
+        assert!(normalize_language_code("../en").is_err());
+    }
+
+    #[test]
+    fn parse_language_aliases_trims_and_deduplicates() {
+        assert_eq!(
+            parse_language_aliases("Dutch, nederlands,,dutch"),
+            vec!["dutch".to_string(), "nederlands".to_string()]
+        );
+    }
+}
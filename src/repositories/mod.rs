@@ -1,4 +1,11 @@
 pub mod articles;
 pub mod examples;
+pub mod glossary;
+pub mod horde_log;
 pub mod images;
+pub mod languages;
+pub mod research_domains;
+pub mod research_sources;
+pub mod research_traces;
+pub mod translation_overrides;
 pub mod translations;
@@ -0,0 +1,219 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::entities::{prelude::*, research_domain_policy};
+use crate::error::Error;
+use crate::llm::domain_policy::{
+    install_research_domain_policy, parse_leak_markers, DomainAction, DomainRule,
+};
+
+const MAX_DOMAIN_PATTERN_CHARS: usize = 255;
+const MAX_LEAK_MARKERS_CHARS: usize = 1000;
+const MAX_DOMAIN_SCORE: i32 = 100;
+
+pub async fn list_domain_policy_rows(
+    db: &DatabaseConnection,
+) -> Result<Vec<research_domain_policy::Model>, Error> {
+    ResearchDomainPolicy::find()
+        .order_by_asc(research_domain_policy::Column::Action)
+        .order_by_asc(research_domain_policy::Column::Pattern)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research domain policy: {}", e)))
+}
+
+pub fn domain_rule_from_row(row: &research_domain_policy::Model) -> Option<DomainRule> {
+    Some(DomainRule {
+        pattern: row.pattern.clone(),
+        action: DomainAction::parse(&row.action)?,
+        score: row.score,
+        leak_markers: parse_leak_markers(&row.leak_markers),
+    })
+}
+
+/// Installs the stored domain rules for research ranking and leak validation.
+pub async fn load_research_domain_policy(db: &DatabaseConnection) -> Result<(), Error> {
+    let rules = list_domain_policy_rows(db)
+        .await?
+        .iter()
+        .filter_map(domain_rule_from_row)
+        .collect();
+    install_research_domain_policy(rules);
+    Ok(())
+}
+
+/// Adds or replaces the rule for `pattern`.
+pub async fn save_domain_rule(
+    db: &DatabaseConnection,
+    rule: DomainRule,
+) -> Result<research_domain_policy::Model, Error> {
+    let rule = normalize_domain_rule(rule)?;
+    let leak_markers = rule.leak_markers.join(",");
+    let now = chrono::Utc::now().naive_utc();
+    let existing = ResearchDomainPolicy::find()
+        .filter(research_domain_policy::Column::Pattern.eq(rule.pattern.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research domain rule: {}", e)))?;
+
+    let saved = match existing {
+        Some(row) => {
+            let mut active = research_domain_policy::ActiveModel::from(row);
+            active.action = ActiveValue::set(rule.action.as_str().to_string());
+            active.score = ActiveValue::set(rule.score);
+            active.leak_markers = ActiveValue::set(leak_markers);
+            active.updated_at = ActiveValue::set(now);
+            active.update(db).await
+        }
+        None => {
+            research_domain_policy::ActiveModel {
+                id: ActiveValue::set(Uuid::new_v4().to_string()),
+                pattern: ActiveValue::set(rule.pattern),
+                action: ActiveValue::set(rule.action.as_str().to_string()),
+                score: ActiveValue::set(rule.score),
+                leak_markers: ActiveValue::set(leak_markers),
+                created_at: ActiveValue::set(now),
+                updated_at: ActiveValue::set(now),
+            }
+            .insert(db)
+            .await
+        }
+    }
+    .map_err(|e| Error::Database(format!("Error saving research domain rule: {}", e)))?;
+
+    load_research_domain_policy(db).await?;
+    Ok(saved)
+}
+
+pub async fn delete_domain_rule(
+    db: &DatabaseConnection,
+    id: &str,
+) -> Result<research_domain_policy::Model, Error> {
+    let row = ResearchDomainPolicy::find_by_id(id.to_string())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research domain rule: {}", e)))?
+        .ok_or(Error::NotFound(Some(
+            "Research domain rule not found".to_string(),
+        )))?;
+    ResearchDomainPolicy::delete_by_id(id.to_string())
+        .exec(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error deleting research domain rule: {}", e)))?;
+
+    load_research_domain_policy(db).await?;
+    Ok(row)
+}
+
+fn normalize_domain_rule(rule: DomainRule) -> Result<DomainRule, Error> {
+    let pattern = rule
+        .pattern
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    let valid_chars = pattern
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '*'));
+    if pattern.is_empty()
+        || pattern.chars().count() > MAX_DOMAIN_PATTERN_CHARS
+        || !valid_chars
+        || pattern.chars().all(|c| c == '*' || c == '.')
+    {
+        return Err(Error::BadRequest(format!(
+            "Domain patterns must be 1 to {} characters of letters, digits, '.', '-' or '*'",
+            MAX_DOMAIN_PATTERN_CHARS
+        )));
+    }
+    if !(0..=MAX_DOMAIN_SCORE).contains(&rule.score) {
+        return Err(Error::BadRequest(format!(
+            "Domain scores must be between 0 and {}",
+            MAX_DOMAIN_SCORE
+        )));
+    }
+    let leak_markers = parse_leak_markers(&rule.leak_markers.join(","));
+    if leak_markers.join(",").chars().count() > MAX_LEAK_MARKERS_CHARS {
+        return Err(Error::BadRequest(format!(
+            "Leak markers must be at most {} characters",
+            MAX_LEAK_MARKERS_CHARS
+        )));
+    }
+
+    Ok(DomainRule {
+        pattern,
+        action: rule.action,
+        score: rule.score,
+        leak_markers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::EntityTrait;
+
+    use crate::entities::prelude::ResearchDomainPolicy as ResearchDomainPolicyRows;
+    use crate::llm::domain_policy::{DomainAction, DomainRule, ResearchDomainPolicy};
+    use crate::test_support::TestContext;
+
+    use super::{domain_rule_from_row, list_domain_policy_rows, normalize_domain_rule};
+
+    fn rule(pattern: &str, score: i32, leak_markers: &[&str]) -> DomainRule {
+        DomainRule {
+            pattern: pattern.to_string(),
+            action: DomainAction::Boost,
+            score,
+            leak_markers: leak_markers
+                .iter()
+                .map(|marker| marker.to_string())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn normalize_domain_rule_lowercases_patterns_and_markers() {
+        assert_eq!(
+            normalize_domain_rule(rule(" Reuters.COM. ", 20, &[" Reuters ", ""])).unwrap(),
+            rule("reuters.com", 20, &["reuters"])
+        );
+    }
+
+    #[test]
+    fn normalize_domain_rule_rejects_bad_patterns_and_scores() {
+        assert!(normalize_domain_rule(rule("", 10, &[])).is_err());
+        assert!(normalize_domain_rule(rule("*", 10, &[])).is_err());
+        assert!(normalize_domain_rule(rule("https://reuters.com/", 10, &[])).is_err());
+        assert!(normalize_domain_rule(rule("reuters.com", 101, &[])).is_err());
+        assert!(normalize_domain_rule(rule("*.gov", 30, &[])).is_ok());
+    }
+
+    #[tokio::test]
+    async fn schema_compatibility_seeds_an_empty_policy_table() {
+        let ctx = TestContext::new().await;
+        let db = &ctx.state.db;
+        ResearchDomainPolicyRows::delete_many()
+            .exec(db)
+            .await
+            .unwrap();
+
+        crate::app_state::apply_test_schema_compatibility(db).await;
+
+        let policy = ResearchDomainPolicy::new(
+            list_domain_policy_rows(db)
+                .await
+                .unwrap()
+                .iter()
+                .filter_map(domain_rule_from_row)
+                .collect(),
+        );
+        assert_eq!(policy.score("transport.gov"), 30);
+        for domain in ["www.bbc.co.uk", "bbc.com", "bbc.in", "news.bbc.in"] {
+            assert!(
+                policy.leak_markers(domain).contains(&"bbc".to_string()),
+                "{} should be covered by a bbc rule",
+                domain
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use sha2::{Digest, Sha256};
+
+use crate::entities::{prelude::*, research_source};
+use crate::error::Error;
+
+pub struct CachedSource<'a> {
+    pub url: &'a str,
+    pub title: &'a str,
+    pub domain: &'a str,
+    pub snippet: &'a str,
+    pub search_query: &'a str,
+    pub content: &'a str,
+    pub published_on: Option<NaiveDate>,
+}
+
+pub fn research_url_hash(url: &str) -> String {
+    format!("{:x}", Sha256::digest(url.trim().as_bytes()))
+}
+
+pub fn research_content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Search queries are cached by their normalized form so spacing and case don't miss.
+pub fn normalize_research_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+        .chars()
+        .take(1000)
+        .collect()
+}
+
+pub fn is_fresh_research_source(row: &research_source::Model, now: NaiveDateTime) -> bool {
+    row.fetched_at + Duration::seconds(i64::from(row.ttl_seconds)) > now
+}
+
+pub async fn find_fresh_research_source(
+    db: &DatabaseConnection,
+    url: &str,
+) -> Result<Option<research_source::Model>, Error> {
+    let row = ResearchSource::find_by_id(research_url_hash(url))
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research source: {}", e)))?;
+    let now = chrono::Utc::now().naive_utc();
+    Ok(row.filter(|row| is_fresh_research_source(row, now)))
+}
+
+/// Fresh sources previously fetched for this query, in the order they were fetched.
+pub async fn find_fresh_research_sources_for_query(
+    db: &DatabaseConnection,
+    query: &str,
+) -> Result<Vec<research_source::Model>, Error> {
+    let rows = ResearchSource::find()
+        .filter(research_source::Column::SearchQuery.eq(normalize_research_query(query)))
+        .order_by_asc(research_source::Column::FetchedAt)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research sources: {}", e)))?;
+    let now = chrono::Utc::now().naive_utc();
+    Ok(rows
+        .into_iter()
+        .filter(|row| is_fresh_research_source(row, now))
+        .collect())
+}
+
+pub async fn save_research_source(
+    db: &DatabaseConnection,
+    source: CachedSource<'_>,
+    ttl: Duration,
+) -> Result<(), Error> {
+    let url_hash = research_url_hash(source.url);
+    let existing = ResearchSource::find_by_id(url_hash.clone())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research source: {}", e)))?;
+    let is_new = existing.is_none();
+    let mut active = match existing {
+        Some(row) => research_source::ActiveModel::from(row),
+        None => research_source::ActiveModel {
+            url_hash: ActiveValue::set(url_hash),
+            ..Default::default()
+        },
+    };
+    active.url = ActiveValue::set(source.url.to_string());
+    active.title = ActiveValue::set(source.title.to_string());
+    active.domain = ActiveValue::set(source.domain.to_string());
+    active.snippet = ActiveValue::set(source.snippet.to_string());
+    active.search_query = ActiveValue::set(normalize_research_query(source.search_query));
+    active.content = ActiveValue::set(source.content.to_string());
+    active.content_hash = ActiveValue::set(research_content_hash(source.content));
+    active.published_on = ActiveValue::set(source.published_on);
+    active.fetched_at = ActiveValue::set(chrono::Utc::now().naive_utc());
+    active.ttl_seconds = ActiveValue::set(ttl.num_seconds().clamp(0, i64::from(i32::MAX)) as i32);
+    if is_new {
+        active.insert(db).await
+    } else {
+        active.update(db).await
+    }
+    .map_err(|e| Error::Database(format!("Error saving research source: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{
+        find_fresh_research_source, find_fresh_research_sources_for_query,
+        is_fresh_research_source, normalize_research_query, research_content_hash,
+        research_url_hash, save_research_source, CachedSource,
+    };
+    use crate::entities::research_source;
+    use crate::test_support::TestContext;
+
+    fn cached_row(fetched_at: chrono::NaiveDateTime, ttl_seconds: i32) -> research_source::Model {
+        research_source::Model {
+            url_hash: research_url_hash("https://transport.gov/memo"),
+            url: "https://transport.gov/memo".to_string(),
+            title: "Memo".to_string(),
+            domain: "transport.gov".to_string(),
+            snippet: String::new(),
+            search_query: "transport memo".to_string(),
+            content: "Memo text".to_string(),
+            content_hash: String::new(),
+            fetched_at,
+            ttl_seconds,
+            published_on: None,
+        }
+    }
+
+    #[test]
+    fn research_sources_expire_after_their_ttl() {
+        let fetched_at = NaiveDate::from_ymd_opt(2026, 5, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        let row = cached_row(fetched_at, 3600);
+
+        assert!(is_fresh_research_source(
+            &row,
+            fetched_at + chrono::Duration::minutes(59)
+        ));
+        assert!(!is_fresh_research_source(
+            &row,
+            fetched_at + chrono::Duration::minutes(61)
+        ));
+    }
+
+    #[test]
+    fn research_queries_normalize_case_and_spacing() {
+        assert_eq!(
+            normalize_research_query("  Transport   Ministry\nMemo "),
+            "transport ministry memo"
+        );
+    }
+
+    #[tokio::test]
+    async fn saved_research_sources_are_reused_by_url_and_query_until_expired() {
+        let ctx = TestContext::new().await;
+        let db = &ctx.state.db;
+        let source = |content| CachedSource {
+            url: "https://transport.gov/memo",
+            title: "Transport memo",
+            domain: "transport.gov",
+            snippet: "New rules",
+            search_query: "Transport  ministry memo",
+            content,
+            published_on: NaiveDate::from_ymd_opt(2026, 4, 30),
+        };
+
+        save_research_source(db, source("First fetch"), chrono::Duration::minutes(60))
+            .await
+            .unwrap();
+        save_research_source(db, source("Second fetch"), chrono::Duration::minutes(60))
+            .await
+            .unwrap();
+
+        let by_url = find_fresh_research_source(db, "https://transport.gov/memo")
+            .await
+            .unwrap()
+            .expect("fresh source should be cached");
+        assert_eq!(by_url.content, "Second fetch");
+        assert_eq!(by_url.content_hash, research_content_hash("Second fetch"));
+        assert_eq!(by_url.published_on, NaiveDate::from_ymd_opt(2026, 4, 30));
+        let by_query = find_fresh_research_sources_for_query(db, "transport ministry MEMO")
+            .await
+            .unwrap();
+        assert_eq!(by_query.len(), 1);
+
+        save_research_source(db, source("Expired"), chrono::Duration::zero())
+            .await
+            .unwrap();
+        assert!(find_fresh_research_source(db, "https://transport.gov/memo")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,99 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait, QueryOrder, QuerySelect,
+};
+
+use crate::entities::{prelude::*, research_trace};
+use crate::error::Error;
+use crate::llm::article_generator::ResearchTrace as Trace;
+
+/// Stores the trace for a job, replacing the one from any earlier attempt.
+pub async fn save_research_trace(
+    db: &DatabaseConnection,
+    job_id: &str,
+    trace: &Trace,
+) -> Result<(), Error> {
+    let payload = serde_json::to_string(trace)
+        .map_err(|e| Error::Database(format!("Error encoding research trace: {}", e)))?;
+    let now = chrono::Utc::now().naive_utc();
+    let existing = ResearchTrace::find_by_id(job_id.to_string())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research trace: {}", e)))?;
+    let is_new = existing.is_none();
+    let mut active = match existing {
+        Some(row) => research_trace::ActiveModel::from(row),
+        None => research_trace::ActiveModel {
+            job_id: ActiveValue::set(job_id.to_string()),
+            created_at: ActiveValue::set(now),
+            ..Default::default()
+        },
+    };
+    active.search_provider = ActiveValue::set(trace.search_provider.chars().take(32).collect());
+    active.source_count = ActiveValue::set(trace.used_source_count() as i32);
+    active.failed = ActiveValue::set(trace.error.is_some());
+    active.trace = ActiveValue::set(payload);
+    active.updated_at = ActiveValue::set(now);
+    if is_new {
+        active.insert(db).await
+    } else {
+        active.update(db).await
+    }
+    .map_err(|e| Error::Database(format!("Error saving research trace: {}", e)))?;
+    Ok(())
+}
+
+pub async fn find_research_trace(
+    db: &DatabaseConnection,
+    job_id: &str,
+) -> Result<Option<(research_trace::Model, Trace)>, Error> {
+    let Some(row) = ResearchTrace::find_by_id(job_id.to_string())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research trace: {}", e)))?
+    else {
+        return Ok(None);
+    };
+    let trace = serde_json::from_str(&row.trace)
+        .map_err(|e| Error::Database(format!("Error decoding research trace: {}", e)))?;
+    Ok(Some((row, trace)))
+}
+
+pub async fn list_recent_research_traces(
+    db: &DatabaseConnection,
+    limit: u64,
+) -> Result<Vec<research_trace::Model>, Error> {
+    ResearchTrace::find()
+        .order_by_desc(research_trace::Column::UpdatedAt)
+        .limit(limit)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading research traces: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_research_trace, list_recent_research_traces, save_research_trace};
+    use crate::llm::article_generator::ResearchTrace;
+    use crate::test_support::TestContext;
+
+    #[tokio::test]
+    async fn research_traces_are_replaced_per_job() {
+        let ctx = TestContext::new().await;
+        let db = &ctx.state.db;
+        let mut trace = ResearchTrace::new("auto");
+        trace.search_provider = "duckduckgo".to_string();
+        trace.error = Some("no sources".to_string());
+        save_research_trace(db, "job-1", &trace).await.unwrap();
+
+        trace.error = None;
+        trace.prompt_context = "Research file".to_string();
+        save_research_trace(db, "job-1", &trace).await.unwrap();
+
+        let (row, loaded) = find_research_trace(db, "job-1").await.unwrap().unwrap();
+        assert!(!row.failed);
+        assert_eq!(row.search_provider, "duckduckgo");
+        assert_eq!(loaded, trace);
+        assert_eq!(list_recent_research_traces(db, 10).await.unwrap().len(), 1);
+        assert!(find_research_trace(db, "job-2").await.unwrap().is_none());
+    }
+}
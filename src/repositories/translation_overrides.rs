@@ -0,0 +1,139 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::entities::{prelude::*, translation_override};
+use crate::error::Error;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::repositories::translations::{translation_source_hash, TranslationField};
+
+/// A reviewed translation applies only while the source field still hashes to what
+/// the reviewer saw and no edit has marked it stale.
+pub fn is_current_override(override_row: &translation_override::Model, source_text: &str) -> bool {
+    !override_row.stale && override_row.source_hash == translation_source_hash(source_text)
+}
+
+pub async fn find_translation_overrides(
+    db: &DatabaseConnection,
+    article_ids: &[String],
+    language: &SupportedTranslationLanguage,
+) -> Result<Vec<translation_override::Model>, Error> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    TranslationOverride::find()
+        .filter(translation_override::Column::ArticleId.is_in(article_ids.iter().cloned()))
+        .filter(translation_override::Column::LanguageCode.eq(language.code.as_ref()))
+        .order_by_asc(translation_override::Column::Field)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading translation overrides: {}", e)))
+}
+
+/// Stores `translation` as the reviewed rendering of `field` for the current
+/// `source_text`, replacing any earlier override and clearing its stale flag.
+pub async fn save_translation_override(
+    db: &DatabaseConnection,
+    article_id: &str,
+    language: &SupportedTranslationLanguage,
+    field: TranslationField,
+    source_text: &str,
+    translation: &str,
+    reviewed_by: &str,
+) -> Result<translation_override::Model, Error> {
+    let now = chrono::Utc::now().naive_utc();
+    let existing = find_field_override(db, article_id, language, field).await?;
+
+    match existing {
+        Some(row) => {
+            let mut active = translation_override::ActiveModel::from(row);
+            active.source_hash = ActiveValue::set(translation_source_hash(source_text));
+            active.translation = ActiveValue::set(translation.to_string());
+            active.reviewed_by = ActiveValue::set(reviewed_by.to_string());
+            active.stale = ActiveValue::set(false);
+            active.updated_at = ActiveValue::set(now);
+            active.update(db).await
+        }
+        None => {
+            translation_override::ActiveModel {
+                id: ActiveValue::set(Uuid::new_v4().to_string()),
+                article_id: ActiveValue::set(article_id.to_string()),
+                language_code: ActiveValue::set(language.code.to_string()),
+                field: ActiveValue::set(field.as_str().to_string()),
+                source_hash: ActiveValue::set(translation_source_hash(source_text)),
+                translation: ActiveValue::set(translation.to_string()),
+                reviewed_by: ActiveValue::set(reviewed_by.to_string()),
+                stale: ActiveValue::set(false),
+                created_at: ActiveValue::set(now),
+                updated_at: ActiveValue::set(now),
+            }
+            .insert(db)
+            .await
+        }
+    }
+    .map_err(|e| Error::Database(format!("Error saving translation override: {}", e)))
+}
+
+pub async fn delete_translation_override(
+    db: &DatabaseConnection,
+    article_id: &str,
+    language: &SupportedTranslationLanguage,
+    field: TranslationField,
+) -> Result<bool, Error> {
+    let result = TranslationOverride::delete_many()
+        .filter(translation_override::Column::ArticleId.eq(article_id))
+        .filter(translation_override::Column::LanguageCode.eq(language.code.as_ref()))
+        .filter(translation_override::Column::Field.eq(field.as_str()))
+        .exec(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error deleting translation override: {}", e)))?;
+    Ok(result.rows_affected > 0)
+}
+
+/// Flags overrides whose source field no longer matches `sources`, in every
+/// language. Stale overrides are kept for re-review but no longer served.
+pub async fn mark_stale_translation_overrides(
+    db: &DatabaseConnection,
+    article_id: &str,
+    sources: &[(TranslationField, &str)],
+) -> Result<u64, Error> {
+    let now = chrono::Utc::now().naive_utc();
+    let mut marked = 0;
+    for (field, source_text) in sources {
+        marked += TranslationOverride::update_many()
+            .col_expr(translation_override::Column::Stale, Expr::value(true))
+            .col_expr(translation_override::Column::UpdatedAt, Expr::value(now))
+            .filter(translation_override::Column::ArticleId.eq(article_id))
+            .filter(translation_override::Column::Field.eq(field.as_str()))
+            .filter(translation_override::Column::Stale.eq(false))
+            .filter(
+                translation_override::Column::SourceHash.ne(translation_source_hash(source_text)),
+            )
+            .exec(db)
+            .await
+            .map_err(|e| {
+                Error::Database(format!("Error marking translation overrides stale: {}", e))
+            })?
+            .rows_affected;
+    }
+    Ok(marked)
+}
+
+async fn find_field_override(
+    db: &DatabaseConnection,
+    article_id: &str,
+    language: &SupportedTranslationLanguage,
+    field: TranslationField,
+) -> Result<Option<translation_override::Model>, Error> {
+    TranslationOverride::find()
+        .filter(translation_override::Column::ArticleId.eq(article_id))
+        .filter(translation_override::Column::LanguageCode.eq(language.code.as_ref()))
+        .filter(translation_override::Column::Field.eq(field.as_str()))
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading translation override: {}", e)))
+}
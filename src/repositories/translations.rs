@@ -16,14 +16,20 @@ pub enum TranslationField {
     Title,
     Description,
     Markdown,
+    ImageAltText,
+    ImageCaption,
+    CommentBody,
 }
 
 impl TranslationField {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::Title => "title",
             Self::Description => "description",
             Self::Markdown => "markdown",
+            Self::ImageAltText => "image_alt_text",
+            Self::ImageCaption => "image_caption",
+            Self::CommentBody => "comment_body",
         }
     }
 }
@@ -51,19 +57,74 @@ pub fn translation_cache_key(
     field: TranslationField,
     source_text: &str,
     prompt_version: i32,
+    glossary_version: &str,
+) -> String {
+    let mut key = format!(
+        "{}:{}:{}:{}",
+        article_id,
+        field.as_str(),
+        translation_source_hash(source_text),
+        prompt_version
+    );
+    // Keys minted before the glossary existed carry no suffix, so text no
+    // glossary term matches keeps serving them.
+    if !glossary_version.is_empty() {
+        key.push_str(":glossary:");
+        key.push_str(glossary_version);
+    }
+    stable_identifier("translation-cache", &key)
+}
+
+/// Translation-memory key for one markdown segment. Segments are keyed by content
+/// alone so an unchanged paragraph is reused across edits and articles.
+pub fn translation_segment_cache_key(
+    segment_text: &str,
+    prompt_version: i32,
+    glossary_version: &str,
 ) -> String {
     stable_identifier(
-        "translation-cache",
+        "translation-segment",
         &format!(
-            "{}:{}:{}:{}",
-            article_id,
-            field.as_str(),
-            translation_source_hash(source_text),
-            prompt_version
+            "{}:{}:{}",
+            translation_source_hash(segment_text),
+            prompt_version,
+            glossary_version
         ),
     )
 }
 
+pub fn image_translation_cache_key(
+    article_id: &str,
+    image_id: &str,
+    field: TranslationField,
+    source_text: &str,
+    prompt_version: i32,
+    glossary_version: &str,
+) -> String {
+    translation_cache_key(
+        &format!("{}/image/{}", article_id, image_id),
+        field,
+        source_text,
+        prompt_version,
+        glossary_version,
+    )
+}
+
+pub fn comment_translation_cache_key(
+    comment_id: &str,
+    source_text: &str,
+    prompt_version: i32,
+    glossary_version: &str,
+) -> String {
+    translation_cache_key(
+        &format!("comment/{}", comment_id),
+        TranslationField::CommentBody,
+        source_text,
+        prompt_version,
+        glossary_version,
+    )
+}
+
 fn stable_identifier(namespace: &str, value: &str) -> String {
     let digest = format!(
         "{:x}",
@@ -79,11 +140,11 @@ fn stable_identifier(namespace: &str, value: &str) -> String {
     )
 }
 
-fn language_row_id(language: SupportedTranslationLanguage) -> String {
-    stable_identifier("translation-language", language.code)
+pub(crate) fn language_row_id(code: &str) -> String {
+    stable_identifier("translation-language", code)
 }
 
-fn translation_row_id(source_hash: &str, language: SupportedTranslationLanguage) -> String {
+fn translation_row_id(source_hash: &str, language: &SupportedTranslationLanguage) -> String {
     stable_identifier(
         "translation-entry",
         &format!("{}:{}", source_hash, language.code),
@@ -122,14 +183,14 @@ pub async fn find_translations_for_keys(
 pub async fn save_translation(
     db: &DatabaseConnection,
     cache_key: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     translated_text: &str,
 ) -> Result<(), Error> {
     save_translations(
         db,
         &[TranslationWrite {
             cache_key: cache_key.to_string(),
-            language,
+            language: language.clone(),
             text: translated_text.to_string(),
         }],
     )
@@ -149,9 +210,9 @@ pub async fn save_translations(
         Box::pin(async move {
             let mut ensured_languages = HashSet::new();
             for write in &writes {
-                let language_id = language_row_id(write.language);
+                let language_id = language_row_id(&write.language.code);
                 if ensured_languages.insert(language_id.clone()) {
-                    ensure_language_row(tx, &language_id, write.language).await?;
+                    ensure_language_row(tx, &language_id, &write.language).await?;
                 }
                 upsert_translation(tx, &language_id, write).await?;
             }
@@ -187,7 +248,7 @@ pub async fn delete_translations_for_keys(
 async fn ensure_language_row<C: ConnectionTrait>(
     db: &C,
     language_id: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> Result<(), Error> {
     if Language::find_by_id(language_id.to_string())
         .one(db)
@@ -198,6 +259,12 @@ async fn ensure_language_row<C: ConnectionTrait>(
         let language_row = language::ActiveModel {
             id: ActiveValue::set(language_id.to_string()),
             name: ActiveValue::set(language.name.to_string()),
+            code: ActiveValue::set(Some(language.code.to_string())),
+            aliases: ActiveValue::set(language.aliases.join(",")),
+            direction: ActiveValue::set(language.direction.as_str().to_string()),
+            enabled: ActiveValue::set(language.enabled),
+            site_locale: ActiveValue::set(language.site_locale),
+            sort_order: ActiveValue::set(0),
         };
         Language::insert(language_row)
             .exec(db)
@@ -213,7 +280,7 @@ async fn upsert_translation<C: ConnectionTrait>(
     language_id: &str,
     write: &TranslationWrite,
 ) -> Result<(), Error> {
-    let translation_id = translation_row_id(&write.cache_key, write.language);
+    let translation_id = translation_row_id(&write.cache_key, &write.language);
 
     if let Some(existing) = Translation::find_by_id(translation_id.clone())
         .one(db)
@@ -246,7 +313,8 @@ async fn upsert_translation<C: ConnectionTrait>(
 #[cfg(test)]
 mod tests {
     use super::{
-        stable_identifier, translation_cache_key, translation_source_hash, TranslationField,
+        image_translation_cache_key, stable_identifier, translation_cache_key,
+        translation_source_hash, TranslationField,
     };
 
     #[test]
@@ -270,20 +338,72 @@ mod tests {
 
     #[test]
     fn translation_cache_key_is_field_specific() {
-        let title_key = translation_cache_key("article-1", TranslationField::Title, "Same", 1);
+        let title_key = translation_cache_key("article-1", TranslationField::Title, "Same", 1, "");
         let description_key =
-            translation_cache_key("article-1", TranslationField::Description, "Same", 1);
+            translation_cache_key("article-1", TranslationField::Description, "Same", 1, "");
 
         assert_ne!(title_key, description_key);
     }
 
     #[test]
     fn translation_cache_key_changes_with_article_and_prompt_version() {
-        let base = translation_cache_key("article-1", TranslationField::Title, "Same", 1);
-        let other_article = translation_cache_key("article-2", TranslationField::Title, "Same", 1);
-        let other_prompt = translation_cache_key("article-1", TranslationField::Title, "Same", 2);
+        let base = translation_cache_key("article-1", TranslationField::Title, "Same", 1, "");
+        let other_article =
+            translation_cache_key("article-2", TranslationField::Title, "Same", 1, "");
+        let other_prompt =
+            translation_cache_key("article-1", TranslationField::Title, "Same", 2, "");
 
         assert_ne!(base, other_article);
         assert_ne!(base, other_prompt);
     }
+
+    #[test]
+    fn translation_cache_key_changes_with_glossary_version() {
+        let base = translation_cache_key("article-1", TranslationField::Title, "Same", 1, "");
+        let glossary =
+            translation_cache_key("article-1", TranslationField::Title, "Same", 1, "0123abcd");
+
+        assert_eq!(
+            base,
+            stable_identifier(
+                "translation-cache",
+                &format!(
+                    "article-1:title:{}:1",
+                    super::translation_source_hash("Same")
+                )
+            )
+        );
+        assert_ne!(base, glossary);
+    }
+
+    #[test]
+    fn image_translation_cache_key_is_image_specific() {
+        let first = image_translation_cache_key(
+            "article-1",
+            "img-1",
+            TranslationField::ImageAltText,
+            "Same",
+            1,
+            "",
+        );
+        let second = image_translation_cache_key(
+            "article-1",
+            "img-2",
+            TranslationField::ImageAltText,
+            "Same",
+            1,
+            "",
+        );
+        let caption = image_translation_cache_key(
+            "article-1",
+            "img-1",
+            TranslationField::ImageCaption,
+            "Same",
+            1,
+            "",
+        );
+
+        assert_ne!(first, second);
+        assert_ne!(first, caption);
+    }
 }
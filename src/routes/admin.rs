@@ -1,6 +1,6 @@
 mod service;
 
-use axum::extract::{Path, Query};
+use axum::extract::{Form, Path, Query};
 use axum::response::{Html, Redirect};
 use axum::routing::{get, post};
 use axum::Router;
@@ -10,16 +10,50 @@ use crate::app_state::AppState;
 use crate::audit::log_audit;
 use crate::auth::AuthUser;
 use crate::error::Error;
+use crate::llm::domain_policy::{
+    parse_leak_markers, DomainAction, DomainRule, DEFAULT_DOMAIN_SCORE,
+};
+use crate::llm::glossary::GlossaryEntry;
+use crate::llm::prompt_registry::TextDirection;
+use crate::repositories::glossary::{delete_glossary_entry, save_glossary_entry};
+use crate::repositories::languages::{parse_language_aliases, save_language, LanguageSettings};
+use crate::repositories::research_domains::{delete_domain_rule, save_domain_rule};
 use crate::services::article_jobs::ArticleJobService;
 use crate::translation_jobs::cancel_translation_job;
 use crate::wibble_request::WibbleRequest;
 
-use self::service::{load_admin_articles_page, load_admin_jobs_page};
+use self::service::{
+    load_admin_articles_page, load_admin_glossary_page, load_admin_jobs_page,
+    load_admin_language_rows, load_admin_providers_page, load_admin_research_domains_page,
+    load_admin_research_trace_page,
+};
 
 pub fn localized_router() -> Router<AppState> {
     Router::new()
         .route("/admin/articles", get(get_admin_articles))
         .route("/admin/jobs", get(get_admin_jobs))
+        .route("/admin/jobs/{id}/research", get(get_admin_research_trace))
+        .route("/admin/providers", get(get_admin_providers))
+        .route(
+            "/admin/languages",
+            get(get_admin_languages).post(post_admin_language),
+        )
+        .route(
+            "/admin/glossary",
+            get(get_admin_glossary).post(post_admin_glossary_entry),
+        )
+        .route(
+            "/admin/glossary/{id}/delete",
+            post(post_delete_admin_glossary_entry),
+        )
+        .route(
+            "/admin/research-domains",
+            get(get_admin_research_domains).post(post_admin_research_domain_rule),
+        )
+        .route(
+            "/admin/research-domains/{id}/delete",
+            post(post_delete_admin_research_domain_rule),
+        )
         .route(
             "/admin/article-jobs/{id}/cancel",
             post(post_cancel_article_job),
@@ -74,9 +108,272 @@ async fn get_admin_jobs(wr: WibbleRequest) -> Result<Html<String>, Error> {
         .insert("failed_article_jobs", &page.failed_article_jobs)
         .insert("active_translation_jobs", &page.active_translation_jobs)
         .insert("failed_translation_jobs", &page.failed_translation_jobs)
+        .insert(
+            "completed_translation_jobs",
+            &page.completed_translation_jobs,
+        )
+        .insert("translation_segment_reuse", &page.translation_segment_reuse)
+        .insert("research_traces", &page.research_traces)
+        .render()
+}
+
+async fn get_admin_research_trace(
+    wr: WibbleRequest,
+    Path(id): Path<String>,
+) -> Result<Html<String>, Error> {
+    require_admin_user(&wr)?;
+    let page = load_admin_research_trace_page(&wr.state.db, &id).await?;
+
+    wr.template("admin_research_trace")
+        .await
+        .insert("title", "Admin - Research trace")
+        .insert("robots", "noindex,nofollow")
+        .insert("job_id", &id)
+        .insert("job", &page.job)
+        .insert("trace", &page.trace)
+        .insert("recorded_at", &page.recorded_at)
+        .render()
+}
+
+async fn get_admin_providers(wr: WibbleRequest) -> Result<Html<String>, Error> {
+    require_admin_user(&wr)?;
+    let page = load_admin_providers_page(&wr.state).await?;
+
+    wr.template("admin_providers")
+        .await
+        .insert("title", "Admin - Image Providers")
+        .insert("robots", "noindex,nofollow")
+        .insert("image_provider", &page.image_provider)
+        .insert("window_days", &page.window_days)
+        .insert("total_kudos", &page.total_kudos)
+        .insert("kudos_by_day", &page.kudos_by_day)
+        .insert("wait_summary", &page.wait_summary)
+        .insert("recent_logs", &page.recent_logs)
+        .render()
+}
+
+#[derive(Deserialize)]
+struct AdminLanguageForm {
+    code: String,
+    name: String,
+    #[serde(default)]
+    aliases: String,
+    #[serde(default)]
+    direction: String,
+    enabled: Option<String>,
+    site_locale: Option<String>,
+}
+
+async fn get_admin_languages(wr: WibbleRequest) -> Result<Html<String>, Error> {
+    require_admin_user(&wr)?;
+    let languages = load_admin_language_rows(&wr.state.db).await?;
+
+    wr.template("admin_languages")
+        .await
+        .insert("title", "Admin - Languages")
+        .insert("robots", "noindex,nofollow")
+        .insert("languages", &languages)
+        .render()
+}
+
+async fn post_admin_language(
+    wr: WibbleRequest,
+    Form(form): Form<AdminLanguageForm>,
+) -> Result<Redirect, Error> {
+    let auth_user = require_admin_user(&wr)?;
+    let saved = save_language(
+        &wr.state.db,
+        LanguageSettings {
+            code: form.code,
+            name: form.name,
+            aliases: parse_language_aliases(&form.aliases),
+            direction: TextDirection::parse(&form.direction),
+            enabled: form.enabled.is_some(),
+            site_locale: form.site_locale.is_some(),
+        },
+    )
+    .await?;
+    let code = saved.code.clone().unwrap_or_default();
+    let details = serde_json::json!({
+        "name": saved.name,
+        "direction": saved.direction,
+        "enabled": saved.enabled,
+        "site_locale": saved.site_locale,
+    })
+    .to_string();
+    log_audit(
+        &wr.state.db,
+        auth_user,
+        "save_language",
+        "language",
+        &code,
+        Some(details),
+    )
+    .await?;
+    Ok(Redirect::to(&wr.localized_path("/admin/languages")))
+}
+
+#[derive(Deserialize)]
+struct AdminGlossaryForm {
+    term: String,
+    #[serde(default)]
+    language_code: String,
+    #[serde(default)]
+    translation: String,
+}
+
+async fn get_admin_glossary(wr: WibbleRequest) -> Result<Html<String>, Error> {
+    require_admin_user(&wr)?;
+    let page = load_admin_glossary_page(&wr.state.db).await?;
+
+    wr.template("admin_glossary")
+        .await
+        .insert("title", "Admin - Glossary")
+        .insert("robots", "noindex,nofollow")
+        .insert("entries", &page.entries)
+        .insert("languages", &page.languages)
+        .insert("glossary_version", &page.version)
         .render()
 }
 
+async fn post_admin_glossary_entry(
+    wr: WibbleRequest,
+    Form(form): Form<AdminGlossaryForm>,
+) -> Result<Redirect, Error> {
+    let auth_user = require_admin_user(&wr)?;
+    let saved = save_glossary_entry(
+        &wr.state.db,
+        GlossaryEntry {
+            term: form.term,
+            language_code: Some(form.language_code),
+            translation: Some(form.translation),
+        },
+    )
+    .await?;
+    let details = serde_json::json!({
+        "term": saved.term,
+        "language_code": saved.language_code,
+        "translation": saved.translation,
+    })
+    .to_string();
+    log_audit(
+        &wr.state.db,
+        auth_user,
+        "save_glossary_term",
+        "translation_glossary",
+        &saved.id,
+        Some(details),
+    )
+    .await?;
+    Ok(Redirect::to(&wr.localized_path("/admin/glossary")))
+}
+
+async fn post_delete_admin_glossary_entry(
+    wr: WibbleRequest,
+    Path(id): Path<String>,
+) -> Result<Redirect, Error> {
+    let auth_user = require_admin_user(&wr)?;
+    let deleted = delete_glossary_entry(&wr.state.db, &id).await?;
+    let details = serde_json::json!({
+        "term": deleted.term,
+        "language_code": deleted.language_code,
+    })
+    .to_string();
+    log_audit(
+        &wr.state.db,
+        auth_user,
+        "delete_glossary_term",
+        "translation_glossary",
+        &deleted.id,
+        Some(details),
+    )
+    .await?;
+    Ok(Redirect::to(&wr.localized_path("/admin/glossary")))
+}
+
+#[derive(Deserialize)]
+struct AdminResearchDomainForm {
+    pattern: String,
+    action: String,
+    #[serde(default)]
+    score: Option<i32>,
+    #[serde(default)]
+    leak_markers: String,
+}
+
+async fn get_admin_research_domains(wr: WibbleRequest) -> Result<Html<String>, Error> {
+    require_admin_user(&wr)?;
+    let page = load_admin_research_domains_page(&wr.state.db).await?;
+
+    wr.template("admin_research_domains")
+        .await
+        .insert("title", "Admin - Research domains")
+        .insert("robots", "noindex,nofollow")
+        .insert("rules", &page.rules)
+        .insert("allowlist_active", &page.allowlist_active)
+        .insert("default_score", &page.default_score)
+        .render()
+}
+
+async fn post_admin_research_domain_rule(
+    wr: WibbleRequest,
+    Form(form): Form<AdminResearchDomainForm>,
+) -> Result<Redirect, Error> {
+    let auth_user = require_admin_user(&wr)?;
+    let action = DomainAction::parse(&form.action)
+        .ok_or_else(|| Error::BadRequest(format!("Unknown domain action {}", form.action)))?;
+    let saved = save_domain_rule(
+        &wr.state.db,
+        DomainRule {
+            pattern: form.pattern,
+            action,
+            score: form.score.unwrap_or(DEFAULT_DOMAIN_SCORE),
+            leak_markers: parse_leak_markers(&form.leak_markers),
+        },
+    )
+    .await?;
+    let details = serde_json::json!({
+        "pattern": saved.pattern,
+        "action": saved.action,
+        "score": saved.score,
+        "leak_markers": saved.leak_markers,
+    })
+    .to_string();
+    log_audit(
+        &wr.state.db,
+        auth_user,
+        "save_research_domain_rule",
+        "research_domain_policy",
+        &saved.id,
+        Some(details),
+    )
+    .await?;
+    Ok(Redirect::to(&wr.localized_path("/admin/research-domains")))
+}
+
+async fn post_delete_admin_research_domain_rule(
+    wr: WibbleRequest,
+    Path(id): Path<String>,
+) -> Result<Redirect, Error> {
+    let auth_user = require_admin_user(&wr)?;
+    let deleted = delete_domain_rule(&wr.state.db, &id).await?;
+    let details = serde_json::json!({
+        "pattern": deleted.pattern,
+        "action": deleted.action,
+    })
+    .to_string();
+    log_audit(
+        &wr.state.db,
+        auth_user,
+        "delete_research_domain_rule",
+        "research_domain_policy",
+        &deleted.id,
+        Some(details),
+    )
+    .await?;
+    Ok(Redirect::to(&wr.localized_path("/admin/research-domains")))
+}
+
 async fn post_cancel_article_job(
     wr: WibbleRequest,
     Path(id): Path<String>,
@@ -144,3 +441,250 @@ fn require_admin_user(wr: &WibbleRequest) -> Result<&AuthUser, Error> {
     }
     Ok(auth_user)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::response::{Html, IntoResponse};
+    use sea_orm::{ActiveModelTrait, ActiveValue};
+
+    use crate::entities::translation_glossary;
+    use crate::error::Error;
+    use crate::llm::article_generator::{CandidateOutcome, ResearchTrace, TraceCandidate};
+    use crate::llm::domain_policy::research_domain_policy;
+    use crate::llm::prompt_registry::find_supported_translation_language;
+    use crate::rate_limit::RequesterTier;
+    use crate::repositories::horde_log::{
+        record_horde_log, HordeLogEntry, HORDE_EVENT_COMPLETED, HORDE_EVENT_SUBMISSION,
+    };
+    use crate::repositories::research_traces::save_research_trace;
+    use crate::services::site_text::default_site_language;
+    use crate::test_support::{admin_user, author_user, TestContext};
+    use crate::wibble_request::WibbleRequest;
+
+    fn sample_request(
+        state: crate::app_state::AppState,
+        auth_user: crate::auth::AuthUser,
+    ) -> WibbleRequest {
+        WibbleRequest {
+            state,
+            style: "style".to_string(),
+            request_path: "/en/admin/providers".to_string(),
+            rate_limit_key: format!("user:{}", auth_user.email),
+            auth_user: Some(auth_user),
+            requester_tier: RequesterTier::Admin,
+            site_language: default_site_language(),
+            browser_translation_language: None,
+            saved_article_language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn providers_page_reports_horde_ledger() {
+        let ctx = TestContext::new().await;
+        record_horde_log(
+            &ctx.state.db,
+            HordeLogEntry {
+                event: HORDE_EVENT_SUBMISSION,
+                request_id: Some("horde-req-1".to_string()),
+                message: "{}".to_string(),
+                kudos: 12,
+                ..HordeLogEntry::default()
+            },
+        )
+        .await
+        .unwrap();
+        record_horde_log(
+            &ctx.state.db,
+            HordeLogEntry {
+                event: HORDE_EVENT_COMPLETED,
+                request_id: Some("horde-req-1".to_string()),
+                message: "A filing cabinet".to_string(),
+                kudos: 11,
+                worker_model: Some("AlbedoBase XL".to_string()),
+                wait_seconds: Some(42),
+                ..HordeLogEntry::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let Html(html) =
+            super::get_admin_providers(sample_request(ctx.state.clone(), admin_user()))
+                .await
+                .unwrap();
+
+        assert!(html.contains("AI Horde kudos burn"));
+        assert!(html.contains("AlbedoBase XL"));
+        assert!(html.contains("42s"));
+        assert!(html.contains("<strong>11</strong>"));
+
+        let err = super::get_admin_providers(sample_request(
+            ctx.state.clone(),
+            author_user("author@example.com"),
+        ))
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn language_registry_saves_new_languages_for_admins() {
+        let ctx = TestContext::new().await;
+        let form = || super::AdminLanguageForm {
+            code: "NL".to_string(),
+            name: "Dutch".to_string(),
+            aliases: "nederlands, dutch".to_string(),
+            direction: "ltr".to_string(),
+            enabled: Some("on".to_string()),
+            site_locale: None,
+        };
+
+        let _redirect = super::post_admin_language(
+            sample_request(ctx.state.clone(), admin_user()),
+            axum::extract::Form(form()),
+        )
+        .await
+        .unwrap();
+
+        let dutch = find_supported_translation_language("nederlands").unwrap();
+        assert_eq!(dutch.code, "nl");
+        assert!(!dutch.site_locale);
+
+        let Html(html) =
+            super::get_admin_languages(sample_request(ctx.state.clone(), admin_user()))
+                .await
+                .unwrap();
+        assert!(html.contains("Dutch"));
+        assert!(html.contains("English fallback"));
+
+        let err = super::post_admin_language(
+            sample_request(ctx.state.clone(), author_user("author@example.com")),
+            axum::extract::Form(form()),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn glossary_page_lists_terms_for_admins_only() {
+        let ctx = TestContext::new().await;
+        let now = chrono::Utc::now().naive_utc();
+        translation_glossary::ActiveModel {
+            id: ActiveValue::set("glossary-1".to_string()),
+            term: ActiveValue::set("Ministry of Queues".to_string()),
+            language_code: ActiveValue::set(Some("pt".to_string())),
+            translation: ActiveValue::set(Some("Ministério das Filas".to_string())),
+            created_at: ActiveValue::set(now),
+            updated_at: ActiveValue::set(now),
+        }
+        .insert(&ctx.state.db)
+        .await
+        .unwrap();
+
+        let Html(html) = super::get_admin_glossary(sample_request(ctx.state.clone(), admin_user()))
+            .await
+            .unwrap();
+        assert!(html.contains("Ministry of Queues"));
+        assert!(html.contains("Ministério das Filas"));
+        assert!(html.contains("/admin/glossary/glossary-1/delete"));
+
+        let err = super::get_admin_glossary(sample_request(
+            ctx.state.clone(),
+            author_user("author@example.com"),
+        ))
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn research_trace_page_shows_candidates_and_context_for_admins_only() {
+        let ctx = TestContext::new().await;
+        let mut trace = ResearchTrace::new("manual");
+        trace.search_provider = "local".to_string();
+        trace.candidates.push(TraceCandidate {
+            title: "Transport memo".to_string(),
+            url: "https://transport.gov/memo".to_string(),
+            domain: "transport.gov".to_string(),
+            score: 30,
+            outcome: CandidateOutcome::Fetched,
+            published: Some("2026-04-30".to_string()),
+            context_chars: 120,
+            elapsed_ms: 42,
+            error: None,
+        });
+        trace.prompt_context = "Research file:\n1. Transport memo (transport.gov)".to_string();
+        save_research_trace(&ctx.state.db, "trace-job", &trace)
+            .await
+            .unwrap();
+
+        let Html(jobs) = super::get_admin_jobs(sample_request(ctx.state.clone(), admin_user()))
+            .await
+            .unwrap();
+        assert!(jobs.contains("/admin/jobs/trace-job/research"));
+
+        let Html(html) = super::get_admin_research_trace(
+            sample_request(ctx.state.clone(), admin_user()),
+            axum::extract::Path("trace-job".to_string()),
+        )
+        .await
+        .unwrap();
+        // Tera autoescapes the slashes in the candidate link.
+        assert!(html.contains(r#"href="https:&#x2F;&#x2F;transport.gov&#x2F;memo""#));
+        assert!(html.contains("Transport memo"));
+        assert!(html.contains("fetched"));
+        assert!(html.contains("2026-04-30"));
+        assert!(html.contains("Research file:"));
+
+        let err = super::get_admin_research_trace(
+            sample_request(ctx.state.clone(), author_user("author@example.com")),
+            axum::extract::Path("trace-job".to_string()),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn research_domain_rules_are_saved_and_listed_for_admins_only() {
+        let ctx = TestContext::new().await;
+
+        let redirect = super::post_admin_research_domain_rule(
+            sample_request(ctx.state.clone(), admin_user()),
+            axum::extract::Form(super::AdminResearchDomainForm {
+                pattern: "Content-Farm.example".to_string(),
+                action: "block".to_string(),
+                score: None,
+                leak_markers: "Content Farm".to_string(),
+            }),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        assert_eq!(
+            redirect
+                .headers()
+                .get(axum::http::header::LOCATION)
+                .unwrap(),
+            "/en/admin/research-domains"
+        );
+        assert!(!research_domain_policy().permits("news.content-farm.example"));
+
+        let Html(html) =
+            super::get_admin_research_domains(sample_request(ctx.state.clone(), admin_user()))
+                .await
+                .unwrap();
+        assert!(html.contains("content-farm.example"));
+        assert!(html.contains("content farm"));
+        assert!(html.contains("reuters.com"));
+
+        let err = super::get_admin_research_domains(sample_request(
+            ctx.state.clone(),
+            author_user("author@example.com"),
+        ))
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Auth(_)));
+    }
+}
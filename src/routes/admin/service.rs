@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 use sea_orm::{
@@ -9,14 +10,29 @@ use serde_json::Value;
 
 use crate::app_state::AppState;
 use crate::entities::{
-    article_job, audit_log, content as content_entity, prelude::*, translation_job,
+    article_job, audit_log, content as content_entity, horde_log, prelude::*, research_trace,
+    translation_job,
 };
 use crate::error::Error;
+use crate::llm::article_generator::ResearchTrace;
+use crate::llm::domain_policy::DEFAULT_DOMAIN_SCORE;
+use crate::llm::glossary::translation_glossary;
+use crate::llm::prompt_registry::registered_translation_languages;
 use crate::rate_limit::RateLimitMetricsSnapshot;
+use crate::repositories::glossary::list_glossary_rows;
+use crate::repositories::horde_log::{
+    load_horde_log_stats_since, load_recent_horde_logs, HordeLogStat, HORDE_EVENT_CENSORED,
+    HORDE_EVENT_COMPLETED, HORDE_EVENT_FAILED, HORDE_EVENT_SUBMISSION,
+};
+use crate::repositories::languages::list_language_rows;
+use crate::repositories::research_domains::list_domain_policy_rows;
+use crate::repositories::research_traces::{find_research_trace, list_recent_research_traces};
 use crate::services::article_jobs::{
     ARTICLE_JOB_STATUS_CANCELLED, ARTICLE_JOB_STATUS_COMPLETED, ARTICLE_JOB_STATUS_FAILED,
     ARTICLE_JOB_STATUS_PROCESSING, ARTICLE_JOB_STATUS_QUEUED,
 };
+use crate::services::site_catalog::site_catalogs;
+use crate::services::translation_memory::SegmentReuse;
 use crate::translation_jobs::{
     TRANSLATION_JOB_STATUS_CANCELLED, TRANSLATION_JOB_STATUS_COMPLETED,
     TRANSLATION_JOB_STATUS_FAILED, TRANSLATION_JOB_STATUS_PROCESSING,
@@ -77,6 +93,7 @@ pub(super) struct FeatureUsageSummary {
     searches: u64,
     sources: u64,
     fetched_content_chars: u64,
+    cache_hits: u64,
 }
 
 #[derive(Serialize)]
@@ -122,9 +139,97 @@ pub(super) struct TranslationJobRow {
     updated_at: String,
     next_retry_at: Option<String>,
     last_error: Option<String>,
+    segment_reuse: Option<String>,
     can_cancel: bool,
 }
 
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
+pub(super) struct HordeKudosDay {
+    day: String,
+    kudos: i64,
+    submissions: u64,
+    completed: u64,
+    failed: u64,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
+pub(super) struct HordeWaitSummary {
+    samples: usize,
+    p50: Option<i32>,
+    p90: Option<i32>,
+    p99: Option<i32>,
+    max: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub(super) struct HordeLogRow {
+    created_at: String,
+    event: String,
+    request_id: Option<String>,
+    worker_model: Option<String>,
+    kudos: i32,
+    wait_seconds: Option<i32>,
+    error: Option<String>,
+}
+
+pub(super) struct AdminProvidersPageData {
+    pub(super) image_provider: String,
+    pub(super) window_days: i64,
+    pub(super) total_kudos: i64,
+    pub(super) kudos_by_day: Vec<HordeKudosDay>,
+    pub(super) wait_summary: HordeWaitSummary,
+    pub(super) recent_logs: Vec<HordeLogRow>,
+}
+
+#[derive(Serialize)]
+pub(super) struct AdminLanguageRow {
+    code: String,
+    name: String,
+    aliases: String,
+    direction: String,
+    enabled: bool,
+    site_locale: bool,
+    has_catalog: bool,
+}
+
+#[derive(Serialize)]
+pub(super) struct AdminGlossaryRow {
+    id: String,
+    term: String,
+    language_code: String,
+    language_name: String,
+    translation: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+pub(super) struct AdminGlossaryLanguage {
+    code: Cow<'static, str>,
+    name: Cow<'static, str>,
+}
+
+pub(super) struct AdminGlossaryPageData {
+    pub(super) entries: Vec<AdminGlossaryRow>,
+    pub(super) languages: Vec<AdminGlossaryLanguage>,
+    pub(super) version: String,
+}
+
+#[derive(Serialize)]
+pub(super) struct AdminResearchDomainRow {
+    id: String,
+    pattern: String,
+    action: String,
+    score: i32,
+    leak_markers: String,
+    updated_at: String,
+}
+
+pub(super) struct AdminResearchDomainsPageData {
+    pub(super) rules: Vec<AdminResearchDomainRow>,
+    pub(super) allowlist_active: bool,
+    pub(super) default_score: i32,
+}
+
 pub(super) struct AdminArticlesPageData {
     pub(super) articles: Vec<AdminArticleRow>,
     pub(super) current_sort: String,
@@ -145,6 +250,35 @@ pub(super) struct AdminJobsPageData {
     pub(super) failed_article_jobs: Vec<ArticleJobRow>,
     pub(super) active_translation_jobs: Vec<TranslationJobRow>,
     pub(super) failed_translation_jobs: Vec<TranslationJobRow>,
+    pub(super) completed_translation_jobs: Vec<TranslationJobRow>,
+    pub(super) translation_segment_reuse: Option<String>,
+    pub(super) research_traces: Vec<ResearchTraceRow>,
+}
+
+#[derive(Serialize)]
+pub(super) struct ResearchTraceRow {
+    job_id: String,
+    prompt: Option<String>,
+    search_provider: String,
+    source_count: i32,
+    failed: bool,
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+pub(super) struct AdminResearchTraceJob {
+    id: String,
+    prompt: String,
+    feature_type: String,
+    status: String,
+    phase: String,
+    error_summary: Option<String>,
+}
+
+pub(super) struct AdminResearchTracePageData {
+    pub(super) job: Option<AdminResearchTraceJob>,
+    pub(super) trace: ResearchTrace,
+    pub(super) recorded_at: String,
 }
 
 pub(super) async fn load_admin_articles_page(
@@ -227,6 +361,9 @@ pub(super) async fn load_admin_jobs_page(state: &AppState) -> Result<AdminJobsPa
         50,
     )
     .await?;
+    let completed_translation_jobs =
+        load_translation_jobs_by_statuses(db, &[TRANSLATION_JOB_STATUS_COMPLETED], 25).await?;
+    let research_traces = list_recent_research_traces(db, 25).await?;
 
     let content_map = load_content_metadata_map(
         db,
@@ -236,6 +373,7 @@ pub(super) async fn load_admin_jobs_page(state: &AppState) -> Result<AdminJobsPa
             .chain(
                 translation_jobs_recent
                     .iter()
+                    .chain(&completed_translation_jobs)
                     .map(|job| job.article_id.clone()),
             ),
     )
@@ -264,9 +402,242 @@ pub(super) async fn load_admin_jobs_page(state: &AppState) -> Result<AdminJobsPa
             .iter()
             .map(|job| translation_job_row(job, &content_map))
             .collect(),
+        translation_segment_reuse: format_segment_reuse(total_segment_reuse(
+            &completed_translation_jobs,
+        )),
+        completed_translation_jobs: completed_translation_jobs
+            .iter()
+            .map(|job| translation_job_row(job, &content_map))
+            .collect(),
+        research_traces: research_trace_rows(&article_jobs_recent, research_traces),
     })
 }
 
+pub(super) async fn load_admin_research_trace_page(
+    db: &DatabaseConnection,
+    job_id: &str,
+) -> Result<AdminResearchTracePageData, Error> {
+    let (row, trace) = find_research_trace(db, job_id)
+        .await?
+        .ok_or(Error::NotFound(Some(
+            "Research trace not found".to_string(),
+        )))?;
+    let job = ArticleJob::find_by_id(job_id.to_string())
+        .one(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error loading article job: {}", e)))?
+        .map(|job| AdminResearchTraceJob {
+            id: job.id,
+            prompt: job.prompt,
+            feature_type: job.feature_type,
+            status: job.status,
+            phase: job.phase,
+            error_summary: job.error_summary,
+        });
+
+    Ok(AdminResearchTracePageData {
+        job,
+        trace,
+        recorded_at: format_time(row.updated_at),
+    })
+}
+
+fn research_trace_rows(
+    article_jobs: &[article_job::Model],
+    traces: Vec<research_trace::Model>,
+) -> Vec<ResearchTraceRow> {
+    let prompts = article_jobs
+        .iter()
+        .map(|job| (job.id.as_str(), job.prompt.as_str()))
+        .collect::<HashMap<_, _>>();
+    traces
+        .into_iter()
+        .map(|trace| ResearchTraceRow {
+            prompt: prompts
+                .get(trace.job_id.as_str())
+                .map(|prompt| prompt.to_string()),
+            job_id: trace.job_id,
+            search_provider: trace.search_provider,
+            source_count: trace.source_count,
+            failed: trace.failed,
+            updated_at: format_time(trace.updated_at),
+        })
+        .collect()
+}
+
+const PROVIDER_PANEL_WINDOW_DAYS: i64 = 14;
+const PROVIDER_PANEL_RECENT_LOGS: u64 = 25;
+
+pub(super) async fn load_admin_providers_page(
+    state: &AppState,
+) -> Result<AdminProvidersPageData, Error> {
+    let since =
+        chrono::Utc::now().naive_local() - chrono::Duration::days(PROVIDER_PANEL_WINDOW_DAYS);
+    let stats = load_horde_log_stats_since(&state.db, since).await?;
+    let kudos_by_day = build_horde_kudos_by_day(&stats);
+    let recent_logs = load_recent_horde_logs(&state.db, PROVIDER_PANEL_RECENT_LOGS).await?;
+
+    Ok(AdminProvidersPageData {
+        image_provider: state.image_generator_name.clone(),
+        window_days: PROVIDER_PANEL_WINDOW_DAYS,
+        total_kudos: kudos_by_day.iter().map(|day| day.kudos).sum(),
+        kudos_by_day,
+        wait_summary: build_horde_wait_summary(&stats),
+        recent_logs: recent_logs.iter().map(horde_log_row).collect(),
+    })
+}
+
+/// Rows without a code predate the registry and only exist for the translation cache.
+pub(super) async fn load_admin_language_rows(
+    db: &DatabaseConnection,
+) -> Result<Vec<AdminLanguageRow>, Error> {
+    Ok(list_language_rows(db)
+        .await?
+        .into_iter()
+        .filter_map(|row| {
+            let code = row.code?;
+            Some(AdminLanguageRow {
+                has_catalog: site_catalogs().has_locale(&code),
+                code,
+                name: row.name,
+                aliases: row.aliases.replace(',', ", "),
+                direction: row.direction,
+                enabled: row.enabled,
+                site_locale: row.site_locale,
+            })
+        })
+        .collect())
+}
+
+pub(super) async fn load_admin_glossary_page(
+    db: &DatabaseConnection,
+) -> Result<AdminGlossaryPageData, Error> {
+    let languages = registered_translation_languages()
+        .into_iter()
+        .filter(|language| language.code != "en")
+        .map(|language| AdminGlossaryLanguage {
+            code: language.code,
+            name: language.name,
+        })
+        .collect::<Vec<_>>();
+    let entries = list_glossary_rows(db)
+        .await?
+        .into_iter()
+        .map(|row| {
+            let language_code = row.language_code.unwrap_or_default();
+            let language_name = languages
+                .iter()
+                .find(|language| language.code == language_code)
+                .map_or_else(
+                    || language_code.clone(),
+                    |language| language.name.to_string(),
+                );
+            AdminGlossaryRow {
+                id: row.id,
+                term: row.term,
+                language_code,
+                language_name,
+                translation: row.translation,
+                updated_at: row.updated_at.format("%F %H:%M").to_string(),
+            }
+        })
+        .collect();
+
+    Ok(AdminGlossaryPageData {
+        entries,
+        languages,
+        version: translation_glossary().version().to_string(),
+    })
+}
+
+pub(super) async fn load_admin_research_domains_page(
+    db: &DatabaseConnection,
+) -> Result<AdminResearchDomainsPageData, Error> {
+    let rules = list_domain_policy_rows(db)
+        .await?
+        .into_iter()
+        .map(|row| AdminResearchDomainRow {
+            id: row.id,
+            pattern: row.pattern,
+            action: row.action,
+            score: row.score,
+            leak_markers: row.leak_markers.replace(',', ", "),
+            updated_at: row.updated_at.format("%F %H:%M").to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    Ok(AdminResearchDomainsPageData {
+        allowlist_active: rules.iter().any(|rule| rule.action == "allow"),
+        rules,
+        default_score: DEFAULT_DOMAIN_SCORE,
+    })
+}
+
+/// Kudos are counted on poll outcomes, which report what the Horde actually charged.
+fn build_horde_kudos_by_day(logs: &[HordeLogStat]) -> Vec<HordeKudosDay> {
+    let mut by_day = HashMap::<String, HordeKudosDay>::new();
+    for log in logs {
+        let day = log.created_at.format("%F").to_string();
+        let entry = by_day.entry(day.clone()).or_insert_with(|| HordeKudosDay {
+            day,
+            ..HordeKudosDay::default()
+        });
+        match log.event.as_str() {
+            HORDE_EVENT_SUBMISSION => entry.submissions += 1,
+            HORDE_EVENT_COMPLETED => {
+                entry.completed += 1;
+                entry.kudos += i64::from(log.kudos);
+            }
+            HORDE_EVENT_FAILED | HORDE_EVENT_CENSORED => {
+                entry.failed += 1;
+                entry.kudos += i64::from(log.kudos);
+            }
+            _ => {}
+        }
+    }
+
+    let mut rows = by_day.into_values().collect::<Vec<_>>();
+    rows.sort_by(|left, right| right.day.cmp(&left.day));
+    rows
+}
+
+fn build_horde_wait_summary(logs: &[HordeLogStat]) -> HordeWaitSummary {
+    let mut waits = logs
+        .iter()
+        .filter(|log| log.event == HORDE_EVENT_COMPLETED)
+        .filter_map(|log| log.wait_seconds)
+        .collect::<Vec<_>>();
+    waits.sort_unstable();
+
+    HordeWaitSummary {
+        samples: waits.len(),
+        p50: nearest_rank_percentile(&waits, 50),
+        p90: nearest_rank_percentile(&waits, 90),
+        p99: nearest_rank_percentile(&waits, 99),
+        max: waits.last().copied(),
+    }
+}
+
+fn nearest_rank_percentile(sorted: &[i32], percentile: usize) -> Option<i32> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+fn horde_log_row(log: &horde_log::Model) -> HordeLogRow {
+    HordeLogRow {
+        created_at: format_time(log.created_at),
+        event: log.event.clone(),
+        request_id: log.request_id.clone(),
+        worker_model: log.worker_model.clone(),
+        kudos: log.kudos,
+        wait_seconds: log.wait_seconds,
+        error: log.error.clone(),
+    }
+}
+
 fn admin_article_row(article: content_entity::Model) -> AdminArticleRow {
     AdminArticleRow {
         id: article.id,
@@ -513,6 +884,7 @@ fn translation_job_row(
         updated_at: format_time(job.updated_at),
         next_retry_at: job.next_retry_at.map(format_time),
         last_error: job.last_error.clone(),
+        segment_reuse: format_segment_reuse(job_segment_reuse(job)),
         can_cancel: matches!(
             job.status.as_str(),
             TRANSLATION_JOB_STATUS_QUEUED
@@ -522,6 +894,34 @@ fn translation_job_row(
     }
 }
 
+fn job_segment_reuse(job: &translation_job::Model) -> Option<SegmentReuse> {
+    Some(SegmentReuse {
+        total: u32::try_from(job.segments_total?).ok()?,
+        reused: u32::try_from(job.segments_reused?).ok()?,
+    })
+}
+
+/// Sums segment reuse over jobs that translated an article body.
+fn total_segment_reuse(jobs: &[translation_job::Model]) -> Option<SegmentReuse> {
+    jobs.iter()
+        .filter_map(job_segment_reuse)
+        .reduce(|left, right| SegmentReuse {
+            total: left.total + right.total,
+            reused: left.reused + right.reused,
+        })
+}
+
+fn format_segment_reuse(reuse: Option<SegmentReuse>) -> Option<String> {
+    let reuse = reuse?;
+    let ratio = reuse.ratio()?;
+    Some(format!(
+        "{}/{} ({:.0}%)",
+        reuse.reused,
+        reuse.total,
+        ratio * 100.0
+    ))
+}
+
 fn build_requester_summaries(jobs: &[article_job::Model]) -> Vec<RequesterSummary> {
     let mut by_requester = HashMap::<String, (RequesterSummary, chrono::NaiveDateTime)>::new();
     for job in jobs {
@@ -587,6 +987,7 @@ fn build_feature_usage_summaries(jobs: &[article_job::Model]) -> Vec<FeatureUsag
                 searches: 0,
                 sources: 0,
                 fetched_content_chars: 0,
+                cache_hits: 0,
             });
         entry.jobs += 1;
         entry.model_calls += usage_counter(job, "model_calls");
@@ -594,6 +995,8 @@ fn build_feature_usage_summaries(jobs: &[article_job::Model]) -> Vec<FeatureUsag
         entry.searches += usage_counter(job, "searches");
         entry.sources += usage_counter(job, "sources");
         entry.fetched_content_chars += usage_counter(job, "fetched_content_chars");
+        entry.cache_hits +=
+            usage_counter(job, "search_cache_hits") + usage_counter(job, "source_cache_hits");
     }
 
     let mut rows = by_feature.into_values().collect::<Vec<_>>();
@@ -668,3 +1071,73 @@ fn parse_u64_field(value: Option<&str>, key: &str) -> Option<u64> {
 fn format_time(value: chrono::NaiveDateTime) -> String {
     value.format("%F %T").to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::repositories::horde_log::{
+        HordeLogStat, HORDE_EVENT_COMPLETED, HORDE_EVENT_FAILED, HORDE_EVENT_SUBMISSION,
+    };
+
+    use super::{build_horde_kudos_by_day, build_horde_wait_summary, nearest_rank_percentile};
+
+    fn horde_log(day: u32, event: &str, kudos: i32, wait_seconds: Option<i32>) -> HordeLogStat {
+        HordeLogStat {
+            created_at: NaiveDate::from_ymd_opt(2026, 4, day)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            event: event.to_string(),
+            kudos,
+            wait_seconds,
+        }
+    }
+
+    #[test]
+    fn kudos_burn_counts_outcomes_per_day() {
+        let logs = vec![
+            horde_log(20, HORDE_EVENT_SUBMISSION, 12, None),
+            horde_log(20, HORDE_EVENT_COMPLETED, 10, Some(30)),
+            horde_log(21, HORDE_EVENT_SUBMISSION, 12, None),
+            horde_log(21, HORDE_EVENT_FAILED, 0, Some(120)),
+            horde_log(21, HORDE_EVENT_COMPLETED, 14, Some(45)),
+        ];
+
+        let days = build_horde_kudos_by_day(&logs);
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].day, "2026-04-21");
+        assert_eq!(days[0].kudos, 14);
+        assert_eq!(days[0].submissions, 1);
+        assert_eq!(days[0].completed, 1);
+        assert_eq!(days[0].failed, 1);
+        assert_eq!(days[1].kudos, 10);
+    }
+
+    #[test]
+    fn wait_summary_uses_completed_waits_only() {
+        let logs = vec![
+            horde_log(20, HORDE_EVENT_COMPLETED, 10, Some(30)),
+            horde_log(20, HORDE_EVENT_COMPLETED, 10, Some(10)),
+            horde_log(20, HORDE_EVENT_COMPLETED, 10, Some(90)),
+            horde_log(20, HORDE_EVENT_FAILED, 0, Some(600)),
+        ];
+
+        let summary = build_horde_wait_summary(&logs);
+
+        assert_eq!(summary.samples, 3);
+        assert_eq!(summary.p50, Some(30));
+        assert_eq!(summary.p90, Some(90));
+        assert_eq!(summary.max, Some(90));
+    }
+
+    #[test]
+    fn nearest_rank_percentile_handles_small_samples() {
+        assert_eq!(nearest_rank_percentile(&[], 50), None);
+        assert_eq!(nearest_rank_percentile(&[7], 99), Some(7));
+        let values = (1..=100).collect::<Vec<_>>();
+        assert_eq!(nearest_rank_percentile(&values, 50), Some(50));
+        assert_eq!(nearest_rank_percentile(&values, 99), Some(99));
+    }
+}
@@ -12,7 +12,7 @@ use crate::app_state::AppState;
 use crate::content as content_page;
 use crate::error::Error;
 use crate::services::article_language::{
-    requested_article_language_query_value, resolve_article_language,
+    article_source_language, requested_article_language_query_value, resolve_article_language,
     resolve_requested_article_language,
 };
 use crate::services::site_paths::detect_site_language_from_path;
@@ -36,6 +36,7 @@ struct ContentQuery {
     source: Option<String>,
     comments_page: Option<u64>,
     lang: Option<String>,
+    translate_comments: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -58,10 +59,12 @@ async fn get_content(
     let route_language = detect_site_language_from_path(&wr.request_path);
     let requested_language = resolve_requested_article_language(query.lang.as_deref());
     if let Some(raw_language) = query.lang.as_deref() {
-        let canonical_language = requested_language.map(requested_article_language_query_value);
+        let canonical_language = requested_language
+            .as_ref()
+            .map(|language| requested_article_language_query_value(language.as_ref()));
         if canonical_language != Some(raw_language) {
             return Ok(axum::response::Redirect::to(&content_location_with_query(
-                wr.site_language,
+                &wr.site_language,
                 &slug,
                 query.source.as_deref(),
                 query.comments_page,
@@ -71,19 +74,20 @@ async fn get_content(
             .into_response());
         }
     }
-    let requested_language = requested_language.unwrap_or(route_language);
+    let requested_language = requested_language.unwrap_or_else(|| route_language.clone());
     let automatic_selection = resolve_article_language(
+        &article_source_language(),
         None,
-        route_language,
+        route_language.as_ref(),
         None,
-        wr.browser_translation_language,
+        wr.browser_translation_language.as_ref(),
         &[],
     );
     let cookie_header = article_language_cookie_header(
-        wr.site_language,
+        &wr.site_language,
         &slug,
-        requested_language,
-        automatic_selection.preferred_language.code,
+        requested_language.clone(),
+        &automatic_selection.preferred_language.code,
         query.lang.is_some(),
     );
     let mut content_request = wr.clone();
@@ -95,6 +99,7 @@ async fn get_content(
         &slug,
         query.source.as_deref(),
         query.comments_page,
+        query.translate_comments.as_deref(),
         requested_language,
     )
     .await?;
@@ -115,7 +120,7 @@ mod tests {
     fn content_location_preserves_language_query() {
         assert_eq!(
             content_location(
-                find_supported_translation_language("en").unwrap(),
+                &find_supported_translation_language("en").unwrap(),
                 "test-story",
                 Some("pt"),
                 None,
@@ -128,7 +133,7 @@ mod tests {
     fn content_location_appends_anchor_after_language_query() {
         assert_eq!(
             content_location(
-                find_supported_translation_language("en").unwrap(),
+                &find_supported_translation_language("en").unwrap(),
                 "test-story",
                 Some("pt"),
                 Some("comments"),
@@ -141,7 +146,7 @@ mod tests {
     fn content_location_with_query_preserves_existing_params() {
         assert_eq!(
             content_location_with_query(
-                find_supported_translation_language("pt").unwrap(),
+                &find_supported_translation_language("pt").unwrap(),
                 "test-story",
                 Some("top"),
                 Some(3),
@@ -155,7 +160,7 @@ mod tests {
     #[test]
     fn article_language_cookie_header_sets_manual_article_cookie() {
         let cookie = article_language_cookie_header(
-            find_supported_translation_language("pt").unwrap(),
+            &find_supported_translation_language("pt").unwrap(),
             "test-story",
             find_supported_translation_language("pt"),
             "en",
@@ -170,7 +175,7 @@ mod tests {
     #[test]
     fn article_language_cookie_header_clears_cookie_for_automatic_mode() {
         let cookie = article_language_cookie_header(
-            find_supported_translation_language("en").unwrap(),
+            &find_supported_translation_language("en").unwrap(),
             "test-story",
             None,
             "en",
@@ -185,7 +190,7 @@ mod tests {
     #[test]
     fn article_language_cookie_header_skips_cookie_when_no_query_was_provided() {
         assert!(article_language_cookie_header(
-            find_supported_translation_language("en").unwrap(),
+            &find_supported_translation_language("en").unwrap(),
             "test-story",
             None,
             "en",
@@ -197,7 +202,7 @@ mod tests {
     #[test]
     fn article_language_cookie_header_clears_cookie_when_choice_matches_automatic_language() {
         let cookie = article_language_cookie_header(
-            find_supported_translation_language("pt").unwrap(),
+            &find_supported_translation_language("pt").unwrap(),
             "test-story",
             find_supported_translation_language("pt"),
             "pt",
@@ -16,6 +16,7 @@ use crate::entities::{content as content_entity, content_comment, content_vote,
 use crate::error::Error;
 use crate::hot_score::calculate_hot_score;
 use crate::services::article_language::resolve_requested_article_language;
+use crate::services::comment_translations::detect_comment_language;
 use crate::wibble_request::WibbleRequest;
 
 use super::navigation::content_location;
@@ -111,6 +112,7 @@ pub(super) async fn post_comment(
         content_id: article.id,
         user_email: auth_user.email.clone(),
         user_name: auth_user.name.clone(),
+        language_code: detect_comment_language(&body).map(|language| language.code.to_string()),
         body,
         created_at: chrono::Utc::now().naive_local(),
     };
@@ -123,11 +125,12 @@ pub(super) async fn post_comment(
     log_audit(db, auth_user, "create_comment", "content", &slug, None).await?;
 
     Ok(Redirect::to(&content_location(
-        wr.site_language,
+        &wr.site_language,
         &slug,
         resolve_requested_article_language(data.lang.as_deref())
             .flatten()
-            .map(|language| language.code),
+            .map(|language| language.code)
+            .as_deref(),
         Some("comments"),
     )))
 }
@@ -275,11 +278,12 @@ pub(super) async fn post_vote(
     .await?;
 
     Ok(Redirect::to(&content_location(
-        wr.site_language,
+        &wr.site_language,
         &slug,
         resolve_requested_article_language(data.lang.as_deref())
             .flatten()
-            .map(|language| language.code),
+            .map(|language| language.code)
+            .as_deref(),
         Some("article-voting"),
     )))
 }
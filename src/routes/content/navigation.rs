@@ -6,7 +6,7 @@ use crate::services::site_paths::localized_path;
 const ARTICLE_LANGUAGE_COOKIE_NAME: &str = "__article_lang";
 
 pub(super) fn content_location(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
     lang: Option<&str>,
     anchor: Option<&str>,
@@ -15,7 +15,7 @@ pub(super) fn content_location(
 }
 
 pub(super) fn content_location_with_query(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
     source: Option<&str>,
     comments_page: Option<u64>,
@@ -44,12 +44,15 @@ pub(super) fn content_location_with_query(
     path
 }
 
-fn article_language_cookie_path(site_language: SupportedTranslationLanguage, slug: &str) -> String {
+fn article_language_cookie_path(
+    site_language: &SupportedTranslationLanguage,
+    slug: &str,
+) -> String {
     localized_path(site_language, &format!("/content/{}", slug))
 }
 
 fn article_language_cookie(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
     value: &str,
     max_age: u64,
@@ -73,14 +76,14 @@ fn article_language_cookie(
 }
 
 fn clear_article_language_cookie(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
 ) -> String {
     article_language_cookie(site_language, slug, "", 0)
 }
 
 pub(super) fn article_language_cookie_header(
-    site_language: SupportedTranslationLanguage,
+    site_language: &SupportedTranslationLanguage,
     slug: &str,
     requested_language: Option<SupportedTranslationLanguage>,
     automatic_language_code: &str,
@@ -99,7 +102,7 @@ pub(super) fn article_language_cookie_header(
                 Some(article_language_cookie(
                     site_language,
                     slug,
-                    language.code,
+                    &language.code,
                     30 * 24 * 60 * 60,
                 ))
             }
@@ -7,8 +7,9 @@ use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::create as create_page;
-use crate::create::clarify::normalize_clarification_answer;
+use crate::create::clarify::normalize_optional_clarification_answer;
 use crate::error::Error;
+use crate::permissions::can_review_article_job;
 use crate::services::article_jobs::{spawn_due_article_jobs, ArticleJobService};
 use crate::services::site_paths::localized_path;
 use crate::wibble_request::WibbleRequest;
@@ -20,19 +21,33 @@ pub fn localized_router() -> Router<AppState> {
             "/wait/{id}/clarify",
             axum::routing::post(post_wait_clarification),
         )
+        .route("/wait/{id}/outline", axum::routing::post(post_wait_outline))
         .route("/create", get(get_create).post(create_article))
 }
 
 #[derive(Deserialize)]
 struct WaitClarificationData {
+    #[serde(default)]
     answer: String,
+    #[serde(default)]
+    answer_2: String,
+    #[serde(default)]
+    answer_3: String,
+}
+
+#[derive(Deserialize)]
+struct WaitOutlineData {
+    #[serde(default)]
+    headline: String,
+    #[serde(default)]
+    beats: String,
 }
 
 async fn get_wait(wr: WibbleRequest, Path(id): Path<String>) -> Response {
-    let site_language = wr.site_language;
+    let site_language = wr.site_language.clone();
     match create_page::wait(wr, &id).await {
         create_page::WaitResponse::Redirect(slug) => Redirect::to(&localized_path(
-            site_language,
+            &site_language,
             &format!("/content/{}", slug),
         ))
         .into_response(),
@@ -49,6 +64,25 @@ async fn create_article(
     Form(data): Form<create_page::PostCreateData>,
 ) -> impl IntoResponse {
     let author_email = wr.auth_user.as_ref().map(|u| u.email.clone());
+    let selected_language =
+        match create_page::normalize_create_language(data.language.as_deref(), &wr.site_language) {
+            Ok(language) => language,
+            Err(Error::BadRequest(message)) => {
+                return match create_page::render_create_page(
+                    &wr,
+                    data.prompt.trim(),
+                    Some(&message),
+                    create_page::CreateModeSelection::Auto,
+                    &wr.site_language,
+                )
+                .await
+                {
+                    Ok(html) => (StatusCode::BAD_REQUEST, html).into_response(),
+                    Err(e) => e.into_response(),
+                };
+            }
+            Err(e) => return e.into_response(),
+        };
     let selected_mode = match create_page::normalize_create_mode(data.mode.as_deref()) {
         Ok(mode) => mode,
         Err(Error::BadRequest(message)) => {
@@ -57,6 +91,7 @@ async fn create_article(
                 data.prompt.trim(),
                 Some(&message),
                 create_page::CreateModeSelection::Auto,
+                &selected_language,
             )
             .await
             {
@@ -74,6 +109,7 @@ async fn create_article(
                 data.prompt.trim(),
                 Some(&message),
                 selected_mode,
+                &selected_language,
             )
             .await
             {
@@ -91,12 +127,20 @@ async fn create_article(
         wr.requester_tier,
         wr.rate_limit_key.clone(),
         selected_mode,
+        &selected_language,
     )
     .await
     {
         Ok(id) => Redirect::to(&wr.localized_path(&format!("/wait/{}", id))).into_response(),
         Err(Error::BadRequest(message)) | Err(Error::Auth(message)) => {
-            match create_page::render_create_page(&wr, &prompt, Some(&message), selected_mode).await
+            match create_page::render_create_page(
+                &wr,
+                &prompt,
+                Some(&message),
+                selected_mode,
+                &selected_language,
+            )
+            .await
             {
                 Ok(html) => (StatusCode::BAD_REQUEST, html).into_response(),
                 Err(e) => e.into_response(),
@@ -115,13 +159,21 @@ async fn post_wait_clarification(
     Path(id): Path<String>,
     Form(data): Form<WaitClarificationData>,
 ) -> Response {
-    let answer = match normalize_clarification_answer(&data.answer) {
-        Ok(answer) => answer,
+    let answers = match [
+        data.answer.as_str(),
+        data.answer_2.as_str(),
+        data.answer_3.as_str(),
+    ]
+    .into_iter()
+    .map(normalize_optional_clarification_answer)
+    .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(answers) => answers,
         Err(e) => return e.into_response(),
     };
 
     let service = ArticleJobService::new(wr.state.clone());
-    match service.submit_clarification_answer(&id, &answer).await {
+    match service.submit_clarification_answers(&id, &answers).await {
         Ok(Some(_)) => {
             spawn_due_article_jobs(wr.state.clone()).await;
             Redirect::to(&wr.localized_path(&format!("/wait/{}", id))).into_response()
@@ -130,3 +182,34 @@ async fn post_wait_clarification(
         Err(e) => e.into_response(),
     }
 }
+
+async fn post_wait_outline(
+    wr: WibbleRequest,
+    Path(id): Path<String>,
+    Form(data): Form<WaitOutlineData>,
+) -> Result<Response, Error> {
+    let auth_user = wr
+        .auth_user
+        .as_ref()
+        .ok_or_else(|| Error::Auth("Login required".to_string()))?;
+    let service = ArticleJobService::new(wr.state.clone());
+    let Some(job) = service.load_job(&id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    if !can_review_article_job(auth_user, &job) {
+        return Err(Error::Auth(
+            "Not authorized to review this outline".to_string(),
+        ));
+    }
+
+    match service
+        .submit_outline_choice(&id, &data.headline, &data.beats)
+        .await?
+    {
+        Some(_) => {
+            spawn_due_article_jobs(wr.state.clone()).await;
+            Ok(Redirect::to(&wr.localized_path(&format!("/wait/{}", id))).into_response())
+        }
+        None => Ok(StatusCode::NOT_FOUND.into_response()),
+    }
+}
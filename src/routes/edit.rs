@@ -12,12 +12,16 @@ use crate::error::Error;
 use crate::wibble_request::WibbleRequest;
 
 use self::agent::{apply_agent_edit, render_agent_edit_preview, MAX_AGENT_EDIT_REQUEST_CHARS};
-use self::service::{apply_article_edit, render_edit_page, require_editable_article};
+use self::service::{
+    apply_article_edit, apply_translation_review, render_edit_page, render_translation_review_page,
+    require_editable_article,
+};
 
 #[cfg(test)]
 use self::agent::{build_unified_diff, markdown_image_count, text_paragraphs};
 
 pub(super) const MAX_IMAGE_UPLOAD_BYTES: usize = 12 * 1024 * 1024;
+pub(super) const MAX_IMAGE_TEXT_CHARS: usize = 1000;
 
 pub fn localized_router() -> Router<AppState> {
     Router::new()
@@ -34,11 +38,27 @@ pub fn localized_router() -> Router<AppState> {
             "/content/{slug}/images/{image_id}",
             post(post_replace_image).layer(DefaultBodyLimit::max(MAX_IMAGE_UPLOAD_BYTES)),
         )
+        .route(
+            "/content/{slug}/images/{image_id}/url",
+            post(post_replace_image_from_url),
+        )
+        .route(
+            "/content/{slug}/images/{image_id}/framing",
+            post(post_update_image_framing),
+        )
+        .route(
+            "/content/{slug}/images/{image_id}/text",
+            post(post_update_image_text),
+        )
         .route(
             "/content/{slug}/images/{image_id}/regenerate",
             post(post_regenerate_image),
         )
         .route("/content/{slug}/publish", post(post_toggle_publish))
+        .route(
+            "/content/{slug}/translations/{language}",
+            axum::routing::get(get_translation_review).post(post_translation_review),
+        )
 }
 
 #[derive(Deserialize, Debug)]
@@ -48,9 +68,30 @@ struct EditArticleData {
     markdown: String,
 }
 
+/// Reviewed translation fields, each with the text the editor prefilled; only
+/// edited fields are saved, and a cleared field falls back to the machine translation.
+#[derive(Deserialize, Debug, Default)]
+struct TranslationReviewData {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    markdown: String,
+    #[serde(default)]
+    original_title: String,
+    #[serde(default)]
+    original_description: String,
+    #[serde(default)]
+    original_markdown: String,
+}
+
 #[derive(Deserialize, Debug)]
 struct AgentEditRequestData {
     change_request: String,
+    /// Checkbox: research the change before rewriting.
+    #[serde(default)]
+    research: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -63,6 +104,35 @@ struct ApplyAgentEditData {
     prompt_version: i32,
 }
 
+#[derive(Deserialize, Debug)]
+struct ImageTextData {
+    alt_text: String,
+    #[serde(default)]
+    caption: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct ImageUrlData {
+    url: String,
+}
+
+/// Crop box and focal point as percentages of the stored image; blank fields clear them.
+#[derive(Deserialize, Debug, Default)]
+struct ImageFramingData {
+    #[serde(default)]
+    crop_x: String,
+    #[serde(default)]
+    crop_y: String,
+    #[serde(default)]
+    crop_width: String,
+    #[serde(default)]
+    crop_height: String,
+    #[serde(default)]
+    focal_x: String,
+    #[serde(default)]
+    focal_y: String,
+}
+
 async fn get_edit_article(
     wr: WibbleRequest,
     Path(slug): Path<String>,
@@ -103,6 +173,30 @@ async fn post_replace_image(
     service::replace_article_image(wr, &slug, &image_id, multipart).await
 }
 
+async fn post_replace_image_from_url(
+    wr: WibbleRequest,
+    Path((slug, image_id)): Path<(String, String)>,
+    Form(data): Form<ImageUrlData>,
+) -> Result<Redirect, Error> {
+    service::replace_article_image_from_url(wr, &slug, &image_id, &data).await
+}
+
+async fn post_update_image_framing(
+    wr: WibbleRequest,
+    Path((slug, image_id)): Path<(String, String)>,
+    Form(data): Form<ImageFramingData>,
+) -> Result<Redirect, Error> {
+    service::update_article_image_framing(wr, &slug, &image_id, &data).await
+}
+
+async fn post_update_image_text(
+    wr: WibbleRequest,
+    Path((slug, image_id)): Path<(String, String)>,
+    Form(data): Form<ImageTextData>,
+) -> Result<Redirect, Error> {
+    service::update_article_image_text(wr, &slug, &image_id, &data).await
+}
+
 async fn post_regenerate_image(
     wr: WibbleRequest,
     Path((slug, image_id)): Path<(String, String)>,
@@ -110,6 +204,21 @@ async fn post_regenerate_image(
     service::regenerate_article_image(wr, &slug, &image_id).await
 }
 
+async fn get_translation_review(
+    wr: WibbleRequest,
+    Path((slug, language)): Path<(String, String)>,
+) -> Result<Html<String>, Error> {
+    render_translation_review_page(wr, &slug, &language).await
+}
+
+async fn post_translation_review(
+    wr: WibbleRequest,
+    Path((slug, language)): Path<(String, String)>,
+    Form(data): Form<TranslationReviewData>,
+) -> Result<Redirect, Error> {
+    apply_translation_review(wr, &slug, &language, &data).await
+}
+
 async fn post_toggle_publish(
     wr: WibbleRequest,
     Path(slug): Path<String>,
@@ -128,18 +237,52 @@ mod tests {
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
     use tokio::net::TcpListener;
 
+    use crate::article_id::normalize_content_model;
     use crate::entities::{
         content as content_entity, content_image, prelude::AuditLog, prelude::Content,
-        prelude::ContentImage,
+        prelude::ContentImage, prelude::TranslationOverride,
     };
+    use crate::error::Error;
     use crate::image_status::{IMAGE_STATUS_COMPLETED, IMAGE_STATUS_FAILED, IMAGE_STATUS_PENDING};
+    use crate::llm::edit_agent::edit_research_brief;
+    use crate::llm::prompt_registry::find_supported_translation_language;
     use crate::rate_limit::RequesterTier;
+    use crate::repositories::research_sources::{save_research_source, CachedSource};
+    use crate::repositories::research_traces::find_research_trace;
+    use crate::services::article_translations::{
+        ensure_cached_article_translation, load_cached_article_translation,
+        owned_article_source_text,
+    };
     use crate::services::site_text::default_site_language;
     use crate::test_support::{author_user, TestContext};
     use crate::wibble_request::WibbleRequest;
 
     use super::{build_unified_diff, markdown_image_count, text_paragraphs};
 
+    struct BracketTranslator;
+
+    impl crate::llm::translate::Translate for BracketTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
+        ) -> Result<String, Error> {
+            Ok(text
+                .split("\n\n")
+                .map(|block| match block.strip_prefix("## ") {
+                    Some(heading) => format!("## [{}] {}", target_language.code, heading),
+                    None if block.split_whitespace().count() > 4 => format!(
+                        "[{}] {} palavras traduzidas.",
+                        target_language.code,
+                        block.split_whitespace().count()
+                    ),
+                    None => format!("[{}] {}", target_language.code, block),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"))
+        }
+    }
+
     fn sample_article(author_email: &str) -> content_entity::ActiveModel {
         content_entity::ActiveModel {
             id: ActiveValue::set("story-1".to_string()),
@@ -173,6 +316,7 @@ mod tests {
             longview_count: ActiveValue::set(0),
             impression_count: ActiveValue::set(0),
             click_count: ActiveValue::set(0),
+            language_id: ActiveValue::set(None),
             author_email: ActiveValue::set(Some(author_email.to_string())),
             published: ActiveValue::set(false),
             recovered_from_dead_link: ActiveValue::set(false),
@@ -201,6 +345,7 @@ mod tests {
             prompt_hash: ActiveValue::set(None),
             prompt: ActiveValue::set("Prompt".to_string()),
             alt_text: ActiveValue::set("Alt".to_string()),
+            caption: ActiveValue::set(None),
             created_at: ActiveValue::set(chrono::Utc::now().naive_utc()),
             flagged: ActiveValue::set(false),
             regenerate: ActiveValue::set(false),
@@ -218,6 +363,13 @@ mod tests {
             provider_job_url: ActiveValue::set(
                 failed.then(|| "https://example.test/job".to_string()),
             ),
+            source_image_id: ActiveValue::set(None),
+            crop_x: ActiveValue::set(None),
+            crop_y: ActiveValue::set(None),
+            crop_width: ActiveValue::set(None),
+            crop_height: ActiveValue::set(None),
+            focal_x: ActiveValue::set(None),
+            focal_y: ActiveValue::set(None),
         }
     }
 
@@ -225,15 +377,17 @@ mod tests {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
         let address = listener.local_addr().unwrap();
         tokio::spawn(async move {
-            let (mut socket, _) = listener.accept().await.unwrap();
-            let mut buffer = vec![0; 16 * 1024];
-            let _ = socket.read(&mut buffer).await.unwrap();
-            let response = format!(
-                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
-                response_body.len(),
-                response_body
-            );
-            socket.write_all(response.as_bytes()).await.unwrap();
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 16 * 1024];
+                let _ = socket.read(&mut buffer).await.unwrap();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    response_body.len(),
+                    response_body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
         });
 
         format!("http://{address}/v1/chat/completions")
@@ -263,9 +417,8 @@ mod tests {
         assert!(diff.contains("+new line"));
     }
 
-    #[tokio::test]
-    async fn agent_edit_preview_renders_mocked_revision() {
-        let response_body = serde_json::json!({
+    fn mock_edit_proposal_response() -> String {
+        serde_json::json!({
             "choices": [{
                 "message": {
                     "tool_calls": [{
@@ -287,7 +440,12 @@ mod tests {
                 "total_tokens": 2
             }
         })
-        .to_string();
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn agent_edit_preview_renders_mocked_revision() {
+        let response_body = mock_edit_proposal_response();
         let mock_url = spawn_mock_llm_server(response_body).await;
         let ctx =
             TestContext::new_with_overrides(&[("OPENROUTER_API_URL", mock_url.as_str())]).await;
@@ -301,6 +459,7 @@ mod tests {
             Path("story-slug".to_string()),
             Form(super::AgentEditRequestData {
                 change_request: "Make it drier".to_string(),
+                research: None,
             }),
         )
         .await
@@ -312,6 +471,63 @@ mod tests {
         assert!(html.contains("Prompt version: 1"));
     }
 
+    #[tokio::test]
+    async fn agent_edit_preview_can_research_the_change_first() {
+        let mock_url = spawn_mock_llm_server(mock_edit_proposal_response()).await;
+        let ctx =
+            TestContext::new_with_overrides(&[("OPENROUTER_API_URL", mock_url.as_str())]).await;
+        sample_article("author@example.com")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        // The mocked model cannot plan queries, so research searches for the brief itself.
+        let brief = edit_research_brief("Research Bulletin", "Add the committee hearing");
+        save_research_source(
+            &ctx.state.db,
+            CachedSource {
+                url: "https://transport.gov/hearing",
+                title: "Committee hearing notes",
+                domain: "transport.gov",
+                snippet: "",
+                search_query: &brief,
+                content: "The committee held a hearing on the memo.",
+                published_on: Some(chrono::Utc::now().date_naive()),
+            },
+            chrono::Duration::minutes(60),
+        )
+        .await
+        .unwrap();
+
+        let Html(html) = super::post_agent_edit_preview(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path("story-slug".to_string()),
+            Form(super::AgentEditRequestData {
+                change_request: "Add the committee hearing".to_string(),
+                research: Some("on".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert!(html.contains("Research sources:"));
+        assert!(html.contains("Committee hearing notes"));
+        assert!(html.contains("Revised Bulletin"));
+
+        let preview = AuditLog::find()
+            .filter(crate::entities::audit_log::Column::Action.eq("agent_edit_preview"))
+            .one(&ctx.state.db)
+            .await
+            .unwrap()
+            .unwrap();
+        let details: serde_json::Value =
+            serde_json::from_str(preview.details.as_deref().unwrap()).unwrap();
+        let trace_id = details["research_trace_id"].as_str().unwrap();
+        assert!(find_research_trace(&ctx.state.db, trace_id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
     #[tokio::test]
     async fn edit_page_surfaces_image_replacement_controls() {
         let ctx = TestContext::new().await;
@@ -339,6 +555,147 @@ mod tests {
         assert!(html.contains("Current stored image."));
     }
 
+    #[tokio::test]
+    async fn image_text_update_saves_alt_text_and_caption() {
+        let ctx = TestContext::new().await;
+        sample_article("author@example.com")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        sample_image("story-1", IMAGE_STATUS_COMPLETED)
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+
+        let _ = super::post_update_image_text(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "img-1".to_string())),
+            Form(super::ImageTextData {
+                alt_text: "  Clerks stacking folders  ".to_string(),
+                caption: "The archive, shortly before closing.".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let refreshed = ContentImage::find_by_id("img-1")
+            .one(&ctx.state.db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(refreshed.alt_text, "Clerks stacking folders");
+        assert_eq!(
+            refreshed.caption.as_deref(),
+            Some("The archive, shortly before closing.")
+        );
+
+        let Html(html) = super::get_edit_article(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path("story-slug".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(html.contains("/content/story-slug/images/img-1/text"));
+        assert!(html.contains("The archive, shortly before closing."));
+
+        let err = super::post_update_image_text(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "img-1".to_string())),
+            Form(super::ImageTextData {
+                alt_text: "   ".to_string(),
+                caption: String::new(),
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::BadRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn image_framing_update_stores_fractions_and_renders_percentages() {
+        let ctx = TestContext::new().await;
+        sample_article("author@example.com")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        sample_image("story-1", IMAGE_STATUS_COMPLETED)
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+
+        let _ = super::post_update_image_framing(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "img-1".to_string())),
+            Form(super::ImageFramingData {
+                crop_x: "10".to_string(),
+                crop_y: "0".to_string(),
+                crop_width: "80".to_string(),
+                crop_height: "100".to_string(),
+                focal_x: "40".to_string(),
+                focal_y: "25.5".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let refreshed = ContentImage::find_by_id("img-1")
+            .one(&ctx.state.db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(refreshed.crop_x, Some(0.1));
+        assert_eq!(refreshed.crop_width, Some(0.8));
+        assert_eq!(refreshed.focal_x, Some(0.4));
+        assert_eq!(refreshed.focal_y, Some(0.255));
+
+        let Html(html) = super::get_edit_article(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path("story-slug".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(html.contains("/content/story-slug/images/img-1/framing"));
+        assert!(html.contains(r#"name="focal_y" min="0" max="100" step="0.1" value="25.5""#));
+
+        let err = super::post_update_image_framing(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "img-1".to_string())),
+            Form(super::ImageFramingData {
+                crop_x: "50".to_string(),
+                crop_width: "60".to_string(),
+                ..super::ImageFramingData::default()
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::BadRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn image_url_replacement_rejects_private_addresses() {
+        let ctx = TestContext::new().await;
+        sample_article("author@example.com")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        sample_image("story-1", IMAGE_STATUS_COMPLETED)
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+
+        let err = super::post_replace_image_from_url(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "img-1".to_string())),
+            Form(super::ImageUrlData {
+                url: "http://127.0.0.1:9/image.jpg".to_string(),
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::BadRequest(_)));
+        assert!(err.to_string().contains("public internet address"));
+    }
+
     #[tokio::test]
     async fn regeneration_reset_clears_transient_image_state() {
         let ctx = TestContext::new().await;
@@ -425,4 +782,120 @@ mod tests {
         assert!(published.published);
         assert!(audit_count >= 2);
     }
+
+    #[tokio::test]
+    async fn translation_review_overrides_machine_text_until_the_source_changes() {
+        let ctx = TestContext::new().await;
+        let article = sample_article("author@example.com")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        let portuguese = find_supported_translation_language("pt").unwrap();
+        let source = owned_article_source_text(&normalize_content_model(article), &[]).unwrap();
+        let machine = ensure_cached_article_translation(
+            &BracketTranslator,
+            &ctx.state.db,
+            &source.as_ref(),
+            &portuguese,
+        )
+        .await
+        .unwrap();
+
+        let Html(html) = super::get_translation_review(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "pt".to_string())),
+        )
+        .await
+        .unwrap();
+        assert!(html.contains("[pt] Research Bulletin"));
+
+        let _redirect = super::post_translation_review(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "pt".to_string())),
+            Form(super::TranslationReviewData {
+                title: "Boletim de Pesquisa".to_string(),
+                description: machine.description.clone(),
+                markdown: machine.markdown.replace('\n', "\r\n"),
+                original_title: machine.title.clone(),
+                original_description: machine.description.clone(),
+                original_markdown: machine.markdown.clone(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            TranslationOverride::find()
+                .count(&ctx.state.db)
+                .await
+                .unwrap(),
+            1,
+            "fields submitted as shown should not be saved as reviewed"
+        );
+        let reviewed =
+            load_cached_article_translation(&ctx.state.db, &source.as_ref(), &portuguese)
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(reviewed.title, "Boletim de Pesquisa");
+        assert!(reviewed.description.starts_with("[pt] "));
+
+        let _redirect = super::post_edit_article(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path("story-slug".to_string()),
+            Form(super::EditArticleData {
+                title: "Research Bulletin, Revised".to_string(),
+                description: source.description.clone(),
+                markdown: source.markdown.clone(),
+            }),
+        )
+        .await
+        .unwrap();
+        let stored = TranslationOverride::find()
+            .one(&ctx.state.db)
+            .await
+            .unwrap()
+            .expect("the reviewed title should survive the edit");
+        assert!(stored.stale);
+        assert_eq!(stored.translation, "Boletim de Pesquisa");
+
+        let Html(html) = super::get_translation_review(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "pt".to_string())),
+        )
+        .await
+        .unwrap();
+        assert!(html.contains("The source changed since this field was reviewed."));
+
+        let _redirect = super::post_translation_review(
+            sample_request(ctx.state.clone(), "author@example.com"),
+            Path(("story-slug".to_string(), "pt".to_string())),
+            Form(super::TranslationReviewData {
+                title: "Boletim de Pesquisa".to_string(),
+                description: machine.description.clone(),
+                markdown: machine.markdown.clone(),
+                original_title: "Boletim de Pesquisa".to_string(),
+                original_description: machine.description.clone(),
+                original_markdown: machine.markdown.clone(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(
+            TranslationOverride::find()
+                .one(&ctx.state.db)
+                .await
+                .unwrap()
+                .unwrap()
+                .stale,
+            "resubmitting a stale review unchanged should keep it stale"
+        );
+        assert!(matches!(
+            super::get_translation_review(
+                sample_request(ctx.state.clone(), "author@example.com"),
+                Path(("story-slug".to_string(), "en".to_string())),
+            )
+            .await,
+            Err(Error::NotFound(_))
+        ));
+    }
 }
@@ -3,9 +3,12 @@ use similar::TextDiff;
 
 use crate::error::Error;
 use crate::llm::article_generator::{
-    ensure_minimum_paragraph_count, split_paragraphs, validate_article_output,
+    ensure_minimum_paragraph_count, ensure_no_internal_source_domain_mentions,
+    ensure_no_research_citation_scaffolding, split_paragraphs, validate_article_output,
+    validate_researched_article_output,
 };
-use crate::llm::edit_agent::generate_edit_proposal;
+use crate::llm::edit_agent::{generate_edit_proposal, research_edit_request};
+use crate::services::article_jobs::ArticleJobService;
 use crate::services::editorial_policy::enforce_edit_request_policy;
 use crate::wibble_request::WibbleRequest;
 
@@ -75,6 +78,14 @@ pub(super) async fn render_agent_edit_preview(
         article.model.as_str()
     };
 
+    let research = if data.research.is_some() {
+        ArticleJobService::new(wr.state.clone())
+            .check_research_rate_limit(wr.requester_tier, &wr.rate_limit_key)?;
+        Some(research_edit_request(&wr.state, model, &article.title, &change_request).await?)
+    } else {
+        None
+    };
+
     let proposal = generate_edit_proposal(
         &wr.state.llm,
         model,
@@ -82,16 +93,38 @@ pub(super) async fn render_agent_edit_preview(
         &article.description,
         current_markdown,
         &change_request,
+        research.as_ref().map(|research| &research.packet),
     )
     .await?;
 
     ensure_minimum_paragraph_count(&text_paragraphs(&proposal.markdown))?;
-    validate_article_output(&proposal.title, &proposal.markdown, expected_images)?;
+    match research.as_ref().map(|research| &research.packet) {
+        Some(research) => {
+            validate_researched_article_output(
+                &proposal.title,
+                &proposal.markdown,
+                expected_images,
+                &research.sources,
+            )?;
+            ensure_no_research_citation_scaffolding(&proposal.description)?;
+            ensure_no_internal_source_domain_mentions(&proposal.description, &research.sources)?;
+        }
+        None => validate_article_output(&proposal.title, &proposal.markdown, expected_images)?,
+    }
 
+    let research_sources = research
+        .as_ref()
+        .map(|research| research.packet.sources.as_slice())
+        .unwrap_or_default();
     let preview_details = serde_json::json!({
         "change_request": change_request,
         "summary": proposal.summary,
         "prompt_version": proposal.prompt_version,
+        "research_trace_id": research.as_ref().map(|research| research.trace_id.as_str()),
+        "research_sources": research_sources
+            .iter()
+            .map(|source| source.url.as_str())
+            .collect::<Vec<_>>(),
     })
     .to_string();
     crate::audit::log_audit(
@@ -112,6 +145,7 @@ pub(super) async fn render_agent_edit_preview(
         .insert("change_request", &change_request)
         .insert("summary", &proposal.summary)
         .insert("prompt_version", &proposal.prompt_version)
+        .insert("research_sources", research_sources)
         .insert("current_title", &article.title)
         .insert("current_description", &article.description)
         .insert("current_markdown", current_markdown)
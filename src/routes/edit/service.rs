@@ -8,16 +8,30 @@ use crate::audit::log_audit;
 use crate::auth::AuthUser;
 use crate::entities::{content as content_entity, content_image, prelude::*};
 use crate::error::Error;
+use crate::image_framing::{CropBox, ImageFraming};
 use crate::image_jobs::spawn_image_generation;
 use crate::image_status::{is_pending_status, IMAGE_STATUS_PENDING};
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, supported_translation_languages,
+    SupportedTranslationLanguage,
+};
 use crate::permissions::{can_edit_article, can_toggle_publish};
-use crate::repositories::images::{normalize_uploaded_image, store_image_file};
-use crate::services::article_translations::owned_article_source_text;
-use crate::services::editorial_policy::enforce_article_output_policy;
+use crate::repositories::images::{
+    detach_shared_image, find_article_images, normalize_uploaded_image, store_image_file,
+};
+use crate::services::article_language::article_source_language_for;
+use crate::services::article_translations::{
+    article_image_source_texts, owned_article_source_text,
+};
+use crate::services::editorial_policy::{enforce_article_output_policy, enforce_image_text_policy};
+use crate::services::remote_image::fetch_remote_image;
+use crate::services::translation_review::{
+    load_translation_review, save_translation_review, SubmittedReviewField,
+};
 use crate::translation_jobs::refresh_article_translations_after_edit;
 use crate::wibble_request::WibbleRequest;
 
-use super::MAX_IMAGE_UPLOAD_BYTES;
+use super::{MAX_IMAGE_TEXT_CHARS, MAX_IMAGE_UPLOAD_BYTES};
 
 pub(super) async fn require_editable_article(
     wr: &WibbleRequest,
@@ -56,11 +70,13 @@ pub(super) async fn apply_article_edit(
 ) -> Result<Redirect, Error> {
     let db = &wr.state.db;
     enforce_article_output_policy(&data.title, &data.description, &data.markdown)?;
-    let previous_source = owned_article_source_text(&article);
+    let images = find_article_images(db, &article.id).await?;
+    let previous_source = owned_article_source_text(&article, &images);
     let translatable_content_changed = article.title != data.title
         || article.description != data.description
         || article.markdown.as_deref().unwrap_or("") != data.markdown;
     let article_id = article.id.clone();
+    let source_language = article_source_language_for(article.language_id.as_deref());
 
     let mut active: content_entity::ActiveModel = article.into();
     active.title = ActiveValue::set(data.title.clone());
@@ -81,9 +97,11 @@ pub(super) async fn apply_article_edit(
                 previous_source,
                 crate::services::article_translations::OwnedArticleSourceText {
                     article_id,
+                    source_language,
                     title: data.title.clone(),
                     description: data.description.clone(),
                     markdown: data.markdown.clone(),
+                    images: article_image_source_texts(&images),
                 },
             )
             .await?;
@@ -95,6 +113,12 @@ pub(super) async fn apply_article_edit(
     ))
 }
 
+fn percent_field(fraction: Option<f64>) -> String {
+    fraction
+        .map(|fraction| format!("{}", (fraction * 1000.0).round() / 10.0))
+        .unwrap_or_default()
+}
+
 pub(super) async fn render_edit_page(
     wr: WibbleRequest,
     slug: &str,
@@ -115,12 +139,20 @@ pub(super) async fn render_edit_page(
             serde_json::json!({
                 "id": img.id,
                 "alt_text": img.alt_text,
+                "caption": img.caption.as_deref().unwrap_or(""),
                 "prompt": img.prompt,
                 "status": img.status,
                 "status_label": text.image_status_label(&img.status),
                 "status_note": text.image_status_note(&img.status),
                 "is_generating": is_generating,
                 "can_regenerate": !is_generating,
+                "is_reused": img.source_image_id.is_some(),
+                "crop_x": percent_field(img.crop_x),
+                "crop_y": percent_field(img.crop_y),
+                "crop_width": percent_field(img.crop_width),
+                "crop_height": percent_field(img.crop_height),
+                "focal_x": percent_field(img.focal_x),
+                "focal_y": percent_field(img.focal_y),
                 "last_error": img.last_error,
             })
         })
@@ -129,6 +161,17 @@ pub(super) async fn render_edit_page(
         .as_str()
         .unwrap_or_default()
         .replace("%MAX_CHARS%", &agent_edit_max_length.to_string());
+    let source_language = article_source_language_for(article.language_id.as_deref());
+    let translation_languages = supported_translation_languages()
+        .into_iter()
+        .filter(|language| language.code != source_language.code)
+        .map(|language| {
+            serde_json::json!({
+                "code": language.code,
+                "name": text.translation_language_name(&language),
+            })
+        })
+        .collect::<Vec<_>>();
 
     wr.template("edit")
         .await
@@ -145,20 +188,117 @@ pub(super) async fn render_edit_page(
         .insert("images", &image_data)
         .insert("agent_edit_max_length", &agent_edit_max_length)
         .insert("max_chars_note", &max_chars_note)
+        .insert("translation_languages", &translation_languages)
         .render()
 }
 
-pub(super) async fn replace_article_image(
+fn reviewable_translation_language(
+    code: &str,
+    article: &content_entity::Model,
+) -> Result<SupportedTranslationLanguage, Error> {
+    let source_language = article_source_language_for(article.language_id.as_deref());
+    find_supported_translation_language(code)
+        .filter(|language| language.code == code)
+        .filter(|language| language.code != source_language.code)
+        .ok_or_else(|| Error::NotFound(Some(format!("Translation language {} not found", code))))
+}
+
+pub(super) async fn render_translation_review_page(
     wr: WibbleRequest,
     slug: &str,
-    image_id: &str,
-    mut multipart: Multipart,
+    language_code: &str,
+) -> Result<Html<String>, Error> {
+    let text = wr.site_text();
+    let (_auth_user, article) = require_editable_article(&wr, slug).await?;
+    let language = reviewable_translation_language(language_code, &article)?;
+    let images = find_article_images(&wr.state.db, &article.id).await?;
+    let source = owned_article_source_text(&article, &images).ok_or_else(|| {
+        Error::NotFound(Some(format!("Article {} has no text to translate", slug)))
+    })?;
+    let fields = load_translation_review(&wr.state.db, &source.as_ref(), &language).await?;
+    let language_name = text.translation_language_name(&language);
+
+    wr.template("translation_review")
+        .await
+        .insert(
+            "title",
+            &text.translation_review_meta_title(&language_name, &article.title),
+        )
+        .insert("robots", "noindex,nofollow")
+        .insert("slug", slug)
+        .insert("language_code", &language.code)
+        .insert("language_name", &language_name)
+        .insert("language_direction", language.direction.as_str())
+        .insert("fields", &fields)
+        .render()
+}
+
+pub(super) async fn apply_translation_review(
+    wr: WibbleRequest,
+    slug: &str,
+    language_code: &str,
+    data: &super::TranslationReviewData,
 ) -> Result<Redirect, Error> {
     let (auth_user, article) = require_editable_article(&wr, slug).await?;
+    let language = reviewable_translation_language(language_code, &article)?;
+    enforce_article_output_policy(&data.title, &data.description, &data.markdown)?;
     let db = &wr.state.db;
+    let images = find_article_images(db, &article.id).await?;
+    let source = owned_article_source_text(&article, &images).ok_or_else(|| {
+        Error::NotFound(Some(format!("Article {} has no text to translate", slug)))
+    })?;
+    let outcome = save_translation_review(
+        db,
+        &source.as_ref(),
+        &language,
+        &auth_user.email,
+        [
+            SubmittedReviewField {
+                shown: &data.original_title,
+                submitted: &data.title,
+            },
+            SubmittedReviewField {
+                shown: &data.original_description,
+                submitted: &data.description,
+            },
+            SubmittedReviewField {
+                shown: &data.original_markdown,
+                submitted: &data.markdown,
+            },
+        ],
+    )
+    .await?;
+
+    let details = serde_json::json!({
+        "language": language.code,
+        "saved_fields": outcome.saved,
+        "cleared_fields": outcome.cleared,
+    })
+    .to_string();
+    log_audit(
+        db,
+        &auth_user,
+        "review_translation",
+        "content",
+        slug,
+        Some(details),
+    )
+    .await?;
 
+    Ok(Redirect::to(&wr.localized_path(&format!(
+        "/content/{}/translations/{}",
+        slug, language.code
+    ))))
+}
+
+async fn find_editable_image(
+    wr: &WibbleRequest,
+    slug: &str,
+    image_id: &str,
+) -> Result<(AuthUser, content_image::Model), Error> {
+    let (auth_user, article) = require_editable_article(wr, slug).await?;
     let img = ContentImage::find_by_id(image_id.to_string())
-        .one(db)
+        .one(&wr.state.db)
         .await
         .map_err(|e| Error::Database(format!("Error finding image: {}", e)))?
         .ok_or_else(|| Error::NotFound(Some(format!("Image {} not found", image_id))))?;
@@ -168,6 +308,58 @@ pub(super) async fn replace_article_image(
             "Image does not belong to this article".to_string(),
         ));
     }
+    Ok((auth_user, img))
+}
+
+async fn store_replacement_image(
+    wr: &WibbleRequest,
+    auth_user: &AuthUser,
+    slug: &str,
+    img: content_image::Model,
+    image_data: Vec<u8>,
+    source: &str,
+) -> Result<Redirect, Error> {
+    let db = &wr.state.db;
+    let image_id = img.id.clone();
+    detach_shared_image(db, &image_id).await?;
+    store_image_file(&image_id, image_data).await?;
+    // An upload no longer matches the prompt or the old framing, so reset both.
+    let mut active = content_image::ActiveModel::from(img);
+    active.prompt_hash = ActiveValue::set(None);
+    active.source_image_id = ActiveValue::set(None);
+    active.crop_x = ActiveValue::set(None);
+    active.crop_y = ActiveValue::set(None);
+    active.crop_width = ActiveValue::set(None);
+    active.crop_height = ActiveValue::set(None);
+    active.focal_x = ActiveValue::set(None);
+    active.focal_y = ActiveValue::set(None);
+    active
+        .update(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error updating replaced image: {}", e)))?;
+
+    log_audit(
+        db,
+        auth_user,
+        "replace_image",
+        "content_image",
+        &image_id,
+        Some(format!("article={} source={}", slug, source)),
+    )
+    .await?;
+
+    Ok(Redirect::to(
+        &wr.localized_path(&format!("/content/{}/edit#images", slug)),
+    ))
+}
+
+pub(super) async fn replace_article_image(
+    wr: WibbleRequest,
+    slug: &str,
+    image_id: &str,
+    mut multipart: Multipart,
+) -> Result<Redirect, Error> {
+    let (auth_user, img) = find_editable_image(&wr, slug, image_id).await?;
 
     let mut image_data = None;
     while let Some(field) = multipart.next_field().await.map_err(map_multipart_error)? {
@@ -179,12 +371,90 @@ pub(super) async fn replace_article_image(
 
     let image_data =
         image_data.ok_or_else(|| Error::BadRequest("No image uploaded".to_string()))?;
-    store_image_file(image_id, image_data).await?;
+    store_replacement_image(&wr, &auth_user, slug, img, image_data, "upload").await
+}
+
+pub(super) async fn replace_article_image_from_url(
+    wr: WibbleRequest,
+    slug: &str,
+    image_id: &str,
+    data: &super::ImageUrlData,
+) -> Result<Redirect, Error> {
+    let (auth_user, img) = find_editable_image(&wr, slug, image_id).await?;
+    let downloaded = fetch_remote_image(&data.url, MAX_IMAGE_UPLOAD_BYTES).await?;
+    let image_data = normalize_uploaded_image(&downloaded)?;
+    store_replacement_image(&wr, &auth_user, slug, img, image_data, "url").await
+}
+
+fn parse_percent(value: &str, field: &str) -> Result<Option<f64>, Error> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let percent = value
+        .parse::<f64>()
+        .ok()
+        .filter(|percent| (0.0..=100.0).contains(percent))
+        .ok_or_else(|| Error::BadRequest(format!("{} must be between 0 and 100", field)))?;
+    Ok(Some(percent / 100.0))
+}
+
+pub(super) fn parse_image_framing(data: &super::ImageFramingData) -> Result<ImageFraming, Error> {
+    let crop = match (
+        parse_percent(&data.crop_x, "Crop left")?,
+        parse_percent(&data.crop_y, "Crop top")?,
+        parse_percent(&data.crop_width, "Crop width")?,
+        parse_percent(&data.crop_height, "Crop height")?,
+    ) {
+        (None, None, None, None) => None,
+        (x, y, width, height) => Some(CropBox::new(
+            x.unwrap_or(0.0),
+            y.unwrap_or(0.0),
+            width.unwrap_or(1.0 - x.unwrap_or(0.0)),
+            height.unwrap_or(1.0 - y.unwrap_or(0.0)),
+        )?),
+    };
+    let focal = match (
+        parse_percent(&data.focal_x, "Focal point X")?,
+        parse_percent(&data.focal_y, "Focal point Y")?,
+    ) {
+        (Some(x), Some(y)) => Some((x, y)),
+        (None, None) => None,
+        _ => {
+            return Err(Error::BadRequest(
+                "Set both focal point coordinates or neither".to_string(),
+            ))
+        }
+    };
+    Ok(ImageFraming { crop, focal })
+}
+
+pub(super) async fn update_article_image_framing(
+    wr: WibbleRequest,
+    slug: &str,
+    image_id: &str,
+    data: &super::ImageFramingData,
+) -> Result<Redirect, Error> {
+    let (auth_user, img) = find_editable_image(&wr, slug, image_id).await?;
+    let framing = parse_image_framing(data)?;
+    let db = &wr.state.db;
+
+    let mut active = content_image::ActiveModel::from(img);
+    active.crop_x = ActiveValue::set(framing.crop.map(|crop| crop.x));
+    active.crop_y = ActiveValue::set(framing.crop.map(|crop| crop.y));
+    active.crop_width = ActiveValue::set(framing.crop.map(|crop| crop.width));
+    active.crop_height = ActiveValue::set(framing.crop.map(|crop| crop.height));
+    active.focal_x = ActiveValue::set(framing.focal.map(|(x, _)| x));
+    active.focal_y = ActiveValue::set(framing.focal.map(|(_, y)| y));
+    active
+        .update(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error updating image framing: {}", e)))?;
 
     log_audit(
         db,
         &auth_user,
-        "replace_image",
+        "update_image_framing",
         "content_image",
         image_id,
         Some(format!("article={}", slug)),
@@ -196,6 +466,90 @@ pub(super) async fn replace_article_image(
     ))
 }
 
+pub(super) async fn update_article_image_text(
+    wr: WibbleRequest,
+    slug: &str,
+    image_id: &str,
+    data: &super::ImageTextData,
+) -> Result<Redirect, Error> {
+    let (auth_user, article) = require_editable_article(&wr, slug).await?;
+    let db = &wr.state.db;
+
+    let alt_text = data.alt_text.trim();
+    let caption = data.caption.trim();
+    if alt_text.is_empty() {
+        return Err(Error::BadRequest("Alt text cannot be empty".to_string()));
+    }
+    if alt_text.chars().count() > MAX_IMAGE_TEXT_CHARS
+        || caption.chars().count() > MAX_IMAGE_TEXT_CHARS
+    {
+        return Err(Error::BadRequest(format!(
+            "Alt text and captions are limited to {} characters",
+            MAX_IMAGE_TEXT_CHARS
+        )));
+    }
+    enforce_image_text_policy(alt_text, caption)?;
+
+    let images = find_article_images(db, &article.id).await?;
+    let image = images
+        .iter()
+        .find(|image| image.id == image_id)
+        .cloned()
+        .ok_or_else(|| Error::NotFound(Some(format!("Image {} not found", image_id))))?;
+    let caption = (!caption.is_empty()).then(|| caption.to_string());
+    if image.alt_text == alt_text && image.caption == caption {
+        return Ok(Redirect::to(
+            &wr.localized_path(&format!("/content/{}/edit#images", slug)),
+        ));
+    }
+
+    let previous_source = owned_article_source_text(&article, &images);
+    let mut active = content_image::ActiveModel::from(image);
+    active.alt_text = ActiveValue::set(alt_text.to_string());
+    active.caption = ActiveValue::set(caption);
+    let updated = active
+        .update(db)
+        .await
+        .map_err(|e| Error::Database(format!("Error updating image text: {}", e)))?;
+    let updated_images = images
+        .into_iter()
+        .map(|image| {
+            if image.id == updated.id {
+                updated.clone()
+            } else {
+                image
+            }
+        })
+        .collect::<Vec<_>>();
+
+    log_audit(
+        db,
+        &auth_user,
+        "update_image_text",
+        "content_image",
+        image_id,
+        Some(format!("article={}", slug)),
+    )
+    .await?;
+    if let (Some(previous_source), Some(current_source)) = (
+        previous_source,
+        owned_article_source_text(&article, &updated_images),
+    ) {
+        refresh_article_translations_after_edit(
+            wr.state.clone(),
+            &auth_user,
+            slug,
+            previous_source,
+            current_source,
+        )
+        .await?;
+    }
+
+    Ok(Redirect::to(
+        &wr.localized_path(&format!("/content/{}/edit#images", slug)),
+    ))
+}
+
 pub(super) async fn regenerate_article_image(
     wr: WibbleRequest,
     slug: &str,
@@ -1,15 +1,16 @@
 use axum::body::Body;
+use axum::extract::OriginalUri;
 use axum::http::header::SET_COOKIE;
 use axum::http::HeaderValue;
 use axum::middleware::Next;
-use axum::response::{IntoResponse, Redirect};
+use axum::response::{Redirect, Response};
 use axum::routing::get;
 use axum::Router;
 
 use crate::app_state::AppState;
-use crate::services::site_paths::{
-    detect_site_language_from_path, find_supported_site_language, site_language_cookie_header,
-};
+use crate::error::Error;
+use crate::llm::prompt_registry::registered_translation_languages;
+use crate::services::site_paths::{detect_site_language_from_path, site_language_cookie_header};
 use crate::wibble_request::WibbleRequest;
 
 pub fn router() -> Router<AppState> {
@@ -42,21 +43,32 @@ async fn redirect_same_path(wr: WibbleRequest) -> Redirect {
 pub async fn persist_site_language_cookie(
     req: axum::http::Request<Body>,
     next: Next,
-) -> impl IntoResponse {
-    let explicit_language = detect_site_language_from_path(req.uri().path()).or_else(|| {
-        req.uri()
-            .path()
-            .trim_start_matches('/')
-            .split('/')
-            .next()
-            .and_then(find_supported_site_language)
-    });
+) -> Result<Response, Error> {
+    // The locale dispatcher in `server` strips the `/{code}` prefix from `req.uri()`.
+    let path = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let prefix = path
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    if registered_translation_languages()
+        .iter()
+        .any(|language| !language.enabled && language.code == prefix)
+    {
+        return Err(Error::NotFound(None));
+    }
+
+    let explicit_language = detect_site_language_from_path(&path);
     let mut response = next.run(req).await;
     if let Some(language) = explicit_language {
-        let cookie = site_language_cookie_header(language);
+        let cookie = site_language_cookie_header(&language);
         if let Ok(value) = HeaderValue::from_str(&cookie) {
             response.headers_mut().append(SET_COOKIE, value);
         }
     }
-    response
+    Ok(response)
 }
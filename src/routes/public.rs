@@ -1,19 +1,22 @@
 use axum::body::{Body, Bytes};
 use axum::extract::{Path, Query};
-use axum::http::StatusCode;
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::middleware::Next;
 use axum::response::{Html, IntoResponse, Response};
 use axum::routing::get;
 use axum::Router;
 use rand::Rng;
+use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::error::Error;
+use crate::image_framing::ImageVariant;
 use crate::newslist::{ContentListParams, NewsList};
 use crate::wibble_request::WibbleRequest;
 
 pub fn localized_router() -> Router<AppState> {
     Router::new()
+        .route("/", get(get_localized_index))
         .route(
             "/image_info/{id}",
             get(crate::image_info::get_image_info_handler),
@@ -24,29 +27,62 @@ pub fn localized_router() -> Router<AppState> {
 pub fn global_router() -> Router<AppState> {
     Router::new()
         .route("/sitemap.xml", get(crate::sitemap::get_sitemap))
+        .route("/sitemaps/{name}", get(crate::sitemap::get_child_sitemap))
         .route("/robots.txt", get(crate::sitemap::get_robots_txt))
         .route("/image/{id}", get(get_image))
 }
 
-pub(crate) async fn get_localized_index(
+async fn get_localized_index(
     wr: WibbleRequest,
     Query(data): Query<ContentListParams>,
 ) -> Result<Html<String>, Error> {
     wr.news_list(data).await
 }
 
-async fn get_image(wr: WibbleRequest, Path(id): Path<String>) -> Result<Response, StatusCode> {
-    let img = crate::image::get_image(&wr.state, &id, wr.auth_user.as_ref())
+#[derive(Deserialize, Debug)]
+struct ImageQuery {
+    variant: Option<String>,
+}
+
+async fn get_image(
+    wr: WibbleRequest,
+    Path(id): Path<String>,
+    Query(query): Query<ImageQuery>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let variant = ImageVariant::parse(query.variant.as_deref());
+    let img = crate::image::get_image(&wr.state, &id, variant, wr.auth_user.as_ref())
         .await
         .map_err(|_| StatusCode::NOT_FOUND)?;
 
-    Response::builder()
+    let mut response = Response::builder().header("Cache-Control", img.cache_control);
+    if let Some(etag) = img.etag.as_deref() {
+        response = response.header("ETag", etag);
+        if etag_matches(&headers, etag) {
+            return response
+                .status(StatusCode::NOT_MODIFIED)
+                .body(Body::empty())
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+    response
         .header("Content-Type", img.content_type)
-        .header("Cache-Control", img.cache_control)
         .body(Body::from(Bytes::from(img.bytes)))
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
 }
 
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(|candidate| candidate.trim().trim_start_matches("W/"))
+                .any(|candidate| candidate == etag || candidate == "*")
+        })
+}
+
 pub async fn handle_error(
     wr: WibbleRequest,
     req: axum::http::Request<Body>,
@@ -0,0 +1,62 @@
+use std::fmt::Debug;
+
+use futures::future::BoxFuture;
+use url::Url;
+
+use crate::error::Error;
+
+pub mod duckduckgo;
+pub mod local_index;
+pub mod searxng;
+
+pub const RESEARCH_USER_AGENT: &str = "WibbleResearch/1.0";
+
+/// One raw search result; ranking against domain preferences happens in research mode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchHit {
+    pub title: String,
+    pub url: String,
+    pub domain: String,
+    pub snippet: String,
+}
+
+impl SearchHit {
+    pub fn new(title: String, url: String, snippet: String) -> Self {
+        let domain = url_domain(&url);
+        Self {
+            title,
+            url,
+            domain,
+            snippet,
+        }
+    }
+}
+
+/// Web search backends used by the bounded research desk, selected by `RESEARCH_SEARCH_PROVIDER`.
+pub trait SearchProvider: Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<Vec<SearchHit>, Error>>;
+}
+
+pub(crate) fn research_http_client() -> Result<reqwest::Client, Error> {
+    reqwest::Client::builder()
+        .user_agent(RESEARCH_USER_AGENT)
+        .build()
+        .map_err(|e| Error::Llm(format!("Failed to build research HTTP client: {}", e)))
+}
+
+pub(crate) fn url_domain(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|value| value.domain().map(str::to_string))
+        .unwrap_or_default()
+}
+
+pub(crate) fn is_http_url(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
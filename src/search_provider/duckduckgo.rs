@@ -0,0 +1,126 @@
+use futures::future::BoxFuture;
+use regex::Regex;
+use url::Url;
+
+use crate::error::Error;
+use crate::search_provider::{is_http_url, research_http_client, SearchHit, SearchProvider};
+
+const DUCKDUCKGO_HTML_URL: &str = "https://duckduckgo.com/html/";
+
+#[derive(Debug, Clone)]
+pub struct DuckDuckGoSearchProvider {
+    reqwest: reqwest::Client,
+}
+
+impl DuckDuckGoSearchProvider {
+    pub fn new() -> Result<Self, Error> {
+        Ok(Self {
+            reqwest: research_http_client()?,
+        })
+    }
+}
+
+impl SearchProvider for DuckDuckGoSearchProvider {
+    fn name(&self) -> &'static str {
+        "duckduckgo"
+    }
+
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<Vec<SearchHit>, Error>> {
+        Box::pin(async move {
+            let response = self
+                .reqwest
+                .get(DUCKDUCKGO_HTML_URL)
+                .query(&[("q", query)])
+                .send()
+                .await
+                .map_err(|e| Error::Llm(format!("Failed to search public web: {}", e)))?
+                .text()
+                .await
+                .map_err(|e| Error::Llm(format!("Failed to read search response: {}", e)))?;
+            let mut hits = parse_duckduckgo_html(&response);
+            hits.truncate(limit);
+            Ok(hits)
+        })
+    }
+}
+
+pub(crate) fn parse_duckduckgo_html(html: &str) -> Vec<SearchHit> {
+    let entry_re = Regex::new(
+        r#"(?s)result__a" href="(?P<href>[^"]+)".*?>(?P<title>.*?)</a>.*?result__snippet[^>]*>(?P<snippet>.*?)</"#,
+    )
+    .expect("search result regex must compile");
+    let tag_re = Regex::new(r"<[^>]+>").expect("tag stripping regex must compile");
+
+    entry_re
+        .captures_iter(html)
+        .filter_map(|capture| {
+            let url = resolve_duckduckgo_url(capture.name("href")?.as_str())?;
+            Some(SearchHit::new(
+                decode_html_entities(&tag_re.replace_all(capture.name("title")?.as_str(), "")),
+                url,
+                decode_html_entities(&tag_re.replace_all(capture.name("snippet")?.as_str(), "")),
+            ))
+        })
+        .collect()
+}
+
+fn resolve_duckduckgo_url(raw: &str) -> Option<String> {
+    let href = if raw.starts_with("//") {
+        format!("https:{}", raw)
+    } else {
+        raw.to_string()
+    };
+    let parsed = Url::parse(&href).ok()?;
+    let uddg = parsed
+        .query_pairs()
+        .find(|(key, _)| key == "uddg")
+        .map(|(_, value)| value.to_string())?;
+    if is_http_url(&uddg) {
+        Some(uddg)
+    } else {
+        None
+    }
+}
+
+fn decode_html_entities(input: &str) -> String {
+    input
+        .replace("&amp;", "&")
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_duckduckgo_html, resolve_duckduckgo_url};
+
+    #[test]
+    fn duckduckgo_redirect_url_is_decoded() {
+        let decoded = resolve_duckduckgo_url(
+            "//duckduckgo.com/l/?uddg=https%3A%2F%2Fexample.gov%2Fmemo&rut=test",
+        )
+        .unwrap();
+
+        assert_eq!(decoded, "https://example.gov/memo");
+    }
+
+    #[test]
+    fn duckduckgo_html_results_are_parsed_into_hits() {
+        let html = r##"<a rel="nofollow" class="result__a" href="//duckduckgo.com/l/?uddg=https%3A%2F%2Ftransport.gov%2Fmemo&amp;rut=x">Transport <b>memo</b></a>
+<a class="result__snippet" href="#">The ministry&#x27;s new rules</a>"##;
+
+        let hits = parse_duckduckgo_html(html);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].title, "Transport memo");
+        assert_eq!(hits[0].url, "https://transport.gov/memo");
+        assert_eq!(hits[0].domain, "transport.gov");
+        assert_eq!(hits[0].snippet, "The ministry's new rules");
+    }
+}
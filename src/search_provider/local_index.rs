@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+
+use futures::future::BoxFuture;
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::search_provider::{SearchHit, SearchProvider};
+
+const MIN_TERM_CHARS: usize = 3;
+
+/// A document in a local research index fixture.
+#[derive(Clone, Debug, Deserialize)]
+pub struct LocalIndexDocument {
+    pub title: String,
+    pub url: String,
+    #[serde(default)]
+    pub snippet: String,
+    #[serde(default)]
+    pub body: String,
+}
+
+/// Offline search over a JSON file of documents, used for tests and air-gapped deployments.
+#[derive(Debug, Clone, Default)]
+pub struct LocalIndexSearchProvider {
+    documents: Vec<IndexedDocument>,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedDocument {
+    document: LocalIndexDocument,
+    title_terms: HashSet<String>,
+    snippet_terms: HashSet<String>,
+    body_terms: HashSet<String>,
+}
+
+impl LocalIndexSearchProvider {
+    pub fn new() -> Result<Self, Error> {
+        let path = env::var("RESEARCH_LOCAL_INDEX").map_err(|_| {
+            Error::Llm("RESEARCH_LOCAL_INDEX must be set for the local search provider".to_string())
+        })?;
+        Self::load(&path)
+    }
+
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let raw = fs::read_to_string(path).map_err(|e| {
+            Error::Llm(format!("Failed to read local search index {}: {}", path, e))
+        })?;
+        let documents: Vec<LocalIndexDocument> = serde_json::from_str(&raw).map_err(|e| {
+            Error::Llm(format!(
+                "Failed to parse local search index {}: {}",
+                path, e
+            ))
+        })?;
+        Ok(Self::from_documents(documents))
+    }
+
+    pub fn from_documents(documents: Vec<LocalIndexDocument>) -> Self {
+        Self {
+            documents: documents
+                .into_iter()
+                .map(|document| IndexedDocument {
+                    title_terms: index_terms(&document.title),
+                    snippet_terms: index_terms(&document.snippet),
+                    body_terms: index_terms(&document.body),
+                    document,
+                })
+                .collect(),
+        }
+    }
+
+    fn rank(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query_terms = index_terms(query);
+        let mut scored = self
+            .documents
+            .iter()
+            .enumerate()
+            .filter_map(|(position, indexed)| {
+                let score = query_terms
+                    .iter()
+                    .map(|term| {
+                        3 * usize::from(indexed.title_terms.contains(term))
+                            + 2 * usize::from(indexed.snippet_terms.contains(term))
+                            + usize::from(indexed.body_terms.contains(term))
+                    })
+                    .sum::<usize>();
+                (score > 0).then_some((score, position, indexed))
+            })
+            .collect::<Vec<_>>();
+        scored.sort_by(|left, right| right.0.cmp(&left.0).then(left.1.cmp(&right.1)));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, indexed)| {
+                let document = &indexed.document;
+                let snippet = if document.snippet.is_empty() {
+                    document.body.chars().take(240).collect()
+                } else {
+                    document.snippet.clone()
+                };
+                SearchHit::new(document.title.clone(), document.url.clone(), snippet)
+            })
+            .collect()
+    }
+}
+
+impl SearchProvider for LocalIndexSearchProvider {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<Vec<SearchHit>, Error>> {
+        Box::pin(async move { Ok(self.rank(query, limit)) })
+    }
+}
+
+fn index_terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| term.chars().count() >= MIN_TERM_CHARS)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalIndexDocument, LocalIndexSearchProvider};
+    use crate::search_provider::SearchProvider;
+
+    fn document(title: &str, url: &str, body: &str) -> LocalIndexDocument {
+        LocalIndexDocument {
+            title: title.to_string(),
+            url: url.to_string(),
+            snippet: String::new(),
+            body: body.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn local_index_ranks_title_matches_above_body_matches() {
+        let provider = LocalIndexSearchProvider::from_documents(vec![
+            document(
+                "Council agenda",
+                "https://example.com/agenda",
+                "The transport ministry was mentioned once.",
+            ),
+            document(
+                "Transport ministry emissions policy",
+                "https://transport.gov/policy",
+                "Full policy text.",
+            ),
+            document(
+                "Bakery opening",
+                "https://example.com/bakery",
+                "Croissants.",
+            ),
+        ]);
+
+        let hits = provider
+            .search("transport ministry emissions", 5)
+            .await
+            .unwrap();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].url, "https://transport.gov/policy");
+        assert_eq!(hits[0].domain, "transport.gov");
+        assert_eq!(
+            hits[1].snippet,
+            "The transport ministry was mentioned once."
+        );
+    }
+}
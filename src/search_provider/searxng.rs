@@ -0,0 +1,105 @@
+use std::env;
+
+use futures::future::BoxFuture;
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::search_provider::{is_http_url, research_http_client, SearchHit, SearchProvider};
+
+#[derive(Debug, Clone)]
+pub struct SearxngSearchProvider {
+    reqwest: reqwest::Client,
+    base_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearxngResponse {
+    #[serde(default)]
+    results: Vec<SearxngResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearxngResult {
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    content: String,
+}
+
+impl SearxngSearchProvider {
+    pub fn new() -> Result<Self, Error> {
+        let base_url = env::var("SEARXNG_URL").map_err(|_| {
+            Error::Llm("SEARXNG_URL must be set for the searxng search provider".to_string())
+        })?;
+        Self::with_base_url(&base_url)
+    }
+
+    pub fn with_base_url(base_url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            reqwest: research_http_client()?,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+impl SearchProvider for SearxngSearchProvider {
+    fn name(&self) -> &'static str {
+        "searxng"
+    }
+
+    fn search<'a>(
+        &'a self,
+        query: &'a str,
+        limit: usize,
+    ) -> BoxFuture<'a, Result<Vec<SearchHit>, Error>> {
+        Box::pin(async move {
+            let response = self
+                .reqwest
+                .get(format!("{}/search", self.base_url))
+                .query(&[("q", query), ("format", "json")])
+                .send()
+                .await
+                .map_err(|e| Error::Llm(format!("Failed to query SearXNG: {}", e)))?
+                .error_for_status()
+                .map_err(|e| Error::Llm(format!("SearXNG rejected the search: {}", e)))?
+                .text()
+                .await
+                .map_err(|e| Error::Llm(format!("Failed to read SearXNG response: {}", e)))?;
+            let mut hits = parse_searxng_json(&response)?;
+            hits.truncate(limit);
+            Ok(hits)
+        })
+    }
+}
+
+pub(crate) fn parse_searxng_json(body: &str) -> Result<Vec<SearchHit>, Error> {
+    let response: SearxngResponse = serde_json::from_str(body)
+        .map_err(|e| Error::Llm(format!("Failed to parse SearXNG response: {}", e)))?;
+    Ok(response
+        .results
+        .into_iter()
+        .filter(|result| is_http_url(&result.url))
+        .map(|result| SearchHit::new(result.title, result.url, result.content))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_searxng_json;
+
+    #[test]
+    fn searxng_json_results_are_parsed_and_non_http_links_dropped() {
+        let hits = parse_searxng_json(
+            r#"{"query":"ministry","results":[
+                {"url":"https://transport.gov/memo","title":"Transport memo","content":"New rules","engine":"bing"},
+                {"url":"magnet:?xt=urn:btih:abc","title":"Torrent","content":""}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].domain, "transport.gov");
+        assert_eq!(hits[0].snippet, "New rules");
+    }
+}
@@ -1,46 +1,35 @@
-use axum::{middleware, routing::get, Router};
+use axum::extract::{OriginalUri, Request};
+use axum::http::Uri;
+use axum::response::{IntoResponse, Response};
+use axum::{middleware, Router};
+use tower::ServiceExt;
 use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
 
 use crate::app_state::AppState;
-use crate::llm::prompt_registry::supported_translation_languages;
+use crate::llm::prompt_registry::registered_translation_languages;
 use crate::rate_limit::rate_limit_middleware;
 use crate::routes::{admin, auth, content, create, edit, legacy, public};
 
 pub fn build_router(state: AppState) -> Router {
-    let serve_dir = ServeDir::new("static");
-    let localized_app = Router::new()
-        .merge(public::localized_router())
-        .merge(create::localized_router())
-        .merge(content::localized_router())
-        .merge(edit::localized_router())
-        .merge(admin::localized_router())
-        .merge(auth::localized_router())
-        .layer(middleware::from_fn(legacy::persist_site_language_cookie));
-
-    let mut router = Router::new()
+    let localized_routes = LocalizedRoutes {
+        app: Router::new()
+            .merge(public::localized_router())
+            .merge(create::localized_router())
+            .merge(content::localized_router())
+            .merge(edit::localized_router())
+            .merge(admin::localized_router())
+            .merge(auth::localized_router())
+            .layer(middleware::from_fn(legacy::persist_site_language_cookie))
+            .with_state(state.clone()),
+        static_files: ServeDir::new("static"),
+    };
+
+    Router::new()
         .merge(public::global_router())
         .merge(auth::global_callback_router())
-        .merge(legacy::router());
-
-    for language in supported_translation_languages() {
-        router = router
-            .route(
-                &format!("/{code}", code = language.code),
-                get(public::get_localized_index),
-            )
-            .route(
-                &format!("/{code}/", code = language.code),
-                get(public::get_localized_index),
-            )
-            .nest(
-                &format!("/{code}", code = language.code),
-                localized_app.clone(),
-            );
-    }
-
-    router
-        .fallback_service(serve_dir)
+        .merge(legacy::router())
+        .fallback(move |req: Request| localized_routes.clone().dispatch(req))
         .layer(TraceLayer::new_for_http())
         .layer(middleware::from_fn_with_state(
             state.rate_limit_state.clone(),
@@ -53,12 +42,56 @@ pub fn build_router(state: AppState) -> Router {
         .with_state(state)
 }
 
+/// Serves `/{code}/...` for every language in the live registry, so a language an admin adds
+/// is routed without a restart. Disabled languages still reach the localized app, where
+/// `legacy::persist_site_language_cookie` answers 404 until they are re-enabled; any other
+/// path falls through to the static files.
+#[derive(Clone)]
+struct LocalizedRoutes {
+    app: Router,
+    static_files: ServeDir,
+}
+
+impl LocalizedRoutes {
+    async fn dispatch(self, mut req: Request) -> Response {
+        let original_uri = req.uri().clone();
+        let Some(localized_uri) = strip_language_prefix(&original_uri) else {
+            return self.static_files.oneshot(req).await.into_response();
+        };
+        req.extensions_mut().insert(OriginalUri(original_uri));
+        *req.uri_mut() = localized_uri;
+        self.app.oneshot(req).await.into_response()
+    }
+}
+
+/// `/pt/create?x=1` becomes `/create?x=1` and `/pt` becomes `/` when `pt` is registered.
+fn strip_language_prefix(uri: &Uri) -> Option<Uri> {
+    let path = uri.path().strip_prefix('/')?;
+    let (code, rest) = match path.find('/') {
+        Some(index) => (&path[..index], &path[index..]),
+        None => (path, "/"),
+    };
+    if !registered_translation_languages()
+        .iter()
+        .any(|language| language.code == code)
+    {
+        return None;
+    }
+    let path_and_query = match uri.query() {
+        Some(query) => format!("{}?{}", rest, query),
+        None => rest.to_string(),
+    };
+    path_and_query.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use axum::body::Body;
     use http::{Request, StatusCode};
     use tower::ServiceExt;
 
+    use crate::llm::prompt_registry::TextDirection;
+    use crate::repositories::languages::{save_language, LanguageSettings};
     use crate::server::build_router;
     use crate::test_support::TestContext;
 
@@ -108,6 +141,56 @@ mod tests {
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8_lossy(&body);
+        assert!(html.contains("Redactar informe"));
+    }
+
+    #[tokio::test]
+    async fn languages_added_after_startup_are_routed_without_a_rebuild() {
+        let ctx = TestContext::new().await;
+        let app = build_router(ctx.state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/sv/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        save_language(
+            &ctx.state.db,
+            LanguageSettings {
+                code: "sv".to_string(),
+                name: "Swedish".to_string(),
+                aliases: vec!["svenska".to_string()],
+                direction: TextDirection::Ltr,
+                enabled: true,
+                site_locale: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/sv/create?prompt=x")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(Request::builder().uri("/sv").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
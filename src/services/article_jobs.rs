@@ -25,11 +25,15 @@ use self::support::{build_job_preview_payload, default_usage_counters, merge_job
 mod tests {
     use sea_orm::{ActiveValue, EntityTrait};
 
-    use crate::create::clarify::build_clarification_request;
+    use crate::create::clarify::ClarificationRequest;
     use crate::entities::content;
     use crate::entities::{article_job, prelude::ArticleJob};
-    use crate::llm::article_generator::ResearchModeSource;
+    use crate::llm::article_generator::{
+        parse_outline_payload, ArticleOutline, ResearchModeSource,
+    };
+    use crate::llm::clarifier::{ClarificationProposal, ClarifyingQuestion};
     use crate::rate_limit::RequesterTier;
+    use crate::services::article_language::article_source_language;
     use crate::test_support::TestContext;
     use serde_json::Value;
 
@@ -76,6 +80,7 @@ mod tests {
             longview_count: 0,
             impression_count: 0,
             click_count: 0,
+            language_id: None,
             author_email: Some("author@example.com".to_string()),
             published: false,
             recovered_from_dead_link: false,
@@ -193,11 +198,20 @@ mod tests {
                     RequesterTier::Authenticated,
                     "user:author@example.com".to_string(),
                     None,
+                    &article_source_language(),
                 ),
             )
             .await
             .unwrap();
-        let clarification = build_clarification_request(&prompt).expect("clarification expected");
+        let question = |text: &str| ClarifyingQuestion {
+            question: text.to_string(),
+            suggested_answers: vec!["The transport ministry".to_string()],
+        };
+        let clarification = ClarificationRequest::from_proposal(ClarificationProposal {
+            questions: vec![question("Which office?"), question("What did it announce?")],
+            fallback_instruction: None,
+        })
+        .expect("clarification expected");
 
         service
             .request_clarification(&job_id, serde_json::to_string(&clarification).unwrap())
@@ -211,8 +225,15 @@ mod tests {
         assert_eq!(waiting.phase, ARTICLE_JOB_PHASE_AWAITING_USER_INPUT);
         assert_eq!(waiting.status, ARTICLE_JOB_STATUS_PROCESSING);
 
+        assert!(service
+            .submit_clarification_answers(&job_id, &[None, None])
+            .await
+            .is_err());
         let resumed = service
-            .submit_clarification_answer(&job_id, "The transport ministry")
+            .submit_clarification_answers(
+                &job_id,
+                &[Some("The transport ministry".to_string()), None],
+            )
             .await
             .unwrap()
             .expect("job should resume");
@@ -220,10 +241,92 @@ mod tests {
         assert_eq!(resumed.phase, ARTICLE_JOB_PHASE_QUEUED);
         assert_eq!(resumed.status, ARTICLE_JOB_STATUS_QUEUED);
         assert!(resumed.prompt.contains("Clarification from requester"));
-        assert!(resumed.prompt.contains("The transport ministry"));
+        assert!(resumed
+            .prompt
+            .contains("Question: Which office?\nAnswer: The transport ministry"));
+        assert!(!resumed.prompt.contains("What did it announce?"));
         assert!(resumed.preview_payload.is_none());
     }
 
+    #[tokio::test]
+    async fn outline_review_waits_for_the_author_and_resumes_with_their_choice() {
+        let ctx = TestContext::new().await;
+        let service = ArticleJobService::new(ctx.state.clone());
+        let job_id = "job-outline".to_string();
+        service
+            .create_job(
+                job_id.clone(),
+                ArticleJobRequest::create(
+                    "The transport ministry asks buses to reflect".to_string(),
+                    Some("author@example.com".to_string()),
+                    RequesterTier::Authenticated,
+                    "user:author@example.com".to_string(),
+                    None,
+                    &article_source_language(),
+                ),
+            )
+            .await
+            .unwrap();
+        let outline = ArticleOutline {
+            headline_options: vec![
+                "Ministry Schedules Bus Feelings".to_string(),
+                "Buses Asked To Reflect".to_string(),
+            ],
+            angle: "Bus morale is treated as routine compliance.".to_string(),
+            beats: vec!["The memo lands".to_string(), "Union response".to_string()],
+            image_slots: vec!["Minister at a lectern".to_string()],
+            chosen_headline: None,
+            approved: false,
+            review_deadline: None,
+        };
+
+        service
+            .request_outline_review(&job_id, outline)
+            .await
+            .unwrap();
+        let waiting = ArticleJob::find_by_id(job_id.clone())
+            .one(&ctx.state.db)
+            .await
+            .unwrap()
+            .expect("article job should exist");
+        assert_eq!(waiting.phase, ARTICLE_JOB_PHASE_AWAITING_USER_INPUT);
+        assert!(parse_outline_payload(waiting.preview_payload.as_deref())
+            .expect("outline should be stored")
+            .awaiting_review());
+
+        assert!(service
+            .submit_outline_choice(&job_id, "A headline nobody proposed", "The memo lands")
+            .await
+            .is_err());
+        let resumed = service
+            .submit_outline_choice(
+                &job_id,
+                "Buses Asked To Reflect",
+                "The memo lands\nDrivers ask for clarity",
+            )
+            .await
+            .unwrap()
+            .expect("job should resume");
+
+        assert_eq!(resumed.phase, ARTICLE_JOB_PHASE_QUEUED);
+        assert_eq!(resumed.status, ARTICLE_JOB_STATUS_QUEUED);
+        let approved = parse_outline_payload(resumed.preview_payload.as_deref())
+            .expect("approved outline should stay in the payload");
+        assert!(approved.approved);
+        assert_eq!(
+            approved.chosen_headline.as_deref(),
+            Some("Buses Asked To Reflect")
+        );
+        assert_eq!(
+            approved.beats,
+            vec!["The memo lands", "Drivers ask for clarity"]
+        );
+        assert!(service
+            .submit_outline_choice(&job_id, "Buses Asked To Reflect", "The memo lands")
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn research_job_preview_payload_round_trips_through_persisted_job_row() {
         let ctx = TestContext::new().await;
@@ -248,6 +351,7 @@ mod tests {
             updated_at: ActiveValue::set(now),
             started_at: ActiveValue::set(None),
             finished_at: ActiveValue::set(Some(now)),
+            language_code: ActiveValue::set(None),
         })
         .exec(&ctx.state.db)
         .await
@@ -1,5 +1,6 @@
 use crate::app_state::AppState;
 use crate::llm::article_generator::ResearchModeSource;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::rate_limit::RequesterTier;
 
 pub const ARTICLE_JOB_STATUS_QUEUED: &str = "queued";
@@ -80,6 +81,7 @@ pub struct ArticleJobRequest {
     pub(super) author_email: Option<String>,
     pub(super) prompt: String,
     pub(super) feature_type: ArticleJobFeatureType,
+    pub(super) language_code: Option<String>,
 }
 
 impl ArticleJobRequest {
@@ -89,6 +91,7 @@ impl ArticleJobRequest {
         requester_tier: RequesterTier,
         rate_limit_key: String,
         research_mode: Option<ResearchModeSource>,
+        language: &SupportedTranslationLanguage,
     ) -> Self {
         Self {
             article_id: None,
@@ -97,6 +100,7 @@ impl ArticleJobRequest {
             author_email,
             prompt,
             feature_type: ArticleJobFeatureType::from_research_mode(research_mode),
+            language_code: Some(language.code.to_string()),
         }
     }
 
@@ -108,6 +112,7 @@ impl ArticleJobRequest {
             author_email: None,
             prompt,
             feature_type: ArticleJobFeatureType::DeadLinkRecovery,
+            language_code: None,
         }
     }
 }
@@ -5,10 +5,13 @@ use tracing::{event, Level};
 use uuid::Uuid;
 
 use crate::audit::log_system_audit;
-use crate::create::clarify::{append_clarification_answer, parse_clarification_request};
+use crate::create::clarify::{
+    append_clarification_answers, clarification_timeout_seconds, parse_clarification_request,
+};
 use crate::entities::article_job;
 use crate::entities::prelude::ArticleJob;
 use crate::error::Error;
+use crate::llm::article_generator::{parse_outline_payload, ArticleOutline};
 use crate::rate_limit::{ArticleRateLimit, RequesterTier};
 
 use super::definitions::{
@@ -16,7 +19,7 @@ use super::definitions::{
     ARTICLE_JOB_PHASE_AWAITING_USER_INPUT, ARTICLE_JOB_PHASE_CANCELLED, ARTICLE_JOB_PHASE_QUEUED,
     ARTICLE_JOB_STATUS_CANCELLED, ARTICLE_JOB_STATUS_PROCESSING, ARTICLE_JOB_STATUS_QUEUED,
 };
-use super::support::{default_usage_counters, load_article_job, now};
+use super::support::{default_usage_counters, load_article_job, merge_preview_payload_value, now};
 
 impl ArticleJobService {
     pub fn new(state: crate::app_state::AppState) -> Self {
@@ -91,6 +94,29 @@ impl ArticleJobService {
             })
     }
 
+    pub fn check_clarifying_question_rate_limit(
+        &self,
+        requester_tier: RequesterTier,
+        rate_limit_key: &str,
+    ) -> Result<(), Error> {
+        self.state
+            .rate_limit_state
+            .check_clarifying_question_limit(requester_tier, rate_limit_key)
+            .map_err(|limit| {
+                let limit_name = match limit {
+                    ArticleRateLimit::Hourly => "hourly",
+                    ArticleRateLimit::Daily => "daily",
+                };
+                event!(
+                    Level::WARN,
+                    limit = limit_name,
+                    tier = ?requester_tier,
+                    "Skipped clarifying questions due to rate limit",
+                );
+                Error::RateLimited
+            })
+    }
+
     pub async fn create_job(&self, id: String, request: ArticleJobRequest) -> Result<(), Error> {
         let prompt_chars = request.prompt.chars().count();
         let prompt = request.prompt;
@@ -116,6 +142,7 @@ impl ArticleJobService {
             updated_at: ActiveValue::set(reference_time),
             started_at: ActiveValue::set(None),
             finished_at: ActiveValue::set(None),
+            language_code: ActiveValue::set(request.language_code),
         })
         .exec(&self.state.db)
         .await
@@ -221,6 +248,7 @@ impl ArticleJobService {
             .update(&self.state.db)
             .await
             .map_err(|e| Error::Database(format!("Error requesting clarification: {}", e)))?;
+        self.state.mark_generation_finished(id).await;
         let details = serde_json::json!({
             "phase": ARTICLE_JOB_PHASE_AWAITING_USER_INPUT,
         })
@@ -243,20 +271,10 @@ impl ArticleJobService {
                 id
             ))));
         };
-        let mut object = job
-            .preview_payload
-            .as_deref()
-            .and_then(|value| serde_json::from_str::<Value>(value).ok())
-            .and_then(|value| value.as_object().cloned())
-            .unwrap_or_default();
-        if let Some(payload_object) = payload.as_object() {
-            for (key, value) in payload_object {
-                object.insert(key.clone(), value.clone());
-            }
-        }
+        let preview_payload = merge_preview_payload_value(job.preview_payload.as_deref(), payload);
 
         let mut active: article_job::ActiveModel = job.into();
-        active.preview_payload = ActiveValue::set(Some(Value::Object(object).to_string()));
+        active.preview_payload = ActiveValue::set(Some(preview_payload));
         active.updated_at = ActiveValue::set(now());
         active.update(&self.state.db).await.map_err(|e| {
             Error::Database(format!("Error updating article job preview payload: {}", e))
@@ -264,10 +282,12 @@ impl ArticleJobService {
         Ok(())
     }
 
-    pub async fn submit_clarification_answer(
+    /// Resumes a job waiting for clarification. `answers` line up with the stored questions;
+    /// `None` leaves that question to the writer.
+    pub async fn submit_clarification_answers(
         &self,
         id: &str,
-        answer: &str,
+        answers: &[Option<String>],
     ) -> Result<Option<article_job::Model>, Error> {
         let Some(job) = self.load_job(id).await? else {
             return Ok(None);
@@ -280,10 +300,17 @@ impl ArticleJobService {
         }
         let clarification = parse_clarification_request(job.preview_payload.as_deref())
             .ok_or_else(|| Error::BadRequest("Clarification prompt is missing".to_string()))?;
-        let prompt = append_clarification_answer(&job.prompt, &clarification.question, answer);
+        if answers.iter().all(Option::is_none) {
+            return Err(Error::BadRequest(
+                "Answer at least one clarification question before resuming generation."
+                    .to_string(),
+            ));
+        }
+        let prompt = append_clarification_answers(&job.prompt, &clarification.questions(), answers);
         let updated = self.resume_job_with_prompt(job, prompt).await?;
         let details = serde_json::json!({
-            "answer_chars": answer.chars().count(),
+            "answered_questions": answers.iter().flatten().count(),
+            "answer_chars": answers.iter().flatten().map(|answer| answer.chars().count()).sum::<usize>(),
         })
         .to_string();
         log_system_audit(
@@ -296,4 +323,96 @@ impl ArticleJobService {
         .await?;
         Ok(Some(updated))
     }
+
+    /// Pauses a running job so its author can review the outline. The worker returns right
+    /// after this, so the job is released here for the resume that follows approval.
+    pub async fn request_outline_review(
+        &self,
+        id: &str,
+        mut outline: ArticleOutline,
+    ) -> Result<(), Error> {
+        let Some(job) = self.load_job(id).await? else {
+            return Err(Error::NotFound(Some(format!(
+                "Article job {} not found",
+                id
+            ))));
+        };
+        let reference_time = now();
+        outline.review_deadline = Some(
+            (reference_time + chrono::Duration::seconds(clarification_timeout_seconds()))
+                .format("%F %T")
+                .to_string(),
+        );
+        let preview_payload =
+            merge_preview_payload_value(job.preview_payload.as_deref(), outline.preview_payload());
+
+        let mut active: article_job::ActiveModel = job.into();
+        active.phase = ActiveValue::set(ARTICLE_JOB_PHASE_AWAITING_USER_INPUT.to_string());
+        active.status = ActiveValue::set(ARTICLE_JOB_STATUS_PROCESSING.to_string());
+        active.preview_payload = ActiveValue::set(Some(preview_payload));
+        active.updated_at = ActiveValue::set(reference_time);
+        active
+            .update(&self.state.db)
+            .await
+            .map_err(|e| Error::Database(format!("Error requesting outline review: {}", e)))?;
+        self.state.mark_generation_finished(id).await;
+        let details = serde_json::json!({
+            "phase": ARTICLE_JOB_PHASE_AWAITING_USER_INPUT,
+            "headline_options": outline.headline_options.len(),
+            "beats": outline.beats.len(),
+        })
+        .to_string();
+        log_system_audit(
+            &self.state.db,
+            "article_job_outline_review_requested",
+            "article_job",
+            id,
+            Some(details),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Approves the outline with the author's headline pick and beat edits, then requeues the
+    /// job so drafting continues from it.
+    pub async fn submit_outline_choice(
+        &self,
+        id: &str,
+        headline: &str,
+        beats: &str,
+    ) -> Result<Option<article_job::Model>, Error> {
+        let Some(job) = self.load_job(id).await? else {
+            return Ok(None);
+        };
+        let outline = parse_outline_payload(job.preview_payload.as_deref())
+            .filter(|outline| {
+                job.phase == ARTICLE_JOB_PHASE_AWAITING_USER_INPUT && outline.awaiting_review()
+            })
+            .ok_or_else(|| {
+                Error::BadRequest(format!(
+                    "Article job {} is not waiting for outline approval",
+                    id
+                ))
+            })?;
+        let approved = outline.with_author_choice(headline, beats)?;
+        let updated = self.resume_job_with_outline(job, &approved).await?;
+        let details = serde_json::json!({
+            "headline_option": outline
+                .headline_options
+                .iter()
+                .position(|option| Some(option) == approved.chosen_headline.as_ref()),
+            "beats_changed": approved.beats != outline.beats,
+            "beats": approved.beats.len(),
+        })
+        .to_string();
+        log_system_audit(
+            &self.state.db,
+            "article_job_outline_approved",
+            "article_job",
+            id,
+            Some(details),
+        )
+        .await?;
+        Ok(Some(updated))
+    }
 }
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::sync::atomic::Ordering;
 
+use chrono::NaiveDateTime;
 use sea_orm::{ActiveModelTrait, ActiveValue};
 use tokio::sync::OwnedSemaphorePermit;
 use tracing::{event, Level};
@@ -10,6 +11,7 @@ use crate::create::clarify::{append_clarification_fallback, parse_clarification_
 use crate::entities::{article_job, content};
 use crate::error::Error;
 use crate::image_jobs::enqueue_pending_images;
+use crate::llm::article_generator::{parse_outline_payload, ArticleOutline};
 
 use super::definitions::{
     is_in_progress_job_status, is_terminal_job_status, ArticleJobService, ArticleJobTrace,
@@ -21,7 +23,7 @@ use super::definitions::{
 use super::support::{
     build_generation_future, build_job_preview_payload, load_article_image_progress,
     load_article_job, load_article_job_for_article, load_job_article_content, log_job_transition,
-    merge_job_usage_counters, now,
+    merge_job_usage_counters, merge_preview_payload_value, now,
 };
 
 impl ArticleJobService {
@@ -30,7 +32,8 @@ impl ArticleJobService {
             return Ok(None);
         };
         let job = if job.phase == ARTICLE_JOB_PHASE_AWAITING_USER_INPUT {
-            self.maybe_resume_clarification_timeout(job).await?
+            let job = self.maybe_resume_clarification_timeout(job).await?;
+            self.maybe_resume_outline_review_timeout(job).await?
         } else {
             self.reconcile_job_model(job, true).await?
         };
@@ -276,6 +279,7 @@ impl ArticleJobService {
         let updated = self.resume_job_with_prompt(job.clone(), prompt).await?;
         let details = serde_json::json!({
             "question": clarification.question,
+            "question_count": clarification.questions().len(),
             "auto_resume_at": clarification.auto_resume_at,
         })
         .to_string();
@@ -290,6 +294,46 @@ impl ArticleJobService {
         Ok(updated)
     }
 
+    async fn maybe_resume_outline_review_timeout(
+        &self,
+        job: article_job::Model,
+    ) -> Result<article_job::Model, Error> {
+        if job.phase != ARTICLE_JOB_PHASE_AWAITING_USER_INPUT {
+            return Ok(job);
+        }
+        let Some(mut outline) = parse_outline_payload(job.preview_payload.as_deref())
+            .filter(|outline| outline.awaiting_review())
+        else {
+            return Ok(job);
+        };
+        let Some(review_deadline) = outline
+            .review_deadline
+            .as_deref()
+            .and_then(|value| NaiveDateTime::parse_from_str(value, "%F %T").ok())
+        else {
+            return Ok(job);
+        };
+        if review_deadline > now() {
+            return Ok(job);
+        }
+
+        outline.approved = true;
+        let updated = self.resume_job_with_outline(job.clone(), &outline).await?;
+        let details = serde_json::json!({
+            "review_deadline": outline.review_deadline,
+        })
+        .to_string();
+        log_system_audit(
+            &self.state.db,
+            "article_job_outline_review_timed_out",
+            "article_job",
+            &job.id,
+            Some(details),
+        )
+        .await?;
+        Ok(updated)
+    }
+
     async fn mark_job_processing(
         &self,
         job: article_job::Model,
@@ -314,21 +358,40 @@ impl ArticleJobService {
         &self,
         job: article_job::Model,
         prompt: String,
+    ) -> Result<article_job::Model, Error> {
+        self.resume_job(job, prompt, None).await
+    }
+
+    /// Requeues a job with its reviewed outline kept in the preview payload, where the
+    /// planning step picks it up instead of planning again.
+    pub(super) async fn resume_job_with_outline(
+        &self,
+        job: article_job::Model,
+        outline: &ArticleOutline,
+    ) -> Result<article_job::Model, Error> {
+        let prompt = job.prompt.clone();
+        let preview_payload =
+            merge_preview_payload_value(job.preview_payload.as_deref(), outline.preview_payload());
+        self.resume_job(job, prompt, Some(preview_payload)).await
+    }
+
+    async fn resume_job(
+        &self,
+        job: article_job::Model,
+        prompt: String,
+        preview_payload: Option<String>,
     ) -> Result<article_job::Model, Error> {
         let mut active: article_job::ActiveModel = job.into();
         let reference_time = now();
         active.prompt = ActiveValue::set(prompt);
         active.phase = ActiveValue::set(ARTICLE_JOB_PHASE_QUEUED.to_string());
         active.status = ActiveValue::set(ARTICLE_JOB_STATUS_QUEUED.to_string());
-        active.preview_payload = ActiveValue::set(None);
+        active.preview_payload = ActiveValue::set(preview_payload);
         active.error_summary = ActiveValue::set(None);
         active.finished_at = ActiveValue::set(None);
         active.updated_at = ActiveValue::set(reference_time);
         active.update(&self.state.db).await.map_err(|e| {
-            Error::Database(format!(
-                "Error resuming article job from clarification: {}",
-                e
-            ))
+            Error::Database(format!("Error resuming article job from user input: {}", e))
         })
     }
 
@@ -11,6 +11,8 @@ use crate::create::create_article;
 use crate::entities::{article_job, content, content_image, prelude::*};
 use crate::error::Error;
 use crate::image_status::{is_pending_status, IMAGE_STATUS_COMPLETED, IMAGE_STATUS_FAILED};
+use crate::llm::prompt_registry::find_supported_translation_language;
+use crate::services::article_language::article_source_language;
 
 use super::definitions::{
     ArticleJobFeatureType, ArticleJobTrace, ImageProgress, ARTICLE_JOB_STATUS_PROCESSING,
@@ -143,8 +145,21 @@ pub(super) fn build_generation_future(
     let prompt = job.prompt.clone();
     let author_email = job.author_email.clone();
     let research_mode = feature_type.research_mode();
+    let language = job
+        .language_code
+        .as_deref()
+        .and_then(find_supported_translation_language)
+        .unwrap_or_else(article_source_language);
     Ok(Box::pin(async move {
-        create_article(&state, job_id, prompt, author_email, research_mode).await
+        create_article(
+            &state,
+            job_id,
+            prompt,
+            author_email,
+            research_mode,
+            &language,
+        )
+        .await
     }))
 }
 
@@ -157,6 +172,9 @@ pub(super) fn default_usage_counters(prompt_chars: usize) -> Value {
         "searches": 0,
         "sources": 0,
         "fetched_content_chars": 0,
+        "search_cache_hits": 0,
+        "source_cache_hits": 0,
+        "cached_content_chars": 0,
         "image_total": 0,
         "image_completed": 0,
         "image_processing": 0,
@@ -201,6 +219,20 @@ pub(super) fn merge_job_usage_counters(
     value.to_string()
 }
 
+/// Overlays the top-level keys of `payload` on the stored preview payload.
+pub(super) fn merge_preview_payload_value(existing: Option<&str>, payload: Value) -> String {
+    let mut object = existing
+        .and_then(|value| serde_json::from_str::<Value>(value).ok())
+        .and_then(|value| value.as_object().cloned())
+        .unwrap_or_default();
+    if let Some(payload_object) = payload.as_object() {
+        for (key, value) in payload_object {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+    Value::Object(object).to_string()
+}
+
 pub(super) fn build_job_preview_payload(
     existing: Option<&str>,
     article: &content::Model,
@@ -1,7 +1,9 @@
 use crate::llm::prompt_registry::{
-    find_supported_translation_language, SupportedTranslationLanguage,
+    find_supported_translation_language, registered_translation_languages,
+    SupportedTranslationLanguage,
 };
 use crate::llm::translate::default_translation_fallback_language;
+use crate::repositories::translations::language_row_id;
 
 const DEFAULT_SOURCE_LANGUAGE_CODE: &str = "en";
 pub const AUTOMATIC_LANGUAGE_QUERY_VALUE: &str = "auto";
@@ -22,7 +24,7 @@ pub enum ServedLanguageSource {
     EnglishFallback,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ArticleLanguageSelection {
     pub source_language: SupportedTranslationLanguage,
     pub preferred_language: SupportedTranslationLanguage,
@@ -38,24 +40,60 @@ pub fn article_source_language() -> SupportedTranslationLanguage {
         .expect("English must remain the default source article language")
 }
 
+/// The value stored in `content.language_id` for an article written in `language`.
+pub fn article_language_id(language: &SupportedTranslationLanguage) -> String {
+    language_row_id(&language.code)
+}
+
+/// The language an article was written in. Articles without a recorded
+/// language predate the create form's language choice and were written in
+/// English.
+pub fn article_source_language_for(language_id: Option<&str>) -> SupportedTranslationLanguage {
+    language_id
+        .and_then(|language_id| {
+            registered_translation_languages()
+                .into_iter()
+                .find(|language| article_language_id(language) == language_id.trim())
+        })
+        .unwrap_or_else(article_source_language)
+}
+
+/// The languages an article can be read in without falling back: its source
+/// language first, then each language with a complete cached translation.
+pub fn article_alternate_languages(
+    source_language: &SupportedTranslationLanguage,
+    cached_translations: &[SupportedTranslationLanguage],
+) -> Vec<SupportedTranslationLanguage> {
+    std::iter::once(source_language.clone())
+        .chain(
+            cached_translations
+                .iter()
+                .filter(|language| language.code != source_language.code)
+                .cloned(),
+        )
+        .collect()
+}
+
 pub fn resolve_article_language(
-    explicit_language: Option<SupportedTranslationLanguage>,
-    route_language: Option<SupportedTranslationLanguage>,
-    saved_language: Option<SupportedTranslationLanguage>,
-    browser_language: Option<SupportedTranslationLanguage>,
+    source_language: &SupportedTranslationLanguage,
+    explicit_language: Option<&SupportedTranslationLanguage>,
+    route_language: Option<&SupportedTranslationLanguage>,
+    saved_language: Option<&SupportedTranslationLanguage>,
+    browser_language: Option<&SupportedTranslationLanguage>,
     available_translations: &[SupportedTranslationLanguage],
 ) -> ArticleLanguageSelection {
-    let source_language = article_source_language();
-
     let (preferred_language, preferred_language_source) = match explicit_language {
-        Some(language) => (language, PreferredLanguageSource::Explicit),
+        Some(language) => (language.clone(), PreferredLanguageSource::Explicit),
         None => match route_language {
-            Some(language) => (language, PreferredLanguageSource::Route),
+            Some(language) => (language.clone(), PreferredLanguageSource::Route),
             None => match saved_language {
-                Some(language) => (language, PreferredLanguageSource::Cookie),
+                Some(language) => (language.clone(), PreferredLanguageSource::Cookie),
                 None => match browser_language {
-                    Some(language) => (language, PreferredLanguageSource::Browser),
-                    None => (source_language, PreferredLanguageSource::ArticleSource),
+                    Some(language) => (language.clone(), PreferredLanguageSource::Browser),
+                    None => (
+                        source_language.clone(),
+                        PreferredLanguageSource::ArticleSource,
+                    ),
                 },
             },
         },
@@ -74,17 +112,20 @@ pub fn resolve_article_language(
             .any(|language| language.code == fallback_language.code);
 
     let (served_language, served_language_source) = if translation_available {
-        (preferred_language, ServedLanguageSource::Preferred)
+        (preferred_language.clone(), ServedLanguageSource::Preferred)
     } else if fallback_language.code != source_language.code && english_fallback_available {
         (fallback_language, ServedLanguageSource::EnglishFallback)
     } else if fallback_language.code == source_language.code {
-        (source_language, ServedLanguageSource::EnglishFallback)
+        (
+            source_language.clone(),
+            ServedLanguageSource::EnglishFallback,
+        )
     } else {
-        (source_language, ServedLanguageSource::ArticleSource)
+        (source_language.clone(), ServedLanguageSource::ArticleSource)
     };
 
     ArticleLanguageSelection {
-        source_language,
+        source_language: source_language.clone(),
         preferred_language,
         preferred_language_source,
         served_language,
@@ -114,9 +155,9 @@ pub fn resolve_requested_article_language(
 }
 
 pub fn requested_article_language_query_value(
-    language: Option<SupportedTranslationLanguage>,
-) -> &'static str {
-    language.map_or(AUTOMATIC_LANGUAGE_QUERY_VALUE, |language| language.code)
+    language: Option<&SupportedTranslationLanguage>,
+) -> &str {
+    language.map_or(AUTOMATIC_LANGUAGE_QUERY_VALUE, |language| &language.code)
 }
 
 #[cfg(test)]
@@ -124,7 +165,8 @@ mod tests {
     use crate::llm::prompt_registry::find_supported_translation_language;
 
     use super::{
-        article_source_language, requested_article_language_query_value, resolve_article_language,
+        article_language_id, article_source_language, article_source_language_for,
+        requested_article_language_query_value, resolve_article_language,
         resolve_requested_article_language, PreferredLanguageSource, ServedLanguageSource,
     };
 
@@ -133,16 +175,51 @@ mod tests {
         assert_eq!(article_source_language().code, "en");
     }
 
+    #[test]
+    fn article_source_language_round_trips_through_the_language_id() {
+        let portuguese = find_supported_translation_language("pt").unwrap();
+
+        assert_eq!(
+            article_source_language_for(Some(&article_language_id(&portuguese))).code,
+            "pt"
+        );
+        assert_eq!(article_source_language_for(None).code, "en");
+        assert_eq!(article_source_language_for(Some("unknown")).code, "en");
+    }
+
+    #[test]
+    fn non_english_articles_are_served_in_their_own_language() {
+        let portuguese = find_supported_translation_language("pt").unwrap();
+
+        let selection = resolve_article_language(&portuguese, None, None, None, None, &[]);
+
+        assert_eq!(selection.source_language.code, "pt");
+        assert!(!selection.translation_requested);
+        assert_eq!(selection.served_language.code, "pt");
+
+        let english = find_supported_translation_language("en");
+        let selection =
+            resolve_article_language(&portuguese, english.as_ref(), None, None, None, &[]);
+
+        assert!(selection.translation_requested);
+        assert_eq!(selection.served_language.code, "pt");
+        assert_eq!(
+            selection.served_language_source,
+            ServedLanguageSource::ArticleSource
+        );
+    }
+
     #[test]
     fn explicit_language_beats_browser_language() {
         let browser_language = find_supported_translation_language("fr");
         let available_translations = [find_supported_translation_language("pt").unwrap()];
 
         let selection = resolve_article_language(
-            find_supported_translation_language("pt"),
+            &article_source_language(),
+            find_supported_translation_language("pt").as_ref(),
             None,
             None,
-            browser_language,
+            browser_language.as_ref(),
             &available_translations,
         );
 
@@ -163,8 +240,14 @@ mod tests {
         let browser_language = find_supported_translation_language("es");
         let available_translations = [find_supported_translation_language("es").unwrap()];
 
-        let selection =
-            resolve_article_language(None, None, None, browser_language, &available_translations);
+        let selection = resolve_article_language(
+            &article_source_language(),
+            None,
+            None,
+            None,
+            browser_language.as_ref(),
+            &available_translations,
+        );
 
         assert_eq!(selection.preferred_language.code, "es");
         assert_eq!(
@@ -178,7 +261,14 @@ mod tests {
     fn missing_translation_falls_back_to_source_article_immediately() {
         let browser_language = find_supported_translation_language("pt");
 
-        let selection = resolve_article_language(None, None, None, browser_language, &[]);
+        let selection = resolve_article_language(
+            &article_source_language(),
+            None,
+            None,
+            None,
+            browser_language.as_ref(),
+            &[],
+        );
 
         assert_eq!(selection.preferred_language.code, "pt");
         assert!(selection.translation_requested);
@@ -195,8 +285,14 @@ mod tests {
         let browser_language = find_supported_translation_language("de");
         let available_translations = [find_supported_translation_language("de").unwrap()];
 
-        let selection =
-            resolve_article_language(None, None, None, browser_language, &available_translations);
+        let selection = resolve_article_language(
+            &article_source_language(),
+            None,
+            None,
+            None,
+            browser_language.as_ref(),
+            &available_translations,
+        );
 
         assert_eq!(selection.preferred_language.code, "de");
         assert_eq!(
@@ -211,7 +307,14 @@ mod tests {
         let saved_language = find_supported_translation_language("pt");
         let browser_language = find_supported_translation_language("fr");
 
-        let selection = resolve_article_language(None, None, saved_language, browser_language, &[]);
+        let selection = resolve_article_language(
+            &article_source_language(),
+            None,
+            None,
+            saved_language.as_ref(),
+            browser_language.as_ref(),
+            &[],
+        );
 
         assert_eq!(selection.preferred_language.code, "pt");
         assert_eq!(
@@ -226,10 +329,11 @@ mod tests {
         let browser_language = find_supported_translation_language("fr");
 
         let selection = resolve_article_language(
+            &article_source_language(),
             None,
-            find_supported_translation_language("es"),
-            saved_language,
-            browser_language,
+            find_supported_translation_language("es").as_ref(),
+            saved_language.as_ref(),
+            browser_language.as_ref(),
             &[],
         );
 
@@ -263,7 +367,10 @@ mod tests {
     fn requested_article_language_query_value_uses_canonical_codes() {
         let requested = find_supported_translation_language("pt");
 
-        assert_eq!(requested_article_language_query_value(requested), "pt");
+        assert_eq!(
+            requested_article_language_query_value(requested.as_ref()),
+            "pt"
+        );
         assert_eq!(requested_article_language_query_value(None), "auto");
     }
 }
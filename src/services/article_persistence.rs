@@ -8,6 +8,8 @@ use uuid::Uuid;
 
 use crate::entities::{content, content_image, prelude::*};
 use crate::error::Error;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::services::article_language::article_language_id;
 
 pub struct SaveContentRequest {
     pub id: String,
@@ -20,6 +22,7 @@ pub struct SaveContentRequest {
     pub title: String,
     pub instructions: String,
     pub author_email: Option<String>,
+    pub source_language: SupportedTranslationLanguage,
 }
 
 pub struct PreparedContentUpsert {
@@ -154,6 +157,7 @@ fn build_saved_content_model(input: SavedContentModelInput, now: DateTime) -> co
         longview_count: 0,
         impression_count: 0,
         click_count: 0,
+        language_id: Some(article_language_id(&input.request.source_language)),
         author_email: input.request.author_email,
         published,
         recovered_from_dead_link: input.recovered_from_dead_link,
@@ -188,6 +192,7 @@ fn next_available_slug(base_slug: &str, existing_slugs: &[String]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::article_language::article_source_language;
 
     fn sample_time() -> DateTime {
         chrono::NaiveDate::from_ymd_opt(2026, 4, 18)
@@ -230,6 +235,7 @@ mod tests {
                     title: "Title".to_string(),
                     instructions: "prompt".to_string(),
                     author_email: None,
+                    source_language: article_source_language(),
                 },
                 slug: "article-slug".to_string(),
                 recovered_from_dead_link: false,
@@ -255,6 +261,7 @@ mod tests {
                     title: "Title".to_string(),
                     instructions: "prompt".to_string(),
                     author_email: Some("author@example.com".to_string()),
+                    source_language: article_source_language(),
                 },
                 slug: "article-slug".to_string(),
                 recovered_from_dead_link: false,
@@ -280,6 +287,7 @@ mod tests {
                     title: "Title".to_string(),
                     instructions: "prompt".to_string(),
                     author_email: None,
+                    source_language: article_source_language(),
                 },
                 slug: "article-slug".to_string(),
                 recovered_from_dead_link: true,
@@ -305,6 +313,7 @@ mod tests {
                     title: "Title".to_string(),
                     instructions: "prompt".to_string(),
                     author_email: None,
+                    source_language: article_source_language(),
                 },
                 slug: "article-slug".to_string(),
                 recovered_from_dead_link: false,
@@ -314,4 +323,31 @@ mod tests {
 
         assert_eq!(model.prompt_version, 42);
     }
+
+    #[test]
+    fn build_saved_content_model_records_the_source_language() {
+        let portuguese = crate::test_support::preferred_language("pt");
+        let model = build_saved_content_model(
+            SavedContentModelInput {
+                request: SaveContentRequest {
+                    id: "article-id".to_string(),
+                    markdown: "# Título\n\nCorpo".to_string(),
+                    prompt_version: 1,
+                    start_time: sample_time(),
+                    model: "test-model".to_string(),
+                    description: "desc".to_string(),
+                    image_id: None,
+                    title: "Título".to_string(),
+                    instructions: "prompt".to_string(),
+                    author_email: None,
+                    source_language: portuguese.clone(),
+                },
+                slug: "article-slug".to_string(),
+                recovered_from_dead_link: false,
+            },
+            sample_time(),
+        );
+
+        assert_eq!(model.language_id, Some(article_language_id(&portuguese)));
+    }
 }
@@ -3,23 +3,45 @@ use std::collections::{HashMap, HashSet};
 use sea_orm::DatabaseConnection;
 
 use crate::article_id::canonical_article_id;
-use crate::entities::content;
+use crate::entities::{content, content_image, translation_override};
 use crate::error::Error;
+use crate::llm::glossary::translation_glossary;
 use crate::llm::prompt_registry::{
     supported_translation_languages, translation_prompt, SupportedTranslationLanguage,
 };
 use crate::llm::translate::Translate;
+use crate::repositories::translation_overrides::{find_translation_overrides, is_current_override};
 use crate::repositories::translations::{
-    delete_translations_for_keys, find_translations_for_keys, save_translations,
-    translation_cache_key, StoredTranslation, TranslationField, TranslationWrite,
+    delete_translations_for_keys, find_translations_for_keys, image_translation_cache_key,
+    save_translations, translation_cache_key, StoredTranslation, TranslationField,
+    TranslationWrite,
 };
+use crate::services::article_language::article_source_language_for;
+use crate::services::translation_memory::{translate_markdown_with_memory, SegmentReuse};
+use crate::services::translation_quality::{check_translated_field, check_translated_markdown};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ArticleSourceText<'a> {
     pub article_id: &'a str,
+    /// The language the article was written in.
+    pub source_language: SupportedTranslationLanguage,
     pub title: &'a str,
     pub description: &'a str,
     pub markdown: &'a str,
+    pub images: &'a [ArticleImageSourceText],
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArticleImageSourceText {
+    pub image_id: String,
+    pub alt_text: String,
+    pub caption: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageTextSource {
+    pub article_id: String,
+    pub image: ArticleImageSourceText,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -32,9 +54,11 @@ pub struct ArticleSummarySourceText<'a> {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct OwnedArticleSourceText {
     pub article_id: String,
+    pub source_language: SupportedTranslationLanguage,
     pub title: String,
     pub description: String,
     pub markdown: String,
+    pub images: Vec<ArticleImageSourceText>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -50,6 +74,29 @@ pub struct CachedArticleTranslation {
     pub title: String,
     pub description: String,
     pub markdown: String,
+    pub images: Vec<CachedImageTextTranslation>,
+    /// Set when the body was assembled from translation memory by this call.
+    pub markdown_segments: Option<SegmentReuse>,
+}
+
+impl CachedArticleTranslation {
+    /// Image alt text and captions do not gate serving a translation; images without
+    /// cached text fall back to the source until a translation job fills them in.
+    pub fn lacks_image_text(&self, source: &ArticleSourceText<'_>) -> bool {
+        source.images.iter().any(|image| {
+            !self.images.iter().any(|translated| {
+                translated.image_id == image.image_id
+                    && translated.caption.is_some() == image.caption.is_some()
+            })
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedImageTextTranslation {
+    pub image_id: String,
+    pub alt_text: String,
+    pub caption: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -60,10 +107,10 @@ pub struct CachedArticleSummaryTranslation {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-struct CachedArticleTranslationFields {
-    title: Option<String>,
-    description: Option<String>,
-    markdown: Option<String>,
+pub struct CachedArticleTranslationFields {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub markdown: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -72,6 +119,13 @@ struct CachedArticleSummaryTranslationFields {
     description: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ImageTextCacheKeys {
+    image_id: String,
+    alt_text: String,
+    caption: Option<String>,
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 struct MissingArticleTranslationFields {
     title: bool,
@@ -89,9 +143,11 @@ impl OwnedArticleSourceText {
     pub fn as_ref(&self) -> ArticleSourceText<'_> {
         ArticleSourceText {
             article_id: &self.article_id,
+            source_language: self.source_language.clone(),
             title: &self.title,
             description: &self.description,
             markdown: &self.markdown,
+            images: &self.images,
         }
     }
 }
@@ -106,18 +162,45 @@ impl OwnedArticleSummarySourceText {
     }
 }
 
-pub fn owned_article_source_text(article: &content::Model) -> Option<OwnedArticleSourceText> {
+pub fn owned_article_source_text(
+    article: &content::Model,
+    images: &[content_image::Model],
+) -> Option<OwnedArticleSourceText> {
     Some(OwnedArticleSourceText {
         article_id: canonical_article_id(&article.id),
+        source_language: article_source_language_for(article.language_id.as_deref()),
         title: article.title.clone(),
         description: article.description.clone(),
         markdown: article.markdown.clone()?,
+        images: article_image_source_texts(images),
     })
 }
 
+pub fn article_image_source_texts(images: &[content_image::Model]) -> Vec<ArticleImageSourceText> {
+    let mut sources = images
+        .iter()
+        .map(article_image_source_text)
+        .collect::<Vec<_>>();
+    sources.sort_by(|left, right| left.image_id.cmp(&right.image_id));
+    sources
+}
+
+pub fn article_image_source_text(image: &content_image::Model) -> ArticleImageSourceText {
+    ArticleImageSourceText {
+        image_id: image.id.clone(),
+        alt_text: image.alt_text.clone(),
+        caption: image
+            .caption
+            .as_deref()
+            .map(str::trim)
+            .filter(|caption| !caption.is_empty())
+            .map(str::to_string),
+    }
+}
+
 pub fn article_translation_job_key(
     article_id: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> String {
     format!("{}:{}", article_id, language.code)
 }
@@ -125,7 +208,7 @@ pub fn article_translation_job_key(
 pub async fn load_cached_article_summary_translations(
     db: &DatabaseConnection,
     sources: &[OwnedArticleSummarySourceText],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> Result<HashMap<String, CachedArticleSummaryTranslation>, Error> {
     if sources.is_empty() {
         return Ok(HashMap::new());
@@ -145,35 +228,126 @@ pub async fn load_cached_article_summary_translations(
         .flat_map(|(_, keys)| keys.iter().cloned())
         .collect::<Vec<_>>();
     let translations = find_translations_for_keys(db, &cache_keys).await?;
+    let article_ids = sources
+        .iter()
+        .map(|source| canonical_article_id(&source.article_id))
+        .collect::<Vec<_>>();
+    let overrides = find_translation_overrides(db, &article_ids, language).await?;
 
     Ok(keys_by_article
         .into_iter()
-        .filter_map(|(article_id, keys)| {
-            assemble_cached_article_summary_translation(&keys, &translations, language)
-                .map(|translation| (article_id, translation))
+        .zip(sources)
+        .filter_map(|((article_id, keys), source)| {
+            assemble_cached_article_summary_translation(&keys, &translations, language).map(
+                |translation| {
+                    let translation = apply_summary_translation_overrides(
+                        translation,
+                        source.as_ref(),
+                        &overrides,
+                    );
+                    (article_id, translation)
+                },
+            )
         })
         .collect())
 }
 
+pub async fn load_cached_image_text_translations(
+    db: &DatabaseConnection,
+    sources: &[ImageTextSource],
+    language: &SupportedTranslationLanguage,
+) -> Result<HashMap<String, CachedImageTextTranslation>, Error> {
+    if sources.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let keys = sources
+        .iter()
+        .map(|source| image_text_keys(&source.article_id, &source.image))
+        .collect::<Vec<_>>();
+    let cache_keys = keys
+        .iter()
+        .flat_map(image_text_key_list)
+        .collect::<Vec<_>>();
+    let translations = find_translations_for_keys(db, &cache_keys).await?;
+    let translation_map = language_translation_map(&translations, language);
+
+    Ok(keys
+        .iter()
+        .filter_map(|keys| assemble_cached_image_text(keys, &translation_map))
+        .map(|translation| (translation.image_id.clone(), translation))
+        .collect())
+}
+
 pub async fn cached_translation_languages(
     db: &DatabaseConnection,
-    source: ArticleSourceText<'_>,
+    source: &ArticleSourceText<'_>,
 ) -> Result<Vec<SupportedTranslationLanguage>, Error> {
-    let cache_keys = cache_keys(source);
+    let cache_keys = article_field_cache_keys(source);
     let translations = find_translations_for_keys(db, &cache_keys).await?;
 
     Ok(complete_cached_languages(&cache_keys, &translations))
 }
 
+/// `cached_translation_languages` for several articles with a single lookup,
+/// keyed by article id.
+pub async fn cached_translation_languages_by_article(
+    db: &DatabaseConnection,
+    sources: &[OwnedArticleSourceText],
+) -> Result<HashMap<String, Vec<SupportedTranslationLanguage>>, Error> {
+    let keys_by_article = sources
+        .iter()
+        .map(|source| {
+            (
+                source.article_id.clone(),
+                article_field_cache_keys(&source.as_ref()).to_vec(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let all_keys = keys_by_article
+        .iter()
+        .flat_map(|(_, keys)| keys.iter().cloned())
+        .collect::<Vec<_>>();
+    let translations = find_translations_for_keys(db, &all_keys).await?;
+    let by_language = cached_keys_by_language(&translations);
+
+    Ok(keys_by_article
+        .into_iter()
+        .map(|(article_id, keys)| {
+            let languages = complete_languages_for_keys(&keys, &by_language);
+            (article_id, languages)
+        })
+        .collect())
+}
+
 pub async fn load_cached_article_translation(
     db: &DatabaseConnection,
-    source: ArticleSourceText<'_>,
-    language: SupportedTranslationLanguage,
+    source: &ArticleSourceText<'_>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<Option<CachedArticleTranslation>, Error> {
     let cache_keys = cache_keys(source);
     let translations = find_translations_for_keys(db, &cache_keys).await?;
+    let overrides = article_translation_overrides(db, source.article_id, language).await?;
 
     Ok(assemble_cached_article_translation(
+        &cache_keys,
+        &image_text_cache_keys(source),
+        &translations,
+        language,
+    )
+    .map(|translation| apply_article_translation_overrides(translation, source, &overrides)))
+}
+
+/// Machine translations of the article text fields, without reviewed overrides.
+pub async fn load_machine_translation_fields(
+    db: &DatabaseConnection,
+    source: &ArticleSourceText<'_>,
+    language: &SupportedTranslationLanguage,
+) -> Result<CachedArticleTranslationFields, Error> {
+    let cache_keys = article_field_cache_keys(source);
+    let translations = find_translations_for_keys(db, &cache_keys).await?;
+
+    Ok(cached_translation_fields(
         &cache_keys,
         &translations,
         language,
@@ -184,7 +358,7 @@ pub async fn ensure_cached_article_summary_translation<T: Translate>(
     translator: &T,
     db: &DatabaseConnection,
     source: ArticleSummarySourceText<'_>,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> Result<CachedArticleSummaryTranslation, Error> {
     let cache_keys = summary_cache_keys(source);
     let translations = find_translations_for_keys(db, &cache_keys).await?;
@@ -196,11 +370,19 @@ pub async fn ensure_cached_article_summary_translation<T: Translate>(
 
     let title = match cached_fields.title {
         Some(title) => title,
-        None => translator.translate(source.title, language).await?,
+        None => {
+            let title = translator.translate(source.title, language).await?;
+            check_translated_field(source.title, &title, language)?;
+            title
+        }
     };
     let description = match cached_fields.description {
         Some(description) => description,
-        None => translator.translate(source.description, language).await?,
+        None => {
+            let description = translator.translate(source.description, language).await?;
+            check_translated_field(source.description, &description, language)?;
+            description
+        }
     };
     let writes = missing_summary_translation_writes(
         &cache_keys,
@@ -209,19 +391,24 @@ pub async fn ensure_cached_article_summary_translation<T: Translate>(
         [&title, &description],
     );
     save_translations(db, &writes).await?;
-
-    Ok(CachedArticleSummaryTranslation {
-        language,
-        title,
-        description,
-    })
+    let overrides = article_translation_overrides(db, source.article_id, language).await?;
+
+    Ok(apply_summary_translation_overrides(
+        CachedArticleSummaryTranslation {
+            language: language.clone(),
+            title,
+            description,
+        },
+        source,
+        &overrides,
+    ))
 }
 
 pub async fn ensure_cached_article_translation<T: Translate>(
     translator: &T,
     db: &DatabaseConnection,
-    source: ArticleSourceText<'_>,
-    language: SupportedTranslationLanguage,
+    source: &ArticleSourceText<'_>,
+    language: &SupportedTranslationLanguage,
 ) -> Result<CachedArticleTranslation, Error> {
     let cache_keys = cache_keys(source);
     let translations = find_translations_for_keys(db, &cache_keys).await?;
@@ -234,66 +421,290 @@ pub async fn ensure_cached_article_translation<T: Translate>(
 
     let title = match cached_fields.title {
         Some(title) => title,
-        None => translator.translate(source.title, language).await?,
+        None => {
+            let title = translator.translate(source.title, language).await?;
+            check_translated_field(source.title, &title, language)?;
+            title
+        }
     };
     let description = match cached_fields.description {
         Some(description) => description,
-        None => translator.translate(source.description, language).await?,
+        None => {
+            let description = translator.translate(source.description, language).await?;
+            check_translated_field(source.description, &description, language)?;
+            description
+        }
     };
-    let markdown = match cached_fields.markdown {
-        Some(markdown) => markdown,
-        None => translator.translate(source.markdown, language).await?,
+    let (markdown, markdown_segments, segment_writes) = match cached_fields.markdown {
+        Some(markdown) => (markdown, None, Vec::new()),
+        None => {
+            let translation =
+                translate_markdown_with_memory(translator, db, source.markdown, language).await?;
+            check_translated_markdown(source.markdown, &translation.markdown, language)?;
+            (
+                translation.markdown,
+                Some(translation.reuse),
+                translation.writes,
+            )
+        }
     };
-    let writes = missing_translation_writes(
+    let mut writes = missing_translation_writes(
         &cache_keys,
         language,
         &missing_fields,
         [&title, &description, &markdown],
     );
+    writes.extend(segment_writes);
+    let translation_map = language_translation_map(&translations, language);
+    let mut images = Vec::with_capacity(source.images.len());
+    for (image, keys) in source.images.iter().zip(image_text_cache_keys(source)) {
+        let alt_text = match translation_map.get(keys.alt_text.as_str()) {
+            Some(alt_text) => (*alt_text).to_string(),
+            None => {
+                let alt_text = translator.translate(&image.alt_text, language).await?;
+                check_translated_field(&image.alt_text, &alt_text, language)?;
+                writes.push(TranslationWrite {
+                    cache_key: keys.alt_text.clone(),
+                    language: language.clone(),
+                    text: alt_text.clone(),
+                });
+                alt_text
+            }
+        };
+        let caption = match (image.caption.as_deref(), keys.caption.as_deref()) {
+            (Some(source_caption), Some(caption_key)) => match translation_map.get(caption_key) {
+                Some(caption) => Some((*caption).to_string()),
+                None => {
+                    let caption = translator.translate(source_caption, language).await?;
+                    check_translated_field(source_caption, &caption, language)?;
+                    writes.push(TranslationWrite {
+                        cache_key: caption_key.to_string(),
+                        language: language.clone(),
+                        text: caption.clone(),
+                    });
+                    Some(caption)
+                }
+            },
+            _ => None,
+        };
+        images.push(CachedImageTextTranslation {
+            image_id: keys.image_id,
+            alt_text,
+            caption,
+        });
+    }
     save_translations(db, &writes).await?;
-
-    Ok(CachedArticleTranslation {
-        language,
-        title,
-        description,
-        markdown,
-    })
+    let overrides = article_translation_overrides(db, source.article_id, language).await?;
+
+    Ok(apply_article_translation_overrides(
+        CachedArticleTranslation {
+            language: language.clone(),
+            title,
+            description,
+            markdown,
+            images,
+            markdown_segments,
+        },
+        source,
+        &overrides,
+    ))
 }
 
 pub async fn invalidate_cached_article_translations(
     db: &DatabaseConnection,
-    source: ArticleSourceText<'_>,
+    source: &ArticleSourceText<'_>,
 ) -> Result<u64, Error> {
     delete_translations_for_keys(db, &cache_keys(source)).await
 }
 
+async fn article_translation_overrides(
+    db: &DatabaseConnection,
+    article_id: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<Vec<translation_override::Model>, Error> {
+    find_translation_overrides(db, &[canonical_article_id(article_id)], language).await
+}
+
+/// Returns the reviewed text for `field` when a current override exists.
+pub fn current_override_text<'a>(
+    overrides: &'a [translation_override::Model],
+    article_id: &str,
+    field: TranslationField,
+    source_text: &str,
+) -> Option<&'a str> {
+    let article_id = canonical_article_id(article_id);
+    overrides
+        .iter()
+        .find(|row| {
+            canonical_article_id(&row.article_id) == article_id
+                && row.field == field.as_str()
+                && is_current_override(row, source_text)
+        })
+        .map(|row| row.translation.as_str())
+}
+
+fn apply_article_translation_overrides(
+    mut translation: CachedArticleTranslation,
+    source: &ArticleSourceText<'_>,
+    overrides: &[translation_override::Model],
+) -> CachedArticleTranslation {
+    let article_id = source.article_id;
+    if let Some(title) =
+        current_override_text(overrides, article_id, TranslationField::Title, source.title)
+    {
+        translation.title = title.to_string();
+    }
+    if let Some(description) = current_override_text(
+        overrides,
+        article_id,
+        TranslationField::Description,
+        source.description,
+    ) {
+        translation.description = description.to_string();
+    }
+    if let Some(markdown) = current_override_text(
+        overrides,
+        article_id,
+        TranslationField::Markdown,
+        source.markdown,
+    ) {
+        translation.markdown = markdown.to_string();
+    }
+    translation
+}
+
+fn apply_summary_translation_overrides(
+    mut translation: CachedArticleSummaryTranslation,
+    source: ArticleSummarySourceText<'_>,
+    overrides: &[translation_override::Model],
+) -> CachedArticleSummaryTranslation {
+    let article_id = source.article_id;
+    if let Some(title) =
+        current_override_text(overrides, article_id, TranslationField::Title, source.title)
+    {
+        translation.title = title.to_string();
+    }
+    if let Some(description) = current_override_text(
+        overrides,
+        article_id,
+        TranslationField::Description,
+        source.description,
+    ) {
+        translation.description = description.to_string();
+    }
+    translation
+}
+
 fn translation_prompt_version() -> i32 {
     translation_prompt().version
 }
 
-fn cache_keys(source: ArticleSourceText<'_>) -> Vec<String> {
-    vec![
+fn translation_glossary_version(source_text: &str) -> String {
+    translation_glossary().version_for(source_text)
+}
+
+fn cache_keys(source: &ArticleSourceText<'_>) -> Vec<String> {
+    let mut keys = article_field_cache_keys(source).to_vec();
+    keys.extend(
+        image_text_cache_keys(source)
+            .iter()
+            .flat_map(image_text_key_list),
+    );
+    keys
+}
+
+fn article_field_cache_keys(source: &ArticleSourceText<'_>) -> [String; 3] {
+    [
         translation_cache_key(
             source.article_id,
             TranslationField::Title,
             source.title,
             translation_prompt_version(),
+            &translation_glossary_version(source.title),
         ),
         translation_cache_key(
             source.article_id,
             TranslationField::Description,
             source.description,
             translation_prompt_version(),
+            &translation_glossary_version(source.description),
         ),
         translation_cache_key(
             source.article_id,
             TranslationField::Markdown,
             source.markdown,
             translation_prompt_version(),
+            &translation_glossary_version(source.markdown),
         ),
     ]
 }
 
+fn image_text_cache_keys(source: &ArticleSourceText<'_>) -> Vec<ImageTextCacheKeys> {
+    source
+        .images
+        .iter()
+        .map(|image| image_text_keys(source.article_id, image))
+        .collect()
+}
+
+fn image_text_keys(article_id: &str, image: &ArticleImageSourceText) -> ImageTextCacheKeys {
+    ImageTextCacheKeys {
+        image_id: image.image_id.clone(),
+        alt_text: image_translation_cache_key(
+            article_id,
+            &image.image_id,
+            TranslationField::ImageAltText,
+            &image.alt_text,
+            translation_prompt_version(),
+            &translation_glossary_version(&image.alt_text),
+        ),
+        caption: image.caption.as_deref().map(|caption| {
+            image_translation_cache_key(
+                article_id,
+                &image.image_id,
+                TranslationField::ImageCaption,
+                caption,
+                translation_prompt_version(),
+                &translation_glossary_version(caption),
+            )
+        }),
+    }
+}
+
+fn image_text_key_list(keys: &ImageTextCacheKeys) -> Vec<String> {
+    std::iter::once(keys.alt_text.clone())
+        .chain(keys.caption.clone())
+        .collect()
+}
+
+fn language_translation_map<'a>(
+    translations: &'a [StoredTranslation],
+    language: &SupportedTranslationLanguage,
+) -> HashMap<&'a str, &'a str> {
+    translations
+        .iter()
+        .filter(|translation| translation.language.code == language.code)
+        .map(|translation| (translation.cache_key.as_str(), translation.text.as_str()))
+        .collect()
+}
+
+fn assemble_cached_image_text(
+    keys: &ImageTextCacheKeys,
+    translation_map: &HashMap<&str, &str>,
+) -> Option<CachedImageTextTranslation> {
+    let alt_text = translation_map.get(keys.alt_text.as_str())?.to_string();
+    let caption = match keys.caption.as_deref() {
+        Some(caption_key) => Some(translation_map.get(caption_key)?.to_string()),
+        None => None,
+    };
+
+    Some(CachedImageTextTranslation {
+        image_id: keys.image_id.clone(),
+        alt_text,
+        caption,
+    })
+}
+
 fn summary_cache_keys(source: ArticleSummarySourceText<'_>) -> [String; 2] {
     [
         translation_cache_key(
@@ -301,12 +712,14 @@ fn summary_cache_keys(source: ArticleSummarySourceText<'_>) -> [String; 2] {
             TranslationField::Title,
             source.title,
             translation_prompt_version(),
+            &translation_glossary_version(source.title),
         ),
         translation_cache_key(
             source.article_id,
             TranslationField::Description,
             source.description,
             translation_prompt_version(),
+            &translation_glossary_version(source.description),
         ),
     ]
 }
@@ -315,29 +728,39 @@ fn complete_cached_languages(
     cache_keys: &[String],
     translations: &[StoredTranslation],
 ) -> Vec<SupportedTranslationLanguage> {
-    let mut by_language = HashMap::<&'static str, HashSet<&str>>::new();
+    complete_languages_for_keys(cache_keys, &cached_keys_by_language(translations))
+}
+
+fn cached_keys_by_language(translations: &[StoredTranslation]) -> HashMap<&str, HashSet<&str>> {
+    let mut by_language = HashMap::<&str, HashSet<&str>>::new();
     for translation in translations {
         by_language
-            .entry(translation.language.code)
+            .entry(&translation.language.code)
             .or_default()
             .insert(translation.cache_key.as_str());
     }
+    by_language
+}
 
+fn complete_languages_for_keys(
+    cache_keys: &[String],
+    by_language: &HashMap<&str, HashSet<&str>>,
+) -> Vec<SupportedTranslationLanguage> {
     supported_translation_languages()
         .iter()
-        .copied()
         .filter(|language| {
             by_language
-                .get(language.code)
+                .get(language.code.as_ref())
                 .is_some_and(|hashes| cache_keys.iter().all(|hash| hashes.contains(hash.as_str())))
         })
+        .cloned()
         .collect()
 }
 
 fn cached_translation_fields(
     cache_keys: &[String],
     translations: &[StoredTranslation],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> CachedArticleTranslationFields {
     let translation_map = translations
         .iter()
@@ -361,7 +784,7 @@ fn cached_translation_fields(
 fn cached_summary_translation_fields(
     cache_keys: &[String],
     translations: &[StoredTranslation],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> CachedArticleSummaryTranslationFields {
     let translation_map = translations
         .iter()
@@ -381,7 +804,7 @@ fn cached_summary_translation_fields(
 
 fn missing_translation_writes(
     cache_keys: &[String],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     missing_fields: &MissingArticleTranslationFields,
     translated_texts: [&str; 3],
 ) -> Vec<TranslationWrite> {
@@ -397,7 +820,7 @@ fn missing_translation_writes(
         .filter(|((_, _), missing)| *missing)
         .map(|((cache_key, text), _)| TranslationWrite {
             cache_key: cache_key.clone(),
-            language,
+            language: language.clone(),
             text: text.to_string(),
         })
         .collect()
@@ -405,7 +828,7 @@ fn missing_translation_writes(
 
 fn missing_summary_translation_writes(
     cache_keys: &[String],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     missing_fields: &MissingArticleSummaryTranslationFields,
     translated_texts: [&str; 2],
 ) -> Vec<TranslationWrite> {
@@ -417,7 +840,7 @@ fn missing_summary_translation_writes(
         .filter(|((_, _), missing)| *missing)
         .map(|((cache_key, text), _)| TranslationWrite {
             cache_key: cache_key.clone(),
-            language,
+            language: language.clone(),
             text: text.to_string(),
         })
         .collect()
@@ -425,33 +848,41 @@ fn missing_summary_translation_writes(
 
 fn assemble_cached_article_translation(
     cache_keys: &[String],
+    image_keys: &[ImageTextCacheKeys],
     translations: &[StoredTranslation],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> Option<CachedArticleTranslation> {
     let cached_fields = cached_translation_fields(cache_keys, translations, language);
     let title = cached_fields.title?;
     let description = cached_fields.description?;
     let markdown = cached_fields.markdown?;
+    let translation_map = language_translation_map(translations, language);
+    let images = image_keys
+        .iter()
+        .filter_map(|keys| assemble_cached_image_text(keys, &translation_map))
+        .collect();
 
     Some(CachedArticleTranslation {
-        language,
+        language: language.clone(),
         title,
         description,
         markdown,
+        images,
+        markdown_segments: None,
     })
 }
 
 fn assemble_cached_article_summary_translation(
     cache_keys: &[String],
     translations: &[StoredTranslation],
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> Option<CachedArticleSummaryTranslation> {
     let cached_fields = cached_summary_translation_fields(cache_keys, translations, language);
     let title = cached_fields.title?;
     let description = cached_fields.description?;
 
     Some(CachedArticleSummaryTranslation {
-        language,
+        language: language.clone(),
         title,
         description,
     })
@@ -459,39 +890,62 @@ fn assemble_cached_article_summary_translation(
 
 #[cfg(test)]
 mod tests {
-    use crate::llm::prompt_registry::find_supported_translation_language;
-    use crate::repositories::translations::StoredTranslation;
+    use crate::error::Error;
+    use crate::llm::prompt_registry::{
+        find_supported_translation_language, SupportedTranslationLanguage,
+    };
+    use crate::llm::translate::Translate;
+    use crate::repositories::translations::{find_translations_for_keys, StoredTranslation};
+    use crate::services::article_language::article_source_language;
+    use crate::test_support::TestContext;
 
     use super::{
         article_translation_job_key, assemble_cached_article_translation, cache_keys,
-        cached_translation_fields, complete_cached_languages, missing_translation_writes,
-        ArticleSourceText, MissingArticleTranslationFields,
+        cached_translation_fields, complete_cached_languages,
+        ensure_cached_article_summary_translation, image_text_cache_keys,
+        missing_translation_writes, summary_cache_keys, ArticleImageSourceText, ArticleSourceText,
+        ArticleSummarySourceText, MissingArticleTranslationFields,
     };
 
+    /// Answers like a chat assistant instead of translating.
+    struct LeakyTranslator;
+
+    impl Translate for LeakyTranslator {
+        async fn translate(
+            &self,
+            _text: &str,
+            _target_language: &SupportedTranslationLanguage,
+        ) -> Result<String, Error> {
+            Ok("As an AI language model I cannot translate this.".to_string())
+        }
+    }
+
     fn source_text() -> ArticleSourceText<'static> {
         ArticleSourceText {
             article_id: "article-1",
+            source_language: article_source_language(),
             title: "Report",
             description: "Brief summary",
             markdown: "Brief summary\n\nBody paragraph",
+            images: &[],
         }
     }
 
     #[test]
     fn complete_cached_languages_requires_all_article_fields() {
         let source = source_text();
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
         let portuguese = find_supported_translation_language("pt").unwrap();
 
         let incomplete = vec![
             StoredTranslation {
                 cache_key: cache_keys[0].clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: "Relatorio".to_string(),
             },
             StoredTranslation {
                 cache_key: cache_keys[1].clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: "Resumo".to_string(),
             },
         ];
@@ -502,14 +956,14 @@ mod tests {
     #[test]
     fn complete_cached_languages_returns_supported_languages_with_full_coverage() {
         let source = source_text();
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
         let portuguese = find_supported_translation_language("pt").unwrap();
 
         let complete = cache_keys
             .iter()
             .map(|key| StoredTranslation {
                 cache_key: key.clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: format!("translated:{key}"),
             })
             .collect::<Vec<_>>();
@@ -522,29 +976,30 @@ mod tests {
     #[test]
     fn assemble_cached_article_translation_uses_field_hashes() {
         let source = source_text();
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
         let portuguese = find_supported_translation_language("pt").unwrap();
 
         let translations = vec![
             StoredTranslation {
                 cache_key: cache_keys[1].clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: "Resumo".to_string(),
             },
             StoredTranslation {
                 cache_key: cache_keys[2].clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: "Resumo\n\nCorpo".to_string(),
             },
             StoredTranslation {
                 cache_key: cache_keys[0].clone(),
-                language: portuguese,
+                language: portuguese.clone(),
                 text: "Relatorio".to_string(),
             },
         ];
 
         let cached =
-            assemble_cached_article_translation(&cache_keys, &translations, portuguese).unwrap();
+            assemble_cached_article_translation(&cache_keys, &[], &translations, &portuguese)
+                .unwrap();
 
         assert_eq!(cached.title, "Relatorio");
         assert_eq!(cached.description, "Resumo");
@@ -554,16 +1009,16 @@ mod tests {
     #[test]
     fn cached_translation_fields_preserve_partial_rows() {
         let source = source_text();
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
         let portuguese = find_supported_translation_language("pt").unwrap();
 
         let translations = vec![StoredTranslation {
             cache_key: cache_keys[0].clone(),
-            language: portuguese,
+            language: portuguese.clone(),
             text: "Relatorio".to_string(),
         }];
 
-        let cached_fields = cached_translation_fields(&cache_keys, &translations, portuguese);
+        let cached_fields = cached_translation_fields(&cache_keys, &translations, &portuguese);
 
         assert_eq!(cached_fields.title.as_deref(), Some("Relatorio"));
         assert!(cached_fields.description.is_none());
@@ -575,7 +1030,7 @@ mod tests {
         let portuguese = find_supported_translation_language("pt").unwrap();
 
         assert_eq!(
-            article_translation_job_key("article-1", portuguese),
+            article_translation_job_key("article-1", &portuguese),
             "article-1:pt"
         );
     }
@@ -584,12 +1039,14 @@ mod tests {
     fn cache_keys_remain_distinct_when_source_text_matches() {
         let source = ArticleSourceText {
             article_id: "article-1",
+            source_language: article_source_language(),
             title: "Same text",
             description: "Same text",
             markdown: "Same text",
+            images: &[],
         };
 
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
 
         assert_ne!(cache_keys[0], cache_keys[1]);
         assert_ne!(cache_keys[1], cache_keys[2]);
@@ -599,11 +1056,11 @@ mod tests {
     #[test]
     fn missing_translation_writes_only_stage_uncached_fields() {
         let source = source_text();
-        let cache_keys = cache_keys(source);
+        let cache_keys = cache_keys(&source);
         let portuguese = find_supported_translation_language("pt").unwrap();
         let writes = missing_translation_writes(
             &cache_keys,
-            portuguese,
+            &portuguese,
             &MissingArticleTranslationFields {
                 title: true,
                 description: false,
@@ -618,4 +1075,99 @@ mod tests {
         assert_eq!(writes[1].cache_key, cache_keys[2]);
         assert_eq!(writes[1].text, "Corpo");
     }
+
+    #[test]
+    fn missing_image_text_leaves_the_article_translation_servable() {
+        let images = vec![
+            ArticleImageSourceText {
+                image_id: "image-1".to_string(),
+                alt_text: "A filing cabinet".to_string(),
+                caption: Some("Archive, level two".to_string()),
+            },
+            ArticleImageSourceText {
+                image_id: "image-2".to_string(),
+                alt_text: "A stamp".to_string(),
+                caption: None,
+            },
+        ];
+        let source = ArticleSourceText {
+            images: &images,
+            ..source_text()
+        };
+        let cache_keys = cache_keys(&source);
+        let image_keys = image_text_cache_keys(&source);
+        let portuguese = find_supported_translation_language("pt").unwrap();
+
+        assert_eq!(cache_keys.len(), 6);
+        let mut translations = cache_keys[..5]
+            .iter()
+            .map(|key| StoredTranslation {
+                cache_key: key.clone(),
+                language: portuguese.clone(),
+                text: format!("pt:{key}"),
+            })
+            .collect::<Vec<_>>();
+        assert!(complete_cached_languages(&cache_keys, &translations).is_empty());
+        assert_eq!(
+            complete_cached_languages(&cache_keys[..3], &translations),
+            vec![portuguese.clone()]
+        );
+        let partial = assemble_cached_article_translation(
+            &cache_keys,
+            &image_keys,
+            &translations,
+            &portuguese,
+        )
+        .unwrap();
+        assert_eq!(partial.images.len(), 1);
+        assert!(partial.lacks_image_text(&source));
+
+        translations.push(StoredTranslation {
+            cache_key: cache_keys[5].clone(),
+            language: portuguese.clone(),
+            text: "Um carimbo".to_string(),
+        });
+        let cached = assemble_cached_article_translation(
+            &cache_keys,
+            &image_keys,
+            &translations,
+            &portuguese,
+        )
+        .unwrap();
+
+        assert_eq!(cached.images.len(), 2);
+        assert_eq!(cached.images[0].image_id, "image-1");
+        assert!(cached.images[0].caption.is_some());
+        assert_eq!(cached.images[1].alt_text, "Um carimbo");
+        assert!(cached.images[1].caption.is_none());
+        assert!(!cached.lacks_image_text(&source));
+    }
+
+    #[tokio::test]
+    async fn rejected_summary_translation_caches_nothing() {
+        let ctx = TestContext::new().await;
+        let source = ArticleSummarySourceText {
+            article_id: "article-1",
+            title: "Committee accepts the quarterly memo",
+            description: "Officials said the memo was filed with the usual care.",
+        };
+        let portuguese = find_supported_translation_language("pt").unwrap();
+
+        let err = ensure_cached_article_summary_translation(
+            &LeakyTranslator,
+            &ctx.state.db,
+            source,
+            &portuguese,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.to_string().contains("failed quality checks"));
+        assert!(
+            find_translations_for_keys(&ctx.state.db, &summary_cache_keys(source))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
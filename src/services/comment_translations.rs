@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use sea_orm::DatabaseConnection;
+use tracing::{event, Level};
+
+use crate::error::Error;
+use crate::llm::glossary::translation_glossary;
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, translation_prompt, SupportedTranslationLanguage,
+};
+use crate::llm::translate::Translate;
+use crate::rate_limit::{RateLimitState, RequesterTier};
+use crate::repositories::translations::{
+    comment_translation_cache_key, find_translations_for_keys, save_translations, TranslationWrite,
+};
+use crate::services::translation_quality::{check_translated_field, detect_language};
+
+/// Comments are short, so detection settles for a handful of words.
+const MIN_COMMENT_DETECTION_WORDS: usize = 6;
+/// Uncached comments translated inside one page request. The rest are left for
+/// the next request, which finds these ones cached and moves on.
+pub const MAX_COMMENT_TRANSLATIONS_PER_REQUEST: usize = 5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CommentSourceText<'a> {
+    pub comment_id: &'a str,
+    pub body: &'a str,
+    pub language_code: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommentTranslations {
+    /// Translated bodies keyed by comment id.
+    pub texts: HashMap<String, String>,
+    /// Set when the translation rate limit stopped some comments from being translated.
+    pub rate_limited: bool,
+    /// Set when the per-request cap left uncached comments for a later request.
+    pub deferred: bool,
+}
+
+pub fn detect_comment_language(body: &str) -> Option<SupportedTranslationLanguage> {
+    detect_language(body, MIN_COMMENT_DETECTION_WORDS).and_then(find_supported_translation_language)
+}
+
+/// Comments of unknown language can always be offered for translation.
+pub fn comment_needs_translation(
+    comment: CommentSourceText<'_>,
+    language: &SupportedTranslationLanguage,
+) -> bool {
+    comment.language_code != Some(&language.code)
+}
+
+fn comment_cache_key(comment: CommentSourceText<'_>) -> String {
+    comment_translation_cache_key(
+        comment.comment_id,
+        comment.body,
+        translation_prompt().version,
+        &translation_glossary().version_for(comment.body),
+    )
+}
+
+/// Translates `comments` into `language`, serving cached bodies first. At most
+/// `MAX_COMMENT_TRANSLATIONS_PER_REQUEST` uncached comments go to the LLM per
+/// call, and each counts against the background translation rate limit; a
+/// comment the LLM fails to translate is left in its original language.
+pub async fn translate_comments<T: Translate>(
+    translator: &T,
+    db: &DatabaseConnection,
+    rate_limits: &RateLimitState,
+    comments: &[CommentSourceText<'_>],
+    language: &SupportedTranslationLanguage,
+    requester_tier: RequesterTier,
+    rate_limit_key: &str,
+) -> Result<CommentTranslations, Error> {
+    let comments = comments
+        .iter()
+        .copied()
+        .filter(|comment| comment_needs_translation(*comment, language))
+        .map(|comment| (comment, comment_cache_key(comment)))
+        .collect::<Vec<_>>();
+    let cache_keys = comments
+        .iter()
+        .map(|(_, key)| key.clone())
+        .collect::<Vec<_>>();
+    let cached = find_translations_for_keys(db, &cache_keys)
+        .await?
+        .into_iter()
+        .filter(|translation| translation.language.code == language.code)
+        .map(|translation| (translation.cache_key, translation.text))
+        .collect::<HashMap<_, _>>();
+
+    let mut translations = CommentTranslations::default();
+    let mut writes = Vec::new();
+    let mut attempted = 0;
+    for (comment, cache_key) in comments {
+        if let Some(text) = cached.get(&cache_key) {
+            translations
+                .texts
+                .insert(comment.comment_id.to_string(), text.clone());
+            continue;
+        }
+        if translations.rate_limited || translations.deferred {
+            continue;
+        }
+        if attempted == MAX_COMMENT_TRANSLATIONS_PER_REQUEST {
+            translations.deferred = true;
+            continue;
+        }
+        if rate_limits
+            .check_translation_generation_limit(requester_tier, rate_limit_key)
+            .is_err()
+        {
+            translations.rate_limited = true;
+            continue;
+        }
+        attempted += 1;
+        let translated = match translator.translate(comment.body, language).await {
+            Ok(translated) => {
+                check_translated_field(comment.body, &translated, language).map(|_| translated)
+            }
+            Err(err) => Err(err),
+        };
+        match translated {
+            Ok(text) => {
+                writes.push(TranslationWrite {
+                    cache_key,
+                    language: language.clone(),
+                    text: text.clone(),
+                });
+                translations
+                    .texts
+                    .insert(comment.comment_id.to_string(), text);
+            }
+            Err(err) => {
+                event!(
+                    Level::WARN,
+                    comment_id = comment.comment_id,
+                    language = %language.code,
+                    error = %err,
+                    "Comment translation failed"
+                );
+            }
+        }
+    }
+    save_translations(db, &writes).await?;
+    Ok(translations)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::llm::prompt_registry::find_supported_translation_language;
+    use crate::llm::translate::Translate;
+    use crate::rate_limit::RequesterTier;
+    use crate::test_support::TestContext;
+
+    use super::{
+        detect_comment_language, translate_comments, CommentSourceText,
+        MAX_COMMENT_TRANSLATIONS_PER_REQUEST,
+    };
+
+    struct CountingTranslator {
+        calls: std::sync::Mutex<usize>,
+    }
+
+    impl Translate for CountingTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
+        ) -> Result<String, Error> {
+            *self.calls.lock().unwrap() += 1;
+            Ok(format!("[{}] {}", target_language.code, text))
+        }
+    }
+
+    #[test]
+    fn detects_comment_language_from_a_short_sample() {
+        assert_eq!(
+            detect_comment_language(
+                "Não sei se isso foi uma boa ideia, mas com certeza foi engraçado."
+            )
+            .map(|language| language.code),
+            Some("pt".into())
+        );
+        assert_eq!(detect_comment_language("Great piece!"), None);
+    }
+
+    #[tokio::test]
+    async fn translates_only_foreign_comments_and_reuses_the_cache() {
+        let ctx = TestContext::new().await;
+        let english = find_supported_translation_language("en").unwrap();
+        let translator = CountingTranslator {
+            calls: std::sync::Mutex::new(0),
+        };
+        let comments = [
+            CommentSourceText {
+                comment_id: "comment-1",
+                body: "Isso foi uma surpresa para todos nós.",
+                language_code: Some("pt"),
+            },
+            CommentSourceText {
+                comment_id: "comment-2",
+                body: "Already in English.",
+                language_code: Some("en"),
+            },
+        ];
+
+        for _ in 0..2 {
+            let translations = translate_comments(
+                &translator,
+                &ctx.state.db,
+                &ctx.state.rate_limit_state,
+                &comments,
+                &english,
+                RequesterTier::Anonymous,
+                "ip:127.0.0.1",
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                translations.texts.get("comment-1").map(String::as_str),
+                Some("[en] Isso foi uma surpresa para todos nós.")
+            );
+            assert!(!translations.texts.contains_key("comment-2"));
+            assert!(!translations.rate_limited);
+        }
+        assert_eq!(*translator.calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn caps_uncached_translations_per_request() {
+        let ctx = TestContext::new().await;
+        let english = find_supported_translation_language("en").unwrap();
+        let translator = CountingTranslator {
+            calls: std::sync::Mutex::new(0),
+        };
+        let ids = (0..MAX_COMMENT_TRANSLATIONS_PER_REQUEST + 2)
+            .map(|index| format!("comment-{}", index))
+            .collect::<Vec<_>>();
+        let comments = ids
+            .iter()
+            .map(|id| CommentSourceText {
+                comment_id: id,
+                body: "Isso foi uma surpresa para todos nós.",
+                language_code: Some("pt"),
+            })
+            .collect::<Vec<_>>();
+        let translate = || {
+            translate_comments(
+                &translator,
+                &ctx.state.db,
+                &ctx.state.rate_limit_state,
+                &comments,
+                &english,
+                RequesterTier::Authenticated,
+                "user:reader@example.com",
+            )
+        };
+
+        let first = translate().await.unwrap();
+        assert_eq!(first.texts.len(), MAX_COMMENT_TRANSLATIONS_PER_REQUEST);
+        assert!(first.deferred);
+
+        let second = translate().await.unwrap();
+        assert_eq!(second.texts.len(), comments.len());
+        assert!(!second.deferred);
+        assert_eq!(*translator.calls.lock().unwrap(), comments.len());
+    }
+}
@@ -59,6 +59,10 @@ pub fn enforce_edit_request_policy(change_request: &str) -> Result<(), Error> {
     enforce_request_policy(change_request, "edit request")
 }
 
+pub fn enforce_image_text_policy(alt_text: &str, caption: &str) -> Result<(), Error> {
+    enforce_request_policy(&format!("{}\n{}", alt_text, caption), "image text")
+}
+
 pub fn enforce_article_output_policy(
     title: &str,
     description: &str,
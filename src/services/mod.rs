@@ -2,6 +2,12 @@ pub mod article_jobs;
 pub mod article_language;
 pub mod article_persistence;
 pub mod article_translations;
+pub mod comment_translations;
 pub mod editorial_policy;
+pub mod remote_image;
+pub mod site_catalog;
 pub mod site_paths;
 pub mod site_text;
+pub mod translation_memory;
+pub mod translation_quality;
+pub mod translation_review;
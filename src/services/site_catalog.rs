@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentMessage, FluentResource, FluentValue};
+use fluent_syntax::ast;
+use serde_json::{Map, Value};
+use unic_langid::LanguageIdentifier;
+
+use crate::error::Error;
+
+pub const DEFAULT_SITE_LOCALE: &str = "en";
+const TEMPLATE_MESSAGE_PREFIX: &str = "ui-";
+
+static SITE_CATALOGS: OnceLock<SiteCatalogs> = OnceLock::new();
+
+/// Argument passed to a catalog message; numbers drive plural selection.
+#[derive(Clone, Debug)]
+pub enum CatalogArg<'a> {
+    Text(&'a str),
+    Number(u64),
+}
+
+impl<'a> From<&'a str> for CatalogArg<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<u64> for CatalogArg<'_> {
+    fn from(value: u64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<usize> for CatalogArg<'_> {
+    fn from(value: usize) -> Self {
+        Self::Number(value as u64)
+    }
+}
+
+struct Catalog {
+    resource: Arc<FluentResource>,
+    bundle: FluentBundle<Arc<FluentResource>>,
+}
+
+/// Per-locale Fluent catalogs for the site chrome. Plural categories come from the
+/// CLDR rules `fluent-bundle` ships, so a new locale only needs its `.ftl` file.
+/// Lookups walk `locale -> base language -> en`.
+pub struct SiteCatalogs {
+    catalogs: HashMap<String, Catalog>,
+    template_strings: HashMap<String, Value>,
+}
+
+/// Loads `LOCALES_DIR` (default `locales`) once at startup so a broken catalog
+/// stops the server instead of surfacing as missing strings later.
+pub fn init_site_catalogs() -> Result<&'static SiteCatalogs, Error> {
+    if let Some(catalogs) = SITE_CATALOGS.get() {
+        return Ok(catalogs);
+    }
+    let catalogs = SiteCatalogs::load_dir(&locales_dir()).map_err(|e| {
+        Error::Template(tera::Error::msg(format!(
+            "Failed to load site locale catalogs: {}",
+            e
+        )))
+    })?;
+    Ok(SITE_CATALOGS.get_or_init(|| catalogs))
+}
+
+pub fn site_catalogs() -> &'static SiteCatalogs {
+    SITE_CATALOGS.get_or_init(|| {
+        let dir = locales_dir();
+        SiteCatalogs::load_dir(&dir)
+            .unwrap_or_else(|e| panic!("Failed to load site locale catalogs from {}: {}", dir, e))
+    })
+}
+
+fn locales_dir() -> String {
+    env::var("LOCALES_DIR").unwrap_or_else(|_| "locales".to_string())
+}
+
+impl SiteCatalogs {
+    pub fn load_dir(dir: &str) -> Result<Self, String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir, e))?;
+        let mut sources = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| format!("{}: {}", dir, e))?.path();
+            if path.extension().and_then(|value| value.to_str()) != Some("ftl") {
+                continue;
+            }
+            let Some(code) = path.file_stem().and_then(|value| value.to_str()) else {
+                continue;
+            };
+            let source =
+                fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            sources.push((code.to_ascii_lowercase(), source));
+        }
+        Self::from_sources(sources).map_err(|e| format!("{} ({})", e, Path::new(dir).display()))
+    }
+
+    pub fn from_sources(sources: Vec<(String, String)>) -> Result<Self, String> {
+        let mut catalogs = HashMap::new();
+        for (code, source) in sources {
+            let catalog =
+                load_catalog(&code, source).map_err(|e| format!("{}.ftl: {}", code, e))?;
+            catalogs.insert(code, catalog);
+        }
+        if !catalogs.contains_key(DEFAULT_SITE_LOCALE) {
+            return Err(format!("missing {}.ftl", DEFAULT_SITE_LOCALE));
+        }
+
+        let mut loaded = Self {
+            catalogs,
+            template_strings: HashMap::new(),
+        };
+        let codes: Vec<String> = loaded.catalogs.keys().cloned().collect();
+        for code in codes {
+            let strings = loaded.build_template_strings(&code);
+            loaded.template_strings.insert(code, strings);
+        }
+        Ok(loaded)
+    }
+
+    pub fn has_locale(&self, code: &str) -> bool {
+        self.catalogs.contains_key(code)
+    }
+
+    /// A message without placeables; returns the id when the message is missing
+    /// or needs arguments, which makes the gap visible on the page.
+    pub fn text<'a>(&'a self, locale: &str, id: &'a str) -> &'a str {
+        self.message_text(locale, id).unwrap_or(id)
+    }
+
+    /// Borrowed text for messages whose value is plain text; `None` when missing
+    /// or when the value has placeables that need formatting.
+    pub fn message_text(&self, locale: &str, id: &str) -> Option<&str> {
+        let (_, message) = self.lookup(locale, id)?;
+        match message.value()?.elements.as_slice() {
+            [] => Some(""),
+            [ast::PatternElement::TextElement { value }] => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn format(&self, locale: &str, id: &str, args: &[(&str, CatalogArg)]) -> String {
+        let Some((catalog, message)) = self.lookup(locale, id) else {
+            return id.to_string();
+        };
+        let Some(pattern) = message.value() else {
+            return id.to_string();
+        };
+        let args = fluent_args(args);
+        let mut errors = Vec::new();
+        catalog
+            .bundle
+            .format_pattern(pattern, Some(&args), &mut errors)
+            .into_owned()
+    }
+
+    /// Every `ui-<section>` message's attributes, merged across the fallback chain,
+    /// as the `ui.<section>.<key>` object the templates read.
+    pub fn template_strings(&self, locale: &str) -> Value {
+        self.template_strings
+            .get(locale)
+            .or_else(|| self.template_strings.get(DEFAULT_SITE_LOCALE))
+            .cloned()
+            .unwrap_or(Value::Null)
+    }
+
+    fn lookup<'a>(&'a self, locale: &str, id: &str) -> Option<(&'a Catalog, FluentMessage<'a>)> {
+        fallback_chain(locale).into_iter().find_map(|code| {
+            let catalog = self.catalogs.get(code)?;
+            catalog
+                .bundle
+                .get_message(id)
+                .map(|message| (catalog, message))
+        })
+    }
+
+    fn build_template_strings(&self, locale: &str) -> Value {
+        let mut sections = Map::new();
+        for code in fallback_chain(locale).into_iter().rev() {
+            let Some(catalog) = self.catalogs.get(code) else {
+                continue;
+            };
+            for message in template_messages(&catalog.resource) {
+                let Some(section) = message.id.name.strip_prefix(TEMPLATE_MESSAGE_PREFIX) else {
+                    continue;
+                };
+                let entry = sections
+                    .entry(section.to_string())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                for attribute in &message.attributes {
+                    let mut errors = Vec::new();
+                    let text = catalog
+                        .bundle
+                        .format_pattern(&attribute.value, None, &mut errors);
+                    entry[attribute.id.name] = Value::String(text.into_owned());
+                }
+            }
+        }
+        Value::Object(sections)
+    }
+}
+
+fn load_catalog(code: &str, source: String) -> Result<Catalog, String> {
+    let locale: LanguageIdentifier = code
+        .parse()
+        .map_err(|e| format!("invalid locale code: {}", e))?;
+    let resource = FluentResource::try_new(source).map_err(|(resource, errors)| {
+        let error = &errors[0];
+        let line = resource.source()[..error.pos.start].lines().count().max(1);
+        format!("line {}: {}", line, error)
+    })?;
+    let resource = Arc::new(resource);
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+    // The output is HTML-escaped by the templates; bidi isolation marks would only
+    // leak into attributes and `<title>`.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(Arc::clone(&resource))
+        .map_err(|errors| errors[0].to_string())?;
+    Ok(Catalog { resource, bundle })
+}
+
+fn template_messages(resource: &FluentResource) -> impl Iterator<Item = &ast::Message<&str>> {
+    resource.entries().filter_map(|entry| match entry {
+        ast::Entry::Message(message) => Some(message),
+        _ => None,
+    })
+}
+
+fn fluent_args<'a>(args: &'a [(&str, CatalogArg)]) -> FluentArgs<'a> {
+    let mut fluent_args = FluentArgs::with_capacity(args.len());
+    for (name, value) in args {
+        match value {
+            CatalogArg::Text(text) => fluent_args.set(*name, FluentValue::from(*text)),
+            CatalogArg::Number(number) => fluent_args.set(*name, FluentValue::from(*number)),
+        }
+    }
+    fluent_args
+}
+
+fn fallback_chain(locale: &str) -> Vec<&str> {
+    let mut chain = vec![locale];
+    if let Some((base, _)) = locale.split_once(['-', '_']) {
+        chain.push(base);
+    }
+    if !chain.contains(&DEFAULT_SITE_LOCALE) {
+        chain.push(DEFAULT_SITE_LOCALE);
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CatalogArg, SiteCatalogs};
+
+    fn catalogs() -> SiteCatalogs {
+        SiteCatalogs::from_sources(vec![
+            (
+                "en".to_string(),
+                "# Site chrome\n\
+                 greeting = Hello, { $name }!\n\
+                 only-english = Fallback text\n\
+                 comment-count = { $count ->\n    [one] { $count } comment\n   *[other] { $count } comments\n    }\n\
+                 ui-base =\n    .login = Login\n    .logout = Logout\n"
+                    .to_string(),
+            ),
+            (
+                "pt".to_string(),
+                "greeting = Olá, { $name }!\n\
+                 comment-count = { $count ->\n    [0] Nenhum comentário\n    [one] { $count } comentário\n   *[other] { $count } comentários\n    }\n\
+                 ui-base =\n    .login = Entrar\n"
+                    .to_string(),
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn formats_variables_and_plural_categories_per_locale() {
+        let catalogs = catalogs();
+
+        assert_eq!(
+            catalogs.format("pt", "greeting", &[("name", CatalogArg::from("Ana"))]),
+            "Olá, Ana!"
+        );
+        assert_eq!(
+            catalogs.format("en", "comment-count", &[("count", 1u64.into())]),
+            "1 comment"
+        );
+        assert_eq!(
+            catalogs.format("en", "comment-count", &[("count", 0u64.into())]),
+            "0 comments"
+        );
+        assert_eq!(
+            catalogs.format("pt", "comment-count", &[("count", 0u64.into())]),
+            "Nenhum comentário"
+        );
+        assert_eq!(
+            catalogs.format("pt", "comment-count", &[("count", 3u64.into())]),
+            "3 comentários"
+        );
+    }
+
+    #[test]
+    fn missing_messages_fall_back_through_the_chain() {
+        let catalogs = catalogs();
+
+        assert_eq!(catalogs.text("pt-BR", "only-english"), "Fallback text");
+        assert_eq!(catalogs.text("fr", "only-english"), "Fallback text");
+        assert_eq!(catalogs.text("pt", "not-a-message"), "not-a-message");
+    }
+
+    #[test]
+    fn template_strings_merge_attributes_over_the_default_locale() {
+        let ui = catalogs().template_strings("pt");
+
+        assert_eq!(ui["base"]["login"].as_str(), Some("Entrar"));
+        assert_eq!(ui["base"]["logout"].as_str(), Some("Logout"));
+    }
+
+    #[test]
+    fn rejects_select_expressions_without_a_default() {
+        let result = SiteCatalogs::from_sources(vec![(
+            "en".to_string(),
+            "broken = { $count ->\n    [one] one\n    }\n".to_string(),
+        )]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn shipped_catalogs_define_every_template_string() {
+        let catalogs = SiteCatalogs::load_dir("locales").unwrap();
+        let english = catalogs.catalogs.get("en").unwrap();
+
+        for (code, catalog) in &catalogs.catalogs {
+            for message in super::template_messages(&english.resource) {
+                let id = message.id.name;
+                if !id.starts_with(super::TEMPLATE_MESSAGE_PREFIX) {
+                    continue;
+                }
+                let localized = catalog
+                    .bundle
+                    .get_message(id)
+                    .unwrap_or_else(|| panic!("{}.ftl is missing {}", code, id));
+                for attribute in &message.attributes {
+                    assert!(
+                        localized.get_attribute(attribute.id.name).is_some(),
+                        "{}.ftl is missing {}.{}",
+                        code,
+                        id,
+                        attribute.id.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plural_categories_follow_each_locale() {
+        let catalogs = SiteCatalogs::from_sources(vec![
+            (
+                "en".to_string(),
+                "items = { $count ->\n    [one] one item\n   *[other] many items\n    }\n"
+                    .to_string(),
+            ),
+            (
+                "fr".to_string(),
+                "items = { $count ->\n    [one] un article\n   *[other] des articles\n    }\n"
+                    .to_string(),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            catalogs.format("en", "items", &[("count", 0u64.into())]),
+            "many items"
+        );
+        assert_eq!(
+            catalogs.format("fr", "items", &[("count", 0u64.into())]),
+            "un article"
+        );
+        assert_eq!(
+            catalogs.format("fr", "items", &[("count", 2u64.into())]),
+            "des articles"
+        );
+    }
+}
@@ -3,21 +3,17 @@ use std::env;
 use http::HeaderMap;
 
 use crate::llm::prompt_registry::{
-    find_supported_translation_language, SupportedTranslationLanguage,
+    find_supported_translation_language, supported_translation_languages,
+    SupportedTranslationLanguage,
 };
 pub const SITE_LANGUAGE_COOKIE_NAME: &str = "__site_lang";
 
-fn english_site_language() -> SupportedTranslationLanguage {
-    find_supported_translation_language("en").expect("English must remain a supported site locale")
-}
-
-fn portuguese_site_language() -> SupportedTranslationLanguage {
-    find_supported_translation_language("pt")
-        .expect("Portuguese must remain a supported site locale")
-}
-
-pub fn supported_site_languages() -> [SupportedTranslationLanguage; 2] {
-    [english_site_language(), portuguese_site_language()]
+/// Enabled registry languages flagged as full site locales, in registry order.
+pub fn supported_site_languages() -> Vec<SupportedTranslationLanguage> {
+    supported_translation_languages()
+        .into_iter()
+        .filter(|language| language.site_locale)
+        .collect()
 }
 
 pub fn find_supported_site_language(value: &str) -> Option<SupportedTranslationLanguage> {
@@ -67,15 +63,15 @@ pub fn site_relative_path(path_and_query: &str) -> String {
     }
 }
 
-pub fn localized_root_path(language: SupportedTranslationLanguage) -> String {
+pub fn localized_root_path(language: &SupportedTranslationLanguage) -> String {
     format!("/{}/", language.code)
 }
 
-pub fn locale_prefix(language: SupportedTranslationLanguage) -> String {
+pub fn locale_prefix(language: &SupportedTranslationLanguage) -> String {
     format!("/{}", language.code)
 }
 
-pub fn localized_path(language: SupportedTranslationLanguage, path_and_query: &str) -> String {
+pub fn localized_path(language: &SupportedTranslationLanguage, path_and_query: &str) -> String {
     let relative = site_relative_path(path_and_query);
     if relative == "/" {
         return localized_root_path(language);
@@ -83,7 +79,7 @@ pub fn localized_path(language: SupportedTranslationLanguage, path_and_query: &s
     format!("/{}{}", language.code, relative)
 }
 
-pub fn site_language_cookie_header(language: SupportedTranslationLanguage) -> String {
+pub fn site_language_cookie_header(language: &SupportedTranslationLanguage) -> String {
     let secure = if env::var("SITE_URL")
         .unwrap_or_else(|_| "http://localhost:8000".to_string())
         .starts_with("https://")
@@ -163,9 +159,9 @@ mod tests {
             "/content/story?lang=fr"
         );
         assert_eq!(
-            localized_path(english, "/pt/content/story?lang=fr"),
+            localized_path(&english, "/pt/content/story?lang=fr"),
             "/en/content/story?lang=fr"
         );
-        assert_eq!(localized_root_path(english), "/en/");
+        assert_eq!(localized_root_path(&english), "/en/");
     }
 }
@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::llm::glossary::translation_glossary;
+use crate::llm::prompt_registry::{translation_prompt, SupportedTranslationLanguage};
+use crate::llm::translate::Translate;
+use crate::repositories::translations::{
+    find_translations_for_keys, save_translations, translation_segment_cache_key, TranslationWrite,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SegmentKind {
+    Heading,
+    Image,
+    Code,
+    Text,
+}
+
+impl SegmentKind {
+    /// Image and code blocks are copied into the translation unchanged.
+    fn is_verbatim(self) -> bool {
+        matches!(self, Self::Image | Self::Code)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MarkdownSegment<'a> {
+    pub kind: SegmentKind,
+    pub text: &'a str,
+}
+
+/// How much of an article body came out of translation memory. Only segments that
+/// would go to the LLM are counted; image and code blocks are always copied.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct SegmentReuse {
+    pub total: u32,
+    pub reused: u32,
+}
+
+impl SegmentReuse {
+    pub fn ratio(self) -> Option<f64> {
+        (self.total > 0).then(|| f64::from(self.reused) / f64::from(self.total))
+    }
+}
+
+pub struct MarkdownTranslation {
+    pub markdown: String,
+    pub reuse: SegmentReuse,
+    pub writes: Vec<TranslationWrite>,
+}
+
+fn image_block_regex() -> &'static Regex {
+    static IMAGE_BLOCK_REGEX: OnceLock<Regex> = OnceLock::new();
+    IMAGE_BLOCK_REGEX.get_or_init(|| {
+        Regex::new(r#"^!\[[^\]]*\]\([^)]*\)$"#).expect("image block regex must compile")
+    })
+}
+
+fn is_fence(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("```") || line.starts_with("~~~")
+}
+
+fn segment_kind(block: &str) -> SegmentKind {
+    if is_fence(block) {
+        SegmentKind::Code
+    } else if block.starts_with('#') {
+        SegmentKind::Heading
+    } else if image_block_regex().is_match(block) {
+        SegmentKind::Image
+    } else {
+        SegmentKind::Text
+    }
+}
+
+/// Splits markdown into blank-line separated blocks, keeping fenced code blocks
+/// whole even when they contain blank lines.
+pub fn split_markdown_segments(markdown: &str) -> Vec<MarkdownSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut block_start: Option<usize> = None;
+    let mut block_end = 0;
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let content = line.trim_end();
+        if content.is_empty() && !in_fence {
+            if let Some(start) = block_start.take() {
+                segments.push(&markdown[start..block_end]);
+            }
+            continue;
+        }
+        if is_fence(content) {
+            in_fence = !in_fence;
+        }
+        block_start.get_or_insert(line_start);
+        block_end = line_start + line.trim_end().len();
+    }
+    if let Some(start) = block_start {
+        segments.push(&markdown[start..block_end]);
+    }
+
+    segments
+        .into_iter()
+        .map(|text| MarkdownSegment {
+            kind: segment_kind(text),
+            text,
+        })
+        .collect()
+}
+
+fn join_segments<'a>(segments: impl IntoIterator<Item = &'a str>) -> String {
+    segments.into_iter().collect::<Vec<_>>().join("\n\n")
+}
+
+/// Maps a translated chunk back onto its source segments when the translation kept
+/// the block structure; headings must stay headings.
+fn pair_translated_segments(
+    sources: &[MarkdownSegment<'_>],
+    translated: &str,
+) -> Option<Vec<String>> {
+    let translated = split_markdown_segments(translated);
+    if translated.len() != sources.len() {
+        return None;
+    }
+    sources
+        .iter()
+        .zip(&translated)
+        .map(|(source, translated)| {
+            ((source.kind == SegmentKind::Heading) == (translated.kind == SegmentKind::Heading))
+                .then(|| translated.text.to_string())
+        })
+        .collect()
+}
+
+fn segment_cache_key(text: &str) -> String {
+    translation_segment_cache_key(
+        text,
+        translation_prompt().version,
+        &translation_glossary().version_for(text),
+    )
+}
+
+/// Translates an article body segment by segment, reusing stored segment
+/// translations. Consecutive missing segments are sent as one request and split
+/// back up; if the model merged or split blocks they are retried one by one.
+/// The returned writes store the new segments and are saved by the caller.
+pub async fn translate_markdown_with_memory<T: Translate>(
+    translator: &T,
+    db: &DatabaseConnection,
+    markdown: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<MarkdownTranslation, Error> {
+    let segments = split_markdown_segments(markdown);
+    let keys = segments
+        .iter()
+        .map(|segment| segment_cache_key(segment.text))
+        .collect::<Vec<_>>();
+    let stored = find_translations_for_keys(db, &keys)
+        .await?
+        .into_iter()
+        .filter(|translation| translation.language.code == language.code)
+        .map(|translation| (translation.cache_key, translation.text))
+        .collect::<HashMap<_, _>>();
+
+    let mut translated: Vec<Option<String>> = segments
+        .iter()
+        .zip(&keys)
+        .map(|(segment, key)| {
+            if segment.kind.is_verbatim() {
+                Some(segment.text.to_string())
+            } else {
+                stored.get(key).cloned()
+            }
+        })
+        .collect();
+    let reuse = SegmentReuse {
+        total: segments
+            .iter()
+            .filter(|segment| !segment.kind.is_verbatim())
+            .count() as u32,
+        reused: segments
+            .iter()
+            .zip(&translated)
+            .filter(|(segment, text)| !segment.kind.is_verbatim() && text.is_some())
+            .count() as u32,
+    };
+
+    let mut writes = Vec::new();
+    let mut index = 0;
+    while index < segments.len() {
+        if translated[index].is_some() {
+            index += 1;
+            continue;
+        }
+        let run_end = (index..segments.len())
+            .find(|&position| translated[position].is_some())
+            .unwrap_or(segments.len());
+        let run = &segments[index..run_end];
+        let chunk = translator
+            .translate(
+                &join_segments(run.iter().map(|segment| segment.text)),
+                language,
+            )
+            .await?;
+        let run_translations = match pair_translated_segments(run, &chunk) {
+            Some(parts) => parts,
+            None if run.len() == 1 => vec![chunk.trim().to_string()],
+            None => {
+                let mut parts = Vec::with_capacity(run.len());
+                for segment in run {
+                    parts.push(translator.translate(segment.text, language).await?);
+                }
+                parts
+            }
+        };
+        for (offset, text) in run_translations.into_iter().enumerate() {
+            writes.push(TranslationWrite {
+                cache_key: keys[index + offset].clone(),
+                language: language.clone(),
+                text: text.clone(),
+            });
+            translated[index + offset] = Some(text);
+        }
+        index = run_end;
+    }
+
+    Ok(MarkdownTranslation {
+        markdown: join_segments(translated.iter().map(|text| text.as_deref().unwrap_or(""))),
+        reuse,
+        writes,
+    })
+}
+
+/// Seeds translation memory from a whole-body translation made before segments
+/// were stored, so the first edit after an upgrade still reuses unchanged blocks.
+/// Nothing is stored when the block structure does not line up.
+pub async fn remember_translated_segments(
+    db: &DatabaseConnection,
+    source_markdown: &str,
+    translated_markdown: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<usize, Error> {
+    let segments = split_markdown_segments(source_markdown);
+    let Some(parts) = pair_translated_segments(&segments, translated_markdown) else {
+        return Ok(0);
+    };
+    let writes = segments
+        .iter()
+        .zip(parts)
+        .filter(|(segment, _)| !segment.kind.is_verbatim())
+        .map(|(segment, text)| TranslationWrite {
+            cache_key: segment_cache_key(segment.text),
+            language: language.clone(),
+            text,
+        })
+        .collect::<Vec<_>>();
+    save_translations(db, &writes).await?;
+    Ok(writes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pair_translated_segments, split_markdown_segments, SegmentKind, SegmentReuse};
+
+    #[test]
+    fn split_markdown_segments_keeps_headings_images_and_fences_apart() {
+        let markdown = "## Committee Response\n\nFirst paragraph\ncontinues here.\n\n\n![Alt](/image/abc \"Prompt\")\n\n```\ncode\n\nmore code\n```\n\nLast paragraph.  \n";
+        let segments = split_markdown_segments(markdown);
+
+        assert_eq!(
+            segments
+                .iter()
+                .map(|segment| segment.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                SegmentKind::Heading,
+                SegmentKind::Text,
+                SegmentKind::Image,
+                SegmentKind::Code,
+                SegmentKind::Text,
+            ]
+        );
+        assert_eq!(segments[1].text, "First paragraph\ncontinues here.");
+        assert_eq!(segments[3].text, "```\ncode\n\nmore code\n```");
+        assert_eq!(segments[4].text, "Last paragraph.");
+    }
+
+    #[test]
+    fn pair_translated_segments_requires_matching_structure() {
+        let sources = split_markdown_segments("## Title\n\nBody one.\n\nBody two.");
+
+        assert_eq!(
+            pair_translated_segments(&sources, "## Título\n\nCorpo um.\n\nCorpo dois.").unwrap(),
+            vec!["## Título", "Corpo um.", "Corpo dois."]
+        );
+        assert!(pair_translated_segments(&sources, "## Título\n\nCorpo um e dois.").is_none());
+        assert!(pair_translated_segments(&sources, "Título\n\nCorpo um.\n\nCorpo dois.").is_none());
+    }
+
+    #[test]
+    fn segment_reuse_ratio_ignores_empty_bodies() {
+        assert_eq!(SegmentReuse::default().ratio(), None);
+        assert_eq!(
+            SegmentReuse {
+                total: 4,
+                reused: 3
+            }
+            .ratio(),
+            Some(0.75)
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::error::Error;
+use crate::llm::article_generator::ensure_no_prompt_leakage;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::services::translation_memory::{split_markdown_segments, SegmentKind};
+
+/// Paragraphs at least this long may not reappear verbatim in a translation;
+/// shorter ones are often names, quotes or figures that legitimately survive.
+const MIN_UNTRANSLATED_WORDS: usize = 12;
+/// Bodies shorter than this are too small for the length ratio to mean much.
+const MIN_LENGTH_RATIO_SOURCE_CHARS: usize = 200;
+const MIN_LENGTH_RATIO: f64 = 0.4;
+const MAX_LENGTH_RATIO: f64 = 2.5;
+/// Language detection needs a reasonable sample before it can be trusted.
+const MIN_DETECTION_WORDS: usize = 30;
+const MIN_DETECTION_HITS: usize = 3;
+
+/// Frequent words that are distinctive for each language; words shared between
+/// the Romance languages are left out so close relatives stay apart.
+const STOPWORD_PROFILES: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "is", "was", "that", "with", "for", "said", "were", "this",
+            "from", "have", "which",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "não", "com", "os", "ao", "das", "dos", "pelo", "pela", "também", "foi", "são", "seu",
+            "sua", "isso", "uma",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "los", "las", "del", "y", "por", "con", "fue", "pero", "más", "sus", "este",
+            "hasta", "cuando", "muy",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "les", "des", "et", "est", "du", "dans", "pour", "pas", "qui", "sur", "au",
+            "avec", "été", "ont",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "und", "das", "ist", "nicht", "mit", "den", "von", "zu", "ein", "eine",
+            "auf", "für", "sich",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "che", "della", "di", "per", "sono", "gli", "nel", "alla", "delle", "anche", "è",
+            "dei", "lo", "degli", "questo",
+        ],
+    ),
+];
+
+fn image_regex() -> &'static Regex {
+    static IMAGE_REGEX: OnceLock<Regex> = OnceLock::new();
+    IMAGE_REGEX
+        .get_or_init(|| Regex::new(r#"!\[[^\]]*\]\([^)]*\)"#).expect("image regex must compile"))
+}
+
+fn quality_error(language: &SupportedTranslationLanguage, reason: String) -> Error {
+    Error::Llm(format!(
+        "Translation to {} failed quality checks: {}",
+        language.code, reason
+    ))
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct MarkdownOutline {
+    headings: usize,
+    images: usize,
+}
+
+fn markdown_outline(markdown: &str) -> MarkdownOutline {
+    let mut outline = MarkdownOutline::default();
+    for segment in split_markdown_segments(markdown) {
+        if segment.kind == SegmentKind::Code {
+            continue;
+        }
+        outline.headings += segment
+            .text
+            .lines()
+            .filter(|line| line.trim_start().starts_with('#'))
+            .count();
+        outline.images += image_regex().find_iter(segment.text).count();
+    }
+    outline
+}
+
+/// Prose of a markdown body, without code blocks, image syntax and heading marks.
+fn prose_text(markdown: &str) -> String {
+    split_markdown_segments(markdown)
+        .into_iter()
+        .filter(|segment| matches!(segment.kind, SegmentKind::Heading | SegmentKind::Text))
+        .map(|segment| image_regex().replace_all(segment.text, "").into_owned())
+        .map(|text| text.trim_start_matches('#').trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn script_language(text: &str) -> Option<&'static str> {
+    let mut letters = 0;
+    let mut arabic = 0;
+    let mut hebrew = 0;
+    for character in text.chars().filter(|character| character.is_alphabetic()) {
+        letters += 1;
+        match character {
+            '\u{0600}'..='\u{06FF}' | '\u{0750}'..='\u{077F}' => arabic += 1,
+            '\u{0590}'..='\u{05FF}' => hebrew += 1,
+            _ => {}
+        }
+    }
+    if letters == 0 {
+        None
+    } else if arabic * 2 > letters {
+        Some("ar")
+    } else if hebrew * 2 > letters {
+        Some("he")
+    } else {
+        None
+    }
+}
+
+/// Best guess at the language code of `text`, or `None` when it has fewer than
+/// `min_words` words or no profile stands out. Arabic and Hebrew are recognised
+/// by script regardless of length.
+pub fn detect_language(text: &str, min_words: usize) -> Option<&'static str> {
+    if let Some(code) = script_language(text) {
+        return Some(code);
+    }
+    let words = words(text);
+    if words.len() < min_words {
+        return None;
+    }
+    STOPWORD_PROFILES
+        .iter()
+        .map(|(code, stopwords)| {
+            let hits = words
+                .iter()
+                .filter(|word| stopwords.contains(&word.as_str()))
+                .count();
+            (*code, hits)
+        })
+        .filter(|(_, hits)| *hits >= MIN_DETECTION_HITS)
+        .max_by_key(|(_, hits)| *hits)
+        .map(|(code, _)| code)
+}
+
+fn has_detection_profile(language: &SupportedTranslationLanguage) -> bool {
+    matches!(language.code.as_ref(), "ar" | "he")
+        || STOPWORD_PROFILES
+            .iter()
+            .any(|(code, _)| *code == language.code)
+}
+
+fn check_detected_language(
+    source: &str,
+    translated: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<(), Error> {
+    let Some(detected) = detect_language(translated, MIN_DETECTION_WORDS) else {
+        return Ok(());
+    };
+    let unexpected = if has_detection_profile(language) {
+        detected != language.code
+    } else {
+        // Without a profile we can still notice text left in the source language.
+        detect_language(source, MIN_DETECTION_WORDS) == Some(detected)
+    };
+    if unexpected {
+        return Err(quality_error(
+            language,
+            format!("text reads as {} instead of {}", detected, language.code),
+        ));
+    }
+    Ok(())
+}
+
+fn check_prompt_leakage(
+    source: &str,
+    translated: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<(), Error> {
+    // Articles may quote the same phrases the check looks for; only flag new ones.
+    if ensure_no_prompt_leakage(source).is_err() {
+        return Ok(());
+    }
+    ensure_no_prompt_leakage(translated).map_err(|err| quality_error(language, err.to_string()))
+}
+
+fn is_untranslated(source: &str, translated: &str) -> bool {
+    words(source).len() >= MIN_UNTRANSLATED_WORDS && source.trim() == translated.trim()
+}
+
+/// Checks a translated article body: heading and image structure, leftover
+/// source paragraphs, length ratio, detected language and prompt leakage.
+pub fn check_translated_markdown(
+    source: &str,
+    translated: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<(), Error> {
+    let source_outline = markdown_outline(source);
+    let translated_outline = markdown_outline(translated);
+    if source_outline.headings != translated_outline.headings {
+        return Err(quality_error(
+            language,
+            format!(
+                "expected {} headings but found {}",
+                source_outline.headings, translated_outline.headings
+            ),
+        ));
+    }
+    if source_outline.images != translated_outline.images {
+        return Err(quality_error(
+            language,
+            format!(
+                "expected {} image blocks but found {}",
+                source_outline.images, translated_outline.images
+            ),
+        ));
+    }
+
+    let translated_paragraphs = split_markdown_segments(translated)
+        .into_iter()
+        .filter(|segment| segment.kind == SegmentKind::Text)
+        .collect::<Vec<_>>();
+    let untranslated = split_markdown_segments(source)
+        .into_iter()
+        .filter(|segment| segment.kind == SegmentKind::Text)
+        .filter(|segment| {
+            translated_paragraphs
+                .iter()
+                .any(|paragraph| is_untranslated(segment.text, paragraph.text))
+        })
+        .count();
+    if untranslated > 0 {
+        return Err(quality_error(
+            language,
+            format!("{} paragraphs were left untranslated", untranslated),
+        ));
+    }
+
+    let source_prose = prose_text(source);
+    let translated_prose = prose_text(translated);
+    let source_chars = source_prose.chars().count();
+    if source_chars >= MIN_LENGTH_RATIO_SOURCE_CHARS {
+        let ratio = translated_prose.chars().count() as f64 / source_chars as f64;
+        if !(MIN_LENGTH_RATIO..=MAX_LENGTH_RATIO).contains(&ratio) {
+            return Err(quality_error(
+                language,
+                format!("length ratio {:.2} is outside the expected range", ratio),
+            ));
+        }
+    }
+
+    check_detected_language(&source_prose, &translated_prose, language)?;
+    check_prompt_leakage(source, translated, language)
+}
+
+/// Checks a short translated field such as a title, description or caption.
+pub fn check_translated_field(
+    source: &str,
+    translated: &str,
+    language: &SupportedTranslationLanguage,
+) -> Result<(), Error> {
+    if is_untranslated(source, translated) {
+        return Err(quality_error(
+            language,
+            "a field was returned untranslated".to_string(),
+        ));
+    }
+    check_detected_language(source, translated, language)?;
+    check_prompt_leakage(source, translated, language)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::llm::prompt_registry::find_supported_translation_language;
+
+    use super::{check_translated_field, check_translated_markdown, detect_language};
+
+    const SOURCE: &str = "## Committee Response\n\nThe standing committee said that the memo was filed with the usual care, and that the clerks were asked to keep the queue moving for the rest of the week.\n\n![Clerks](/image/abc \"Clerks\")\n\n## Closing Note\n\nOfficials said that nothing further was expected from the office, which has handled this kind of notice for many years without incident.";
+    const PORTUGUESE: &str = "## Resposta do Comitê\n\nO comitê permanente disse que o memorando foi arquivado com o cuidado de sempre, e os funcionários foram orientados a manter a fila andando pelo resto da semana, como também é costume dos servidores.\n\n![Clerks](/image/abc \"Clerks\")\n\n## Nota Final\n\nAs autoridades disseram que não se espera mais nada do escritório, que lida com esse tipo de aviso há muitos anos sem incidentes e com a calma de sempre dos seus funcionários.";
+
+    fn portuguese() -> crate::llm::prompt_registry::SupportedTranslationLanguage {
+        find_supported_translation_language("pt").unwrap()
+    }
+
+    #[test]
+    fn accepts_a_faithful_translation() {
+        check_translated_markdown(SOURCE, PORTUGUESE, &portuguese()).unwrap();
+    }
+
+    #[test]
+    fn rejects_missing_headings_and_images() {
+        let without_heading = PORTUGUESE.replacen("## Nota Final\n\n", "", 1);
+        let without_image = PORTUGUESE.replacen("![Clerks](/image/abc \"Clerks\")\n\n", "", 1);
+
+        let heading_error = check_translated_markdown(SOURCE, &without_heading, &portuguese())
+            .unwrap_err()
+            .to_string();
+        let image_error = check_translated_markdown(SOURCE, &without_image, &portuguese())
+            .unwrap_err()
+            .to_string();
+
+        assert!(heading_error.contains("expected 2 headings but found 1"));
+        assert!(image_error.contains("expected 1 image blocks but found 0"));
+    }
+
+    #[test]
+    fn rejects_leftover_source_paragraphs_and_wrong_language() {
+        let leftover = PORTUGUESE.replacen(
+            "As autoridades disseram que não se espera mais nada do escritório, que lida com esse tipo de aviso há muitos anos sem incidentes e com a calma de sempre dos seus funcionários.",
+            "Officials said that nothing further was expected from the office, which has handled this kind of notice for many years without incident.",
+            1,
+        );
+        let spanish = find_supported_translation_language("es").unwrap();
+
+        assert!(check_translated_markdown(SOURCE, &leftover, &portuguese())
+            .unwrap_err()
+            .to_string()
+            .contains("1 paragraphs were left untranslated"));
+        assert!(check_translated_markdown(SOURCE, PORTUGUESE, &spanish)
+            .unwrap_err()
+            .to_string()
+            .contains("reads as pt instead of es"));
+    }
+
+    #[test]
+    fn rejects_truncated_bodies_and_prompt_leakage() {
+        let truncated = "## Resposta do Comitê\n\nO comitê falou.\n\n![Clerks](/image/abc \"Clerks\")\n\n## Nota Final\n\nNada mais.";
+        let leaked = format!(
+            "{}\n\nAs an AI language model, não posso ajudar.",
+            PORTUGUESE
+        );
+
+        assert!(check_translated_markdown(SOURCE, truncated, &portuguese())
+            .unwrap_err()
+            .to_string()
+            .contains("length ratio"));
+        assert!(check_translated_markdown(SOURCE, &leaked, &portuguese())
+            .unwrap_err()
+            .to_string()
+            .contains("leaked prompt scaffolding"));
+        assert!(check_translated_field(
+            "Officials said nothing.",
+            "As an AI language model I cannot translate this.",
+            &portuguese()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn detects_scripts_and_skips_short_samples() {
+        assert_eq!(
+            detect_language("قالت اللجنة إن المذكرة إجرائية", 30),
+            Some("ar")
+        );
+        assert_eq!(detect_language("Resposta do Comitê", 30), None);
+    }
+}
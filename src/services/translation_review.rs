@@ -0,0 +1,156 @@
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+
+use crate::article_id::canonical_article_id;
+use crate::entities::translation_override;
+use crate::error::Error;
+use crate::llm::prompt_registry::SupportedTranslationLanguage;
+use crate::repositories::translation_overrides::{
+    delete_translation_override, find_translation_overrides, is_current_override,
+    mark_stale_translation_overrides, save_translation_override,
+};
+use crate::repositories::translations::TranslationField;
+use crate::services::article_translations::{load_machine_translation_fields, ArticleSourceText};
+
+/// One row of the translation editor: the source text next to the text that
+/// readers currently get, plus the review state of that field.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct TranslationReviewField {
+    pub name: &'static str,
+    pub source: String,
+    pub translation: String,
+    pub machine_translated: bool,
+    pub reviewed: bool,
+    pub reviewed_by: Option<String>,
+    /// Reviewed text kept from before the source field was edited.
+    pub stale_translation: Option<String>,
+}
+
+/// A submitted editor field next to the text the editor showed for it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SubmittedReviewField<'a> {
+    pub shown: &'a str,
+    pub submitted: &'a str,
+}
+
+impl SubmittedReviewField<'_> {
+    /// Browsers submit textarea line breaks as CRLF, so compare without them.
+    fn edited(&self) -> bool {
+        normalize_review_text(self.shown) != normalize_review_text(self.submitted)
+    }
+}
+
+fn normalize_review_text(text: &str) -> String {
+    text.trim().replace("\r\n", "\n")
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TranslationReviewOutcome {
+    pub saved: usize,
+    pub cleared: usize,
+}
+
+fn reviewable_fields<'a>(source: &ArticleSourceText<'a>) -> [(TranslationField, &'a str); 3] {
+    [
+        (TranslationField::Title, source.title),
+        (TranslationField::Description, source.description),
+        (TranslationField::Markdown, source.markdown),
+    ]
+}
+
+fn field_override(
+    overrides: &[translation_override::Model],
+    field: TranslationField,
+) -> Option<&translation_override::Model> {
+    overrides.iter().find(|row| row.field == field.as_str())
+}
+
+pub async fn load_translation_review(
+    db: &DatabaseConnection,
+    source: &ArticleSourceText<'_>,
+    language: &SupportedTranslationLanguage,
+) -> Result<Vec<TranslationReviewField>, Error> {
+    let machine = load_machine_translation_fields(db, source, language).await?;
+    let overrides =
+        find_translation_overrides(db, &[canonical_article_id(source.article_id)], language)
+            .await?;
+    let machine = [machine.title, machine.description, machine.markdown];
+
+    Ok(reviewable_fields(source)
+        .into_iter()
+        .zip(machine)
+        .map(|((field, source_text), machine)| {
+            let existing = field_override(&overrides, field);
+            let current = existing.filter(|row| is_current_override(row, source_text));
+            let stale_translation = existing
+                .filter(|row| !is_current_override(row, source_text))
+                .map(|row| row.translation.clone());
+            TranslationReviewField {
+                name: field.as_str(),
+                source: source_text.to_string(),
+                translation: current
+                    .map(|row| row.translation.clone())
+                    .or_else(|| machine.clone())
+                    .or_else(|| stale_translation.clone())
+                    .unwrap_or_default(),
+                machine_translated: machine.is_some(),
+                reviewed: current.is_some(),
+                reviewed_by: current.map(|row| row.reviewed_by.clone()),
+                stale_translation,
+            }
+        })
+        .collect())
+}
+
+/// Stores each edited field as a reviewed override of the current source text.
+/// A field cleared by the editor drops its override so readers fall back to the
+/// machine translation. Fields submitted as shown are left alone, so machine text
+/// is not frozen as reviewed and stale reviews stay stale until edited.
+pub async fn save_translation_review(
+    db: &DatabaseConnection,
+    source: &ArticleSourceText<'_>,
+    language: &SupportedTranslationLanguage,
+    reviewed_by: &str,
+    fields: [SubmittedReviewField<'_>; 3],
+) -> Result<TranslationReviewOutcome, Error> {
+    let article_id = canonical_article_id(source.article_id);
+    let mut outcome = TranslationReviewOutcome::default();
+    for ((field, source_text), submitted) in reviewable_fields(source).into_iter().zip(fields) {
+        if !submitted.edited() {
+            continue;
+        }
+        let translation = submitted.submitted.trim();
+        if translation.is_empty() {
+            if delete_translation_override(db, &article_id, language, field).await? {
+                outcome.cleared += 1;
+            }
+            continue;
+        }
+        save_translation_override(
+            db,
+            &article_id,
+            language,
+            field,
+            source_text,
+            translation,
+            reviewed_by,
+        )
+        .await?;
+        outcome.saved += 1;
+    }
+    Ok(outcome)
+}
+
+/// Marks reviewed translations stale in every language after the source text
+/// changed, instead of discarding them with the machine translations.
+pub async fn mark_stale_translation_reviews(
+    db: &DatabaseConnection,
+    source: &ArticleSourceText<'_>,
+) -> Result<u64, Error> {
+    mark_stale_translation_overrides(
+        db,
+        &canonical_article_id(source.article_id),
+        &reviewable_fields(source),
+    )
+    .await
+}
@@ -1,15 +1,39 @@
+use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::header;
 use axum::response::IntoResponse;
 use sea_orm::prelude::DateTime;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tokio::sync::Mutex;
 
 use crate::app_state::AppState;
-use crate::entities::{content, prelude::*};
+use crate::article_id::canonical_article_id;
+use crate::entities::{content, content_image, prelude::*};
 use crate::error::Error;
-use crate::services::site_paths::{localized_root_path, supported_site_languages};
+use crate::image_status::IMAGE_STATUS_COMPLETED;
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, supported_translation_languages,
+    SupportedTranslationLanguage,
+};
+use crate::services::article_language::{article_alternate_languages, article_source_language_for};
+use crate::services::article_translations::{
+    cached_translation_languages_by_article, owned_article_source_text,
+};
+use crate::services::site_paths::{localized_path, supported_site_languages};
+use crate::services::site_text::default_site_language;
+
+/// Child sitemaps stay well below the protocol limit of 50,000 URLs because
+/// every entry also carries its language alternates.
+const SITEMAP_MAX_URLS: usize = 5_000;
+const TRANSLATION_LOOKUP_BATCH: usize = 500;
+const IMAGE_SITEMAP_NAME: &str = "images";
+/// Crawlers fetch the index and every child sitemap in a burst; they all share one
+/// article list, rebuilt at most this often.
+const SITEMAP_CACHE_TTL: Duration = Duration::from_secs(15 * 60);
 
 fn site_url() -> String {
     env::var("SITE_URL")
@@ -28,50 +52,373 @@ fn xml_escape(value: &str) -> String {
         .replace('\'', "&apos;")
 }
 
-pub async fn get_sitemap(State(state): State<AppState>) -> Result<impl IntoResponse, Error> {
-    let items: Vec<(String, DateTime)> = Content::find()
+fn xml_response(xml: String) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+        xml,
+    )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum SitemapFile {
+    Language(SupportedTranslationLanguage, usize),
+    Images(usize),
+}
+
+impl SitemapFile {
+    /// Parses child sitemap names such as `pt-2.xml` or `images-1.xml`.
+    fn parse(name: &str) -> Option<Self> {
+        let (kind, page) = name.strip_suffix(".xml")?.rsplit_once('-')?;
+        let page = page.parse::<usize>().ok().filter(|page| *page > 0)?;
+        if kind == IMAGE_SITEMAP_NAME {
+            return Some(Self::Images(page));
+        }
+        find_supported_translation_language(kind)
+            .filter(|language| language.code == kind)
+            .map(|language| Self::Language(language, page))
+    }
+
+    fn file_name(&self) -> String {
+        match self {
+            Self::Language(language, page) => format!("{}-{}.xml", language.code, page),
+            Self::Images(page) => format!("{}-{}.xml", IMAGE_SITEMAP_NAME, page),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SitemapArticle {
+    slug: String,
+    created_at: DateTime,
+    /// Source language first, then every language with a complete translation.
+    languages: Vec<SupportedTranslationLanguage>,
+    image_ids: Vec<String>,
+}
+
+impl SitemapArticle {
+    fn path(&self) -> String {
+        format!("/content/{}", self.slug)
+    }
+
+    fn available_in(&self, language: &SupportedTranslationLanguage) -> bool {
+        self.languages
+            .iter()
+            .any(|available| available.code == language.code)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SitemapEntry {
+    path: String,
+    lastmod: Option<DateTime>,
+    alternates: Vec<SupportedTranslationLanguage>,
+    default_language: SupportedTranslationLanguage,
+}
+
+#[derive(Debug)]
+struct CachedSitemapArticles {
+    articles: Arc<Vec<SitemapArticle>>,
+    loaded_at: Instant,
+}
+
+/// The published-article list behind `/sitemap.xml` and its child sitemaps.
+#[derive(Clone, Debug)]
+pub struct SitemapCache {
+    cached: Arc<Mutex<Option<CachedSitemapArticles>>>,
+    ttl: Duration,
+}
+
+impl Default for SitemapCache {
+    fn default() -> Self {
+        Self::new(SITEMAP_CACHE_TTL)
+    }
+}
+
+impl SitemapCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            cached: Arc::new(Mutex::new(None)),
+            ttl,
+        }
+    }
+
+    /// The lock is held while reloading so concurrent misses wait for one query
+    /// instead of each rebuilding the list.
+    async fn articles(&self, db: &DatabaseConnection) -> Result<Arc<Vec<SitemapArticle>>, Error> {
+        let mut cached = self.cached.lock().await;
+        if let Some(cached) = cached
+            .as_ref()
+            .filter(|cached| cached.loaded_at.elapsed() < self.ttl)
+        {
+            return Ok(Arc::clone(&cached.articles));
+        }
+        let articles = Arc::new(load_sitemap_articles(db).await?);
+        *cached = Some(CachedSitemapArticles {
+            articles: Arc::clone(&articles),
+            loaded_at: Instant::now(),
+        });
+        Ok(articles)
+    }
+}
+
+async fn load_sitemap_articles(db: &DatabaseConnection) -> Result<Vec<SitemapArticle>, Error> {
+    let articles = Content::find()
         .filter(content::Column::Flagged.eq(false))
         .filter(content::Column::Generating.eq(false))
         .filter(content::Column::Published.eq(true))
-        .select_only()
-        .column(content::Column::Slug)
-        .column(content::Column::CreatedAt)
         .order_by_desc(content::Column::CreatedAt)
-        .into_tuple()
-        .all(&state.db)
+        .all(db)
         .await
         .map_err(|e| Error::Database(format!("Failed to build sitemap query: {}", e)))?;
+    let mut images = HashMap::<String, Vec<content_image::Model>>::new();
+    for image in ContentImage::find()
+        .filter(content_image::Column::ContentId.is_in(articles.iter().map(|a| a.id.clone())))
+        .order_by_asc(content_image::Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(|e| Error::Database(format!("Failed to load sitemap images: {}", e)))?
+    {
+        images
+            .entry(image.content_id.clone())
+            .or_default()
+            .push(image);
+    }
 
-    let site_url = site_url();
-    let mut xml = String::with_capacity(items.len() * 128 + 512);
+    let enabled_languages = supported_translation_languages();
+    let mut sitemap_articles = Vec::with_capacity(articles.len());
+    for batch in articles.chunks(TRANSLATION_LOOKUP_BATCH) {
+        let sources = batch
+            .iter()
+            .filter_map(|article| {
+                owned_article_source_text(
+                    article,
+                    images.get(&article.id).map_or(&[][..], Vec::as_slice),
+                )
+            })
+            .collect::<Vec<_>>();
+        let translations = cached_translation_languages_by_article(db, &sources).await?;
+        for article in batch {
+            let source_language = article_source_language_for(article.language_id.as_deref());
+            let cached = translations
+                .get(&canonical_article_id(&article.id))
+                .map_or(&[][..], Vec::as_slice);
+            let languages = readable_languages(&source_language, cached, &enabled_languages);
+            // Neither the source language nor any cached translation is enabled, so the
+            // article has no URL worth listing.
+            if languages.is_empty() {
+                continue;
+            }
+            let image_ids = images
+                .get(&article.id)
+                .into_iter()
+                .flatten()
+                .filter(|image| image.status == IMAGE_STATUS_COMPLETED && !image.flagged)
+                .map(|image| image.id.clone())
+                .collect();
+            sitemap_articles.push(SitemapArticle {
+                slug: article.slug.clone(),
+                created_at: article.created_at,
+                languages,
+                image_ids,
+            });
+        }
+    }
+    Ok(sitemap_articles)
+}
+
+/// The enabled languages an article can be read in, source language first.
+fn readable_languages(
+    source_language: &SupportedTranslationLanguage,
+    cached: &[SupportedTranslationLanguage],
+    enabled_languages: &[SupportedTranslationLanguage],
+) -> Vec<SupportedTranslationLanguage> {
+    article_alternate_languages(source_language, cached)
+        .into_iter()
+        .filter(|language| enabled_languages.contains(language))
+        .collect()
+}
+
+/// Every URL listed in the sitemap for `language`: the localized home page for
+/// site locales, then each article readable in that language.
+fn language_entries(
+    language: &SupportedTranslationLanguage,
+    articles: &[SitemapArticle],
+) -> Vec<SitemapEntry> {
+    let site_languages = supported_site_languages();
+    let home = site_languages
+        .iter()
+        .any(|site_language| site_language.code == language.code)
+        .then(|| SitemapEntry {
+            path: "/".to_string(),
+            lastmod: None,
+            alternates: site_languages.clone(),
+            default_language: default_site_language(),
+        });
+    home.into_iter()
+        .chain(
+            articles
+                .iter()
+                .filter(|article| article.available_in(language))
+                .filter_map(|article| {
+                    Some(SitemapEntry {
+                        path: article.path(),
+                        lastmod: Some(article.created_at),
+                        alternates: article.languages.clone(),
+                        default_language: article.languages.first()?.clone(),
+                    })
+                }),
+        )
+        .collect()
+}
+
+fn page_count(items: usize) -> usize {
+    items.div_ceil(SITEMAP_MAX_URLS)
+}
+
+fn page_slice<T>(items: &[T], page: usize) -> Option<&[T]> {
+    let start = (page - 1) * SITEMAP_MAX_URLS;
+    (start < items.len()).then(|| &items[start..items.len().min(start + SITEMAP_MAX_URLS)])
+}
+
+fn sitemap_files(articles: &[SitemapArticle]) -> Vec<SitemapFile> {
+    let mut files = supported_translation_languages()
+        .into_iter()
+        .flat_map(|language| {
+            (1..=page_count(language_entries(&language, articles).len()))
+                .map(move |page| SitemapFile::Language(language.clone(), page))
+        })
+        .collect::<Vec<_>>();
+    let illustrated = articles
+        .iter()
+        .filter(|article| !article.image_ids.is_empty())
+        .count();
+    files.extend((1..=page_count(illustrated)).map(SitemapFile::Images));
+    files
+}
+
+fn render_sitemap_index(site_url: &str, files: &[SitemapFile]) -> String {
+    let mut xml = String::with_capacity(files.len() * 96 + 256);
     xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
-    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">");
-    for language in supported_site_languages() {
+    xml.push_str("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">");
+    for file in files {
+        xml.push_str("<sitemap><loc>");
+        xml.push_str(&xml_escape(&format!(
+            "{}/sitemaps/{}",
+            site_url,
+            file.file_name()
+        )));
+        xml.push_str("</loc></sitemap>");
+    }
+    xml.push_str("</sitemapindex>");
+    xml
+}
+
+fn render_language_sitemap(
+    site_url: &str,
+    language: &SupportedTranslationLanguage,
+    entries: &[SitemapEntry],
+) -> String {
+    let mut xml = String::with_capacity(entries.len() * 512 + 256);
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    xml.push_str(
+        "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" \
+         xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">",
+    );
+    for entry in entries {
         xml.push_str("<url><loc>");
         xml.push_str(&xml_escape(&format!(
             "{}{}",
             site_url,
-            localized_root_path(language)
+            localized_path(language, &entry.path)
         )));
-        xml.push_str("</loc></url>");
+        xml.push_str("</loc>");
+        if let Some(lastmod) = entry.lastmod {
+            xml.push_str("<lastmod>");
+            xml.push_str(&lastmod.format("%F").to_string());
+            xml.push_str("</lastmod>");
+        }
+        let alternates = entry
+            .alternates
+            .iter()
+            .map(|alternate| (alternate.code.as_ref(), alternate))
+            .chain(std::iter::once(("x-default", &entry.default_language)));
+        for (hreflang, alternate) in alternates {
+            xml.push_str("<xhtml:link rel=\"alternate\" hreflang=\"");
+            xml.push_str(hreflang);
+            xml.push_str("\" href=\"");
+            xml.push_str(&xml_escape(&format!(
+                "{}{}",
+                site_url,
+                localized_path(alternate, &entry.path)
+            )));
+            xml.push_str("\"/>");
+        }
+        xml.push_str("</url>");
     }
+    xml.push_str("</urlset>");
+    xml
+}
 
-    for (slug, created_at) in items {
-        for language in supported_site_languages() {
-            let loc = format!("{}/{}/content/{}", site_url, language.code, slug);
-            xml.push_str("<url><loc>");
-            xml.push_str(&xml_escape(&loc));
-            xml.push_str("</loc><lastmod>");
-            xml.push_str(&created_at.format("%F").to_string());
-            xml.push_str("</lastmod></url>");
+fn render_image_sitemap(site_url: &str, articles: &[&SitemapArticle]) -> String {
+    let mut xml = String::with_capacity(articles.len() * 384 + 256);
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    xml.push_str(
+        "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" \
+         xmlns:image=\"http://www.google.com/schemas/sitemap-image/1.1\">",
+    );
+    for article in articles {
+        let Some(language) = article.languages.first() else {
+            continue;
+        };
+        xml.push_str("<url><loc>");
+        xml.push_str(&xml_escape(&format!(
+            "{}{}",
+            site_url,
+            localized_path(language, &article.path())
+        )));
+        xml.push_str("</loc>");
+        for image_id in &article.image_ids {
+            xml.push_str("<image:image><image:loc>");
+            xml.push_str(&xml_escape(&format!("{}/image/{}", site_url, image_id)));
+            xml.push_str("</image:loc></image:image>");
         }
+        xml.push_str("</url>");
     }
     xml.push_str("</urlset>");
+    xml
+}
 
-    Ok((
-        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
-        xml,
-    ))
+pub async fn get_sitemap(State(state): State<AppState>) -> Result<impl IntoResponse, Error> {
+    let articles = state.sitemap_cache.articles(&state.db).await?;
+    Ok(xml_response(render_sitemap_index(
+        &site_url(),
+        &sitemap_files(&articles),
+    )))
+}
+
+pub async fn get_child_sitemap(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<impl IntoResponse, Error> {
+    let not_found = || Error::NotFound(Some(format!("Sitemap {} not found", name)));
+    let file = SitemapFile::parse(&name).ok_or_else(not_found)?;
+    let articles = state.sitemap_cache.articles(&state.db).await?;
+    let xml = match file {
+        SitemapFile::Language(language, page) => {
+            let entries = language_entries(&language, &articles);
+            let entries = page_slice(&entries, page).ok_or_else(not_found)?;
+            render_language_sitemap(&site_url(), &language, entries)
+        }
+        SitemapFile::Images(page) => {
+            let illustrated = articles
+                .iter()
+                .filter(|article| !article.image_ids.is_empty())
+                .collect::<Vec<_>>();
+            let illustrated = page_slice(&illustrated, page).ok_or_else(not_found)?;
+            render_image_sitemap(&site_url(), illustrated)
+        }
+    };
+    Ok(xml_response(xml))
 }
 
 pub async fn get_robots_txt() -> impl IntoResponse {
@@ -85,3 +432,185 @@ pub async fn get_robots_txt() -> impl IntoResponse {
         robots,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use sea_orm::{ActiveValue, EntityTrait};
+
+    use crate::entities::{content, prelude::Content};
+    use crate::test_support::{preferred_language, TestContext};
+
+    use super::{
+        language_entries, page_count, page_slice, readable_languages, render_image_sitemap,
+        render_language_sitemap, render_sitemap_index, SitemapArticle, SitemapCache, SitemapFile,
+        SITEMAP_MAX_URLS,
+    };
+
+    fn article(slug: &str, languages: &[&str]) -> SitemapArticle {
+        SitemapArticle {
+            slug: slug.to_string(),
+            created_at: chrono::NaiveDate::from_ymd_opt(2026, 4, 20)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            languages: languages
+                .iter()
+                .map(|code| preferred_language(code))
+                .collect(),
+            image_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn child_sitemap_names_round_trip() {
+        for file in [
+            SitemapFile::Language(preferred_language("pt"), 2),
+            SitemapFile::Images(1),
+        ] {
+            assert_eq!(SitemapFile::parse(&file.file_name()), Some(file));
+        }
+        assert_eq!(SitemapFile::parse("pt-0.xml"), None);
+        assert_eq!(SitemapFile::parse("klingon-1.xml"), None);
+        assert_eq!(SitemapFile::parse("portuguese-1.xml"), None);
+    }
+
+    #[test]
+    fn language_sitemaps_only_list_articles_readable_in_that_language() {
+        let articles = [
+            article("translated", &["en", "pt"]),
+            article("english-only", &["en"]),
+        ];
+
+        let entries = language_entries(&preferred_language("pt"), &articles);
+        let xml =
+            render_language_sitemap("https://example.test", &preferred_language("pt"), &entries);
+
+        assert!(xml.contains("<loc>https://example.test/pt/</loc>"));
+        assert!(xml.contains("<loc>https://example.test/pt/content/translated</loc>"));
+        assert!(!xml.contains("english-only"));
+        assert!(xml.contains(
+            "<xhtml:link rel=\"alternate\" hreflang=\"en\" href=\"https://example.test/en/content/translated\"/>"
+        ));
+        assert!(xml.contains(
+            "hreflang=\"x-default\" href=\"https://example.test/en/content/translated\""
+        ));
+    }
+
+    #[test]
+    fn sitemap_pages_are_capped() {
+        let items = vec![0; SITEMAP_MAX_URLS + 1];
+
+        assert_eq!(page_count(items.len()), 2);
+        assert_eq!(page_slice(&items, 2).map(<[_]>::len), Some(1));
+        assert!(page_slice(&items, 3).is_none());
+        assert!(
+            render_sitemap_index("https://example.test", &[SitemapFile::Images(1)])
+                .contains("<loc>https://example.test/sitemaps/images-1.xml</loc>")
+        );
+    }
+
+    #[test]
+    fn disabled_source_languages_leave_only_enabled_translations() {
+        let enabled = [preferred_language("en"), preferred_language("es")];
+        let source = preferred_language("pt");
+
+        assert!(readable_languages(&source, &[], &enabled).is_empty());
+        assert_eq!(
+            readable_languages(&source, &[preferred_language("es")], &enabled),
+            [preferred_language("es")]
+        );
+    }
+
+    #[test]
+    fn image_sitemap_skips_articles_without_a_readable_language() {
+        let mut hidden = article("hidden", &[]);
+        hidden.image_ids = vec!["hidden-image".to_string()];
+        let mut shown = article("shown", &["es"]);
+        shown.image_ids = vec!["shown-image".to_string()];
+
+        let xml = render_image_sitemap("https://example.test", &[&hidden, &shown]);
+
+        assert!(!xml.contains("hidden-image"));
+        assert!(xml.contains("https://example.test/es/content/shown"));
+        assert!(language_entries(&preferred_language("es"), &[hidden])
+            .iter()
+            .all(|entry| entry.path != "/content/hidden"));
+    }
+
+    fn sample_article(id: &str, slug: &str) -> content::ActiveModel {
+        content::ActiveModel {
+            id: ActiveValue::set(id.to_string()),
+            slug: ActiveValue::set(slug.to_string()),
+            content: ActiveValue::set(None),
+            created_at: ActiveValue::set(chrono::Utc::now().naive_utc()),
+            generating: ActiveValue::set(false),
+            generation_started_at: ActiveValue::set(None),
+            generation_finished_at: ActiveValue::set(None),
+            flagged: ActiveValue::set(false),
+            model: ActiveValue::set("test-model".to_string()),
+            prompt_version: ActiveValue::set(1),
+            fail_count: ActiveValue::set(0),
+            description: ActiveValue::set("Officials confirmed the schedule.".to_string()),
+            image_id: ActiveValue::set(None),
+            title: ActiveValue::set("Schedule Confirmed".to_string()),
+            user_input: ActiveValue::set("Briefing request".to_string()),
+            image_prompt: ActiveValue::set(None),
+            user_email: ActiveValue::set(None),
+            votes: ActiveValue::set(0),
+            hot_score: ActiveValue::set(0.0),
+            generation_time_ms: ActiveValue::set(None),
+            flarum_id: ActiveValue::set(None),
+            markdown: ActiveValue::set(Some("The schedule was confirmed.".to_string())),
+            converted: ActiveValue::set(true),
+            longview_count: ActiveValue::set(0),
+            impression_count: ActiveValue::set(0),
+            click_count: ActiveValue::set(0),
+            language_id: ActiveValue::set(None),
+            author_email: ActiveValue::set(None),
+            published: ActiveValue::set(true),
+            recovered_from_dead_link: ActiveValue::set(false),
+        }
+    }
+
+    fn slugs(articles: &[SitemapArticle]) -> Vec<&str> {
+        articles
+            .iter()
+            .map(|article| article.slug.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sitemap_articles_are_reused_until_the_cache_expires() {
+        let ctx = TestContext::new().await;
+        let db = &ctx.state.db;
+        Content::insert(sample_article("first", "first-article"))
+            .exec(db)
+            .await
+            .unwrap();
+        let cached = SitemapCache::new(Duration::from_secs(60 * 60));
+        let expired = SitemapCache::new(Duration::ZERO);
+        assert_eq!(
+            slugs(&cached.articles(db).await.unwrap()),
+            ["first-article"]
+        );
+        assert_eq!(
+            slugs(&expired.articles(db).await.unwrap()),
+            ["first-article"]
+        );
+
+        Content::insert(sample_article("second", "second-article"))
+            .exec(db)
+            .await
+            .unwrap();
+        assert_eq!(
+            slugs(&cached.articles(db).await.unwrap()),
+            ["first-article"]
+        );
+        assert_eq!(
+            slugs(&expired.articles(db).await.unwrap()),
+            ["second-article", "first-article"]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use std::sync::OnceLock;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+/// Meta tags publishers use for the original publication date, most specific first.
+const PUBLISHED_META_KEYS: &[&str] = &[
+    "article:published_time",
+    "og:published_time",
+    "datepublished",
+    "parsely-pub-date",
+    "sailthru.date",
+    "dc.date.issued",
+    "dc.date",
+    "dcterms.date",
+    "citation_publication_date",
+    "pubdate",
+    "date",
+];
+const EARLIEST_PLAUSIBLE_YEAR: i32 = 1990;
+
+/// Finds when a page was published: `<meta>` tags, then JSON-LD `datePublished`,
+/// then a `<time datetime>` element, then a date embedded in the URL path.
+pub fn extract_published_date(html: &str, url: &str) -> Option<NaiveDate> {
+    published_from_meta(html)
+        .or_else(|| published_from_json_ld(html))
+        .or_else(|| published_from_time_element(html))
+        .or_else(|| published_from_url(url))
+}
+
+pub fn published_from_url(url: &str) -> Option<NaiveDate> {
+    static URL_DATE: OnceLock<Regex> = OnceLock::new();
+    let url_date = URL_DATE.get_or_init(|| {
+        Regex::new(r"/((?:19|20)\d{2})[/-](\d{1,2})[/-](\d{1,2})(?:[/\-_.]|$)")
+            .expect("url date regex must compile")
+    });
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    url_date.captures(path).and_then(|capture| {
+        plausible_date(
+            capture[1].parse().ok()?,
+            capture[2].parse().ok()?,
+            capture[3].parse().ok()?,
+        )
+    })
+}
+
+fn published_from_meta(html: &str) -> Option<NaiveDate> {
+    static META: OnceLock<Regex> = OnceLock::new();
+    static ATTRIBUTE: OnceLock<Regex> = OnceLock::new();
+    let meta =
+        META.get_or_init(|| Regex::new(r"(?is)<meta\b[^>]*>").expect("meta regex must compile"));
+    let attribute = ATTRIBUTE.get_or_init(|| {
+        Regex::new(r#"(?is)([a-z:\-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+            .expect("attribute regex must compile")
+    });
+
+    let mut found = Vec::new();
+    for tag in meta.find_iter(html) {
+        let mut key = None;
+        let mut content = None;
+        for capture in attribute.captures_iter(tag.as_str()) {
+            let value = capture
+                .get(2)
+                .or_else(|| capture.get(3))
+                .map_or("", |value| value.as_str());
+            match capture[1].to_ascii_lowercase().as_str() {
+                "property" | "name" | "itemprop" => key = Some(value.trim().to_ascii_lowercase()),
+                "content" => content = Some(value),
+                _ => {}
+            }
+        }
+        let (Some(key), Some(content)) = (key, content) else {
+            continue;
+        };
+        if let Some(rank) = PUBLISHED_META_KEYS.iter().position(|known| *known == key) {
+            if let Some(date) = parse_date_prefix(content) {
+                found.push((rank, date));
+            }
+        }
+    }
+    found
+        .into_iter()
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, date)| date)
+}
+
+fn published_from_json_ld(html: &str) -> Option<NaiveDate> {
+    static DATE_PUBLISHED: OnceLock<Regex> = OnceLock::new();
+    let date_published = DATE_PUBLISHED.get_or_init(|| {
+        Regex::new(
+            r#"(?is)<script[^>]+application/ld\+json[^>]*>.*?"datePublished"\s*:\s*"([^"]+)""#,
+        )
+        .expect("json-ld date regex must compile")
+    });
+    date_published
+        .captures(html)
+        .and_then(|capture| parse_date_prefix(&capture[1]))
+}
+
+fn published_from_time_element(html: &str) -> Option<NaiveDate> {
+    static TIME: OnceLock<Regex> = OnceLock::new();
+    let time = TIME.get_or_init(|| {
+        Regex::new(r#"(?is)<time\b[^>]*\bdatetime\s*=\s*["']([^"']+)["']"#)
+            .expect("time element regex must compile")
+    });
+    time.captures(html)
+        .and_then(|capture| parse_date_prefix(&capture[1]))
+}
+
+/// Accepts ISO-8601 dates and datetimes (`2026-05-01`, `2026-05-01T09:00:00Z`).
+fn parse_date_prefix(value: &str) -> Option<NaiveDate> {
+    static ISO_DATE: OnceLock<Regex> = OnceLock::new();
+    let iso_date = ISO_DATE.get_or_init(|| {
+        Regex::new(r"^\s*(\d{4})-(\d{1,2})-(\d{1,2})").expect("iso date regex must compile")
+    });
+    let capture = iso_date.captures(value)?;
+    plausible_date(
+        capture[1].parse().ok()?,
+        capture[2].parse().ok()?,
+        capture[3].parse().ok()?,
+    )
+}
+
+fn plausible_date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let latest = chrono::Utc::now().date_naive() + chrono::Duration::days(1);
+    (year >= EARLIEST_PLAUSIBLE_YEAR && date <= latest).then_some(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{extract_published_date, published_from_url};
+
+    fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    #[test]
+    fn meta_tags_win_over_json_ld_and_url() {
+        let html = r#"<head><meta name="date" content="2024-01-02">
+<meta content="2025-03-04T08:30:00Z" property="article:published_time">
+<script type="application/ld+json">{"datePublished": "2023-01-01"}</script></head>"#;
+
+        assert_eq!(
+            extract_published_date(html, "https://example.com/2022/01/01/story"),
+            date(2025, 3, 4)
+        );
+    }
+
+    #[test]
+    fn json_ld_and_time_elements_are_used_without_meta_tags() {
+        let json_ld = r#"<script type="application/ld+json">{"@type":"NewsArticle","datePublished":"2025-06-07T10:00:00+01:00"}</script>"#;
+        assert_eq!(
+            extract_published_date(json_ld, "https://example.com/story"),
+            date(2025, 6, 7)
+        );
+
+        let time = r#"<p>Posted <time datetime="2025-02-03">3 February</time></p>"#;
+        assert_eq!(
+            extract_published_date(time, "https://example.com/story"),
+            date(2025, 2, 3)
+        );
+    }
+
+    #[test]
+    fn url_paths_carry_dates() {
+        assert_eq!(
+            published_from_url("https://example.com/news/2025/04/09/bus-policy"),
+            date(2025, 4, 9)
+        );
+        assert_eq!(
+            published_from_url("https://example.com/2025-04-09-bus-policy"),
+            date(2025, 4, 9)
+        );
+        assert_eq!(
+            published_from_url("https://example.com/item/20250409"),
+            None
+        );
+        assert_eq!(published_from_url("https://example.com/2025/13/40/x"), None);
+    }
+}
@@ -0,0 +1,292 @@
+use std::sync::OnceLock;
+
+use encoding_rs::{Encoding, UTF_8};
+use regex::Regex;
+
+const MIN_BLOCK_CHARS: usize = 40;
+const MAX_LINK_DENSITY: f64 = 0.5;
+const BOILERPLATE_BLOCK_CHARS: usize = 200;
+const NON_CONTENT_TAGS: &[&str] = &[
+    "head", "script", "style", "noscript", "svg", "template", "iframe", "nav", "header", "footer",
+    "aside", "form", "button", "select", "figure",
+];
+const BOILERPLATE_MARKERS: &[&str] = &[
+    "cookie",
+    "subscribe",
+    "newsletter",
+    "sign up",
+    "sign in",
+    "log in",
+    "all rights reserved",
+    "privacy policy",
+    "terms of use",
+    "advertisement",
+    "share this",
+    "related articles",
+];
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReadablePage {
+    pub title: Option<String>,
+    pub text: String,
+}
+
+/// Decodes a response body using the header charset, then a `<meta>` charset, then BOM/UTF-8.
+pub fn decode_body(bytes: &[u8], content_type: Option<&str>) -> String {
+    let encoding = content_type
+        .and_then(charset_from_content_type)
+        .or_else(|| charset_from_meta(bytes))
+        .unwrap_or(UTF_8);
+    let (decoded, _, _) = encoding.decode(bytes);
+    decoded.into_owned()
+}
+
+/// Readability-style extraction: drop chrome and scripts, narrow to the `<article>`/`<main>`
+/// region when there is one, then keep text blocks that are long enough and not mostly links.
+pub fn extract_readable_text(html: &str) -> ReadablePage {
+    let title = extract_title(html);
+    let mut cleaned = comment_re().replace_all(html, " ").into_owned();
+    for tag in NON_CONTENT_TAGS {
+        cleaned = element_re(tag).replace_all(&cleaned, " ").into_owned();
+    }
+    let region = ["article", "main", "body"]
+        .iter()
+        .find_map(|tag| largest_element_body(&cleaned, tag))
+        .unwrap_or(cleaned.as_str());
+
+    let blocks = block_boundary_re()
+        .split(region)
+        .filter_map(readable_block)
+        .collect::<Vec<_>>();
+    ReadablePage {
+        title,
+        text: blocks.join("\n\n"),
+    }
+}
+
+fn readable_block(fragment: &str) -> Option<String> {
+    let text = html_to_text(fragment);
+    let text_chars = text.chars().count();
+    if text_chars < MIN_BLOCK_CHARS {
+        return None;
+    }
+    let link_chars = anchor_re()
+        .captures_iter(fragment)
+        .filter_map(|capture| capture.get(1))
+        .map(|inner| html_to_text(inner.as_str()).chars().count())
+        .sum::<usize>();
+    if link_chars as f64 / text_chars as f64 > MAX_LINK_DENSITY {
+        return None;
+    }
+    let lowered = text.to_lowercase();
+    if text_chars < BOILERPLATE_BLOCK_CHARS
+        && BOILERPLATE_MARKERS
+            .iter()
+            .any(|marker| lowered.contains(marker))
+    {
+        return None;
+    }
+    Some(text)
+}
+
+fn largest_element_body<'a>(html: &'a str, tag: &str) -> Option<&'a str> {
+    element_inner_re(tag)
+        .captures_iter(html)
+        .filter_map(|capture| capture.get(1))
+        .map(|inner| (html_to_text(inner.as_str()).chars().count(), inner.as_str()))
+        .filter(|(text_chars, _)| *text_chars >= MIN_BLOCK_CHARS)
+        .max_by_key(|(text_chars, _)| *text_chars)
+        .map(|(_, inner)| inner)
+}
+
+fn extract_title(html: &str) -> Option<String> {
+    static OG_TITLE: OnceLock<Regex> = OnceLock::new();
+    let og_title = OG_TITLE.get_or_init(|| {
+        Regex::new(
+            r#"(?is)<meta[^>]+property\s*=\s*["']og:title["'][^>]*content\s*=\s*["']([^"']+)["']"#,
+        )
+        .expect("og:title regex must compile")
+    });
+    og_title
+        .captures(html)
+        .or_else(|| element_inner_re("title").captures(html))
+        .and_then(|capture| capture.get(1))
+        .map(|title| html_to_text(title.as_str()))
+        .filter(|title| !title.is_empty())
+}
+
+fn html_to_text(fragment: &str) -> String {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let tag = TAG.get_or_init(|| Regex::new(r"(?s)<[^>]*>").expect("tag regex must compile"));
+    let stripped = tag.replace_all(fragment, " ");
+    decode_html_entities(&stripped)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub(crate) fn decode_html_entities(input: &str) -> String {
+    static ENTITY: OnceLock<Regex> = OnceLock::new();
+    let entity = ENTITY.get_or_init(|| {
+        Regex::new(r"&(#[0-9]{1,7}|#[xX][0-9a-fA-F]{1,6}|[a-zA-Z]{2,8});")
+            .expect("entity regex must compile")
+    });
+    entity
+        .replace_all(input, |capture: &regex::Captures| {
+            let name = &capture[1];
+            let decoded =
+                if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                } else if let Some(decimal) = name.strip_prefix('#') {
+                    decimal.parse::<u32>().ok().and_then(char::from_u32)
+                } else {
+                    named_entity(name)
+                };
+            decoded
+                .map(String::from)
+                .unwrap_or_else(|| capture[0].to_string())
+        })
+        .into_owned()
+}
+
+fn named_entity(name: &str) -> Option<char> {
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "hellip" => '…',
+        "eacute" => 'é',
+        "euro" => '€',
+        "pound" => '£',
+        _ => return None,
+    })
+}
+
+fn charset_from_content_type(content_type: &str) -> Option<&'static Encoding> {
+    content_type
+        .split(';')
+        .filter_map(|part| part.trim().split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case("charset"))
+        .and_then(|(_, value)| Encoding::for_label(value.trim().trim_matches('"').as_bytes()))
+}
+
+fn charset_from_meta(bytes: &[u8]) -> Option<&'static Encoding> {
+    static META_CHARSET: OnceLock<Regex> = OnceLock::new();
+    let meta_charset = META_CHARSET.get_or_init(|| {
+        Regex::new(r#"(?i)<meta[^>]+charset\s*=\s*["']?([a-zA-Z0-9_\-:.]+)"#)
+            .expect("meta charset regex must compile")
+    });
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(4096)]);
+    meta_charset
+        .captures(&head)
+        .and_then(|capture| capture.get(1))
+        .and_then(|label| Encoding::for_label(label.as_str().as_bytes()))
+}
+
+fn comment_re() -> &'static Regex {
+    static COMMENT: OnceLock<Regex> = OnceLock::new();
+    COMMENT.get_or_init(|| Regex::new(r"(?s)<!--.*?-->").expect("comment regex must compile"))
+}
+
+fn anchor_re() -> &'static Regex {
+    static ANCHOR: OnceLock<Regex> = OnceLock::new();
+    ANCHOR.get_or_init(|| {
+        Regex::new(r"(?is)<a\b[^>]*>(.*?)</a\s*>").expect("anchor regex must compile")
+    })
+}
+
+fn block_boundary_re() -> &'static Regex {
+    static BLOCK: OnceLock<Regex> = OnceLock::new();
+    BLOCK.get_or_init(|| {
+        Regex::new(
+            r"(?i)</?(p|div|br|li|ul|ol|h[1-6]|section|article|main|blockquote|pre|table|tr|td|th|dl|dd|dt|figcaption|hr)\b[^>]*>",
+        )
+        .expect("block boundary regex must compile")
+    })
+}
+
+fn element_re(tag: &str) -> Regex {
+    Regex::new(&format!(
+        r"(?is)<{tag}\b[^>]*>.*?</{tag}\s*>|<{tag}\b[^>]*/>"
+    ))
+    .expect("element regex must compile")
+}
+
+fn element_inner_re(tag: &str) -> Regex {
+    Regex::new(&format!(r"(?is)<{tag}\b[^>]*>(.*?)</{tag}\s*>"))
+        .expect("element regex must compile")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_body, decode_html_entities, extract_readable_text};
+
+    #[test]
+    fn article_text_is_kept_and_chrome_is_dropped() {
+        let html = r#"<html><head><title>Ministry memo &amp; fallout</title>
+<script>var tracking = "ignore this paragraph of script text entirely";</script></head>
+<body><nav><a href="/">Home</a> <a href="/news">News</a> <a href="/sport">Sport and other sections</a></nav>
+<div class="promo"><a href="/a">Read this other story about something else entirely</a></div>
+<article><h1>Ministry memo</h1>
+<p>The transport ministry published a memo on Tuesday setting out new emissions targets for regional buses.</p>
+<p>Officials said the targets would be reviewed by the parliamentary committee in the spring session.</p>
+<p>Subscribe to our newsletter for more updates.</p>
+</article>
+<footer>All rights reserved. Copyright notice for the publisher goes here.</footer></body></html>"#;
+
+        let page = extract_readable_text(html);
+
+        assert_eq!(page.title.as_deref(), Some("Ministry memo & fallout"));
+        assert!(page
+            .text
+            .starts_with("The transport ministry published a memo"));
+        assert!(page.text.contains("parliamentary committee"));
+        assert!(!page.text.contains("tracking"));
+        assert!(!page.text.contains("Subscribe"));
+        assert!(!page.text.contains("other story"));
+        assert!(!page.text.contains("rights reserved"));
+    }
+
+    #[test]
+    fn link_heavy_blocks_are_dropped() {
+        let html = r#"<body><p><a href="/1">First related headline link</a> <a href="/2">Second related headline</a> and</p>
+<p>A plain paragraph of reporting that is long enough to count as body text.</p></body>"#;
+
+        let page = extract_readable_text(html);
+
+        assert_eq!(
+            page.text,
+            "A plain paragraph of reporting that is long enough to count as body text."
+        );
+    }
+
+    #[test]
+    fn body_charset_falls_back_to_meta_declaration() {
+        let mut bytes = b"<meta charset=\"iso-8859-1\"><p>Caf".to_vec();
+        bytes.push(0xe9);
+        bytes.extend_from_slice(b"</p>");
+
+        assert!(decode_body(&bytes, Some("text/html")).contains("Café"));
+        assert_eq!(
+            decode_body("Caf\u{e9}".as_bytes(), Some("text/html; charset=utf-8")),
+            "Café"
+        );
+    }
+
+    #[test]
+    fn numeric_and_named_entities_are_decoded() {
+        assert_eq!(
+            decode_html_entities("&#8220;Fees&#x201D; &amp; &rsquo;costs&unknown;"),
+            "“Fees” & ’costs&unknown;"
+        );
+    }
+}
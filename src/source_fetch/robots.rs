@@ -0,0 +1,170 @@
+/// The subset of robots.txt that matters for single-page fetches: `Allow`/`Disallow`
+/// rules from the group matching our user agent (or `*`), resolved by longest match.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RobotsRules {
+    rules: Vec<RobotsRule>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct RobotsRule {
+    allow: bool,
+    pattern: String,
+}
+
+impl RobotsRules {
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    pub fn disallow_all() -> Self {
+        Self {
+            rules: vec![RobotsRule {
+                allow: false,
+                pattern: "/".to_string(),
+            }],
+        }
+    }
+
+    pub fn parse(body: &str, user_agent: &str) -> Self {
+        let agent = user_agent
+            .split('/')
+            .next()
+            .unwrap_or(user_agent)
+            .to_ascii_lowercase();
+        let mut specific = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        let mut has_specific_group = false;
+
+        for line in body.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let field = field.trim().to_ascii_lowercase();
+            let value = value.trim();
+            match field.as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    let name = value.to_ascii_lowercase();
+                    if is_specific_match(&agent, &name) {
+                        has_specific_group = true;
+                    }
+                    group_agents.push(name);
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let rule = RobotsRule {
+                        allow: field == "allow",
+                        pattern: value.to_string(),
+                    };
+                    if group_agents
+                        .iter()
+                        .any(|name| is_specific_match(&agent, name))
+                    {
+                        specific.push(rule);
+                    } else if group_agents.iter().any(|name| name == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            rules: if has_specific_group {
+                specific
+            } else {
+                wildcard
+            },
+        }
+    }
+
+    pub fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| pattern_matches(&rule.pattern, path))
+            .max_by(|left, right| {
+                left.pattern
+                    .len()
+                    .cmp(&right.pattern.len())
+                    .then(left.allow.cmp(&right.allow))
+            })
+            .is_none_or(|rule| rule.allow)
+    }
+}
+
+fn is_specific_match(agent: &str, name: &str) -> bool {
+    !name.is_empty() && name != "*" && agent.contains(name)
+}
+
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(stripped) => (stripped, true),
+        None => (pattern, false),
+    };
+    let mut pieces = pattern.split('*');
+    let first = pieces.next().unwrap_or_default();
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let pieces = pieces.collect::<Vec<_>>();
+    for (index, piece) in pieces.iter().enumerate() {
+        let is_last = index + 1 == pieces.len();
+        if is_last && anchored {
+            return rest.ends_with(piece);
+        }
+        match rest.find(piece) {
+            Some(position) => rest = &rest[position + piece.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RobotsRules;
+
+    #[test]
+    fn wildcard_group_rules_apply_with_longest_match() {
+        let rules = RobotsRules::parse(
+            "User-agent: *\nDisallow: /private\nAllow: /private/press\n",
+            "WibbleResearch/1.0",
+        );
+
+        assert!(rules.allows("/news/story"));
+        assert!(!rules.allows("/private/memo"));
+        assert!(rules.allows("/private/press/release"));
+    }
+
+    #[test]
+    fn specific_group_replaces_wildcard_group() {
+        let rules = RobotsRules::parse(
+            "User-agent: *\nDisallow: /\n\nUser-agent: WibbleResearch\nDisallow: /drafts\n",
+            "WibbleResearch/1.0",
+        );
+
+        assert!(rules.allows("/news/story"));
+        assert!(!rules.allows("/drafts/1"));
+    }
+
+    #[test]
+    fn wildcards_and_end_anchors_match_paths() {
+        let rules = RobotsRules::parse(
+            "User-agent: *\nDisallow: /*.pdf$\nDisallow: /search*q=\n",
+            "WibbleResearch/1.0",
+        );
+
+        assert!(!rules.allows("/reports/annual.pdf"));
+        assert!(rules.allows("/reports/annual.pdf.html"));
+        assert!(!rules.allows("/search?q=ministry"));
+    }
+}
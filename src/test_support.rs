@@ -15,10 +15,17 @@ use tokio::sync::{Mutex as AsyncMutex, Semaphore};
 use crate::app_state::AppState;
 use crate::auth::{AuthUser, JwksClient};
 use crate::image_generator::replicate::ReplicateImageGenerator;
-use crate::image_generator::ImageGenerator;
-use crate::llm::prompt_registry::find_supported_translation_language;
+use crate::image_generator::{AsyncImageProvider, ImageGenerator};
+use crate::llm::domain_policy::reset_research_domain_policy;
+use crate::llm::glossary::reset_translation_glossary;
+use crate::llm::prompt_registry::{
+    find_supported_translation_language, reset_translation_languages,
+};
 use crate::llm::Llm;
 use crate::rate_limit::RateLimitState;
+use crate::search_provider::local_index::LocalIndexSearchProvider;
+use crate::search_provider::SearchProvider;
+use crate::sitemap::SitemapCache;
 
 fn test_env_lock() -> &'static Mutex<()> {
     static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
@@ -144,6 +151,7 @@ impl TestContext {
         let env_guard = test_env_lock()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
+        reset_admin_settings();
         let db = TestDatabase::create();
         set_test_env(&db.url);
         for (key, value) in overrides {
@@ -158,6 +166,20 @@ impl TestContext {
     }
 }
 
+impl Drop for TestContext {
+    // Runs before the env lock is released, so the next database test starts from the
+    // built-in languages and an empty glossary and domain policy.
+    fn drop(&mut self) {
+        reset_admin_settings();
+    }
+}
+
+fn reset_admin_settings() {
+    reset_translation_languages();
+    reset_translation_glossary();
+    reset_research_domain_policy();
+}
+
 pub async fn test_state_for(database_url: &str) -> AppState {
     let db = Database::connect(database_url)
         .await
@@ -172,7 +194,8 @@ pub async fn test_state_for(database_url: &str) -> AppState {
         llm: Llm::init(),
         image_generator: replicate.clone() as Arc<dyn ImageGenerator>,
         image_generator_name: "replicate".to_string(),
-        replicate_image_generator: Some(replicate),
+        image_provider: replicate as Arc<dyn AsyncImageProvider>,
+        search_provider: Arc::new(LocalIndexSearchProvider::default()) as Arc<dyn SearchProvider>,
         bust_dir: BustDir::new("static").expect("static bust dir should build"),
         rate_limit_state: RateLimitState::new(),
         template_auto_reload: true,
@@ -185,6 +208,7 @@ pub async fn test_state_for(database_url: &str) -> AppState {
         dead_link_recovery_max_per_day: 0,
         dead_link_recovery_timestamps: Arc::new(AsyncMutex::new(Vec::<Instant>::new())),
         jwks_client: JwksClient::new(),
+        sitemap_cache: SitemapCache::default(),
     }
 }
 
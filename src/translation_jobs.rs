@@ -25,14 +25,21 @@ use self::worker::process_translation_job_with_translator;
 
 #[cfg(test)]
 mod tests {
-    use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait};
+    use std::sync::Mutex;
 
-    use crate::entities::{content, prelude::TranslationJob, translation_job};
+    use sea_orm::sea_query::Expr;
+    use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter};
+
+    use crate::entities::{
+        content,
+        prelude::{Content, TranslationJob},
+        translation_job,
+    };
     use crate::error::Error;
     use crate::llm::prompt_registry::find_supported_translation_language;
     use crate::llm::translate::Translate;
     use crate::rate_limit::RequesterTier;
-    use crate::services::article_language::PreferredLanguageSource;
+    use crate::services::article_language::{article_source_language, PreferredLanguageSource};
     use crate::services::article_translations::{
         load_cached_article_translation, ArticleSourceText,
     };
@@ -43,7 +50,7 @@ mod tests {
         process_translation_job_with_translator, request_source_from_preferred_language,
         stale_translation_languages_for_refresh, translation_retry_delay,
         TranslationJobRequestSource, TRANSLATION_JOB_STATUS_COMPLETED,
-        TRANSLATION_JOB_STATUS_PROCESSING,
+        TRANSLATION_JOB_STATUS_FAILED, TRANSLATION_JOB_STATUS_PROCESSING,
     };
 
     #[derive(Default)]
@@ -53,9 +60,54 @@ mod tests {
         async fn translate(
             &self,
             text: &str,
-            target_language: crate::llm::prompt_registry::SupportedTranslationLanguage,
+            target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
+        ) -> Result<String, Error> {
+            Ok(text
+                .split("\n\n")
+                .map(|block| match block.strip_prefix("## ") {
+                    Some(heading) => format!("## [{}] {}", target_language.code, heading),
+                    None => format!("[{}] {}", target_language.code, block),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"))
+        }
+    }
+
+    /// Drops headings and answers like a chat assistant instead of translating.
+    struct LeakyTranslator;
+
+    impl Translate for LeakyTranslator {
+        async fn translate(
+            &self,
+            _text: &str,
+            _target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
+        ) -> Result<String, Error> {
+            Ok("As an AI language model I cannot translate this.".to_string())
+        }
+    }
+
+    /// Translates each block separately so chunks keep their structure, and records
+    /// what was sent.
+    #[derive(Default)]
+    struct RecordingTranslator {
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl Translate for RecordingTranslator {
+        async fn translate(
+            &self,
+            text: &str,
+            target_language: &crate::llm::prompt_registry::SupportedTranslationLanguage,
         ) -> Result<String, Error> {
-            Ok(format!("[{}] {}", target_language.code, text))
+            self.requests.lock().unwrap().push(text.to_string());
+            Ok(text
+                .split("\n\n")
+                .map(|block| match block.strip_prefix("## ") {
+                    Some(heading) => format!("## [{}] {}", target_language.code, heading),
+                    None => format!("[{}] {}", target_language.code, block),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"))
         }
     }
 
@@ -92,6 +144,7 @@ mod tests {
             longview_count: ActiveValue::set(0),
             impression_count: ActiveValue::set(0),
             click_count: ActiveValue::set(0),
+            language_id: ActiveValue::set(None),
             author_email: ActiveValue::set(None),
             published: ActiveValue::set(true),
             recovered_from_dead_link: ActiveValue::set(false),
@@ -101,9 +154,11 @@ mod tests {
     fn article_source<'a>(article_id: &'a str) -> ArticleSourceText<'a> {
         ArticleSourceText {
             article_id,
+            source_language: article_source_language(),
             title: "Research Bulletin",
             description: "Officials said the bulletin remained strictly procedural.",
             markdown: "## Committee Response\n\nThe standing committee accepted the memo without visible alarm.",
+            images: &[],
         }
     }
 
@@ -142,10 +197,14 @@ mod tests {
             find_supported_translation_language("fr").unwrap(),
         ];
 
-        let stale = stale_translation_languages_for_refresh(&languages);
+        let stale = stale_translation_languages_for_refresh(&languages, &languages[0]);
 
         assert_eq!(stale.len(), 2);
         assert!(stale.iter().all(|language| language.code != "en"));
+
+        let stale = stale_translation_languages_for_refresh(&languages, &languages[1]);
+
+        assert!(stale.iter().all(|language| language.code != "pt"));
     }
 
     #[tokio::test]
@@ -158,7 +217,7 @@ mod tests {
         persist_translation_job_request(
             &ctx.state,
             "story-1",
-            preferred_language("pt"),
+            &preferred_language("pt"),
             TranslationJobRequestSource::Explicit,
             RequesterTier::Authenticated,
             "user:author@example.com",
@@ -173,8 +232,8 @@ mod tests {
 
         let translation = load_cached_article_translation(
             &ctx.state.db,
-            article_source("story-1"),
-            preferred_language("pt"),
+            &article_source("story-1"),
+            &preferred_language("pt"),
         )
         .await
         .unwrap()
@@ -188,6 +247,49 @@ mod tests {
         assert_eq!(job.status, TRANSLATION_JOB_STATUS_COMPLETED);
     }
 
+    #[tokio::test]
+    async fn rejected_translation_is_not_cached_and_retries_with_backoff() {
+        let ctx = TestContext::new().await;
+        sample_article("story-9", "rejected-bulletin")
+            .insert(&ctx.state.db)
+            .await
+            .unwrap();
+        persist_translation_job_request(
+            &ctx.state,
+            "story-9",
+            &preferred_language("pt"),
+            TranslationJobRequestSource::Explicit,
+            RequesterTier::Authenticated,
+            "user:author@example.com",
+            false,
+        )
+        .await
+        .unwrap();
+
+        let err =
+            process_translation_job_with_translator(&ctx.state, &LeakyTranslator, "story-9:pt")
+                .await
+                .unwrap_err();
+
+        let job = load_translation_job(&ctx.state.db, "story-9:pt")
+            .await
+            .unwrap()
+            .expect("translation job should exist");
+        assert!(err.to_string().contains("failed quality checks"));
+        assert_eq!(job.status, TRANSLATION_JOB_STATUS_FAILED);
+        assert_eq!(job.fail_count, 1);
+        assert_eq!(job.last_error.as_deref(), Some(err.to_string().as_str()));
+        assert!(job.next_retry_at.is_some());
+        assert!(load_cached_article_translation(
+            &ctx.state.db,
+            &article_source("story-9"),
+            &preferred_language("pt"),
+        )
+        .await
+        .unwrap()
+        .is_none());
+    }
+
     #[tokio::test]
     async fn processing_translation_job_is_resumed_after_restart() {
         let ctx = TestContext::new().await;
@@ -198,7 +300,7 @@ mod tests {
         persist_translation_job_request(
             &ctx.state,
             "story-2",
-            preferred_language("fr"),
+            &preferred_language("fr"),
             TranslationJobRequestSource::Explicit,
             RequesterTier::Authenticated,
             "user:author@example.com",
@@ -226,8 +328,8 @@ mod tests {
 
         let translation = load_cached_article_translation(
             &restarted_state.db,
-            article_source("story-2"),
-            preferred_language("fr"),
+            &article_source("story-2"),
+            &preferred_language("fr"),
         )
         .await
         .unwrap()
@@ -238,7 +340,84 @@ mod tests {
             .unwrap()
             .expect("translation job should remain persisted");
 
-        assert!(translation.markdown.starts_with("[fr]"));
+        assert!(translation.markdown.contains("[fr]"));
         assert_eq!(resumed_job.status, TRANSLATION_JOB_STATUS_COMPLETED);
     }
+
+    #[tokio::test]
+    async fn retranslation_after_edit_only_sends_changed_paragraphs() {
+        let ctx = TestContext::new().await;
+        let mut article = sample_article("story-3", "memory-bulletin");
+        article.markdown = ActiveValue::set(Some(
+            "## Committee Response\n\nFirst paragraph.\n\nSecond paragraph.\n\nThird paragraph."
+                .to_string(),
+        ));
+        article.insert(&ctx.state.db).await.unwrap();
+        let portuguese = preferred_language("pt");
+        let queue_job = || {
+            persist_translation_job_request(
+                &ctx.state,
+                "story-3",
+                &portuguese,
+                TranslationJobRequestSource::Explicit,
+                RequesterTier::Authenticated,
+                "user:author@example.com",
+                false,
+            )
+        };
+        let translator = RecordingTranslator::default();
+        queue_job().await.unwrap();
+        process_translation_job_with_translator(&ctx.state, &translator, "story-3:pt")
+            .await
+            .unwrap();
+        let first_job = load_translation_job(&ctx.state.db, "story-3:pt")
+            .await
+            .unwrap()
+            .expect("translation job should exist");
+        assert_eq!(first_job.segments_total, Some(4));
+        assert_eq!(first_job.segments_reused, Some(0));
+
+        Content::update_many()
+            .col_expr(
+                content::Column::Markdown,
+                Expr::value("## Committee Response\n\nFirst paragraph.\n\nSecond paragraph.\n\nThird paragraph, revised."),
+            )
+            .filter(content::Column::Id.eq("story-3"))
+            .exec(&ctx.state.db)
+            .await
+            .unwrap();
+        translator.requests.lock().unwrap().clear();
+        queue_job().await.unwrap();
+        process_translation_job_with_translator(&ctx.state, &translator, "story-3:pt")
+            .await
+            .unwrap();
+
+        let job = load_translation_job(&ctx.state.db, "story-3:pt")
+            .await
+            .unwrap()
+            .expect("translation job should exist");
+        let translation = load_cached_article_translation(
+            &ctx.state.db,
+            &ArticleSourceText {
+                markdown: "## Committee Response\n\nFirst paragraph.\n\nSecond paragraph.\n\nThird paragraph, revised.",
+                ..article_source("story-3")
+            },
+            &preferred_language("pt"),
+        )
+        .await
+        .unwrap()
+        .expect("edited translation should be cached");
+
+        assert_eq!(
+            *translator.requests.lock().unwrap(),
+            vec!["Third paragraph, revised.".to_string()]
+        );
+        assert_eq!(job.status, TRANSLATION_JOB_STATUS_COMPLETED);
+        assert_eq!(job.segments_total, Some(4));
+        assert_eq!(job.segments_reused, Some(3));
+        assert_eq!(
+            translation.markdown,
+            "## [pt] Committee Response\n\n[pt] First paragraph.\n\n[pt] Second paragraph.\n\n[pt] Third paragraph, revised."
+        );
+    }
 }
@@ -57,7 +57,7 @@ pub(super) fn request_priority(
 
 pub(super) fn article_translation_job_id(
     article_id: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
 ) -> String {
     crate::services::article_translations::article_translation_job_key(article_id, language)
 }
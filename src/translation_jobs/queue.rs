@@ -9,7 +9,6 @@ use crate::entities::{prelude::*, translation_job};
 use crate::error::Error;
 use crate::llm::prompt_registry::SupportedTranslationLanguage;
 use crate::rate_limit::{RequesterTier, TranslationRateLimit};
-use crate::services::article_language::article_source_language;
 
 use super::definitions::{
     article_translation_job_id, request_priority, TranslationJobRequestSource,
@@ -31,7 +30,7 @@ pub(super) async fn load_translation_job(
 pub(super) async fn persist_translation_job_request(
     state: &AppState,
     article_id: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     request_source: TranslationJobRequestSource,
     requester_tier: RequesterTier,
     rate_limit_key: &str,
@@ -56,7 +55,7 @@ pub(super) async fn persist_translation_job_request(
                 event!(
                     Level::WARN,
                     article_id,
-                    language = language.code,
+                    language = %language.code,
                     limit = limit_name,
                     tier = ?requester_tier,
                     "Rejected translation creation due to translation rate limit"
@@ -85,6 +84,8 @@ pub(super) async fn persist_translation_job_request(
                 active.started_at = ActiveValue::set(None);
                 active.finished_at = ActiveValue::set(None);
                 active.next_retry_at = ActiveValue::set(None);
+                active.segments_total = ActiveValue::set(None);
+                active.segments_reused = ActiveValue::set(None);
                 if previous_status != TRANSLATION_JOB_STATUS_FAILED {
                     active.fail_count = ActiveValue::set(0);
                 }
@@ -109,6 +110,8 @@ pub(super) async fn persist_translation_job_request(
                 started_at: ActiveValue::set(None),
                 finished_at: ActiveValue::set(None),
                 next_retry_at: ActiveValue::set(None),
+                segments_total: ActiveValue::set(None),
+                segments_reused: ActiveValue::set(None),
             })
             .exec(&state.db)
             .await
@@ -162,7 +165,7 @@ pub(super) async fn delete_translation_job(state: &AppState, job_id: &str) -> Re
 pub(super) async fn queue_translation_refresh(
     state: &AppState,
     article_id: &str,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     requester_tier: RequesterTier,
     rate_limit_key: &str,
 ) -> Result<(), Error> {
@@ -180,10 +183,11 @@ pub(super) async fn queue_translation_refresh(
 
 pub(super) fn stale_translation_languages_for_refresh(
     cached_languages: &[SupportedTranslationLanguage],
+    source_language: &SupportedTranslationLanguage,
 ) -> Vec<SupportedTranslationLanguage> {
     cached_languages
         .iter()
-        .copied()
-        .filter(|language| language.code != article_source_language().code)
+        .filter(|language| language.code != source_language.code)
+        .cloned()
         .collect()
 }
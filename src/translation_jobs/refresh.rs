@@ -4,8 +4,11 @@ use crate::auth::AuthUser;
 use crate::error::Error;
 use crate::rate_limit::RequesterTier;
 use crate::services::article_translations::{
-    cached_translation_languages, invalidate_cached_article_translations, OwnedArticleSourceText,
+    cached_translation_languages, invalidate_cached_article_translations,
+    load_cached_article_translation, OwnedArticleSourceText,
 };
+use crate::services::translation_memory::remember_translated_segments;
+use crate::services::translation_review::mark_stale_translation_reviews;
 
 use super::queue::{queue_translation_refresh, stale_translation_languages_for_refresh};
 use super::worker::spawn_due_translation_jobs;
@@ -21,25 +24,58 @@ pub async fn refresh_article_translations_after_edit(
         return Ok(());
     }
 
+    let stale_reviews = mark_stale_translation_reviews(&state.db, &current_source.as_ref()).await?;
+    if stale_reviews > 0 {
+        log_audit(
+            &state.db,
+            auth_user,
+            "mark_translation_reviews_stale",
+            "content",
+            slug,
+            Some(serde_json::json!({ "overrides": stale_reviews }).to_string()),
+        )
+        .await?;
+    }
+
     let cached_languages =
-        cached_translation_languages(&state.db, previous_source.as_ref()).await?;
+        cached_translation_languages(&state.db, &previous_source.as_ref()).await?;
     if cached_languages.is_empty() {
         return Ok(());
     }
 
-    let stale_languages = stale_translation_languages_for_refresh(&cached_languages);
+    let stale_languages = stale_translation_languages_for_refresh(
+        &cached_languages,
+        &previous_source.source_language,
+    );
     if stale_languages.is_empty() {
         return Ok(());
     }
 
+    // Keep the outgoing translations as segment memory so the refresh only sends
+    // the edited blocks to the LLM.
+    let mut remembered_segments = 0;
+    for language in &stale_languages {
+        if let Some(translation) =
+            load_cached_article_translation(&state.db, &previous_source.as_ref(), language).await?
+        {
+            remembered_segments += remember_translated_segments(
+                &state.db,
+                &previous_source.markdown,
+                &translation.markdown,
+                language,
+            )
+            .await?;
+        }
+    }
     let removed_rows =
-        invalidate_cached_article_translations(&state.db, previous_source.as_ref()).await?;
+        invalidate_cached_article_translations(&state.db, &previous_source.as_ref()).await?;
     let details = serde_json::json!({
         "languages": stale_languages
             .iter()
-            .map(|language| language.code)
+            .map(|language| language.code.as_ref())
             .collect::<Vec<_>>(),
         "removed_rows": removed_rows,
+        "remembered_segments": remembered_segments,
     })
     .to_string();
     log_audit(
@@ -62,7 +98,7 @@ pub async fn refresh_article_translations_after_edit(
         queue_translation_refresh(
             &state,
             &current_source.article_id,
-            language,
+            &language,
             requester_tier,
             &rate_limit_key,
         )
@@ -13,9 +13,11 @@ use crate::llm::prompt_registry::{
 };
 use crate::llm::translate::Translate;
 use crate::rate_limit::RequesterTier;
+use crate::repositories::images::find_article_images;
 use crate::services::article_translations::{
     ensure_cached_article_translation, load_cached_article_translation, owned_article_source_text,
 };
+use crate::services::translation_memory::SegmentReuse;
 
 use super::definitions::{
     TranslationJobRequestSource, TRANSLATION_JOB_STATUS_CANCELLED,
@@ -66,6 +68,7 @@ async fn mark_completed(
     state: &AppState,
     job: translation_job::Model,
     outcome: &str,
+    segments: Option<SegmentReuse>,
 ) -> Result<(), Error> {
     let Some(current) = load_translation_job(&state.db, &job.id).await? else {
         return Ok(());
@@ -81,6 +84,8 @@ async fn mark_completed(
     active.finished_at = ActiveValue::set(Some(reference_time));
     active.next_retry_at = ActiveValue::set(None);
     active.updated_at = ActiveValue::set(reference_time);
+    active.segments_total = ActiveValue::set(segments.map(|segments| segments.total as i32));
+    active.segments_reused = ActiveValue::set(segments.map(|segments| segments.reused as i32));
     active.update(&state.db).await.map_err(|e| {
         Error::Database(format!("Error marking translation job as completed: {}", e))
     })?;
@@ -90,6 +95,7 @@ async fn mark_completed(
         "language": job.language_code,
         "request_source": job.request_source,
         "outcome": outcome,
+        "segments": segments,
     })
     .to_string();
     log_system_audit(
@@ -229,7 +235,8 @@ pub(super) async fn process_translation_job_with_translator<T: Translate>(
         return Err(err);
     }
 
-    let Some(source) = owned_article_source_text(&article) else {
+    let images = find_article_images(&state.db, &article.id).await?;
+    let Some(source) = owned_article_source_text(&article, &images) else {
         let err = Error::NotFound(Some(format!(
             "Article {} has no markdown available for translation",
             article.id
@@ -238,16 +245,29 @@ pub(super) async fn process_translation_job_with_translator<T: Translate>(
         return Err(err);
     };
     let source_ref = source.as_ref();
-    if load_cached_article_translation(&state.db, source_ref, language)
+    if load_cached_article_translation(&state.db, &source_ref, &language)
         .await?
-        .is_some()
+        .is_some_and(|translation| !translation.lacks_image_text(&source_ref))
     {
-        mark_completed(state, job, "cached").await?;
+        mark_completed(state, job, "cached", None).await?;
         return Ok(());
     }
 
-    ensure_cached_article_translation(translator, &state.db, source_ref, language).await?;
-    mark_completed(state, job, "generated").await?;
+    let translation = match ensure_cached_article_translation(
+        translator,
+        &state.db,
+        &source_ref,
+        &language,
+    )
+    .await
+    {
+        Ok(translation) => translation,
+        Err(err) => {
+            mark_failed(state, job, &err).await?;
+            return Err(err);
+        }
+    };
+    mark_completed(state, job, "generated", translation.markdown_segments).await?;
     Ok(())
 }
 
@@ -305,7 +325,7 @@ pub(super) async fn spawn_due_translation_jobs(state: AppState) {
 pub async fn request_article_translation(
     state: AppState,
     article_id: String,
-    language: SupportedTranslationLanguage,
+    language: &SupportedTranslationLanguage,
     request_source: TranslationJobRequestSource,
     requester_tier: RequesterTier,
     rate_limit_key: String,
@@ -324,7 +344,7 @@ pub async fn request_article_translation(
         event!(
             Level::WARN,
             article_id,
-            language = language.code,
+            language = %language.code,
             error = %err,
             "Failed to queue article translation"
         );
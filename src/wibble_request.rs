@@ -1,10 +1,11 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::env;
 use std::error::Error as StdError;
 use std::sync::Arc;
 use std::sync::RwLock;
 
-use axum::extract::{FromRef, FromRequestParts, Query};
+use axum::extract::{FromRef, FromRequestParts, OriginalUri, Query};
 use axum::response::Html;
 use http::header::{ACCEPT_LANGUAGE, USER_AGENT};
 use http::request::Parts;
@@ -24,7 +25,9 @@ use crate::services::site_paths::{
     detect_site_language_from_path, locale_prefix, localized_path, localized_root_path,
     saved_site_language_from_headers, supported_site_languages,
 };
-use crate::services::site_text::{resolve_site_language, site_text, SiteText};
+use crate::services::site_text::{
+    default_site_language, resolve_site_language, site_text, SiteText,
+};
 
 const ARTICLE_LANGUAGE_COOKIE_NAME: &str = "__article_lang";
 
@@ -44,6 +47,13 @@ where
     pub saved_article_language: Option<SupportedTranslationLanguage>,
 }
 
+/// One `<link rel="alternate" hreflang>` entry rendered by `base.html`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct LanguageAlternate {
+    pub code: Cow<'static, str>,
+    pub href: String,
+}
+
 pub struct Template {
     tera: Arc<RwLock<Tera>>,
     name: String,
@@ -103,24 +113,21 @@ impl WibbleRequest {
             .map(|h| format!("{}?{}", style, h))
             .unwrap_or(style);
         let site_url = Self::get_site_url();
-        let canonical_url = format!("{}{}", site_url, self.request_path);
-        let ui = site_text(self.site_language).template_strings();
+        let canonical_url = Self::localized_url(&self.site_language, &self.request_path);
+        let ui = site_text(&self.site_language).template_strings();
         let locale_prefix = self.locale_prefix();
         let locale_home_url = self.localized_root_path();
-        let alternate_locale_urls: Vec<_> = supported_site_languages()
-            .into_iter()
-            .map(|language| {
-                serde_json::json!({
-                    "code": language.code,
-                    "href": format!("{}{}", site_url, localized_path(language, &self.request_path)),
-                })
-            })
-            .collect();
+        let alternate_locale_urls = Self::language_alternates(
+            &self.request_path,
+            &supported_site_languages(),
+            &default_site_language(),
+        );
         context.insert("style", &busted_style);
         context.insert("site_url", &site_url);
         context.insert("canonical_url", &canonical_url);
-        context.insert("page_language_code", self.site_language.code);
-        context.insert("page_language_name", self.site_language.name);
+        context.insert("page_language_code", &self.site_language.code);
+        context.insert("page_direction", self.site_language.direction.as_str());
+        context.insert("page_language_name", &self.site_language.name);
         context.insert("locale_prefix", &locale_prefix);
         context.insert("locale_home_url", &locale_home_url);
         context.insert("alternate_locale_urls", &alternate_locale_urls);
@@ -128,9 +135,9 @@ impl WibbleRequest {
         if let Some(text_create_new_article) = ui["base"]["create_article"].as_str() {
             context.insert("text_create_new_article", text_create_new_article);
         }
-        if let Some(language) = self.browser_translation_language {
-            context.insert("browser_translation_language_code", language.code);
-            context.insert("browser_translation_language_name", language.name);
+        if let Some(language) = &self.browser_translation_language {
+            context.insert("browser_translation_language_code", &language.code);
+            context.insert("browser_translation_language_name", &language.name);
         }
         if let Some(ref user) = self.auth_user {
             context.insert("auth_user", user);
@@ -144,20 +151,45 @@ impl WibbleRequest {
         }
     }
 
+    /// Absolute URL of `path` under the route prefix of `language`.
+    pub fn localized_url(language: &SupportedTranslationLanguage, path: &str) -> String {
+        format!("{}{}", Self::get_site_url(), localized_path(language, path))
+    }
+
+    /// `hreflang` alternates for `path` in each of `languages`, followed by an
+    /// `x-default` entry for `default_language`.
+    pub fn language_alternates(
+        path: &str,
+        languages: &[SupportedTranslationLanguage],
+        default_language: &SupportedTranslationLanguage,
+    ) -> Vec<LanguageAlternate> {
+        languages
+            .iter()
+            .map(|language| LanguageAlternate {
+                code: language.code.clone(),
+                href: Self::localized_url(language, path),
+            })
+            .chain(std::iter::once(LanguageAlternate {
+                code: Cow::Borrowed("x-default"),
+                href: Self::localized_url(default_language, path),
+            }))
+            .collect()
+    }
+
     pub fn site_text(&self) -> SiteText {
-        site_text(self.site_language)
+        site_text(&self.site_language)
     }
 
     pub fn locale_prefix(&self) -> String {
-        locale_prefix(self.site_language)
+        locale_prefix(&self.site_language)
     }
 
     pub fn localized_root_path(&self) -> String {
-        localized_root_path(self.site_language)
+        localized_root_path(&self.site_language)
     }
 
     pub fn localized_path(&self, path: &str) -> String {
-        localized_path(self.site_language, path)
+        localized_path(&self.site_language, path)
     }
 
     pub fn localized_request_path(&self) -> String {
@@ -248,9 +280,14 @@ where
         let state = AppState::from_ref(state);
         let browser_translation_language =
             WibbleRequest::browser_translation_language_from_headers(&parts.headers);
-        let site_language = detect_site_language_from_path(&request_path)
+        // The locale dispatcher in `server` strips the `/{code}` prefix from `parts.uri`.
+        let site_language = parts
+            .extensions
+            .get::<OriginalUri>()
+            .and_then(|uri| detect_site_language_from_path(uri.path()))
+            .or_else(|| detect_site_language_from_path(&request_path))
             .or_else(|| saved_site_language_from_headers(&parts.headers))
-            .unwrap_or_else(|| resolve_site_language(browser_translation_language));
+            .unwrap_or_else(|| resolve_site_language(browser_translation_language.as_ref()));
         let saved_article_language =
             WibbleRequest::saved_article_language_from_headers(&parts.headers);
         let auth_user = if let Some(token) = extract_auth_token(parts) {
@@ -284,8 +321,29 @@ mod tests {
         HeaderMap, HeaderValue,
     };
 
+    use crate::test_support::preferred_language;
+
     use super::WibbleRequest;
 
+    #[test]
+    fn language_alternates_list_each_language_and_an_x_default() {
+        let alternates = WibbleRequest::language_alternates(
+            "/content/story",
+            &[preferred_language("pt"), preferred_language("fr")],
+            &preferred_language("pt"),
+        );
+
+        assert_eq!(
+            alternates
+                .iter()
+                .map(|alternate| alternate.code.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["pt", "fr", "x-default"]
+        );
+        assert!(alternates[1].href.ends_with("/fr/content/story"));
+        assert!(alternates[2].href.ends_with("/pt/content/story"));
+    }
+
     #[test]
     fn browser_translation_language_from_headers_reads_supported_language() {
         let mut headers = HeaderMap::new();
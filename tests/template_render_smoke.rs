@@ -26,7 +26,7 @@ fn render_context(extra: Value) -> Context {
     );
     object.insert(
         "ui".to_string(),
-        site_text(find_supported_translation_language("en").unwrap()).template_strings(),
+        site_text(&find_supported_translation_language("en").unwrap()).template_strings(),
     );
     object.insert(
         "text_create_new_article".to_string(),
@@ -61,6 +61,11 @@ fn create_template_renders_research_mode_form() {
             ],
             "logged_in": true,
             "selected_create_mode": "research",
+            "article_languages": [
+                {"code": "en", "name": "English"},
+                {"code": "pt", "name": "Portuguese"}
+            ],
+            "selected_article_language": "pt",
             "standard_quota": {"hourly": 20, "daily": 40},
             "research_quota": {"hourly": 5, "daily": 10},
             "authenticated_standard_quota": {"hourly": 20, "daily": 40},
@@ -78,6 +83,7 @@ fn create_template_renders_research_mode_form() {
     assert!(html.contains("Desk mode"));
     assert!(html.contains("Research desk"));
     assert!(html.contains("mode-research"));
+    assert!(html.contains(r#"<option value="pt" selected>Portuguese</option>"#));
     assert!(html.contains("checked"));
 }
 
@@ -103,6 +109,18 @@ fn wait_template_renders_clarification_state() {
                 "image_processing": 0,
                 "image_failed": 0,
                 "clarification_question": "Which ministry issued the notice?",
+                "clarification_questions": [
+                    {
+                        "field": "answer",
+                        "question": "Which ministry issued the notice?",
+                        "suggested_answers": ["Transport ministry"]
+                    },
+                    {
+                        "field": "answer_2",
+                        "question": "What did it announce?",
+                        "suggested_answers": []
+                    }
+                ],
                 "clarification_deadline": "2026-04-20 12:00",
                 "clarification_deadline_note": "If nobody answers by 2026-04-20 12:00, the job resumes with a conservative fallback.",
                 "phase_items": [
@@ -117,6 +135,55 @@ fn wait_template_renders_clarification_state() {
     assert!(html.contains("Clarification needed"));
     assert!(html.contains("Resume drafting"));
     assert!(html.contains("Which ministry issued the notice?"));
+    assert!(html.contains(r#"name="answer_2""#));
+    assert!(html.contains(r#"<option value="Transport ministry">"#));
+}
+
+#[test]
+fn wait_template_renders_outline_review_form() {
+    let html = render(
+        "wait.html",
+        json!({
+            "title": "Wait",
+            "description": "Wait flow",
+            "robots": "noindex,nofollow",
+            "id": "job-1",
+            "wait_auto_refresh": false,
+            "wait_summary": {
+                "article_title": "Incident bulletin",
+                "slug": "incident-bulletin",
+                "stage_title": "Outline ready",
+                "stage_description": "Pick a headline before drafting continues.",
+                "publication_title": "Destination: draft",
+                "publication_note": "Signed-in articles stay private until review.",
+                "image_total": 0,
+                "image_completed": 0,
+                "image_processing": 0,
+                "image_failed": 0,
+                "clarification_question": null,
+                "clarification_questions": [],
+                "outline": {
+                    "headline_options": ["Buses Asked To Reflect", "Ministry Schedules Bus Feelings"],
+                    "angle": "Bus morale is treated as routine compliance.",
+                    "beats": "The memo lands\nUnion response",
+                    "image_slots": ["Minister at a lectern"],
+                    "can_review": true,
+                    "deadline_note": "If nobody answers by 2026-04-20 12:00, drafting continues with this outline."
+                },
+                "phase_items": [
+                    {"label": "Queued", "state": "done"},
+                    {"label": "Outline", "state": "active"},
+                    {"label": "Write", "state": "pending"}
+                ]
+            }
+        }),
+    );
+
+    assert!(html.contains(r#"/wait/job-1/outline""#));
+    assert!(html.contains(r#"value="Buses Asked To Reflect" checked"#));
+    assert!(html.contains("The memo lands\nUnion response</textarea>"));
+    assert!(html.contains("Minister at a lectern"));
+    assert!(!html.contains("/wait/job-1/clarify"));
 }
 
 #[test]
@@ -149,71 +216,158 @@ fn edit_preview_template_renders_diff_and_apply_form() {
 }
 
 #[test]
-fn content_template_renders_research_and_language_metadata() {
+fn translation_review_template_renders_side_by_side_fields() {
     let html = render(
-        "content.html",
+        "translation_review.html",
         json!({
-            "title": "Filed report",
-            "description": "Desc",
+            "title": "Review",
+            "robots": "noindex,nofollow",
             "slug": "story-slug",
-            "id": "story-id",
-            "created_at": "2026-04-19",
-            "image_id": "img-1",
-            "body": "<p>Body</p>",
-            "page_language_code": "en",
-            "page_language_name": "English",
-            "article_source_language_code": "en",
-            "article_source_language_name": "English",
-            "preferred_article_language_code": "en",
-            "preferred_article_language_name": "English",
-            "preferred_article_language_source": "source",
-            "served_article_language_source": "preferred",
-            "article_translation_requested": false,
-            "article_translation_available": true,
-            "article_language_options": [
+            "language_code": "ar",
+            "language_name": "Arabic",
+            "language_direction": "rtl",
+            "fields": [
+                {
+                    "name": "title",
+                    "source": "Research Bulletin",
+                    "translation": "نشرة بحثية",
+                    "machine_translated": true,
+                    "reviewed": true,
+                    "reviewed_by": "author@example.com",
+                    "stale_translation": null
+                },
                 {
-                    "href": "/en/content/story-slug?lang=auto",
-                    "label": "Automatic",
-                    "note": "Original edition: English",
-                    "active": true
+                    "name": "markdown",
+                    "source": "Body",
+                    "translation": "",
+                    "machine_translated": false,
+                    "reviewed": false,
+                    "reviewed_by": null,
+                    "stale_translation": "نص قديم"
                 }
-            ],
-            "article_language_menu_open": false,
-            "article_language_summary_note": "Original edition",
-            "article_language_notice": "Portuguese was requested. This page is currently showing the original English edition while that translation is prepared.",
-            "article_research_metadata_present": true,
-            "article_research_metadata": {
-                "mode_label": "Requested research desk",
-                "source_count": 3
-            },
-            "article_research_note": "Requested research desk. This filing was grounded against 3 public-source briefs before drafting. The source trace is kept off-page so the article body stays deadpan.",
-            "can_edit": true,
-            "can_publish": true,
-            "is_published": false,
-            "vote_score": 7,
-            "voting_open": true,
-            "can_vote": true,
-            "user_vote": "up",
-            "comments": [],
-            "comment_count": 0,
-            "comment_count_label": "0 comments",
-            "comments_open": true,
-            "can_comment": true,
-            "comment_pager": {
-                "total_pages": 1,
-                "current_page": 1,
-                "has_prev": false,
-                "has_next": false,
-                "prev_page": 1,
-                "next_page": 1
-            },
-            "comment_page_label": "Page 1 / 1"
+            ]
         }),
     );
 
+    assert!(html.contains("Review translation: Arabic"));
+    assert!(html.contains("Reviewed by author@example.com"));
+    assert!(html.contains("The source changed since this field was reviewed."));
+    assert!(html.contains(r#"name="markdown" rows="20" dir="rtl""#));
+    assert!(html.contains(r#"name="original_title" value="نشرة بحثية""#));
+}
+
+fn content_fixture() -> Value {
+    json!({
+        "title": "Filed report",
+        "description": "Desc",
+        "slug": "story-slug",
+        "id": "story-id",
+        "created_at": "2026-04-19",
+        "image_id": "img-1",
+        "body": "<p>Body</p>",
+        "page_language_code": "en",
+        "page_language_name": "English",
+        "article_language_code": "en",
+        "article_direction": "ltr",
+        "article_source_language_code": "en",
+        "article_source_language_name": "English",
+        "preferred_article_language_code": "en",
+        "preferred_article_language_name": "English",
+        "preferred_article_language_source": "source",
+        "served_article_language_source": "preferred",
+        "article_translation_requested": false,
+        "article_translation_available": true,
+        "article_language_options": [
+            {
+                "href": "/en/content/story-slug?lang=auto",
+                "label": "Automatic",
+                "note": "Original edition: English",
+                "active": true
+            }
+        ],
+        "article_language_menu_open": false,
+        "article_language_summary_note": "Original edition",
+        "article_language_notice": "Portuguese was requested. This page is currently showing the original English edition while that translation is prepared.",
+        "article_research_metadata_present": true,
+        "article_research_metadata": {
+            "mode_label": "Requested research desk",
+            "source_count": 3
+        },
+        "article_research_note": "Requested research desk. This filing was grounded against 3 public-source briefs before drafting. The source trace is kept off-page so the article body stays deadpan.",
+        "can_edit": true,
+        "can_publish": true,
+        "is_published": false,
+        "vote_score": 7,
+        "voting_open": true,
+        "can_vote": true,
+        "user_vote": "up",
+        "comments": [
+            {
+                "id": "comment-1",
+                "user_name": "Reader",
+                "body": "Que notícia estranha.",
+                "created_at": "2026-04-29 09:00",
+                "translatable": true,
+                "translated_body": "What strange news.",
+                "translated_note": "Translated from Portuguese"
+            },
+            {
+                "id": "comment-2",
+                "user_name": "Other Reader",
+                "body": "Qué noticia tan rara.",
+                "created_at": "2026-04-29 09:05",
+                "translatable": true,
+                "translated_body": null,
+                "translated_note": null
+            }
+        ],
+        "comment_count": 2,
+        "comment_count_label": "2 comments",
+        "comments_open": true,
+        "can_comment": true,
+        "comment_pager": {
+            "total_pages": 1,
+            "current_page": 1,
+            "has_prev": false,
+            "has_next": false,
+            "prev_page": 1,
+            "next_page": 1
+        },
+        "comment_page_label": "Page 1 / 1",
+        "comments_translatable": true,
+        "comments_translated_all": false,
+        "comment_translation_limited": false,
+        "comment_translation_deferred": false
+    })
+}
+
+#[test]
+fn content_template_renders_research_and_language_metadata() {
+    let html = render("content.html", content_fixture());
+
+    assert!(html.contains("What strange news."));
+    assert!(html.contains("Translated from Portuguese"));
+    assert!(html.contains("translate_comments=comment-2"));
+    assert!(html.contains("translate_comments=all"));
+
     assert!(html.contains("Requested research desk"));
     assert!(html.contains("Edition Desk"));
     assert!(html.contains("Comments"));
     assert!(html.contains("&#x2F;en/content/story-slug/edit"));
     assert!(html.contains("Edit article"));
 }
+
+#[test]
+fn content_template_keeps_translated_comment_pages_out_of_crawls() {
+    let mut context = content_fixture();
+    context["comments_translated_all"] = json!(true);
+    context["comment_translation_deferred"] = json!(true);
+    context["comment_pager"]["total_pages"] = json!(2);
+    context["comment_pager"]["has_next"] = json!(true);
+    context["comment_pager"]["next_page"] = json!(2);
+    let html = render("content.html", context);
+
+    assert!(html.contains(r#"btn-sm" rel="nofollow">Older comments</a>"#));
+    assert!(html.contains("Some comments are still shown as written."));
+    assert!(html.contains(r#"rel="nofollow">Translate more</a>"#));
+}